@@ -0,0 +1,102 @@
+#!/usr/bin/env python3
+"""
+Benchmark harness for the polars-vec-ops vertical mean (vec.mean).
+
+Generates a fixed List[Float64] column, reduces it across rows with vec.mean and
+with a NumPy reference (mean over axis 0 of the equivalent 2D array), checks that
+the two agree, and prints the timings in a machine-parseable format. This runs
+once on a dense column and once with a fraction of the elements null, since the
+kernel takes a different loop for each; benches/list_mean.rs times the same two
+paths from Rust with criterion.
+
+Metrics printed per case (stdout, after the '---' marker, prefixed with the case
+name, e.g. dense_wall_time_ms):
+    input_elements:   total scalar elements in the input column (n_rows * width)
+    wall_time_ms:     wall-clock time of vec.mean over the full column (ms)
+    numpy_time_ms:    wall-clock time of numpy.mean(axis=0) on the same data (ms)
+    elements_per_s:   input elements reduced per second by vec.mean
+
+The NumPy conversion is done before timing, so numpy_time_ms is the reduction
+alone. Data generation uses PyArrow to build the column from a flat buffer.
+"""
+
+import sys
+import time
+import numpy as np
+import pyarrow as pa
+import polars as pl
+import polars_vec_ops  # noqa: F401 — registers .vec namespace
+
+# ---------------------------------------------------------------------------
+# Configuration
+# ---------------------------------------------------------------------------
+N_ROWS = 200_000
+WIDTH = 500
+# Case name -> fraction of null elements
+CASES = {"dense": 0.0, "masked": 0.01}
+RANDOM_SEED = 42
+
+
+# ---------------------------------------------------------------------------
+# Helpers
+# ---------------------------------------------------------------------------
+
+def generate_traces_df(null_fraction: float) -> tuple[pl.DataFrame, np.ndarray]:
+    """
+    Build a DataFrame with a single List[Float64] column 'trace' of N_ROWS rows,
+    each WIDTH standard-normal values with null_fraction of the elements null.
+    Also returns the values as a 2D array with NaN in place of the nulls.
+    """
+    rng = np.random.default_rng(RANDOM_SEED)
+    flat = rng.standard_normal(N_ROWS * WIDTH)
+    null_mask = rng.random(N_ROWS * WIDTH) < null_fraction
+    offsets = np.arange(0, N_ROWS * WIDTH + 1, WIDTH, dtype=np.int32)
+    values = pa.array(flat, mask=null_mask if null_mask.any() else None)
+    arrow_list = pa.ListArray.from_arrays(offsets, values)
+    df = pl.from_arrow(pa.table({"trace": arrow_list}))
+    matrix = np.where(null_mask, np.nan, flat).reshape(N_ROWS, WIDTH)
+    return df, matrix
+
+
+# ---------------------------------------------------------------------------
+# Main
+# ---------------------------------------------------------------------------
+
+def run_case(name: str, null_fraction: float) -> None:
+    # 1. Generate test data (outside the measurement window)
+    print(f"Generating {name} test data...", file=sys.stderr, flush=True)
+    df, matrix = generate_traces_df(null_fraction)
+    print(f"  {N_ROWS} rows × {WIDTH} positions", file=sys.stderr, flush=True)
+
+    # 2. Warm-up pass (not counted)
+    _ = df.head(10).select(pl.col("trace").vec.mean())
+
+    # 3. Benchmark window
+    t0 = time.perf_counter()
+    result = df.select(pl.col("trace").vec.mean())
+    wall_time_ms = (time.perf_counter() - t0) * 1000.0
+
+    t0 = time.perf_counter()
+    expected = np.nanmean(matrix, axis=0)
+    numpy_time_ms = (time.perf_counter() - t0) * 1000.0
+
+    # 4. Correctness check against NumPy (nulls are skipped, as nanmean skips NaN)
+    np.testing.assert_allclose(result["trace"][0].to_numpy(), expected, rtol=1e-9)
+    print(f"{name}_correctness_check: PASS", flush=True)
+
+    # 5. Print metrics (machine-parseable)
+    input_elements = N_ROWS * WIDTH
+    print("---")
+    print(f"{name}_input_elements: {input_elements}")
+    print(f"{name}_wall_time_ms: {wall_time_ms:.1f}")
+    print(f"{name}_numpy_time_ms: {numpy_time_ms:.1f}")
+    print(f"{name}_elements_per_s: {input_elements / (wall_time_ms / 1000.0):.3e}")
+
+
+def run_benchmark() -> None:
+    for name, null_fraction in CASES.items():
+        run_case(name, null_fraction)
+
+
+if __name__ == "__main__":
+    run_benchmark()
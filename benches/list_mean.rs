@@ -0,0 +1,98 @@
+//! Criterion benchmarks of the vertical mean (`vec.mean()` / `vec.nanmean()`).
+//!
+//! The kernel takes a different loop depending on the data, so each path is measured on
+//! its own: a dense column (no nulls, NaN propagated), a masked column (1% null elements,
+//! so every element's validity bit is read), and `nanmean`, which checks every value for
+//! NaN. `row_series` is the old approach of adding one row `Series` at a time, kept here
+//! as the baseline the buffer kernel is measured against, and `plugin` calls the exported
+//! plugin symbol the way polars does, to show what the FFI boundary adds to the kernel.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use polars::prelude::*;
+use polars_arrow::array::{ListArray, PrimitiveArray};
+use polars_arrow::bitmap::Bitmap;
+use polars_vec_ops::bench;
+use pyo3_polars::export::polars_ffi::version_0::{
+    export_series, import_series, CallerContext, SeriesExport,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const N_ROWS: usize = 20_000;
+const WIDTH: usize = 500;
+
+/// `N_ROWS` lists of `WIDTH` uniform values, with `null_fraction` of the elements null.
+fn traces(null_fraction: f64) -> Series {
+    let mut rng = StdRng::seed_from_u64(42);
+    let n = N_ROWS * WIDTH;
+    let values: Vec<f64> = (0..n).map(|_| rng.random::<f64>()).collect();
+    let validity = (null_fraction > 0.0)
+        .then(|| Bitmap::from_iter((0..n).map(|_| rng.random::<f64>() >= null_fraction)));
+    let values = PrimitiveArray::new(ArrowDataType::Float64, values.into(), validity);
+    let offsets: Vec<i64> = (0..=N_ROWS).map(|i| (i * WIDTH) as i64).collect();
+    let dtype = ListArray::<i64>::default_datatype(ArrowDataType::Float64);
+    let list = ListArray::<i64>::new(dtype, offsets.try_into().unwrap(), values.boxed(), None);
+    ListChunked::with_chunk("trace".into(), list).into_series()
+}
+
+/// The mean as it was computed before the buffer kernel: each row cast to a Float64
+/// `Series`, nulls filled, and added onto running sums and counts.
+fn row_series_mean(series: &Series) -> PolarsResult<Series> {
+    let mut sums = Series::full_null("sum".into(), WIDTH, &DataType::Float64).fill_null(
+        FillNullStrategy::Zero,
+    )?;
+    let mut counts = sums.clone();
+    for row in series.list()?.amortized_iter().flatten() {
+        let row = row.as_ref().cast(&DataType::Float64)?;
+        let present = row.is_not_null().cast(&DataType::Float64)?;
+        sums = (&sums + &row.fill_null(FillNullStrategy::Zero)?)?;
+        counts = (&counts + &present)?;
+    }
+    &sums / &counts
+}
+
+/// `vec.mean()` through the exported plugin symbol: the input exported over the Arrow C
+/// data interface, the kwargs pickled (an empty dict keeps every default) and the result
+/// imported back.
+fn plugin_mean(series: &Series) -> Series {
+    let mut inputs = [export_series(series)];
+    let kwargs = [0x80, 0x02, b'}', b'.'];
+    let mut out = SeriesExport::empty();
+    unsafe {
+        bench::_polars_plugin_list_mean(
+            inputs.as_mut_ptr(),
+            inputs.len(),
+            kwargs.as_ptr(),
+            kwargs.len(),
+            &mut out,
+            &mut CallerContext::default(),
+        );
+        // The plugin took ownership of the exported inputs
+        std::mem::forget(inputs);
+        import_series(out).unwrap()
+    }
+}
+
+fn bench_list_mean(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_mean");
+    group.throughput(Throughput::Elements((N_ROWS * WIDTH) as u64));
+    group.sample_size(10);
+    for (label, null_fraction) in [("dense", 0.0), ("masked", 0.01)] {
+        let series = traces(null_fraction);
+        group.bench_with_input(BenchmarkId::new("mean", label), &series, |b, s| {
+            b.iter(|| bench::mean(s).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("nanmean", label), &series, |b, s| {
+            b.iter(|| bench::nanmean(s).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("plugin", label), &series, |b, s| {
+            b.iter(|| plugin_mean(s))
+        });
+        group.bench_with_input(BenchmarkId::new("row_series", label), &series, |b, s| {
+            b.iter(|| row_series_mean(s).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_list_mean);
+criterion_main!(benches);
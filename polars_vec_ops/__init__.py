@@ -4,16 +4,122 @@ import polars_vec_ops.expr  # noqa: F401 - registers .vec namespace
 import polars_vec_ops.frame  # noqa: F401 - registers .vec namespace
 from polars_vec_ops._internal import __version__ as __version__
 from polars_vec_ops.expr import (
+    abs_max,
+    add_const,
+    align,
+    angle,
+    arg_sort,
+    assign_clusters,
     avg,
+    baseline_normalize,
+    chunked_mean,
     convolve,
+    corr,
+    corr_matrix,
+    cov_matrix,
+    cross,
+    cum_count,
+    cum_std,
+    cum_sum_from,
+    cv,
+    delta_decode,
+    delta_encode,
+    dequantize,
+    detrend,
     diff,
+    distance_to,
+    entropy,
+    eq,
+    event_triggered_average,
+    fma,
+    gather,
+    ge,
+    group_mean,
+    gt,
     hist,
     histogram,
+    icc,
+    is_max,
+    is_min,
+    le,
+    lt,
+    mae,
+    mann_kendall,
+    mannwhitneyu,
+    matmul,
     max,
+    max_horizontal,
     mean,
+    mean_by_time,
+    mean_ci,
+    mean_std,
     min,
+    min_horizontal,
+    minmax_scale,
+    mul_const,
+    nanmax,
+    nanmean,
+    nanmin,
+    nansum,
+    ne,
+    normalize_vertical,
+    outer,
+    pad_to,
+    pairwise_distance,
+    percentile_rank,
+    permutation_test,
+    project,
+    ptp,
+    quantize,
+    r2,
+    rank,
+    rebin_by_time,
+    reduce_columns,
+    regress,
+    reject,
+    reshape_windows,
+    reverse,
+    rle,
+    rle_decode,
+    rmse,
+    robust_scale,
+    roll,
+    scaled_mean,
+    scatter,
+    search_sorted,
+    self_similarity,
+    shape_report,
+    sigmoid,
+    slice_around,
+    softmax,
+    sort_by,
+    split_half_reliability,
+    stack,
+    stats,
     sum,
+    tanh,
+    to_array,
+    to_matrix,
+    top_k,
+    top_k_rows,
+    transpose,
+    trapz,
+    trend,
+    truncate,
+    ttest,
+    ttest_1samp,
+    value_report,
+    weighted_mean,
+    where,
+    zero_crossings,
+    zscore,
 )  # noqa: F401 - re-export for convenience
+from polars_vec_ops.frame import (  # noqa: F401
+    from_matrix,
+    summarize,
+    to_numpy_matrix,
+    validate,
+)
 
 __all__ = [
     "__version__",
@@ -26,4 +132,108 @@ __all__ = [
     "convolve",
     "histogram", 
     "hist",
+    "zero_crossings",
+    "baseline_normalize",
+    "detrend",
+    "trapz",
+    "event_triggered_average",
+    "slice_around",
+    "rebin_by_time",
+    "align",
+    "transpose",
+    "stack",
+    "matmul",
+    "corr_matrix",
+    "cov_matrix",
+    "trend",
+    "regress",
+    "mannwhitneyu",
+    "ttest",
+    "ttest_1samp",
+    "mean_ci",
+    "permutation_test",
+    "mann_kendall",
+    "entropy",
+    "percentile_rank",
+    "top_k_rows",
+    "is_max",
+    "is_min",
+    "eq",
+    "ne",
+    "gt",
+    "ge",
+    "lt",
+    "le",
+    "where",
+    "min_horizontal",
+    "max_horizontal",
+    "fma",
+    "add_const",
+    "mul_const",
+    "normalize_vertical",
+    "cum_std",
+    "cum_count",
+    "mean_std",
+    "cv",
+    "split_half_reliability",
+    "icc",
+    "pairwise_distance",
+    "distance_to",
+    "assign_clusters",
+    "corr",
+    "rmse",
+    "mae",
+    "r2",
+    "self_similarity",
+    "cross",
+    "outer",
+    "project",
+    "reject",
+    "angle",
+    "stats",
+    "weighted_mean",
+    "softmax",
+    "sigmoid",
+    "tanh",
+    "minmax_scale",
+    "robust_scale",
+    "zscore",
+    "rank",
+    "top_k",
+    "arg_sort",
+    "sort_by",
+    "gather",
+    "scatter",
+    "search_sorted",
+    "rle",
+    "rle_decode",
+    "pad_to",
+    "truncate",
+    "reshape_windows",
+    "to_array",
+    "shape_report",
+    "nansum",
+    "nanmean",
+    "nanmin",
+    "nanmax",
+    "ptp",
+    "abs_max",
+    "scaled_mean",
+    "cum_sum_from",
+    "delta_encode",
+    "delta_decode",
+    "quantize",
+    "dequantize",
+    "group_mean",
+    "chunked_mean",
+    "mean_by_time",
+    "value_report",
+    "summarize",
+    "validate",
+    "reduce_columns",
+    "to_matrix",
+    "to_numpy_matrix",
+    "from_matrix",
+    "reverse",
+    "roll",
 ]
\ No newline at end of file
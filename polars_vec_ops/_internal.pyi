@@ -1 +1,5 @@
+import polars as pl
+
 __version__: str
+
+def array_from_flat(values: pl.Series, width: int, n_rows: int) -> pl.Series: ...
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+from datetime import timedelta
 from pathlib import Path
 from typing import TYPE_CHECKING
 
@@ -9,11 +10,102 @@ from polars._utils.wrap import wrap_expr
 from polars.plugins import register_plugin_function
 
 if TYPE_CHECKING:
-    from polars._typing import IntoExprColumn
+    from collections.abc import Sequence
+
+    from polars._typing import IntoExpr, IntoExprColumn, PolarsDataType
 
 _LIB = Path(__file__).parent
 
 
+def _reduce_kwargs(
+    null_policy: str,
+    min_count: int,
+    keep_rows: bool,
+    validate: str,
+    empty_policy: str,
+    nan_policy: str,
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> dict[str, str | int | bool | None]:
+    """Validate and pack the options shared by the vertical reductions."""
+    if null_policy not in ("ignore", "propagate"):
+        raise ValueError(f"null_policy must be 'ignore' or 'propagate', got {null_policy!r}")
+    if min_count < 0:
+        raise ValueError(f"min_count must be non-negative, got {min_count}")
+    if validate not in ("strict", "fast"):
+        raise ValueError(f"validate must be 'strict' or 'fast', got {validate!r}")
+    if empty_policy not in ("error", "skip", "treat_as_null"):
+        raise ValueError(
+            "empty_policy must be 'error', 'skip' or 'treat_as_null', "
+            f"got {empty_policy!r}"
+        )
+    if nan_policy not in ("propagate", "ignore", "as_null"):
+        raise ValueError(
+            "nan_policy must be 'propagate', 'ignore' or 'as_null', "
+            f"got {nan_policy!r}"
+        )
+    if length is not None and length < 0:
+        raise ValueError(f"length must be non-negative, got {length}")
+    if every < 1:
+        raise ValueError(f"every must be at least 1, got {every}")
+    return {
+        "null_policy": null_policy,
+        "min_count": min_count,
+        "keep_rows": keep_rows,
+        "validate": validate,
+        "empty_policy": empty_policy,
+        "nan_policy": nan_policy,
+        "offset": offset,
+        "length": length,
+        "every": every,
+    }
+
+
+_DELTA_DTYPES = (
+    "int8",
+    "int16",
+    "int32",
+    "int64",
+    "uint32",
+    "uint64",
+    "float32",
+    "float64",
+)
+
+
+def _dtype_name(
+    dtype: PolarsDataType | str | None, choices: tuple[str, ...]
+) -> str | None:
+    """Validate an element dtype given as a polars dtype or name and pass it by name."""
+    if dtype is None:
+        return None
+    name = (dtype if isinstance(dtype, str) else str(dtype)).lower()
+    if name not in choices:
+        raise ValueError(f"dtype must be one of {', '.join(choices)}, got {dtype!r}")
+    return name
+
+
+_DISTANCE_METRICS = (
+    "euclidean",
+    "sqeuclidean",
+    "cityblock",
+    "chebyshev",
+    "cosine",
+    "correlation",
+)
+
+
+def _check_metric(metric: str) -> str:
+    """Validate a distance metric name shared by the distance operations."""
+    if metric not in _DISTANCE_METRICS:
+        raise ValueError(
+            f"metric must be one of {', '.join(map(repr, _DISTANCE_METRICS))}, "
+            f"got {metric!r}"
+        )
+    return metric
+
+
 @pl.api.register_expr_namespace("vec")
 class VecOpsNamespace:
     """Custom namespace for vertical list operations."""
@@ -21,14 +113,68 @@ class VecOpsNamespace:
     def __init__(self, expr: pl.Expr):
         self._expr = expr
 
-    def sum(self) -> pl.Expr:
+    def sum(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        nan_policy: str = "propagate",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
         """
         Sum across rows for list columns (vertical aggregation).
 
         Returns a single row with a list where each element is the sum
         of elements at that position across all input lists.
 
-        All lists must have the same length.
+        All lists must have the same length. The result keeps the input's
+        inner dtype; integer sums (including UInt64 and Int128) are exact and
+        raise an error rather than wrapping if they overflow that dtype.
+
+        Parameters
+        ----------
+        null_policy
+            ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+            position null if any row has a null there. Null rows are always
+            skipped.
+        min_count
+            Positions with fewer than ``min_count`` non-null values are null.
+        keep_rows
+            If True, repeat the aggregate for every input row instead of
+            returning a single row, so it can be used in ``with_columns``
+            (e.g. to subtract the mean trace).
+        validate
+            ``"strict"`` (default) checks that every non-null list has the
+            same length and reports the offending rows. ``"fast"`` skips the
+            check for inputs known to be uniform; mismatched lengths then give
+            an unhelpful error or a wrong result. Array columns are never
+            re-checked.
+        empty_policy
+            What to do with empty lists among non-empty ones: ``"error"``
+            (default) raises, ``"skip"`` leaves them out like null rows and
+            ``"treat_as_null"`` counts them as rows of nulls (which matters for
+            ``null_policy="propagate"`` and ``min_count``).
+        nan_policy
+            How NaN elements of float lists are handled, separately from
+            nulls: ``"propagate"`` (default) makes a position NaN if any row
+            has a NaN there, ``"ignore"`` skips them like numpy's
+            nan-functions and ``"as_null"`` treats them as nulls.
+        offset
+            First row to aggregate; negative values count from the end.
+            With ``length`` this reduces a vertical slice of the rows
+            without slicing the frame first.
+        length
+            Number of rows to aggregate from ``offset``; None (default)
+            takes every remaining row. With ``keep_rows`` the output still
+            has one row per row of the whole column.
+        every
+            Aggregate only every ``every``-th row of the slice, starting
+            with its first (e.g. ``offset=1, every=2`` for the odd rows of
+            a split-half analysis).
 
         Examples
         --------
@@ -48,10 +194,32 @@ class VecOpsNamespace:
             plugin_path=_LIB,
             function_name="list_sum",
             is_elementwise=False,
-            returns_scalar=True,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                nan_policy,
+                offset,
+                length,
+                every,
+            ),
         )
 
-    def mean(self) -> pl.Expr:
+    def mean(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        nan_policy: str = "propagate",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
         """
         Calculate mean across rows for list columns (vertical aggregation).
 
@@ -60,6 +228,47 @@ class VecOpsNamespace:
 
         All lists must have the same length.
 
+        Parameters
+        ----------
+        null_policy
+            ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+            position null if any row has a null there. Null rows are always
+            skipped.
+        min_count
+            Positions with fewer than ``min_count`` non-null values are null.
+        keep_rows
+            If True, repeat the aggregate for every input row instead of
+            returning a single row, so it can be used in ``with_columns``
+            (e.g. to subtract the mean trace).
+        validate
+            ``"strict"`` (default) checks that every non-null list has the
+            same length and reports the offending rows. ``"fast"`` skips the
+            check for inputs known to be uniform; mismatched lengths then give
+            an unhelpful error or a wrong result. Array columns are never
+            re-checked.
+        empty_policy
+            What to do with empty lists among non-empty ones: ``"error"``
+            (default) raises, ``"skip"`` leaves them out like null rows and
+            ``"treat_as_null"`` counts them as rows of nulls (which matters for
+            ``null_policy="propagate"`` and ``min_count``).
+        nan_policy
+            How NaN elements of float lists are handled, separately from
+            nulls: ``"propagate"`` (default) makes a position NaN if any row
+            has a NaN there, ``"ignore"`` skips them like numpy's
+            nan-functions and ``"as_null"`` treats them as nulls.
+        offset
+            First row to aggregate; negative values count from the end.
+            With ``length`` this reduces a vertical slice of the rows
+            without slicing the frame first.
+        length
+            Number of rows to aggregate from ``offset``; None (default)
+            takes every remaining row. With ``keep_rows`` the output still
+            has one row per row of the whole column.
+        every
+            Aggregate only every ``every``-th row of the slice, starting
+            with its first (e.g. ``offset=1, every=2`` for the odd rows of
+            a split-half analysis).
+
         Returns
         -------
         pl.Expr
@@ -83,19 +292,62 @@ class VecOpsNamespace:
             plugin_path=_LIB,
             function_name="list_mean",
             is_elementwise=False,
-            returns_scalar=True,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                nan_policy,
+                offset,
+                length,
+                every,
+            ),
         )
 
     # Alias for mean
-    def avg(self) -> pl.Expr:
+    def avg(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        nan_policy: str = "propagate",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
         """
         Alias for mean(). Calculate average across rows for list columns.
 
         See mean() for full documentation.
         """
-        return self.mean()
+        return self.mean(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            nan_policy,
+            offset,
+            length,
+            every,
+        )
 
-    def min(self) -> pl.Expr:
+    def min(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        nan_policy: str = "propagate",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
         """
         Find minimum element at each position across rows (vertical aggregation).
 
@@ -104,6 +356,47 @@ class VecOpsNamespace:
 
         All lists must have the same length.
 
+        Parameters
+        ----------
+        null_policy
+            ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+            position null if any row has a null there. Null rows are always
+            skipped.
+        min_count
+            Positions with fewer than ``min_count`` non-null values are null.
+        keep_rows
+            If True, repeat the aggregate for every input row instead of
+            returning a single row, so it can be used in ``with_columns``
+            (e.g. to subtract the mean trace).
+        validate
+            ``"strict"`` (default) checks that every non-null list has the
+            same length and reports the offending rows. ``"fast"`` skips the
+            check for inputs known to be uniform; mismatched lengths then give
+            an unhelpful error or a wrong result. Array columns are never
+            re-checked.
+        empty_policy
+            What to do with empty lists among non-empty ones: ``"error"``
+            (default) raises, ``"skip"`` leaves them out like null rows and
+            ``"treat_as_null"`` counts them as rows of nulls (which matters for
+            ``null_policy="propagate"`` and ``min_count``).
+        nan_policy
+            How NaN elements of float lists are handled, separately from
+            nulls: ``"propagate"`` (default) makes a position NaN if any row
+            has a NaN there, ``"ignore"`` skips them like numpy's
+            nan-functions and ``"as_null"`` treats them as nulls.
+        offset
+            First row to aggregate; negative values count from the end.
+            With ``length`` this reduces a vertical slice of the rows
+            without slicing the frame first.
+        length
+            Number of rows to aggregate from ``offset``; None (default)
+            takes every remaining row. With ``keep_rows`` the output still
+            has one row per row of the whole column.
+        every
+            Aggregate only every ``every``-th row of the slice, starting
+            with its first (e.g. ``offset=1, every=2`` for the odd rows of
+            a split-half analysis).
+
         Returns
         -------
         pl.Expr
@@ -127,10 +420,32 @@ class VecOpsNamespace:
             plugin_path=_LIB,
             function_name="list_min",
             is_elementwise=False,
-            returns_scalar=True,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                nan_policy,
+                offset,
+                length,
+                every,
+            ),
         )
 
-    def max(self) -> pl.Expr:
+    def max(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        nan_policy: str = "propagate",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
         """
         Find maximum element at each position across rows (vertical aggregation).
 
@@ -139,6 +454,47 @@ class VecOpsNamespace:
 
         All lists must have the same length.
 
+        Parameters
+        ----------
+        null_policy
+            ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+            position null if any row has a null there. Null rows are always
+            skipped.
+        min_count
+            Positions with fewer than ``min_count`` non-null values are null.
+        keep_rows
+            If True, repeat the aggregate for every input row instead of
+            returning a single row, so it can be used in ``with_columns``
+            (e.g. to subtract the mean trace).
+        validate
+            ``"strict"`` (default) checks that every non-null list has the
+            same length and reports the offending rows. ``"fast"`` skips the
+            check for inputs known to be uniform; mismatched lengths then give
+            an unhelpful error or a wrong result. Array columns are never
+            re-checked.
+        empty_policy
+            What to do with empty lists among non-empty ones: ``"error"``
+            (default) raises, ``"skip"`` leaves them out like null rows and
+            ``"treat_as_null"`` counts them as rows of nulls (which matters for
+            ``null_policy="propagate"`` and ``min_count``).
+        nan_policy
+            How NaN elements of float lists are handled, separately from
+            nulls: ``"propagate"`` (default) makes a position NaN if any row
+            has a NaN there, ``"ignore"`` skips them like numpy's
+            nan-functions and ``"as_null"`` treats them as nulls.
+        offset
+            First row to aggregate; negative values count from the end.
+            With ``length`` this reduces a vertical slice of the rows
+            without slicing the frame first.
+        length
+            Number of rows to aggregate from ``offset``; None (default)
+            takes every remaining row. With ``keep_rows`` the output still
+            has one row per row of the whole column.
+        every
+            Aggregate only every ``every``-th row of the slice, starting
+            with its first (e.g. ``offset=1, every=2`` for the odd rows of
+            a split-half analysis).
+
         Returns
         -------
         pl.Expr
@@ -162,10 +518,21 @@ class VecOpsNamespace:
             plugin_path=_LIB,
             function_name="list_max",
             is_elementwise=False,
-            returns_scalar=True,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                nan_policy,
+                offset,
+                length,
+                every,
+            ),
         )
 
-    def diff(self) -> pl.Expr:
+    def diff(self, n: int = 1, reverse_rows: bool = False) -> pl.Expr:
         """
         Calculate differences between consecutive rows at each position.
 
@@ -177,11 +544,34 @@ class VecOpsNamespace:
 
         All lists must have the same length.
 
+        Parameters
+        ----------
+        n
+            Order of the difference. ``n=2`` gives the second difference
+            ``row[i] - 2 * row[i-1] + row[i-2]``, and so on, computed in a
+            single pass rather than by chaining ``diff()`` ``n`` times. The
+            first ``n`` rows, and any row with a null among itself and the
+            ``n`` rows before it, are lists of nulls, so every row is one when
+            there are no more than ``n`` rows.
+        reverse_rows
+            Difference each row against the following rows instead, as if
+            the rows were reversed: ``row[i] - row[i+1]`` for ``n=1``. The
+            last ``n`` rows are then the lists of nulls.
+
         Returns
         -------
         pl.Expr
-            Expression returning lists with differences, preserving input type.
-            First row is always a list of nulls.
+            Expression returning lists with differences. Like polars' own
+            ``diff``, unsigned integers give a signed dtype, widened so every
+            first difference fits (``UInt8``/``Int8`` give ``Int16``,
+            ``UInt16``/``Int16`` give ``Int32``, ``UInt32``/``UInt64`` give
+            ``Int64``); other dtypes are kept. The first ``n`` rows (last with
+            ``reverse_rows``) are always lists of nulls.
+
+        Raises
+        ------
+        ComputeError
+            If an integer difference does not fit the output dtype.
 
         Examples
         --------
@@ -198,12 +588,15 @@ class VecOpsNamespace:
         │ [-2, -15, -5]      │
         └────────────────────┘
         """
+        if n < 1:
+            raise ValueError(f"n must be at least 1, got {n}")
         return register_plugin_function(
             args=[self._expr],
             plugin_path=_LIB,
             function_name="list_diff",
             is_elementwise=False,
             returns_scalar=False,  # Returns same number of rows
+            kwargs={"n": n, "reverse_rows": reverse_rows},
         )
 
     def convolve(
@@ -238,7 +631,8 @@ class VecOpsNamespace:
         Returns
         -------
         pl.Expr
-            Expression returning lists of Float64 values.
+            Expression returning lists of Float64 values. Array input gives
+            ``Array[f64, n]`` with ``n`` the output length of ``mode``.
 
         Examples
         --------
@@ -519,364 +913,8036 @@ class VecOpsNamespace:
 
     hist = histogram
 
+    def zero_crossings(self, return_indices: bool = False) -> pl.Expr:
+        """
+        Count the sign changes within each list.
 
-def sum(*exprs: IntoExprColumn) -> pl.Expr | list[pl.Expr]:
-    """
-    Sum across rows for list columns (vertical aggregation).
+        Nulls, NaNs and exact zeros carry no sign and are skipped, so
+        ``[1, 0, -1]`` contains a single crossing.
 
-    Returns a single row with a list where each element is the sum
-    of elements at that position across all input lists.
+        Parameters
+        ----------
+        return_indices
+            If ``True``, return the index of the first element after each
+            crossing instead of the number of crossings. Default ``False``.
 
-    All lists must have the same length.
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``UInt32`` counts, or ``List[UInt32]``
+            indices if ``return_indices=True``. Null rows stay null.
 
-    Examples
-    --------
-    >>> import polars_vec_ops as vec
-    >>> df = pl.DataFrame({"a": [[0, 1, 2], [1, 2, 3]]})
-    >>> df.select(vec.sum("a"))
-    shape: (1, 1)
-    ┌───────────┐
-    │ a         │
-    │ ---       │
-    │ list[i64] │
-    ╞═══════════╡
-    │ [1, 3, 5] │
-    └───────────┘
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, -1, 2, 3], [0, 1, 0, -2]]})
+        >>> df.select(pl.col("a").vec.zero_crossings())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 2   │
+        │ 1   │
+        └─────┘
+        >>> df.select(pl.col("a").vec.zero_crossings(return_indices=True))
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[u32] │
+        ╞═══════════╡
+        │ [1, 2]    │
+        │ [3]       │
+        └───────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_zero_crossings",
+            is_elementwise=True,
+            returns_scalar=False,
+            kwargs={"return_indices": return_indices},
+        )
 
-    Can be called with multiple columns:
-    >>> df = pl.DataFrame({"a": [[0, 1], [1, 2]], "b": [[10, 20], [30, 40]]})
-    >>> df.select(vec.sum("a", "b"))
-    shape: (1, 2)
-    ┌───────────┬───────────┐
-    │ a         ┆ b         │
-    │ ---       ┆ ---       │
-    │ list[i64] ┆ list[i64] │
-    ╞═══════════╪═══════════╡
-    │ [1, 3]    ┆ [40, 60]  │
-    └───────────┴───────────┘
-    """
-    results = [VecOpsNamespace(wrap_expr(e)).sum() for e in parse_into_list_of_expressions(*exprs)]
-    return results[0] if len(results) == 1 else results
+    def baseline_normalize(
+        self,
+        baseline_start: int = 0,
+        baseline_end: int | None = None,
+        mode: str = "subtract",
+    ) -> pl.Expr:
+        """
+        Normalize each list against a baseline computed from a slice of itself.
 
+        The baseline is the mean of ``list[baseline_start:baseline_end]``
+        (Python slice semantics, negative indices count from the end).
+        Nulls and non-finite values in the baseline window are ignored.
 
-def mean(*exprs: IntoExprColumn) -> pl.Expr | list[pl.Expr]:
-    """
-    Calculate mean across rows for list columns (vertical aggregation).
+        Parameters
+        ----------
+        baseline_start
+            Index of the first baseline element. Default ``0``.
+        baseline_end
+            Index one past the last baseline element. ``None`` (default)
+            uses the whole list.
+        mode
+            How the baseline is removed, one of:
+            - "subtract": ``x - baseline`` (default)
+            - "divide": ``(x - baseline) / baseline`` (dF/F)
+            - "zscore": ``(x - baseline) / baseline_std``
 
-    Returns a single row with a list where each element is the mean
-    of elements at that position across all input lists.
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 values. Null elements
+            and null rows are preserved.
 
-    All lists must have the same length.
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[2.0, 2.0, 4.0]]})
+        >>> df.select(pl.col("a").vec.baseline_normalize(baseline_end=2))
+        shape: (1, 1)
+        ┌─────────────────┐
+        │ a               │
+        │ ---             │
+        │ list[f64]       │
+        ╞═════════════════╡
+        │ [0.0, 0.0, 2.0] │
+        └─────────────────┘
+        >>> df.select(pl.col("a").vec.baseline_normalize(baseline_end=2, mode="divide"))
+        shape: (1, 1)
+        ┌─────────────────┐
+        │ a               │
+        │ ---             │
+        │ list[f64]       │
+        ╞═════════════════╡
+        │ [0.0, 0.0, 1.0] │
+        └─────────────────┘
+        """
+        if mode not in ("subtract", "divide", "zscore"):
+            raise ValueError(
+                f"mode must be one of 'subtract', 'divide', 'zscore', got {mode!r}"
+            )
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_baseline_normalize",
+            is_elementwise=True,
+            returns_scalar=False,
+            kwargs={
+                "baseline_start": baseline_start,
+                "baseline_end": baseline_end,
+                "mode": mode,
+            },
+        )
 
-    Returns
-    -------
-    pl.Expr
-        Expression returning a list of Float64 values.
+    def detrend(self, method: str = "linear", axis: int = 1) -> pl.Expr:
+        """
+        Remove a constant or linear trend from each list.
 
-    Examples
-    --------
-    >>> import polars_vec_ops as vec
-    >>> df = pl.DataFrame({"a": [[1, 2, 3], [3, 4, 5]]})
-    >>> df.select(vec.mean("a"))
-    shape: (1, 1)
-    ┌─────────────────┐
-    │ a               │
-    │ ---             │
-    │ list[f64]       │
-    ╞═════════════════╡
-    │ [2.0, 3.0, 4.0] │
-    └─────────────────┘
+        Nulls and non-finite values are excluded from the fit but detrended
+        like every other element; null elements stay null.
 
-    Can be called with multiple columns:
-    >>> df = pl.DataFrame({"a": [[1, 2], [3, 4]], "b": [[10, 20], [30, 40]]})
-    >>> df.select(vec.mean("a", "b"))
-    shape: (1, 2)
-    ┌────────────┬──────────────┐
-    │ a          ┆ b            │
-    │ ---        ┆ ---          │
-    │ list[f64]  ┆ list[f64]    │
-    ╞════════════╪══════════════╡
-    │ [2.0, 3.0] ┆ [20.0, 30.0] │
-    └────────────┴──────────────┘
-    """
-    results = [VecOpsNamespace(wrap_expr(e)).mean() for e in parse_into_list_of_expressions(*exprs)]
-    return results[0] if len(results) == 1 else results
+        Parameters
+        ----------
+        method
+            "linear" (default) removes the least-squares line, "constant"
+            removes the mean.
+        axis
+            ``1`` (default) fits the trend within each list against the
+            element index. ``0`` fits it at each position across rows against
+            the row index (all lists must then have the same length).
 
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 values, one row per input row.
 
-def avg(*exprs: IntoExprColumn) -> pl.Expr | list[pl.Expr]:
-    """
-    Alias for mean(). Calculate average across rows for list columns.
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, 2.0, 6.0]]})
+        >>> df.select(pl.col("a").vec.detrend("constant"))
+        shape: (1, 1)
+        ┌───────────────────┐
+        │ a                 │
+        │ ---               │
+        │ list[f64]         │
+        ╞═══════════════════╡
+        │ [-2.0, -1.0, 3.0] │
+        └───────────────────┘
+        """
+        if method not in ("constant", "linear"):
+            raise ValueError(f"method must be 'constant' or 'linear', got {method!r}")
+        if axis not in (0, 1, -1):
+            raise ValueError(f"axis must be 0 or 1, got {axis!r}")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_detrend",
+            is_elementwise=axis != 0,
+            returns_scalar=False,
+            kwargs={"method": method, "axis": axis},
+        )
 
-    See mean() for full documentation.
-    """
-    return mean(*exprs)
+    def trapz(
+        self,
+        x: IntoExprColumn | None = None,
+        dx: float = 1.0,
+    ) -> pl.Expr:
+        """
+        Integrate each list using the trapezoidal rule.
 
+        Points where either x or y is null or non-finite are skipped; the
+        neighbouring valid points are joined directly.
 
-def min(*exprs: IntoExprColumn) -> pl.Expr | list[pl.Expr]:
-    """
-    Find minimum element at each position across rows (vertical aggregation).
+        Parameters
+        ----------
+        x
+            Optional List or Array column of sample positions with the same
+            length as each row's list, for non-uniform spacing.
+        dx
+            Spacing between samples when ``x`` is not given. Default ``1.0``.
 
-    Returns a single row with a list where each element is the minimum
-    of elements at that position across all input lists.
+        Returns
+        -------
+        pl.Expr
+            Expression returning one Float64 area per row. Null rows (in
+            either input) give null.
 
-    All lists must have the same length.
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, 2.0, 3.0]], "t": [[0.0, 1.0, 3.0]]})
+        >>> df.select(pl.col("a").vec.trapz())
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 4.0 │
+        └─────┘
+        >>> df.select(pl.col("a").vec.trapz(x="t"))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 6.5 │
+        └─────┘
+        """
+        args = [self._expr]
+        if x is not None:
+            args.append(wrap_expr(parse_into_expression(x)))
+        return register_plugin_function(
+            args=args,
+            plugin_path=_LIB,
+            function_name="vec_trapz",
+            is_elementwise=True,
+            returns_scalar=False,
+            kwargs={"dx": float(dx)},
+        )
 
-    Returns
-    -------
-    pl.Expr
-        Expression returning a list with the same type as input.
+    def event_triggered_average(
+        self,
+        events: IntoExprColumn,
+        pre: int,
+        post: int,
+        *,
+        return_stats: bool = False,
+    ) -> pl.Expr:
+        """
+        Average windows of a continuous trace aligned on event indices.
 
-    Examples
-    --------
-    >>> import polars_vec_ops as vec
-    >>> df = pl.DataFrame({"a": [[3, 5, 2], [1, 7, 4]]})
-    >>> df.select(vec.min("a"))
-    shape: (1, 1)
-    ┌───────────┐
-    │ a         │
-    │ ---       │
-    │ list[i64] │
-    ╞═══════════╡
-    │ [1, 5, 2] │
-    └───────────┘
+        For every event index ``e`` of a row, the window
+        ``trace[e - pre : e + post]`` is taken from the same row's list. The
+        windows of all events in all rows are averaged element-wise, so the
+        result is a single row (or one row per group in ``group_by``).
 
-    Can be called with multiple columns:
-    >>> df = pl.DataFrame({"a": [[3, 5], [1, 7]], "b": [[10, 20], [5, 15]]})
-    >>> df.select(vec.min("a", "b"))
-    shape: (1, 2)
-    ┌───────────┬───────────┐
-    │ a         ┆ b         │
-    │ ---       ┆ ---       │
-    │ list[i64] ┆ list[i64] │
-    ╞═══════════╪═══════════╡
-    │ [1, 5]    ┆ [5, 15]   │
-    └───────────┴───────────┘
-    """
-    results = [VecOpsNamespace(wrap_expr(e)).min() for e in parse_into_list_of_expressions(*exprs)]
-    return results[0] if len(results) == 1 else results
+        Samples that fall outside the trace, nulls and NaNs contribute
+        nothing, so window positions near the edges may average fewer events.
 
+        Parameters
+        ----------
+        events
+            Column of event indices into each row's trace: either one integer
+            per row or a List of integers per row.
+        pre
+            Number of samples before each event to include.
+        post
+            Number of samples from each event onwards to include (the event
+            sample itself is at window position ``pre``).
+        return_stats
+            If ``True``, return a Struct with ``mean``, ``count`` (events
+            contributing at each position) and ``sem`` (standard error of the
+            mean) fields instead of just the mean. Default ``False``.
 
-def max(*exprs: IntoExprColumn) -> pl.Expr | list[pl.Expr]:
+        Returns
+        -------
+        pl.Expr
+            Expression returning a ``List[Float64]`` of length ``pre + post``,
+            or a Struct of lists if ``return_stats=True``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"trace": [[0, 1, 5, 1, 0, 2, 6, 2]], "events": [[2, 6]]})
+        >>> df.select(pl.col("trace").vec.event_triggered_average("events", pre=1, post=2))
+        shape: (1, 1)
+        ┌─────────────────┐
+        │ trace           │
+        │ ---             │
+        │ list[f64]       │
+        ╞═════════════════╡
+        │ [1.5, 5.5, 1.5] │
+        └─────────────────┘
+        """
+        if pre < 0 or post < 0:
+            raise ValueError("pre and post must be non-negative")
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(events))],
+            plugin_path=_LIB,
+            function_name="event_triggered_average",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"pre": pre, "post": post, "return_stats": return_stats},
+        )
+
+    def slice_around(
+        self,
+        indices: IntoExprColumn,
+        before: int,
+        after: int,
+        pad: float | None = None,
+    ) -> pl.Expr:
+        """
+        Extract a fixed-width window from each list around a per-row index.
+
+        The window is ``list[index - before : index + after]``, so the indexed
+        element sits at window position ``before`` and every row has width
+        ``before + after``.
+
+        Parameters
+        ----------
+        indices
+            Integer column giving the index into each row's list.
+        before
+            Number of elements before the index to include.
+        after
+            Number of elements from the index onwards to include.
+        pad
+            Value for window positions that fall outside the list. ``None``
+            (default) leaves them null.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``Array[inner, before + after]`` with the
+            input's inner dtype. Null lists or null indices give null rows.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4, 5]], "i": [0]})
+        >>> df.select(pl.col("a").vec.slice_around("i", before=1, after=2, pad=0))
+        shape: (1, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ array[i64, 3] │
+        ╞═══════════════╡
+        │ [0, 1, 2]     │
+        └───────────────┘
+        """
+        if before < 0 or after < 0:
+            raise ValueError("before and after must be non-negative")
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(indices))],
+            plugin_path=_LIB,
+            function_name="vec_slice_around",
+            is_elementwise=True,
+            returns_scalar=False,
+            kwargs={
+                "before": before,
+                "after": after,
+                "pad": None if pad is None else float(pad),
+            },
+        )
+
+    def rebin_by_time(
+        self,
+        timestamps: IntoExprColumn,
+        bin_width: float,
+        agg: str = "mean",
+        start: float | None = None,
+        stop: float | None = None,
+    ) -> pl.Expr:
+        """
+        Rebin each list onto a uniform time grid using per-element timestamps.
+
+        Bin ``k`` covers ``[start + k * bin_width, start + (k + 1) * bin_width)``
+        and aggregates every value whose timestamp falls inside it. Bins with
+        no values are null (``0`` for ``agg="count"``).
+
+        Parameters
+        ----------
+        timestamps
+            List column with one timestamp per value. Datetime and Duration
+            lists are binned on their integer representation, so
+            ``bin_width`` is in the column's time unit.
+        bin_width
+            Width of each bin, in the same units as the timestamps.
+        agg
+            Aggregation within each bin: ``"mean"`` (default), ``"sum"``,
+            ``"min"``, ``"max"``, ``"first"``, ``"last"`` or ``"count"``.
+        start
+            Left edge of the first bin. Defaults to each row's earliest
+            timestamp.
+        stop
+            Right edge of the grid; timestamps at or beyond it are dropped.
+            Defaults to covering each row's latest timestamp. Setting both
+            ``start`` and ``stop`` gives every row the same number of bins.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``List[u32]`` for
+            ``agg="count"``). Null values, NaN values and null or non-finite
+            timestamps are skipped.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"v": [[1.0, 2.0, 4.0]], "t": [[0.0, 0.5, 1.2]]})
+        >>> df.select(pl.col("v").vec.rebin_by_time("t", bin_width=1.0))
+        shape: (1, 1)
+        ┌────────────┐
+        │ v          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [1.5, 4.0] │
+        └────────────┘
+        """
+        if bin_width <= 0:
+            raise ValueError("bin_width must be positive")
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(timestamps))],
+            plugin_path=_LIB,
+            function_name="vec_rebin_by_time",
+            is_elementwise=True,
+            returns_scalar=False,
+            kwargs={
+                "bin_width": float(bin_width),
+                "agg": agg,
+                "start": None if start is None else float(start),
+                "stop": None if stop is None else float(stop),
+            },
+        )
+
+    def align(
+        self,
+        other: IntoExprColumn,
+        max_lag: int,
+        return_lag: bool = False,
+    ) -> pl.Expr:
+        """
+        Align each row's ``other`` list to this list by cross-correlation.
+
+        For every lag ``k`` in ``[-max_lag, max_lag]`` the Pearson correlation
+        between ``self[i]`` and ``other[i + k]`` is computed over the
+        overlapping finite pairs, and the lag with the highest correlation is
+        kept (the smallest ``|k|`` wins ties).
+
+        Parameters
+        ----------
+        other
+            List column to align against this one.
+        max_lag
+            Largest lag, in elements, to consider in either direction.
+        return_lag
+            If True, return the estimated lag instead of the aligned lists.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``other`` shifted by the lag as ``List[f64]``
+            (same length as ``other``, positions shifted in from outside it
+            are null), or an ``Int64`` lag if ``return_lag=True``. Rows where
+            no lag has at least two overlapping, non-constant pairs are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0, 1, 3, 1, 0]], "b": [[0, 0, 1, 3, 1]]})
+        >>> df.select(pl.col("a").vec.align("b", max_lag=2, return_lag=True))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        └─────┘
+        """
+        if max_lag < 0:
+            raise ValueError("max_lag must be non-negative")
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_align",
+            is_elementwise=True,
+            returns_scalar=False,
+            kwargs={"max_lag": max_lag, "return_lag": return_lag},
+        )
+
+    def transpose(self) -> pl.Expr:
+        """
+        Transpose the column, swapping rows and list positions.
+
+        An n-row column of length-m lists becomes an m-row column of length-n
+        lists, so that output row ``j`` holds element ``j`` of every input
+        row. Vertical analyses can then be written as horizontal ones.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[inner]`` with one row per list
+            position. Array input also returns a List, since the output width
+            is the input row count. A null input row becomes a null element at
+            that position in every output list.
+
+        Raises
+        ------
+        ComputeError
+            If the non-null lists do not all have the same length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 5, 6]]})
+        >>> df.select(pl.col("a").vec.transpose())
+        shape: (3, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 4]    │
+        │ [2, 5]    │
+        │ [3, 6]    │
+        └───────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_transpose",
+            is_elementwise=False,
+            returns_scalar=False,
+            changes_length=True,
+        )
+
+    def stack(self) -> pl.Expr:
+        """
+        Collapse all rows into a single row holding the column as a matrix.
+
+        The result is one cell containing every input row in order, which is
+        convenient for exporting a trial matrix as a single value. It is the
+        aggregating counterpart of :meth:`transpose`.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row of ``List[List[inner]]`` for
+            List input, or ``List[Array[inner, width]]`` for Array input (the
+            number of rows is not known up front, so the outer level is always
+            a List). Null rows are kept as null entries.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 4]]})
+        >>> df.select(pl.col("a").vec.stack())
+        shape: (1, 1)
+        ┌──────────────────┐
+        │ a                │
+        │ ---              │
+        │ list[list[i64]]  │
+        ╞══════════════════╡
+        │ [[1, 2], [3, 4]] │
+        └──────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_stack",
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
+    def matmul(self, matrix: Sequence[Sequence[float]]) -> pl.Expr:
+        """
+        Multiply each row's vector by a constant matrix.
+
+        A row ``v`` of length ``m`` is mapped to ``v @ matrix`` for an
+        ``m x k`` matrix, giving a row of length ``k``. Use this for linear
+        readouts or projections (e.g. pass ``components.T`` from a fitted PCA).
+
+        Parameters
+        ----------
+        matrix
+            Nested sequence (or 2-D numpy array) of shape ``(m, k)``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, k]`` for Array
+            input). Rows containing a null element are null.
+
+        Raises
+        ------
+        ComputeError
+            If a list's length does not equal ``m``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 5, 6]]})
+        >>> df.select(pl.col("a").vec.matmul([[1, 0], [0, 1], [1, 1]]))
+        shape: (2, 1)
+        ┌──────────────┐
+        │ a            │
+        │ ---          │
+        │ list[f64]    │
+        ╞══════════════╡
+        │ [4.0, 5.0]   │
+        │ [10.0, 11.0] │
+        └──────────────┘
+        """
+        rows = [[float(x) for x in row] for row in matrix]
+        if len({len(row) for row in rows}) > 1:
+            raise ValueError("all matrix rows must have the same length")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_matmul",
+            is_elementwise=True,
+            returns_scalar=False,
+            kwargs={"matrix": rows},
+        )
+
+    def cov_matrix(self, ddof: int = 1) -> pl.Expr:
+        """
+        Covariance matrix between list positions across rows.
+
+        The column is treated as an ``(n_rows x n_positions)`` matrix, like
+        ``np.cov(X, rowvar=False)``. Each entry uses the rows where both
+        positions are non-null and finite (pairwise-complete observations).
+
+        Parameters
+        ----------
+        ddof
+            Delta degrees of freedom (default 1, the sample covariance).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in
+            ``group_by``) holding the ``n_positions x n_positions`` matrix as
+            ``List[List[f64]]``. Entries with no more than ``ddof`` complete
+            rows are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [2, 4], [3, 6]]})
+        >>> df.select(pl.col("a").vec.cov_matrix())
+        shape: (1, 1)
+        ┌──────────────────────────┐
+        │ a                        │
+        │ ---                      │
+        │ list[list[f64]]          │
+        ╞══════════════════════════╡
+        │ [[1.0, 2.0], [2.0, 4.0]] │
+        └──────────────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_cov_matrix",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"ddof": ddof},
+        )
+
+    def corr_matrix(self) -> pl.Expr:
+        """
+        Pearson correlation matrix between list positions across rows.
+
+        The column is treated as an ``(n_rows x n_positions)`` matrix, like
+        ``np.corrcoef(X, rowvar=False)``, using pairwise-complete rows.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in
+            ``group_by``) holding the ``n_positions x n_positions`` matrix as
+            ``List[List[f64]]``. Entries with fewer than two complete rows are
+            null; positions with zero variance give NaN.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 3], [2, 2], [3, 1]]})
+        >>> df.select(pl.col("a").vec.corr_matrix())
+        shape: (1, 1)
+        ┌────────────────────────────┐
+        │ a                          │
+        │ ---                        │
+        │ list[list[f64]]            │
+        ╞════════════════════════════╡
+        │ [[1.0, -1.0], [-1.0, 1.0]] │
+        └────────────────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_corr_matrix",
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
+    def trend(self, return_stats: bool = False) -> pl.Expr:
+        """
+        Per-position linear trend across rows.
+
+        For each list position, fits ``value = intercept + slope * row_index``
+        by ordinary least squares over the rows, e.g. to detect drift in each
+        channel over a session. Null rows keep their row index but contribute
+        no point, and null or non-finite values are skipped.
+
+        Parameters
+        ----------
+        return_stats
+            If True, return a struct with ``slope``, ``intercept`` and ``r2``
+            lists instead of just the slopes.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            of ``List[f64]`` slopes, or a struct of such lists. Positions with
+            fewer than two finite values are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5], [2, 5], [3, 5]]})
+        >>> df.select(pl.col("a").vec.trend())
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [1.0, 0.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_trend",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"return_stats": return_stats},
+        )
+
+    def regress(self, x: IntoExprColumn) -> pl.Expr:
+        """
+        Per-position linear regression of list values against a covariate.
+
+        For each list position, fits ``value = intercept + slope * x`` by
+        ordinary least squares across rows, where ``x`` is a scalar column
+        (e.g. stimulus intensity). This gives a mass-univariate analysis
+        without exploding the frame. Rows where ``x`` or the value is null or
+        non-finite are skipped.
+
+        Parameters
+        ----------
+        x
+            Numeric column with one covariate value per row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            struct with ``slope``, ``intercept`` and ``p_value`` lists. The
+            p-value is the two-sided t-test of a zero slope with ``n - 2``
+            degrees of freedom. Positions with fewer than two usable rows are
+            null, and p-values need at least three.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 0], [3, 0], [5, 0]], "x": [0, 1, 2]})
+        >>> df.select(pl.col("a").vec.regress("x").struct.field("slope"))
+        shape: (1, 1)
+        ┌────────────┐
+        │ slope      │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [2.0, 0.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(x))],
+            plugin_path=_LIB,
+            function_name="list_regress",
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
+    def ttest(self, other: IntoExprColumn, equal_var: bool = False) -> pl.Expr:
+        """
+        Per-position two-sample t-test between this column and ``other``.
+
+        For each list position, the values of this column across rows form
+        sample ``a`` and those of ``other`` form sample ``b`` (e.g. condition
+        A vs condition B traces). Null rows and null or non-finite values are
+        dropped from each sample independently.
+
+        Parameters
+        ----------
+        other
+            List column of the same length holding the second sample.
+        equal_var
+            If False (default), use Welch's t-test; if True, use Student's
+            t-test with pooled variance.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            struct with ``statistic`` and two-sided ``p_value`` lists.
+            Positions where either sample has fewer than two values are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1], [2], [3]], "b": [[3], [4], [5]]})
+        >>> df.select(pl.col("a").vec.ttest("b").struct.field("statistic"))
+        shape: (1, 1)
+        ┌────────────┐
+        │ statistic  │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [-2.44949] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="list_ttest",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"equal_var": equal_var},
+        )
+
+    def mannwhitneyu(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Per-position Mann-Whitney U test between this column and ``other``.
+
+        The rank-based counterpart of :meth:`ttest`. The statistic is ``U``
+        for this column's sample, and the two-sided p-value uses the
+        tie-corrected normal approximation with continuity correction (as
+        ``scipy.stats.mannwhitneyu(..., method="asymptotic")``).
+
+        Parameters
+        ----------
+        other
+            List column of the same length holding the second sample.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            struct with ``statistic`` and ``p_value`` lists. Positions where
+            either sample is empty are null.
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="list_mannwhitneyu",
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
+    def ttest_1samp(self, popmean: float = 0.0) -> pl.Expr:
+        """
+        Per-position one-sample t-test against a constant.
+
+        For each list position, tests whether the values across rows differ
+        from ``popmean``. Null rows and null or non-finite values are dropped.
+
+        Parameters
+        ----------
+        popmean
+            Expected mean under the null hypothesis (default 0).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            struct with ``statistic`` and two-sided ``p_value`` lists.
+            Positions with fewer than two values are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [2, 2], [3, 5]]})
+        >>> df.select(pl.col("a").vec.ttest_1samp(2).struct.field("statistic"))
+        shape: (1, 1)
+        ┌────────────┐
+        │ statistic  │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [0.0, 1.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_ttest_1samp",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"popmean": float(popmean)},
+        )
+
+    def mean_ci(
+        self, n_boot: int = 1000, ci: float = 0.95, seed: int | None = None
+    ) -> pl.Expr:
+        """
+        Vertical mean with bootstrap confidence intervals per position.
+
+        Non-null rows are resampled with replacement ``n_boot`` times, and the
+        interval is the central ``ci`` fraction (percentile method) of the
+        replicate means at each position. Null and non-finite elements are
+        skipped when averaging.
+
+        Parameters
+        ----------
+        n_boot
+            Number of bootstrap replicates.
+        ci
+            Confidence level, between 0 and 1 (default 0.95).
+        seed
+            Seed for the random generator. ``None`` (default) draws a fresh
+            seed each time; pass an integer for reproducible intervals.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            struct with ``mean``, ``lower`` and ``upper`` lists.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5], [2, 5], [3, 5]]})
+        >>> df.select(pl.col("a").vec.mean_ci(seed=0).struct.field("mean"))
+        shape: (1, 1)
+        ┌────────────┐
+        │ mean       │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [2.0, 5.0] │
+        └────────────┘
+        """
+        if not 0 < ci < 1:
+            raise ValueError("ci must be between 0 and 1")
+        if n_boot < 1:
+            raise ValueError("n_boot must be positive")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_mean_ci",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"n_boot": n_boot, "ci": float(ci), "seed": seed},
+        )
+
+    def permutation_test(
+        self,
+        other: IntoExprColumn | None = None,
+        n_perm: int = 1000,
+        statistic: str = "mean",
+        seed: int | None = None,
+    ) -> pl.Expr:
+        """
+        Per-position permutation test across rows.
+
+        Without ``other``, tests whether each position's mean differs from
+        zero by randomly flipping the sign of whole rows. With ``other``, tests
+        whether the two columns differ by shuffling whole rows between the two
+        groups. Permuting whole rows preserves the dependence between
+        positions.
+
+        Parameters
+        ----------
+        other
+            Optional second list column of the same length.
+        n_perm
+            Number of permutations.
+        statistic
+            ``"mean"`` (default) for the mean (or difference of means), or
+            ``"t"`` for the one-sample or Welch t statistic.
+        seed
+            Seed for the random generator. ``None`` (default) draws a fresh
+            seed each time.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            struct with the observed ``statistic`` and two-sided ``p_value``
+            lists, where ``p = (1 + #{|null| >= |observed|}) / (1 + n_perm)``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 0], [2, 0], [3, 0]]})
+        >>> df.select(pl.col("a").vec.permutation_test(seed=0).struct.field("statistic"))
+        shape: (1, 1)
+        ┌────────────┐
+        │ statistic  │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [2.0, 0.0] │
+        └────────────┘
+        """
+        if statistic not in ("mean", "t"):
+            raise ValueError("statistic must be 'mean' or 't'")
+        if n_perm < 1:
+            raise ValueError("n_perm must be positive")
+        args = [self._expr]
+        if other is not None:
+            args.append(wrap_expr(parse_into_expression(other)))
+        return register_plugin_function(
+            args=args,
+            plugin_path=_LIB,
+            function_name="list_permutation_test",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"n_perm": n_perm, "statistic": statistic, "seed": seed},
+        )
+
+    def mann_kendall(self) -> pl.Expr:
+        """
+        Per-position Mann-Kendall trend test across rows.
+
+        A nonparametric, rank-based alternative to :meth:`trend`: for each
+        list position, counts concordant minus discordant pairs of rows
+        (``S``) in row order. Null rows and null or non-finite values are
+        skipped.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            struct with ``statistic`` (``S``), ``tau`` (Kendall's tau-a) and
+            two-sided ``p_value`` lists. The p-value uses the tie-corrected
+            normal approximation with continuity correction. Positions with
+            fewer than two values are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 3], [2, 2], [3, 1]]})
+        >>> df.select(pl.col("a").vec.mann_kendall().struct.field("tau"))
+        shape: (1, 1)
+        ┌─────────────┐
+        │ tau         │
+        │ ---         │
+        │ list[f64]   │
+        ╞═════════════╡
+        │ [1.0, -1.0] │
+        └─────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_mann_kendall",
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
+    def entropy(self, bins: int = 10, base: float | None = None) -> pl.Expr:
+        """
+        Per-position Shannon entropy of the values across rows.
+
+        Integer columns count each distinct value exactly. Float columns are
+        first binned into ``bins`` equal-width bins between the position's
+        minimum and maximum. Null and non-finite values are skipped. Useful
+        for finding informative channels.
+
+        Parameters
+        ----------
+        bins
+            Number of bins for float columns (ignored for integers).
+        base
+            Logarithm base; ``None`` (default) uses the natural log, ``2``
+            gives bits.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            of ``List[f64]`` entropies. Positions with no values are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 1], [2, 1], [3, 1], [4, 1]]})
+        >>> df.select(pl.col("a").vec.entropy(base=2))
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [2.0, 0.0] │
+        └────────────┘
+        """
+        if bins < 1:
+            raise ValueError("bins must be positive")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_entropy",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"bins": bins, "base": None if base is None else float(base)},
+        )
+
+    def percentile_rank(self) -> pl.Expr:
+        """
+        Percentile of each value among all rows at the same position.
+
+        Row-preserving: every element is replaced by its percentile (0-100)
+        within its position's column of values, so each trial can be
+        normalized relative to the population. Ties count half, i.e.
+        ``100 * (n_less + n_equal / 2) / n`` (``kind="mean"`` in
+        ``scipy.stats.percentileofscore``).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array input). Null and non-finite values stay null and are not
+            counted.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [2, 2]]})
+        >>> df.select(pl.col("a").vec.percentile_rank())
+        shape: (2, 1)
+        ┌──────────────┐
+        │ a            │
+        │ ---          │
+        │ list[f64]    │
+        ╞══════════════╡
+        │ [25.0, 50.0] │
+        │ [75.0, 50.0] │
+        └──────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_percentile_rank",
+            is_elementwise=False,
+            returns_scalar=False,
+        )
+
+    def top_k_rows(self, k: int, return_indices: bool = False) -> pl.Expr:
+        """
+        Per-position k largest values across rows.
+
+        For each list position, collects the ``k`` largest values over all
+        rows in descending order, e.g. to inspect the most extreme trials per
+        channel. Null and NaN values are skipped, and ties keep row order.
+
+        Parameters
+        ----------
+        k
+            Number of values to keep per position.
+        return_indices
+            If True, return a struct with ``values`` and the row ``indices``
+            (``UInt32``, counting every row of the input or group).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            of ``List[List[inner]]``: one descending list per position, shorter
+            than ``k`` if the position has fewer values.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5], [4, 2], [3, 8]]})
+        >>> df.select(pl.col("a").vec.top_k_rows(2))
+        shape: (1, 1)
+        ┌──────────────────┐
+        │ a                │
+        │ ---              │
+        │ list[list[i64]]  │
+        ╞══════════════════╡
+        │ [[4, 3], [8, 5]] │
+        └──────────────────┘
+        """
+        if k < 0:
+            raise ValueError("k must be non-negative")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_top_k",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"k": k, "return_indices": return_indices},
+        )
+
+    def is_max(self) -> pl.Expr:
+        """
+        Mark elements equal to the vertical maximum at their position.
+
+        Row-preserving: each element becomes True if it equals the maximum
+        over all rows at its list position (all tied rows are marked), which
+        locates the winning trial per channel. NaN values are never marked
+        and null elements stay null.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[bool]`` (``Array[bool, width]`` for
+            Array input).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5], [4, 2]]})
+        >>> df.select(pl.col("a").vec.is_max())
+        shape: (2, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ list[bool]    │
+        ╞═══════════════╡
+        │ [false, true] │
+        │ [true, false] │
+        └───────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_is_max",
+            is_elementwise=False,
+            returns_scalar=False,
+        )
+
+    def is_min(self) -> pl.Expr:
+        """
+        Mark elements equal to the vertical minimum at their position.
+
+        The counterpart of :meth:`is_max`.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[bool]`` (``Array[bool, width]`` for
+            Array input).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5], [4, 2]]})
+        >>> df.select(pl.col("a").vec.is_min())
+        shape: (2, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ list[bool]    │
+        ╞═══════════════╡
+        │ [true, false] │
+        │ [false, true] │
+        └───────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_is_min",
+            is_elementwise=False,
+            returns_scalar=False,
+        )
+
+    def eq(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Element-wise ``==`` between this column and ``other``.
+
+        Compares each row's list with the matching row of ``other``, which
+        may be a List/Array column of the same per-row length or a numeric
+        column broadcast across the row. Useful for building masks for
+        masked aggregation. Both sides are compared in their supertype, so
+        large integers compare exactly. A null on either side gives null; NaN
+        compares as in polars, equal to itself and greater than any number.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length in each row, or a numeric
+            column or literal compared against every element of the row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[bool]`` (``Array[bool, width]`` for
+            Array input).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5], [4, 2]], "b": [[2, 5], [3, 3]]})
+        >>> df.select(pl.col("a").vec.eq("b"))
+        shape: (2, 1)
+        ┌────────────────┐
+        │ a              │
+        │ ---            │
+        │ list[bool]     │
+        ╞════════════════╡
+        │ [false, true]  │
+        │ [false, false] │
+        └────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_eq",
+            is_elementwise=True,
+        )
+
+    def ne(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Element-wise ``!=`` between this column and ``other``.
+
+        See :meth:`eq` for how ``other`` is matched and how nulls and NaN
+        are handled.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length in each row, or a numeric
+            column or literal compared against every element of the row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[bool]`` (``Array[bool, width]`` for
+            Array input).
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_ne",
+            is_elementwise=True,
+        )
+
+    def gt(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Element-wise ``>`` between this column and ``other``.
+
+        See :meth:`eq` for how ``other`` is matched and how nulls and NaN
+        are handled.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length in each row, or a numeric
+            column or literal compared against every element of the row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[bool]`` (``Array[bool, width]`` for
+            Array input).
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_gt",
+            is_elementwise=True,
+        )
+
+    def ge(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Element-wise ``>=`` between this column and ``other``.
+
+        See :meth:`eq` for how ``other`` is matched and how nulls and NaN
+        are handled.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length in each row, or a numeric
+            column or literal compared against every element of the row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[bool]`` (``Array[bool, width]`` for
+            Array input).
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_ge",
+            is_elementwise=True,
+        )
+
+    def lt(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Element-wise ``<`` between this column and ``other``.
+
+        See :meth:`eq` for how ``other`` is matched and how nulls and NaN
+        are handled.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length in each row, or a numeric
+            column or literal compared against every element of the row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[bool]`` (``Array[bool, width]`` for
+            Array input).
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_lt",
+            is_elementwise=True,
+        )
+
+    def le(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Element-wise ``<=`` between this column and ``other``.
+
+        See :meth:`eq` for how ``other`` is matched and how nulls and NaN
+        are handled.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length in each row, or a numeric
+            column or literal compared against every element of the row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[bool]`` (``Array[bool, width]`` for
+            Array input).
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_le",
+            is_elementwise=True,
+        )
+
+    def where(self, mask: IntoExprColumn, other: IntoExprColumn) -> pl.Expr:
+        """
+        Choose elements from this column or ``other`` by a Boolean list mask.
+
+        Within each row, position ``i`` takes this column's element where
+        ``mask[i]`` is True and ``other``'s element where it is False, without
+        exploding. Combine with :meth:`gt` and friends to build the mask. All
+        three lists must have the same length in each row; a null row in any
+        input gives a null row and a null mask element gives a null element.
+
+        Parameters
+        ----------
+        mask
+            List/Array column of booleans.
+        other
+            List/Array column supplying the elements where ``mask`` is False,
+            or a numeric column or literal used for every such element.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of the common supertype of both inputs
+            (``Array`` for Array input).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1, 5], [4, 2]],
+        ...         "m": [[True, False], [False, True]],
+        ...         "b": [[0, 0], [9, 9]],
+        ...     }
+        ... )
+        >>> df.select(pl.col("a").vec.where("m", "b"))
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 0]    │
+        │ [9, 2]    │
+        └───────────┘
+        """
+        return register_plugin_function(
+            args=[
+                self._expr,
+                wrap_expr(parse_into_expression(mask)),
+                wrap_expr(parse_into_expression(other)),
+            ],
+            plugin_path=_LIB,
+            function_name="vec_where",
+            is_elementwise=True,
+        )
+
+    def min_horizontal(self, *others: IntoExprColumn) -> pl.Expr:
+        """
+        Element-wise minimum across this and other list columns, per row.
+
+        Position ``i`` of each output row is the smallest of the inputs'
+        elements at ``i`` in that row. This is horizontal, unlike the
+        vertical :meth:`min` which reduces across rows. Null elements are
+        ignored (a position is null only when null in every input), a null
+        row in any input gives a null row, and every list in a row must have
+        the same length.
+
+        Parameters
+        ----------
+        *others
+            One or more List/Array columns, or numeric columns or literals
+            broadcast across every position (e.g. ``0`` as a floor).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of the inputs' common supertype
+            (``Array`` for Array input).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5], [4, 2]], "b": [[3, 2], [0, 9]]})
+        >>> df.select(pl.col("a").vec.min_horizontal("b"))
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 2]    │
+        │ [0, 2]    │
+        └───────────┘
+        """
+        if not others:
+            raise ValueError("Must specify at least one other column")
+        return register_plugin_function(
+            args=[self._expr, *(wrap_expr(e) for e in parse_into_list_of_expressions(*others))],
+            plugin_path=_LIB,
+            function_name="vec_min_horizontal",
+            is_elementwise=True,
+        )
+
+    def max_horizontal(self, *others: IntoExprColumn) -> pl.Expr:
+        """
+        Element-wise maximum across this and other list columns, per row.
+
+        The counterpart of :meth:`min_horizontal`.
+
+        Parameters
+        ----------
+        *others
+            One or more List/Array columns, or numeric columns or literals
+            broadcast across every position (e.g. ``0`` as a floor).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of the inputs' common supertype
+            (``Array`` for Array input).
+        """
+        if not others:
+            raise ValueError("Must specify at least one other column")
+        return register_plugin_function(
+            args=[self._expr, *(wrap_expr(e) for e in parse_into_list_of_expressions(*others))],
+            plugin_path=_LIB,
+            function_name="vec_max_horizontal",
+            is_elementwise=True,
+        )
+
+    def fma(self, b: IntoExprColumn, c: IntoExprColumn) -> pl.Expr:
+        """
+        Fused multiply-add ``self * b + c`` element-wise within each row.
+
+        Computes the result in a single kernel pass, avoiding the two
+        intermediate list columns of composing a multiply and an add (e.g.
+        applying a per-trial gain and offset). All three lists must have the
+        same length in each row; a null row or element in any input gives a
+        null row or element.
+
+        Parameters
+        ----------
+        b
+            List/Array column of multipliers, or a numeric column or literal
+            broadcast across the row.
+        c
+            List/Array column of addends, or a numeric column or literal
+            broadcast across the row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array input).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2], [3, 4]], "b": [[2, 2], [0, 1]], "c": [[1, 1], [5, 5]]}
+        ... )
+        >>> df.select(pl.col("a").vec.fma("b", "c"))
+        shape: (2, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [3.0, 5.0] │
+        │ [5.0, 9.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[
+                self._expr,
+                wrap_expr(parse_into_expression(b)),
+                wrap_expr(parse_into_expression(c)),
+            ],
+            plugin_path=_LIB,
+            function_name="vec_fma",
+            is_elementwise=True,
+        )
+
+    def add_const(self, values: Sequence[float]) -> pl.Expr:
+        """
+        Add a constant vector element-wise to each row's list.
+
+        Useful for channel-wise offset calibration vectors that are not
+        stored as a column. Null rows and elements stay null.
+
+        Parameters
+        ----------
+        values
+            Sequence (or 1-D numpy array) with one value per list position.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array input).
+
+        Raises
+        ------
+        ComputeError
+            If a list's length does not equal ``len(values)``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 4]]})
+        >>> df.select(pl.col("a").vec.add_const([10, -1]))
+        shape: (2, 1)
+        ┌─────────────┐
+        │ a           │
+        │ ---         │
+        │ list[f64]   │
+        ╞═════════════╡
+        │ [11.0, 1.0] │
+        │ [13.0, 3.0] │
+        └─────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_add_const",
+            is_elementwise=True,
+            kwargs={"values": [float(x) for x in values]},
+        )
+
+    def mul_const(self, values: Sequence[float]) -> pl.Expr:
+        """
+        Multiply each row's list element-wise by a constant vector.
+
+        Useful for channel-wise gain calibration vectors that are not stored
+        as a column. Null rows and elements stay null.
+
+        Parameters
+        ----------
+        values
+            Sequence (or 1-D numpy array) with one value per list position.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array input).
+
+        Raises
+        ------
+        ComputeError
+            If a list's length does not equal ``len(values)``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 4]]})
+        >>> df.select(pl.col("a").vec.mul_const([2, 0.5]))
+        shape: (2, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [2.0, 1.0] │
+        │ [6.0, 2.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_mul_const",
+            is_elementwise=True,
+            kwargs={"values": [float(x) for x in values]},
+        )
+
+    def normalize_vertical(self, method: str = "mean", mode: str = "divide") -> pl.Expr:
+        """
+        Normalize each row by the vertical aggregate at each position.
+
+        Row-preserving: every element is divided by (or has subtracted) the
+        mean, max or sum of its position across all rows (or the group in
+        ``group_by``), computed in a single pass. Nulls are skipped when
+        aggregating and stay null in the output.
+
+        Parameters
+        ----------
+        method
+            Vertical aggregate to normalize by: ``"mean"``, ``"max"`` or
+            ``"sum"``.
+        mode
+            ``"divide"`` to scale each element by the aggregate, or
+            ``"subtract"`` to center it.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array input).
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 6]]})
+        >>> df.select(pl.col("a").vec.normalize_vertical(method="max"))
+        shape: (2, 1)
+        ┌──────────────────────┐
+        │ a                    │
+        │ ---                  │
+        │ list[f64]            │
+        ╞══════════════════════╡
+        │ [0.333333, 0.333333] │
+        │ [1.0, 1.0]           │
+        └──────────────────────┘
+        """
+        if method not in ("mean", "max", "sum"):
+            raise ValueError(
+                f"method must be one of 'mean', 'max', 'sum', got {method!r}"
+            )
+        if mode not in ("divide", "subtract"):
+            raise ValueError(f"mode must be one of 'divide', 'subtract', got {mode!r}")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_normalize_vertical",
+            is_elementwise=False,
+            returns_scalar=False,
+            kwargs={"method": method, "mode": mode},
+        )
+
+    def cum_std(self, ddof: int = 1) -> pl.Expr:
+        """
+        Expanding standard deviation down the rows at each position.
+
+        Row ``i`` holds, per position, the standard deviation of the non-null
+        values in rows ``0..=i`` (of the group in ``group_by``), computed in a
+        single pass with Welford's algorithm for numerical stability.
+
+        Parameters
+        ----------
+        ddof
+            Delta degrees of freedom; the divisor is ``n - ddof``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array input). Positions with no more than ``ddof`` values so far
+            are null, and null rows stay null.
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 2], [5, 2]]})
+        >>> df.select(pl.col("a").vec.cum_std())
+        shape: (3, 1)
+        ┌─────────────────┐
+        │ a               │
+        │ ---             │
+        │ list[f64]       │
+        ╞═════════════════╡
+        │ [null, null]    │
+        │ [1.414214, 0.0] │
+        │ [2.0, 0.0]      │
+        └─────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_cum_std",
+            is_elementwise=False,
+            returns_scalar=False,
+            kwargs={"ddof": ddof},
+        )
+
+    def cum_count(self) -> pl.Expr:
+        """
+        Expanding count of non-null values down the rows at each position.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[u32]`` (``Array[u32, width]`` for
+            Array input). Null rows stay null.
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, None], [3, 2]]})
+        >>> df.select(pl.col("a").vec.cum_count())
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[u32] │
+        ╞═══════════╡
+        │ [1, 0]    │
+        │ [2, 1]    │
+        └───────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_cum_count",
+            is_elementwise=False,
+            returns_scalar=False,
+        )
+
+    def mean_std(self, ddof: int = 1) -> pl.Expr:
+        """
+        Vertical mean and standard deviation per position in one pass.
+
+        Both statistics come from a single streaming pass over the rows using
+        Welford's algorithm, so large columns are read once and the result
+        stays accurate when values have a large offset. Null elements are
+        skipped.
+
+        Parameters
+        ----------
+        ddof
+            Delta degrees of freedom for the standard deviation; the divisor
+            is ``n - ddof``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            struct with ``mean`` and ``std`` lists. Positions without values
+            have a null mean, and positions with no more than ``ddof`` values
+            a null std.
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 2], [5, 2]]})
+        >>> df.select(pl.col("a").vec.mean_std()).unnest("a")
+        shape: (1, 2)
+        ┌────────────┬────────────┐
+        │ mean       ┆ std        │
+        │ ---        ┆ ---        │
+        │ list[f64]  ┆ list[f64]  │
+        ╞════════════╪════════════╡
+        │ [3.0, 2.0] ┆ [2.0, 0.0] │
+        └────────────┴────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_mean_std",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"ddof": ddof},
+        )
+
+    def cv(
+        self, ddof: int = 1, zero_tol: float = 1e-12, on_zero_mean: str = "null"
+    ) -> pl.Expr:
+        """
+        Coefficient of variation (std / mean) across rows at each position.
+
+        A normalized measure of trial-to-trial variability, computed from the
+        same single Welford pass as :meth:`mean_std`. Null elements are
+        skipped.
+
+        Parameters
+        ----------
+        ddof
+            Delta degrees of freedom for the standard deviation.
+        zero_tol
+            Means with an absolute value at or below this are treated as zero,
+            where the coefficient of variation is undefined.
+        on_zero_mean
+            What to return at positions with a zero mean: ``"null"``
+            (default), ``"nan"``, or ``"raise"`` to fail with an error.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            of ``List[f64]`` (``Array[f64, width]`` for Array input).
+            Positions with no more than ``ddof`` values are null.
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length, or a mean is zero with
+            ``on_zero_mean="raise"``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 2], [5, 2]]})
+        >>> df.select(pl.col("a").vec.cv())
+        shape: (1, 1)
+        ┌─────────────────┐
+        │ a               │
+        │ ---             │
+        │ list[f64]       │
+        ╞═════════════════╡
+        │ [0.666667, 0.0] │
+        └─────────────────┘
+        """
+        if on_zero_mean not in ("null", "nan", "raise"):
+            raise ValueError(
+                "on_zero_mean must be one of 'null', 'nan', 'raise', "
+                f"got {on_zero_mean!r}"
+            )
+        if zero_tol < 0:
+            raise ValueError("zero_tol must be non-negative")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_cv",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={
+                "ddof": ddof,
+                "zero_tol": float(zero_tol),
+                "on_zero_mean": on_zero_mean,
+            },
+        )
+
+    def split_half_reliability(
+        self, n_splits: int = 100, seed: int | None = None
+    ) -> pl.Expr:
+        """
+        Split-half reliability of the per-position profile across rows.
+
+        Each split randomly divides the non-null rows into two halves,
+        averages each half per position and correlates the two mean profiles
+        across positions. The Spearman-Brown corrected correlation
+        ``2r / (1 + r)`` is averaged over ``n_splits`` splits, estimating how
+        reproducible the trial-averaged profile is.
+
+        Parameters
+        ----------
+        n_splits
+            Number of random splits to average over.
+        seed
+            Seed for the random generator. ``None`` (default) draws a fresh
+            seed each time; pass an integer for reproducible results.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single ``f64`` (one per group in
+            ``group_by``). Null when there are fewer than two non-null rows or
+            no split gives a defined correlation.
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5, 2], [1, 4, 2], [2, 5, 1], [1, 5, 3]]})
+        >>> df.select(pl.col("a").vec.split_half_reliability(seed=0).round(3))
+        shape: (1, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ f64   │
+        ╞═══════╡
+        │ 0.969 │
+        └───────┘
+        """
+        if n_splits < 1:
+            raise ValueError("n_splits must be positive")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_split_half_reliability",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"n_splits": n_splits, "seed": seed},
+        )
+
+    def icc(self, kind: str = "icc2") -> pl.Expr:
+        """
+        Intraclass correlation across rows, or Cronbach's alpha.
+
+        Rows are treated as repeated measurements (raters, sessions, trials)
+        of the per-position targets, and the statistic is computed in closed
+        form from the two-way ANOVA mean squares (Shrout & Fleiss, 1979).
+        Null rows are skipped, and only positions with finite values in every
+        remaining row are used.
+
+        Parameters
+        ----------
+        kind
+            ``"icc1"`` (one-way random), ``"icc2"`` (two-way random, absolute
+            agreement), ``"icc3"`` (two-way mixed, consistency), all
+            single-measure, or ``"alpha"`` for Cronbach's alpha (ICC(3,k)).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single ``f64`` (one per group in
+            ``group_by``). Null with fewer than two rows or positions.
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [
+        ...             [9, 6, 8, 7, 10, 6],
+        ...             [2, 1, 4, 1, 5, 2],
+        ...             [5, 3, 6, 2, 6, 4],
+        ...             [8, 2, 8, 6, 9, 7],
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(pl.col("a").vec.icc("icc3").round(2))
+        shape: (1, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 0.71 │
+        └──────┘
+        """
+        if kind not in ("icc1", "icc2", "icc3", "alpha"):
+            raise ValueError(
+                f"kind must be one of 'icc1', 'icc2', 'icc3', 'alpha', got {kind!r}"
+            )
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_icc",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"kind": kind},
+        )
+
+    def pairwise_distance(
+        self, metric: str = "euclidean", long: bool = False
+    ) -> pl.Expr:
+        """
+        Distance between every pair of rows, treating each list as a vector.
+
+        Produces the full rows x rows distance matrix of an embedding or
+        feature column, e.g. for clustering or representational similarity
+        analysis. Metrics follow ``scipy.spatial.distance``.
+
+        Parameters
+        ----------
+        metric
+            ``"euclidean"``, ``"sqeuclidean"``, ``"cityblock"``,
+            ``"chebyshev"``, ``"cosine"`` or ``"correlation"``.
+        long
+            If True, return one row per pair ``i < j`` as a struct with
+            ``i``, ``j`` (``UInt32`` row indices) and ``distance`` instead of
+            the matrix.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            of ``List[List[f64]]`` holding the symmetric matrix, or with
+            ``long=True`` a struct column of ``n * (n - 1) / 2`` rows. Pairs
+            involving a null row or null element have a null distance.
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0, 0], [3, 4], [6, 8]]})
+        >>> df.select(pl.col("a").vec.pairwise_distance(long=True)).unnest("a")
+        shape: (3, 3)
+        ┌─────┬─────┬──────────┐
+        │ i   ┆ j   ┆ distance │
+        │ --- ┆ --- ┆ ---      │
+        │ u32 ┆ u32 ┆ f64      │
+        ╞═════╪═════╪══════════╡
+        │ 0   ┆ 1   ┆ 5.0      │
+        │ 0   ┆ 2   ┆ 10.0     │
+        │ 1   ┆ 2   ┆ 5.0      │
+        └─────┴─────┴──────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_pairwise_distance",
+            is_elementwise=False,
+            returns_scalar=not long,
+            changes_length=long,
+            kwargs={"metric": _check_metric(metric), "long": long},
+        )
+
+    def distance_to(
+        self,
+        reference: Sequence[float] | IntoExprColumn,
+        metric: str = "euclidean",
+    ) -> pl.Expr:
+        """
+        Distance from each row's vector to a reference vector.
+
+        Scores every row against a fixed vector, e.g. for novelty or outlier
+        detection on embeddings. The reference is either a constant sequence
+        or a list column with a single row, such as a vertical mean, or one
+        row per input row.
+
+        Parameters
+        ----------
+        reference
+            Sequence (or 1-D numpy array) with one value per list position, or
+            a List/Array expression or column name.
+        metric
+            ``"euclidean"``, ``"sqeuclidean"``, ``"cityblock"``,
+            ``"chebyshev"``, ``"cosine"`` or ``"correlation"``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one ``f64`` per row. Null rows, null
+            references and rows holding a null element give null.
+
+        Raises
+        ------
+        ComputeError
+            If a list's length does not match the reference's.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0, 0], [3, 4], [6, 8]]})
+        >>> df.with_columns(
+        ...     dist=pl.col("a").vec.distance_to(pl.col("a").vec.mean())
+        ... )
+        shape: (3, 2)
+        ┌───────────┬──────┐
+        │ a         ┆ dist │
+        │ ---       ┆ ---  │
+        │ list[i64] ┆ f64  │
+        ╞═══════════╪══════╡
+        │ [0, 0]    ┆ 5.0  │
+        │ [3, 4]    ┆ 0.0  │
+        │ [6, 8]    ┆ 5.0  │
+        └───────────┴──────┘
+        """
+        kwargs = {"metric": _check_metric(metric), "reference": None}
+        args = [self._expr]
+        if isinstance(reference, (str, pl.Expr, pl.Series)):
+            args.append(wrap_expr(parse_into_expression(reference)))
+        else:
+            kwargs["reference"] = [float(x) for x in reference]
+        return register_plugin_function(
+            args=args,
+            plugin_path=_LIB,
+            function_name="vec_distance_to",
+            is_elementwise=len(args) == 1,
+            kwargs=kwargs,
+        )
+
+    def assign_clusters(
+        self, centroids: Sequence[Sequence[float]], metric: str = "euclidean"
+    ) -> pl.Expr:
+        """
+        Label each row with the index of its nearest centroid.
+
+        Inference-only k-means style assignment: given ``k`` centroid vectors
+        (e.g. fitted elsewhere), each row's list is assigned to the closest
+        one. Ties go to the lower index.
+
+        Parameters
+        ----------
+        centroids
+            ``k`` centroid vectors (or a 2-D numpy array of shape
+            ``(k, width)``), each with one value per list position.
+        metric
+            ``"euclidean"``, ``"sqeuclidean"``, ``"cityblock"``,
+            ``"chebyshev"``, ``"cosine"`` or ``"correlation"``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one ``UInt32`` label per row. Null rows and
+            rows holding a null element are null.
+
+        Raises
+        ------
+        ComputeError
+            If a list's length does not match the centroids'.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0, 1], [9, 8], [1, 0]]})
+        >>> df.select(pl.col("a").vec.assign_clusters([[0, 0], [10, 10]]))
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 0   │
+        │ 1   │
+        │ 0   │
+        └─────┘
+        """
+        centroids = [[float(x) for x in c] for c in centroids]
+        if not centroids:
+            raise ValueError("centroids must not be empty")
+        if len({len(c) for c in centroids}) != 1:
+            raise ValueError("all centroids must have the same length")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_assign_clusters",
+            is_elementwise=True,
+            kwargs={"centroids": centroids, "metric": _check_metric(metric)},
+        )
+
+    def corr(self, other: IntoExprColumn, method: str = "pearson") -> pl.Expr:
+        """
+        Correlation between this list and another within each row.
+
+        Row-wise counterpart of :meth:`corr_matrix`: for every row, correlates
+        the two lists position by position, over the positions where both
+        values are non-null. A NaN makes the row NaN, as does an infinite
+        value for Pearson; Spearman ranks infinities like any other value.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length per row.
+        method
+            ``"pearson"`` or ``"spearman"`` (Pearson correlation of the ranks,
+            with tied values sharing their average rank).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one ``f64`` per row. Null for null rows or
+            fewer than two usable positions; NaN when a list is constant.
+
+        Raises
+        ------
+        ComputeError
+            If the two lists in a row differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [1, 2, 3]], "b": [[2, 4, 7], [3, 2, 1]]}
+        ... )
+        >>> df.select(pl.col("a").vec.corr("b", method="spearman"))
+        shape: (2, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 1.0  │
+        │ -1.0 │
+        └──────┘
+        """
+        if method not in ("pearson", "spearman"):
+            raise ValueError(
+                f"method must be one of 'pearson', 'spearman', got {method!r}"
+            )
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_corr",
+            is_elementwise=True,
+            kwargs={"method": method},
+        )
+
+    def rmse(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Root-mean-square error between this list and another within each row.
+
+        Compares e.g. predicted and observed traces row by row, over the
+        positions where both values are non-null. NaN and infinite values
+        propagate into the result.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length per row, or a numeric
+            column or literal broadcast across each row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one ``f64`` per row. Null for null rows or
+            rows without usable positions.
+
+        Raises
+        ------
+        ComputeError
+            If the two lists in a row differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"obs": [[1, 2, 3]], "pred": [[1, 2, 5]]})
+        >>> df.select(pl.col("obs").vec.rmse("pred").round(3))
+        shape: (1, 1)
+        ┌───────┐
+        │ obs   │
+        │ ---   │
+        │ f64   │
+        ╞═══════╡
+        │ 1.155 │
+        └───────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_rmse",
+            is_elementwise=True,
+        )
+
+    def mae(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Mean absolute error between this list and another within each row.
+
+        Uses the positions where both values are non-null; NaN and infinite
+        values propagate into the result.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length per row, or a numeric
+            column or literal broadcast across each row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one ``f64`` per row. Null for null rows or
+            rows without usable positions.
+
+        Raises
+        ------
+        ComputeError
+            If the two lists in a row differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"obs": [[1, 2, 3]], "pred": [[1, 2, 5]]})
+        >>> df.select(pl.col("obs").vec.mae("pred").round(3))
+        shape: (1, 1)
+        ┌───────┐
+        │ obs   │
+        │ ---   │
+        │ f64   │
+        ╞═══════╡
+        │ 0.667 │
+        └───────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_mae",
+            is_elementwise=True,
+        )
+
+    def r2(self, predicted: IntoExprColumn) -> pl.Expr:
+        """
+        Coefficient of determination of predictions within each row.
+
+        Treats this list as the observations and ``predicted`` as the model
+        output, computing ``1 - SS_res / SS_tot`` row by row (as
+        ``sklearn.metrics.r2_score``) over the positions where both values are
+        non-null. NaN and infinite values propagate into the result.
+
+        Parameters
+        ----------
+        predicted
+            List/Array column with the same length per row, or a numeric
+            column or literal broadcast across each row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one ``f64`` per row. Null for null rows or
+            rows without usable positions; NaN when the observations are
+            constant.
+
+        Raises
+        ------
+        ComputeError
+            If the two lists in a row differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"obs": [[1, 2, 3]], "pred": [[1, 2, 4]]})
+        >>> df.select(pl.col("obs").vec.r2("pred"))
+        shape: (1, 1)
+        ┌─────┐
+        │ obs │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 0.5 │
+        └─────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(predicted))],
+            plugin_path=_LIB,
+            function_name="vec_r2",
+            is_elementwise=True,
+        )
+
+    def self_similarity(self, long: bool = False) -> pl.Expr:
+        """
+        Cosine similarity of every row against every other row.
+
+        Builds the rows x rows similarity matrix of a list column, e.g. for
+        representational similarity analysis. Equivalent to
+        ``1 - pairwise_distance(metric="cosine")``.
+
+        Parameters
+        ----------
+        long
+            If True, return one row per pair ``i < j`` as a struct with
+            ``i``, ``j`` (``UInt32`` row indices) and ``similarity`` instead of
+            the matrix.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            of ``List[List[f64]]`` holding the symmetric matrix, or with
+            ``long=True`` a struct column of ``n * (n - 1) / 2`` rows. Pairs
+            involving a null row or null element are null, and zero vectors
+            give NaN.
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 0], [3, 4]]})
+        >>> df.select(pl.col("a").vec.self_similarity())
+        shape: (1, 1)
+        ┌──────────────────────────┐
+        │ a                        │
+        │ ---                      │
+        │ list[list[f64]]          │
+        ╞══════════════════════════╡
+        │ [[1.0, 0.6], [0.6, 1.0]] │
+        └──────────────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_self_similarity",
+            is_elementwise=False,
+            returns_scalar=not long,
+            changes_length=long,
+            kwargs={"long": long},
+        )
+
+    def cross(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        3D cross product of this vector with another in each row.
+
+        Both columns must be ``Array(_, 3)`` (e.g. orientation or position
+        vectors); the width is checked when the query plan is built, so a
+        mismatch fails before any data is read.
+
+        Parameters
+        ----------
+        other
+            ``Array(_, 3)`` column, or a single-row one (such as a literal
+            axis) used for every row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``Array[f64, 3]``. A null row in either input
+            gives a null row, and a null element nulls the components that
+            depend on it.
+
+        Raises
+        ------
+        InvalidOperationError
+            If either input is not a numeric ``Array(_, 3)``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 0, 0], [1, 2, 3]], "b": [[0, 1, 0], [4, 5, 6]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3), "b": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.select(pl.col("a").vec.cross("b"))
+        shape: (2, 1)
+        ┌───────────────────┐
+        │ a                 │
+        │ ---               │
+        │ array[f64, 3]     │
+        ╞═══════════════════╡
+        │ [0.0, 0.0, 1.0]   │
+        │ [-3.0, 6.0, -3.0] │
+        └───────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_cross",
+            is_elementwise=True,
+        )
+
+    def outer(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Outer product of this list with another in each row.
+
+        Row ``p`` of each result holds ``a[p] * b``, giving an ``m x n``
+        nested list per row, e.g. to build per-trial design matrices. The two
+        lists may differ in length.
+
+        Parameters
+        ----------
+        other
+            List/Array column.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[List[f64]]``. A null row in either
+            input gives a null row, and null elements give null entries.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2]], "b": [[1, 10]]})
+        >>> df.select(pl.col("a").vec.outer("b"))
+        shape: (1, 1)
+        ┌────────────────────────────┐
+        │ a                          │
+        │ ---                        │
+        │ list[list[f64]]            │
+        ╞════════════════════════════╡
+        │ [[1.0, 10.0], [2.0, 20.0]] │
+        └────────────────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_outer",
+            is_elementwise=True,
+        )
+
+    def project(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Vector projection of this list onto another in each row.
+
+        Computes ``(a · b / b · b) * b``, the component of ``a`` along ``b``.
+        See :meth:`reject` for the orthogonal remainder.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length per row, or a single-row
+            one (such as a literal axis) used for every row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array input). Rows where either vector is null or holds a null
+            element are null; a zero ``other`` gives NaN.
+
+        Raises
+        ------
+        ComputeError
+            If the two lists in a row differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 4]], "b": [[2, 0]]})
+        >>> df.select(pl.col("a").vec.project("b"))
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [3.0, 0.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_project",
+            is_elementwise=True,
+        )
+
+    def reject(self, other: IntoExprColumn) -> pl.Expr:
+        """
+        Vector rejection of this list from another in each row.
+
+        Computes ``a - project(a, b)``, the component of ``a`` orthogonal to
+        ``b``, e.g. to remove a shared direction from every row.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length per row, or a single-row
+            one (such as a literal axis) used for every row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array input). Rows where either vector is null or holds a null
+            element are null; a zero ``other`` gives NaN.
+
+        Raises
+        ------
+        ComputeError
+            If the two lists in a row differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 4]], "b": [[2, 0]]})
+        >>> df.select(pl.col("a").vec.reject("b"))
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [0.0, 4.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_reject",
+            is_elementwise=True,
+        )
+
+    def angle(self, other: IntoExprColumn, degrees: bool = False) -> pl.Expr:
+        """
+        Angle between this vector and another in each row.
+
+        The arc cosine of the cosine similarity, a more interpretable unit
+        than :meth:`self_similarity` for geometric data.
+
+        Parameters
+        ----------
+        other
+            List/Array column with the same length per row, or a single-row
+            one (such as a literal axis) used for every row.
+        degrees
+            If True, return degrees instead of radians.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one ``f64`` per row in ``[0, pi]`` (or
+            ``[0, 180]``). Rows where either vector is null or holds a null
+            element are null; a zero vector gives NaN.
+
+        Raises
+        ------
+        ComputeError
+            If the two lists in a row differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 0], [1, 1]], "b": [[0, 1], [1, 0]]})
+        >>> df.select(pl.col("a").vec.angle("b", degrees=True))
+        shape: (2, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 90.0 │
+        │ 45.0 │
+        └──────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(other))],
+            plugin_path=_LIB,
+            function_name="vec_angle",
+            is_elementwise=True,
+            kwargs={"degrees": degrees},
+        )
+
+    def stats(self, ddof: int = 1) -> pl.Expr:
+        """
+        Summary statistics of each row's own list in one pass.
+
+        Computes ``mean``, ``std``, ``min``, ``max`` and ``median`` of every
+        row in a single fused kernel over the flat values buffer, instead of
+        one ``list.*`` pass per statistic.
+
+        Parameters
+        ----------
+        ddof
+            Delta degrees of freedom for the standard deviation.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a struct of five ``f64`` fields per row. Null
+            elements are skipped; null rows give nulls, as do empty lists and
+            a std over no more than ``ddof`` values.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 4, 2, 3], [5, 1]]})
+        >>> df.select(pl.col("a").vec.stats()).unnest("a")
+        shape: (2, 5)
+        ┌──────┬──────────┬─────┬─────┬────────┐
+        │ mean ┆ std      ┆ min ┆ max ┆ median │
+        │ ---  ┆ ---      ┆ --- ┆ --- ┆ ---    │
+        │ f64  ┆ f64      ┆ f64 ┆ f64 ┆ f64    │
+        ╞══════╪══════════╪═════╪═════╪════════╡
+        │ 2.5  ┆ 1.290994 ┆ 1.0 ┆ 4.0 ┆ 2.5    │
+        │ 3.0  ┆ 2.828427 ┆ 1.0 ┆ 5.0 ┆ 3.0    │
+        └──────┴──────────┴─────┴─────┴────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_stats",
+            is_elementwise=True,
+            kwargs={"ddof": ddof},
+        )
+
+    def weighted_mean(self, weights: IntoExprColumn) -> pl.Expr:
+        """
+        Weighted mean of each row's list.
+
+        Computes ``sum(w * v) / sum(w)`` within every row, using a second
+        list column of weights with the same shape. This averages along the
+        list, unlike the vertical :meth:`mean`.
+
+        Parameters
+        ----------
+        weights
+            List/Array column with one weight per element.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one ``f64`` per row, over the positions where
+            both the value and its weight are non-null. Null when no such
+            position exists; NaN when the weights sum to zero. NaN and
+            infinite values propagate.
+
+        Raises
+        ------
+        ComputeError
+            If a row's values and weights differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"v": [[1, 2, 3], [4, 8]], "w": [[1, 1, 2], [3, 1]]})
+        >>> df.select(pl.col("v").vec.weighted_mean("w"))
+        shape: (2, 1)
+        ┌──────┐
+        │ v    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 2.25 │
+        │ 5.0  │
+        └──────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(weights))],
+            plugin_path=_LIB,
+            function_name="vec_weighted_mean",
+            is_elementwise=True,
+        )
+
+    def softmax(self, temperature: float = 1.0) -> pl.Expr:
+        """
+        Softmax within each row's list.
+
+        Computes ``exp(x / T) / sum(exp(x / T))`` along every list, e.g. to
+        turn model logits stored as lists into probabilities. The row maximum
+        is subtracted first, so large logits do not overflow.
+
+        Parameters
+        ----------
+        temperature
+            Positive temperature ``T``. Values above ``1`` flatten the
+            distribution, values below ``1`` sharpen it. Default ``1.0``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 values summing to one.
+            Null elements stay null and are excluded from the normalization.
+
+        Raises
+        ------
+        ValueError
+            If ``temperature`` is not positive.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0.0, 0.0], [1.0, None]]})
+        >>> df.select(pl.col("a").vec.softmax())
+        shape: (2, 1)
+        ┌─────────────┐
+        │ a           │
+        │ ---         │
+        │ list[f64]   │
+        ╞═════════════╡
+        │ [0.5, 0.5]  │
+        │ [1.0, null] │
+        └─────────────┘
+        """
+        if not temperature > 0:
+            raise ValueError(f"temperature must be positive, got {temperature!r}")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_softmax",
+            is_elementwise=True,
+            kwargs={"temperature": float(temperature)},
+        )
+
+    def sigmoid(self) -> pl.Expr:
+        """
+        Logistic sigmoid ``1 / (1 + exp(-x))`` of every list element.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 values in ``[0, 1]``.
+            Null elements and null rows are preserved.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0.0, None]]})
+        >>> df.select(pl.col("a").vec.sigmoid())
+        shape: (1, 1)
+        ┌─────────────┐
+        │ a           │
+        │ ---         │
+        │ list[f64]   │
+        ╞═════════════╡
+        │ [0.5, null] │
+        └─────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_sigmoid",
+            is_elementwise=True,
+        )
+
+    def tanh(self) -> pl.Expr:
+        """
+        Hyperbolic tangent of every list element.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 values in ``[-1, 1]``.
+            Null elements and null rows are preserved.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0.0, None]]})
+        >>> df.select(pl.col("a").vec.tanh())
+        shape: (1, 1)
+        ┌─────────────┐
+        │ a           │
+        │ ---         │
+        │ list[f64]   │
+        ╞═════════════╡
+        │ [0.0, null] │
+        └─────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_tanh",
+            is_elementwise=True,
+        )
+
+    def minmax_scale(
+        self, feature_range: tuple[float, float] = (0.0, 1.0)
+    ) -> pl.Expr:
+        """
+        Min-max scale each row's list to a target range.
+
+        Every element is mapped as ``(x - min) / (max - min)`` and then
+        stretched onto ``feature_range``, using the minimum and maximum of the
+        row's finite values.
+
+        Parameters
+        ----------
+        feature_range
+            ``(min, max)`` of the output range. Default ``(0.0, 1.0)``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 values. Null elements stay
+            null; rows with a constant value map to ``feature_range[0]``, and
+            rows without finite values become NaN.
+
+        Raises
+        ------
+        ValueError
+            If ``feature_range[0] >= feature_range[1]``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, 3.0, 5.0]]})
+        >>> df.select(pl.col("a").vec.minmax_scale())
+        shape: (1, 1)
+        ┌─────────────────┐
+        │ a               │
+        │ ---             │
+        │ list[f64]       │
+        ╞═════════════════╡
+        │ [0.0, 0.5, 1.0] │
+        └─────────────────┘
+        """
+        range_min, range_max = feature_range
+        if not range_min < range_max:
+            raise ValueError(
+                f"feature_range must satisfy min < max, got {feature_range!r}"
+            )
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_minmax_scale",
+            is_elementwise=True,
+            kwargs={"range_min": float(range_min), "range_max": float(range_max)},
+        )
+
+    def robust_scale(self) -> pl.Expr:
+        """
+        Robust scale each row's list by its median and interquartile range.
+
+        Every element is mapped as ``(x - median) / (q75 - q25)``, with the
+        statistics taken from the row's finite values (linearly interpolated
+        percentiles). Outliers therefore barely affect the scaling.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 values. Null elements stay
+            null; a zero IQR only centers the row, and rows without finite
+            values become NaN.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, 3.0, 5.0]]})
+        >>> df.select(pl.col("a").vec.robust_scale())
+        shape: (1, 1)
+        ┌──────────────────┐
+        │ a                │
+        │ ---              │
+        │ list[f64]        │
+        ╞══════════════════╡
+        │ [-1.0, 0.0, 1.0] │
+        └──────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_robust_scale",
+            is_elementwise=True,
+        )
+
+    def zscore(self, ddof: int = 1) -> pl.Expr:
+        """
+        Standardize each row's list against its own mean and standard deviation.
+
+        Every element is mapped as ``(x - mean) / std`` with the statistics
+        of the row's finite values. This normalizes along the list axis, unlike
+        :meth:`normalize_vertical`, which works per position across rows.
+
+        Parameters
+        ----------
+        ddof
+            Delta degrees of freedom for the standard deviation. Default ``1``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 values. Null elements stay
+            null; rows with zero spread are only centered, and rows with too
+            few finite values for ``ddof`` become NaN.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, 2.0, 3.0]]})
+        >>> df.select(pl.col("a").vec.zscore())
+        shape: (1, 1)
+        ┌──────────────────┐
+        │ a                │
+        │ ---              │
+        │ list[f64]        │
+        ╞══════════════════╡
+        │ [-1.0, 0.0, 1.0] │
+        └──────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_zscore",
+            is_elementwise=True,
+            kwargs={"ddof": ddof},
+        )
+
+    def rank(self, method: str = "average", descending: bool = False) -> pl.Expr:
+        """
+        Rank each element within its own list.
+
+        Ranks are 1-based, as in :meth:`polars.Expr.rank`. Ranking two list
+        columns and combining them with the row-wise operations gives
+        Spearman-style statistics per row.
+
+        Parameters
+        ----------
+        method
+            How ties are ranked, one of:
+            - "average": mean of the tied ranks (default)
+            - "min": lowest of the tied ranks
+            - "max": highest of the tied ranks
+            - "dense": like "min", but the next distinct value gets the next
+              integer rank
+            - "ordinal": distinct ranks in order of appearance
+        descending
+            Rank the largest value first. Default ``False``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning lists of Float64 ranks. Nulls stay null and
+            NaN stays NaN; neither takes part in the ranking.
+
+        Raises
+        ------
+        ValueError
+            If ``method`` is not one of the options above.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3.0, 1.0, 3.0, 2.0]]})
+        >>> df.select(pl.col("a").vec.rank())
+        shape: (1, 1)
+        ┌──────────────────────┐
+        │ a                    │
+        │ ---                  │
+        │ list[f64]            │
+        ╞══════════════════════╡
+        │ [3.5, 1.0, 3.5, 2.0] │
+        └──────────────────────┘
+        """
+        methods = ("average", "min", "max", "dense", "ordinal")
+        if method not in methods:
+            raise ValueError(
+                f"method must be one of {', '.join(map(repr, methods))}, "
+                f"got {method!r}"
+            )
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_rank",
+            is_elementwise=True,
+            kwargs={"method": method, "descending": descending},
+        )
+
+    def top_k(self, k: int, return_indices: bool = False) -> pl.Expr:
+        """
+        The k largest elements of each row's list.
+
+        Uses a partial selection per row, so it is much cheaper than sorting
+        every list and taking the head for wide lists. Null and NaN elements
+        are skipped, and ties keep list order. See :meth:`top_k_rows` for the
+        vertical, per-position variant.
+
+        Parameters
+        ----------
+        k
+            Number of elements to keep per row.
+        return_indices
+            If True, return a struct with ``values`` and their 0-based
+            ``indices`` (``UInt32``) within the list.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a descending ``List[inner]`` per row, shorter
+            than ``k`` if the row has fewer values. Null rows stay null.
+
+        Raises
+        ------
+        ValueError
+            If ``k`` is negative.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 5, 3], [4, 2, 8]]})
+        >>> df.select(pl.col("a").vec.top_k(2))
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [5, 3]    │
+        │ [8, 4]    │
+        └───────────┘
+        """
+        if k < 0:
+            raise ValueError("k must be non-negative")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_top_k",
+            is_elementwise=True,
+            kwargs={"k": k, "return_indices": return_indices},
+        )
+
+    def arg_sort(self, descending: bool = False, nulls_last: bool = True) -> pl.Expr:
+        """
+        Indices that would sort each row's list.
+
+        The sort is stable and NaN sorts as the largest value, as in
+        :meth:`polars.Expr.arg_sort`.
+
+        Parameters
+        ----------
+        descending
+            Sort from largest to smallest. Default ``False``.
+        nulls_last
+            Place null elements at the end (default) or the start.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[UInt32]`` (or ``Array[UInt32, width]``)
+            positions within each list. Null rows stay null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3.0, 1.0, 2.0]]})
+        >>> df.select(pl.col("a").vec.arg_sort())
+        shape: (1, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[u32] │
+        ╞═══════════╡
+        │ [1, 2, 0] │
+        └───────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_arg_sort",
+            is_elementwise=True,
+            kwargs={"descending": descending, "nulls_last": nulls_last},
+        )
+
+    def sort_by(
+        self, keys: IntoExprColumn, descending: bool = False, nulls_last: bool = True
+    ) -> pl.Expr:
+        """
+        Sort each row's list by the elements of a companion keys list.
+
+        Within every row, the values are reordered so that the corresponding
+        keys are sorted, without exploding the frame. The values may have any
+        inner type; the keys must be numeric.
+
+        Parameters
+        ----------
+        keys
+            Numeric List/Array column with the same length as the values in
+            every row.
+        descending
+            Sort keys from largest to smallest. Default ``False``.
+        nulls_last
+            Place elements with null keys at the end (default) or the start.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning the values with the input type. A null
+            values or keys row gives a null row.
+
+        Raises
+        ------
+        ComputeError
+            If a row's values and keys differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"v": [["a", "b", "c"]], "k": [[3, 1, 2]]})
+        >>> df.select(pl.col("v").vec.sort_by("k"))
+        shape: (1, 1)
+        ┌─────────────────┐
+        │ v               │
+        │ ---             │
+        │ list[str]       │
+        ╞═════════════════╡
+        │ ["b", "c", "a"] │
+        └─────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(keys))],
+            plugin_path=_LIB,
+            function_name="vec_sort_by",
+            is_elementwise=True,
+            kwargs={"descending": descending, "nulls_last": nulls_last},
+        )
+
+    def gather(self, indices: IntoExprColumn, oob: str = "raise") -> pl.Expr:
+        """
+        Take elements of each row's list at per-row positions from another column.
+
+        Generalizes :meth:`polars.Expr.list.gather` to indices that vary by
+        row: row ``i`` of the result holds ``values[i][indices[i]]``. The
+        indices list may have any length, and negative indices count from the
+        end of the row.
+
+        Parameters
+        ----------
+        indices
+            Integer List/Array column of positions, one list per row.
+        oob
+            What to do with out-of-bounds indices, one of:
+            - "raise": raise an error (default)
+            - "null": return null for that element
+            - "clamp": use the first or last element (null for an empty row)
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[inner]`` with one element per index.
+            Null indices give null elements; a null values or indices row
+            gives a null row.
+
+        Raises
+        ------
+        ValueError
+            If ``oob`` is not one of the options above.
+        ComputeError
+            If ``oob="raise"`` and an index is out of bounds.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"v": [[10, 11, 12], [20, 21]], "i": [[2, 0], [-1]]})
+        >>> df.select(pl.col("v").vec.gather("i"))
+        shape: (2, 1)
+        ┌───────────┐
+        │ v         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [12, 10]  │
+        │ [21]      │
+        └───────────┘
+        """
+        if oob not in ("raise", "null", "clamp"):
+            raise ValueError(
+                f"oob must be one of 'raise', 'null', 'clamp', got {oob!r}"
+            )
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(indices))],
+            plugin_path=_LIB,
+            function_name="vec_gather",
+            is_elementwise=True,
+            kwargs={"oob": oob},
+        )
+
+    def scatter(self, indices: IntoExprColumn, updates: IntoExpr) -> pl.Expr:
+        """
+        Replace elements of each row's list at per-row positions.
+
+        Returns a copy of every list in which the elements at ``indices`` are
+        replaced by ``updates``, e.g. to null out known-bad samples per trial.
+        Negative indices count from the end of the row, and repeated indices
+        keep the last update.
+
+        Parameters
+        ----------
+        indices
+            Integer List/Array column of positions, one list per row.
+        updates
+            New values: a List/Array column with one element per index, or a
+            scalar (column or literal such as ``None``) used for every index
+            of the row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning the values with the input type. Null indices
+            are skipped, and a null indices or updates row leaves the row
+            unchanged.
+
+        Raises
+        ------
+        ComputeError
+            If an index is out of bounds, or a row's updates list differs in
+            length from its indices.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"v": [[1.0, 2.0, 3.0], [4.0, 5.0]], "i": [[0, 2], [-1]]})
+        >>> df.select(pl.col("v").vec.scatter("i", None))
+        shape: (2, 1)
+        ┌───────────────────┐
+        │ v                 │
+        │ ---               │
+        │ list[f64]         │
+        ╞═══════════════════╡
+        │ [null, 2.0, null] │
+        │ [4.0, null]       │
+        └───────────────────┘
+        """
+        return register_plugin_function(
+            args=[
+                self._expr,
+                wrap_expr(parse_into_expression(indices)),
+                wrap_expr(parse_into_expression(updates)),
+            ],
+            plugin_path=_LIB,
+            function_name="vec_scatter",
+            is_elementwise=True,
+        )
+
+    def search_sorted(self, queries: IntoExpr, side: str = "left") -> pl.Expr:
+        """
+        Insertion indices of query values into each row's sorted list.
+
+        Like :func:`numpy.searchsorted`, applied row by row, e.g. to map event
+        timestamps onto per-row sample grids. Each list must be sorted in
+        ascending order; null elements are treated as the largest values.
+
+        Parameters
+        ----------
+        queries
+            Values to look up: a numeric column (or literal) with one query
+            per row, or a List/Array column with several queries per row.
+        side
+            "left" (default) gives the first position where the query could
+            be inserted, "right" the last.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``UInt32`` per row for scalar queries, or
+            ``List[UInt32]`` for list queries. Null or NaN queries and null
+            rows give null.
+
+        Raises
+        ------
+        ValueError
+            If ``side`` is not "left" or "right".
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"t": [[0.0, 1.0, 2.0], [0.0, 5.0]], "q": [1.5, 9.0]})
+        >>> df.select(pl.col("t").vec.search_sorted("q"))
+        shape: (2, 1)
+        ┌─────┐
+        │ t   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 2   │
+        │ 2   │
+        └─────┘
+        """
+        if side not in ("left", "right"):
+            raise ValueError(f"side must be 'left' or 'right', got {side!r}")
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(queries))],
+            plugin_path=_LIB,
+            function_name="vec_search_sorted",
+            is_elementwise=True,
+            kwargs={"side": side},
+        )
+
+    def rle(self) -> pl.Expr:
+        """
+        Run-length encode each row's list.
+
+        Consecutive equal elements collapse into one run, e.g. to compress
+        long state-sequence lists. Consecutive nulls form a run of null.
+        :meth:`rle_decode` inverts the encoding.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a struct with ``values`` (``List[inner]``)
+            and ``lengths`` (``List[UInt32]``) per row. A null row gives null
+            values and lengths.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 1, 2, 2, 2, 1]]})
+        >>> df.select(pl.col("a").vec.rle()).unnest("a")
+        shape: (1, 2)
+        ┌───────────┬───────────┐
+        │ values    ┆ lengths   │
+        │ ---       ┆ ---       │
+        │ list[i64] ┆ list[u32] │
+        ╞═══════════╪═══════════╡
+        │ [1, 2, 1] ┆ [2, 3, 1] │
+        └───────────┴───────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_rle",
+            is_elementwise=True,
+        )
+
+    def rle_decode(self) -> pl.Expr:
+        """
+        Expand a run-length encoding back into one list per row.
+
+        The expression must be a struct column with ``values`` and integer
+        ``lengths`` list fields, as produced by :meth:`rle`.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[inner]`` per row. Null lengths count
+            as zero; a null values or lengths row gives a null row.
+
+        Raises
+        ------
+        ComputeError
+            If a row's values and lengths differ in length, or a length is
+            negative.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 1, 2, 2, 2, 1]]})
+        >>> df.select(pl.col("a").vec.rle().vec.rle_decode())
+        shape: (1, 1)
+        ┌────────────────────┐
+        │ a                  │
+        │ ---                │
+        │ list[i64]          │
+        ╞════════════════════╡
+        │ [1, 1, 2, 2, 2, 1] │
+        └────────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_rle_decode",
+            is_elementwise=True,
+        )
+
+    def pad_to(
+        self, width: int, fill: float | None = None, align: str = "left"
+    ) -> pl.Expr:
+        """
+        Pad each row's list up to a target length.
+
+        Together with :meth:`truncate` this forces ragged lists to the common
+        length that the vertical reductions require.
+
+        Parameters
+        ----------
+        width
+            Target length. Rows already at least this long are unchanged.
+        fill
+            Value for the padding, cast to the inner type. ``None`` (default)
+            pads with nulls.
+        align
+            "left" (default) keeps the values at the start and pads the end,
+            "right" pads the start.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[inner]``. Null rows stay null.
+
+        Raises
+        ------
+        ValueError
+            If ``width`` is negative or ``align`` is not "left" or "right".
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 4, 5]]})
+        >>> df.select(pl.col("a").vec.pad_to(3, fill=0, align="right"))
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [0, 1, 2] │
+        │ [3, 4, 5] │
+        └───────────┘
+        """
+        if width < 0:
+            raise ValueError("width must be non-negative")
+        if align not in ("left", "right"):
+            raise ValueError(f"align must be 'left' or 'right', got {align!r}")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_pad_to",
+            is_elementwise=True,
+            kwargs={
+                "width": width,
+                "fill": None if fill is None else float(fill),
+                "align": align,
+            },
+        )
+
+    def truncate(self, width: int) -> pl.Expr:
+        """
+        Keep at most the first ``width`` elements of each row's list.
+
+        Parameters
+        ----------
+        width
+            Maximum length. Shorter rows are unchanged.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[inner]``. Null rows stay null.
+
+        Raises
+        ------
+        ValueError
+            If ``width`` is negative.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 4, 5]]})
+        >>> df.select(pl.col("a").vec.truncate(2))
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 2]    │
+        │ [3, 4]    │
+        └───────────┘
+        """
+        if width < 0:
+            raise ValueError("width must be non-negative")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_truncate",
+            is_elementwise=True,
+            kwargs={"width": width},
+        )
+
+    def reshape_windows(self, window: int, step: int = 1) -> pl.Expr:
+        """
+        Split each row's list into (possibly overlapping) sub-windows.
+
+        Window ``k`` holds ``list[k * step : k * step + window]``; only
+        complete windows are kept.
+
+        Parameters
+        ----------
+        window
+            Number of elements per window.
+        step
+            Offset between the starts of consecutive windows. Default ``1``;
+            ``step=window`` gives non-overlapping chunks.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[List[inner]]``, one list of windows
+            per row. Null rows stay null.
+
+        Raises
+        ------
+        ValueError
+            If ``window`` or ``step`` is not positive.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4, 5]]})
+        >>> df.select(pl.col("a").vec.reshape_windows(3, step=2))
+        shape: (1, 1)
+        ┌────────────────────────┐
+        │ a                      │
+        │ ---                    │
+        │ list[list[i64]]        │
+        ╞════════════════════════╡
+        │ [[1, 2, 3], [3, 4, 5]] │
+        └────────────────────────┘
+        """
+        if window <= 0 or step <= 0:
+            raise ValueError("window and step must be positive")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_reshape_windows",
+            is_elementwise=True,
+            kwargs={"window": window, "step": step},
+        )
+
+    def to_array(self, width: int) -> pl.Expr:
+        """
+        Validate list lengths and convert to a fixed-width Array column.
+
+        Checks that every non-null row holds exactly ``width`` elements and
+        builds ``Array(inner, width)`` in one pass. Unlike a plain cast, the
+        error names the first row with a different length.
+
+        Parameters
+        ----------
+        width
+            Required length of every non-null list.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``Array(inner, width)``. Null rows stay null.
+
+        Raises
+        ------
+        ValueError
+            If ``width`` is negative.
+        ComputeError
+            If a row has a different length, or an Array input has a
+            different width.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], None, [3, 4]]})
+        >>> df.select(pl.col("a").vec.to_array(2))
+        shape: (3, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ array[i64, 2] │
+        ╞═══════════════╡
+        │ [1, 2]        │
+        │ null          │
+        │ [3, 4]        │
+        └───────────────┘
+        """
+        if width < 0:
+            raise ValueError("width must be non-negative")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_to_array",
+            is_elementwise=True,
+            kwargs={"width": width},
+        )
+
+    def shape_report(self) -> pl.Expr:
+        """
+        Summarize the shape of a list column in one pass.
+
+        Useful for finding out why a vertical reduction reports mismatched
+        lengths, without writing several expressions by hand.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            holding a struct with fields:
+
+            - ``n_rows``: number of rows
+            - ``n_null_rows``: number of null lists
+            - ``min_len``, ``max_len``: shortest and longest non-null list
+              (null if every row is null)
+            - ``n_distinct_lens``: number of distinct non-null list lengths
+            - ``inner_dtype``: the element dtype as a string
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3], None, [4, 5]]})
+        >>> df.select(pl.col("a").vec.shape_report()).unnest("a")
+        shape: (1, 6)
+        ┌────────┬─────────────┬─────────┬─────────┬─────────────────┬─────────────┐
+        │ n_rows ┆ n_null_rows ┆ min_len ┆ max_len ┆ n_distinct_lens ┆ inner_dtype │
+        │ ---    ┆ ---         ┆ ---     ┆ ---     ┆ ---             ┆ ---         │
+        │ u64    ┆ u64         ┆ u64     ┆ u64     ┆ u64             ┆ str         │
+        ╞════════╪═════════════╪═════════╪═════════╪═════════════════╪═════════════╡
+        │ 4      ┆ 1           ┆ 1       ┆ 2       ┆ 2               ┆ i64         │
+        └────────┴─────────────┴─────────┴─────────┴─────────────────┴─────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_shape_report",
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
+    def nansum(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
+        """
+        Sum across rows, skipping NaN elements (like ``numpy.nansum``).
+
+        Same as ``sum(nan_policy="ignore")``; see sum() for the
+        parameters. Positions where every value is NaN or null sum to 0.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, float("nan")], [3.0, 2.0]]})
+        >>> df.select(pl.col("a").vec.nansum())
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [4.0, 2.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_nansum",
+            is_elementwise=False,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                "ignore",
+                offset,
+                length,
+                every,
+            ),
+        )
+
+    def nanmean(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
+        """
+        Mean across rows, skipping NaN elements (like ``numpy.nanmean``).
+
+        Same as ``mean(nan_policy="ignore")``; see mean() for the
+        parameters. Positions where every value is NaN or null are NaN.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, float("nan")], [3.0, 2.0]]})
+        >>> df.select(pl.col("a").vec.nanmean())
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [2.0, 2.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_nanmean",
+            is_elementwise=False,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                "ignore",
+                offset,
+                length,
+                every,
+            ),
+        )
+
+    def nanmin(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
+        """
+        Minimum across rows, skipping NaN elements (like ``numpy.nanmin``).
+
+        Same as ``min(nan_policy="ignore")``; see min() for the
+        parameters. Positions where every value is NaN or null are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, float("nan")], [3.0, 2.0]]})
+        >>> df.select(pl.col("a").vec.nanmin())
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [1.0, 2.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_nanmin",
+            is_elementwise=False,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                "ignore",
+                offset,
+                length,
+                every,
+            ),
+        )
+
+    def nanmax(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
+        """
+        Maximum across rows, skipping NaN elements (like ``numpy.nanmax``).
+
+        Same as ``max(nan_policy="ignore")``; see max() for the
+        parameters. Positions where every value is NaN or null are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, float("nan")], [3.0, 2.0]]})
+        >>> df.select(pl.col("a").vec.nanmax())
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [3.0, 2.0] │
+        └────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_nanmax",
+            is_elementwise=False,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                "ignore",
+                offset,
+                length,
+                every,
+            ),
+        )
+
+    def ptp(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        nan_policy: str = "propagate",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
+        """
+        Peak-to-peak range (max - min) at each position across rows.
+
+        Returns a single row with a list where each element is the difference
+        between the largest and smallest element at that position, computed in
+        one pass rather than as ``max() - min()``.
+
+        All lists must have the same length.
+
+        Parameters
+        ----------
+        null_policy
+            ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+            position null if any row has a null there. Null rows are always
+            skipped.
+        min_count
+            Positions with fewer than ``min_count`` non-null values are null.
+        keep_rows
+            If True, repeat the aggregate for every input row instead of
+            returning a single row, so it can be used in ``with_columns``
+            (e.g. to subtract the mean trace).
+        validate
+            ``"strict"`` (default) checks that every non-null list has the
+            same length and reports the offending rows. ``"fast"`` skips the
+            check for inputs known to be uniform; mismatched lengths then give
+            an unhelpful error or a wrong result. Array columns are never
+            re-checked.
+        empty_policy
+            What to do with empty lists among non-empty ones: ``"error"``
+            (default) raises, ``"skip"`` leaves them out like null rows and
+            ``"treat_as_null"`` counts them as rows of nulls (which matters for
+            ``null_policy="propagate"`` and ``min_count``).
+        nan_policy
+            How NaN elements of float lists are handled, separately from
+            nulls: ``"propagate"`` (default) makes a position NaN if any row
+            has a NaN there, ``"ignore"`` skips them like numpy's
+            nan-functions and ``"as_null"`` treats them as nulls.
+        offset
+            First row to aggregate; negative values count from the end.
+            With ``length`` this reduces a vertical slice of the rows
+            without slicing the frame first.
+        length
+            Number of rows to aggregate from ``offset``; None (default)
+            takes every remaining row. With ``keep_rows`` the output still
+            has one row per row of the whole column.
+        every
+            Aggregate only every ``every``-th row of the slice, starting
+            with its first (e.g. ``offset=1, every=2`` for the odd rows of
+            a split-half analysis).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a list with the same type as input.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 5, 2], [1, 7, 4]]})
+        >>> df.select(pl.col("a").vec.ptp())
+        shape: (1, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [2, 2, 2] │
+        └───────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_ptp",
+            is_elementwise=False,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                nan_policy,
+                offset,
+                length,
+                every,
+            ),
+        )
+
+    def abs_max(
+        self,
+        null_policy: str = "ignore",
+        min_count: int = 0,
+        keep_rows: bool = False,
+        validate: str = "strict",
+        empty_policy: str = "error",
+        nan_policy: str = "propagate",
+        offset: int = 0,
+        length: int | None = None,
+        every: int = 1,
+    ) -> pl.Expr:
+        """
+        Value with the largest magnitude at each position across rows.
+
+        Returns a single row with a list where each element is the value with
+        the largest absolute value at that position, keeping its sign, e.g. the
+        peak deflection regardless of polarity. Ties keep the earliest row.
+
+        All lists must have the same length.
+
+        Parameters
+        ----------
+        null_policy
+            ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+            position null if any row has a null there. Null rows are always
+            skipped.
+        min_count
+            Positions with fewer than ``min_count`` non-null values are null.
+        keep_rows
+            If True, repeat the aggregate for every input row instead of
+            returning a single row, so it can be used in ``with_columns``
+            (e.g. to subtract the mean trace).
+        validate
+            ``"strict"`` (default) checks that every non-null list has the
+            same length and reports the offending rows. ``"fast"`` skips the
+            check for inputs known to be uniform; mismatched lengths then give
+            an unhelpful error or a wrong result. Array columns are never
+            re-checked.
+        empty_policy
+            What to do with empty lists among non-empty ones: ``"error"``
+            (default) raises, ``"skip"`` leaves them out like null rows and
+            ``"treat_as_null"`` counts them as rows of nulls (which matters for
+            ``null_policy="propagate"`` and ``min_count``).
+        nan_policy
+            How NaN elements of float lists are handled, separately from
+            nulls: ``"propagate"`` (default) makes a position NaN if any row
+            has a NaN there, ``"ignore"`` skips them like numpy's
+            nan-functions and ``"as_null"`` treats them as nulls.
+        offset
+            First row to aggregate; negative values count from the end.
+            With ``length`` this reduces a vertical slice of the rows
+            without slicing the frame first.
+        length
+            Number of rows to aggregate from ``offset``; None (default)
+            takes every remaining row. With ``keep_rows`` the output still
+            has one row per row of the whole column.
+        every
+            Aggregate only every ``every``-th row of the slice, starting
+            with its first (e.g. ``offset=1, every=2`` for the odd rows of
+            a split-half analysis).
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a list with the same type as input.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, -5, 2], [-4, 1, 2]]})
+        >>> df.select(pl.col("a").vec.abs_max())
+        shape: (1, 1)
+        ┌─────────────┐
+        │ a           │
+        │ ---         │
+        │ list[i64]   │
+        ╞═════════════╡
+        │ [-4, -5, 2] │
+        └─────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_abs_max",
+            is_elementwise=False,
+            returns_scalar=not keep_rows,
+            kwargs=_reduce_kwargs(
+                null_policy,
+                min_count,
+                keep_rows,
+                validate,
+                empty_policy,
+                nan_policy,
+                offset,
+                length,
+                every,
+            ),
+        )
+
+    def scaled_mean(self, method: str = "minmax", ddof: int = 1) -> pl.Expr:
+        """
+        Mean across rows after rescaling each row on its own.
+
+        Equivalent to ``minmax_scale().vec.mean()`` or
+        ``zscore(ddof).vec.mean()``, but fused into one pass over the column so
+        the rescaled rows are never materialized. Useful for averaging traces
+        with different gains. Null elements are skipped.
+
+        Parameters
+        ----------
+        method
+            ``"minmax"`` (default) scales each row to ``[0, 1]``;
+            ``"zscore"`` standardizes it by its own mean and standard
+            deviation.
+        ddof
+            Delta degrees of freedom for the standard deviation with
+            ``method="zscore"``.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            of ``List[f64]`` (``Array[f64, width]`` for Array input).
+
+        Raises
+        ------
+        ComputeError
+            If non-null lists differ in length.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 3, 5], [2, 2, 6]]})
+        >>> df.select(pl.col("a").vec.scaled_mean())
+        shape: (1, 1)
+        ┌──────────────────┐
+        │ a                │
+        │ ---              │
+        │ list[f64]        │
+        ╞══════════════════╡
+        │ [0.0, 0.25, 1.0] │
+        └──────────────────┘
+        """
+        if method not in ("minmax", "zscore"):
+            raise ValueError(f"method must be 'minmax' or 'zscore', got {method!r}")
+        if ddof < 0:
+            raise ValueError("ddof must be non-negative")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_scaled_mean",
+            is_elementwise=False,
+            returns_scalar=True,
+            kwargs={"method": method, "ddof": ddof},
+        )
+
+    def cum_sum_from(self, initial: IntoExpr) -> pl.Expr:
+        """
+        Rebuild absolute rows from row-to-row differences, the inverse of diff().
+
+        The first output row is ``initial`` and each later row is the previous
+        output row plus the difference row, so
+        ``pl.col("d").vec.cum_sum_from(pl.col("a").first())`` recovers ``a``
+        from ``d = a.vec.diff()``. The first row of the differences is
+        ignored, as diff() always makes it null. Useful for round-tripping
+        delta-encoded trace storage.
+
+        Parameters
+        ----------
+        initial
+            The absolute first row: an expression or literal giving a single
+            list with the same length as the difference rows.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning as many rows as the input, with the supertype
+            of the input and ``initial`` element types.
+
+        Raises
+        ------
+        InvalidOperationError
+            If ``initial`` is not a List/Array.
+        ComputeError
+            If ``initial`` is not a single non-null list, a row's length
+            differs from it, or an integer running sum overflows the result
+            type.
+
+        Notes
+        -----
+        A null element makes its position null in every later row. A null
+        row gives a null row, and every row after it is a list of nulls since
+        the running values are then unknown.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 10], [4, 20], [9, 40]]})
+        >>> df.select(
+        ...     pl.col("a").vec.diff().vec.cum_sum_from(pl.col("a").first())
+        ... )
+        shape: (3, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 10]   │
+        │ [4, 20]   │
+        │ [9, 40]   │
+        └───────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(initial))],
+            plugin_path=_LIB,
+            function_name="list_cum_sum_from",
+            is_elementwise=False,
+        )
+
+    def delta_encode(self, dtype: PolarsDataType | str | None = None) -> pl.Expr:
+        """
+        Delta-encode each row's list along the list axis.
+
+        The first element of every list is kept and each later element is
+        replaced by its difference from the previous one. Smooth traces give
+        small deltas that can be stored in a narrower dtype, shrinking the
+        parquet footprint; :meth:`delta_decode` reverses the encoding, exactly
+        for integers.
+
+        Parameters
+        ----------
+        dtype
+            Element dtype of the result, e.g. ``pl.Int16`` or ``"int16"``.
+            One of int8, int16, int32, int64, uint32, uint64, float32 or
+            float64. ``None`` (default) gives Int64 for integer input and
+            Float64 for float input. The cast is strict, so the first element
+            and every delta must fit.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a list per row (``Array`` for Array input).
+            Null elements stay null and are skipped when taking differences,
+            so they survive the round trip.
+
+        Raises
+        ------
+        InvalidOperationError
+            If a value does not fit in ``dtype``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1000, 1002, 1001, 1005]]})
+        >>> df.select(pl.col("a").vec.delta_encode(pl.Int16))
+        shape: (1, 1)
+        ┌──────────────────┐
+        │ a                │
+        │ ---              │
+        │ list[i16]        │
+        ╞══════════════════╡
+        │ [1000, 2, -1, 4] │
+        └──────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_delta_encode",
+            is_elementwise=True,
+            kwargs={"dtype": _dtype_name(dtype, _DELTA_DTYPES)},
+        )
+
+    def delta_decode(self, dtype: PolarsDataType | str | None = None) -> pl.Expr:
+        """
+        Decode :meth:`delta_encode` output back to absolute values.
+
+        Each element becomes the running sum of the row's elements up to it.
+
+        Parameters
+        ----------
+        dtype
+            Element dtype of the result, with the same choices as in
+            :meth:`delta_encode`, e.g. the original dtype of the column.
+            ``None`` (default) gives Int64 for integer input and Float64 for
+            float input.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a list per row (``Array`` for Array input).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1000, 1002, 1001, 1005]]})
+        >>> encoded = pl.col("a").vec.delta_encode(pl.Int16)
+        >>> df.select(encoded.vec.delta_decode())
+        shape: (1, 1)
+        ┌──────────────────────────┐
+        │ a                        │
+        │ ---                      │
+        │ list[i64]                │
+        ╞══════════════════════════╡
+        │ [1000, 1002, 1001, 1005] │
+        └──────────────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_delta_decode",
+            is_elementwise=True,
+            kwargs={"dtype": _dtype_name(dtype, _DELTA_DTYPES)},
+        )
+
+    def quantize(
+        self,
+        scale: float | None = None,
+        offset: float = 0.0,
+        dtype: PolarsDataType | str = pl.Int16,
+    ) -> pl.Expr:
+        """
+        Quantize float lists to integer levels to reduce storage.
+
+        Each element becomes ``round((x - offset) / scale)`` stored as
+        ``dtype``. Pair with :meth:`dequantize` to get approximate floats
+        back; the error is at most ``scale / 2``.
+
+        Parameters
+        ----------
+        scale
+            Step between levels. ``None`` (default) scales each row on its
+            own so its finite range spans the levels of ``dtype``, and returns
+            the per-row scale and offset with the values.
+        offset
+            Value mapped to level 0 when ``scale`` is given.
+        dtype
+            Integer dtype of the levels: ``pl.Int8``, ``pl.Int16`` (default)
+            or ``pl.Int32``.
+
+        Returns
+        -------
+        pl.Expr
+            With ``scale`` given, an integer list (``Array`` for Array input).
+            Otherwise a struct with fields ``values``, ``scale`` and
+            ``offset``. Non-finite elements become null.
+
+        Raises
+        ------
+        ComputeError
+            If a level does not fit ``dtype`` with the given ``scale``.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0.0, 0.25, 1.0]]})
+        >>> df.select(pl.col("a").vec.quantize(scale=0.01, dtype=pl.Int8))
+        shape: (1, 1)
+        ┌──────────────┐
+        │ a            │
+        │ ---          │
+        │ list[i8]     │
+        ╞══════════════╡
+        │ [0, 25, 100] │
+        └──────────────┘
+        """
+        if scale is not None and not scale > 0:
+            raise ValueError(f"scale must be positive, got {scale}")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_quantize",
+            is_elementwise=True,
+            kwargs={
+                "scale": None if scale is None else float(scale),
+                "offset": float(offset),
+                "dtype": _dtype_name(dtype, ("int8", "int16", "int32")),
+            },
+        )
+
+    def dequantize(self, scale: float | None = None, offset: float = 0.0) -> pl.Expr:
+        """
+        Map quantized levels back to floats as ``level * scale + offset``.
+
+        Parameters
+        ----------
+        scale
+            Step between levels, required for a plain integer list column.
+            Ignored for the struct returned by :meth:`quantize` with per-row
+            scaling, which carries its own scale and offset.
+        offset
+            Value of level 0 for a plain integer list column.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``List[f64]`` (``Array[f64, width]`` for
+            Array values).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[0, 25, 100]]})
+        >>> df.select(pl.col("a").vec.dequantize(scale=0.01))
+        shape: (1, 1)
+        ┌──────────────────┐
+        │ a                │
+        │ ---              │
+        │ list[f64]        │
+        ╞══════════════════╡
+        │ [0.0, 0.25, 1.0] │
+        └──────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_dequantize",
+            is_elementwise=True,
+            kwargs={
+                "scale": None if scale is None else float(scale),
+                "offset": float(offset),
+            },
+        )
+
+    def group_mean(self, keys: IntoExprColumn) -> pl.Expr:
+        """
+        Per-position vertical mean computed separately for each key.
+
+        ``keys`` is a list column with the same shape as this one that labels
+        every element, e.g. the condition of each sample in an interleaved
+        design. At each position, the values are averaged across rows
+        separately for every key.
+
+        Parameters
+        ----------
+        keys
+            List column of integer, string or categorical labels, with the
+            same length as the values in every row.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            of a struct with the sorted distinct ``keys`` and ``mean``, a list
+            holding one ``List[f64]`` trace per key. A key is null at positions
+            where it never occurs.
+
+        Notes
+        -----
+        Null values, null keys and null rows in either column are skipped.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2], [3, 4], [5, 6]], "cond": [[0, 1], [1, 0], [0, 0]]}
+        ... )
+        >>> df.select(pl.col("a").vec.group_mean("cond")).unnest("a")
+        shape: (1, 2)
+        ┌───────────┬──────────────────────────┐
+        │ keys      ┆ mean                     │
+        │ ---       ┆ ---                      │
+        │ list[i64] ┆ list[list[f64]]          │
+        ╞═══════════╪══════════════════════════╡
+        │ [0, 1]    ┆ [[3.0, 5.0], [3.0, 2.0]] │
+        └───────────┴──────────────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(keys))],
+            plugin_path=_LIB,
+            function_name="list_group_mean",
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
+    def chunked_mean(self, every: int) -> pl.Expr:
+        """
+        Average element-wise over blocks of ``every`` consecutive rows.
+
+        Reduces trial-level data to block-level data without a ``group_by``:
+        rows ``0..every`` give the first output row, the next ``every`` rows
+        the second, and so on.
+
+        Parameters
+        ----------
+        every
+            Number of consecutive rows per block.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``ceil(n / every)`` rows of ``List[f64]``
+            (``Array[f64, width]`` for Array input); the last block may hold
+            fewer rows. As the row count changes, use it in ``select`` or
+            ``agg`` rather than ``with_columns``.
+
+        Notes
+        -----
+        Null elements are skipped, positions with no values in a block are
+        null, and a block made only of null rows gives a null row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 4], [5, 6], [7, 8], [9, 10]]})
+        >>> df.select(pl.col("a").vec.chunked_mean(2))
+        shape: (3, 1)
+        ┌─────────────┐
+        │ a           │
+        │ ---         │
+        │ list[f64]   │
+        ╞═════════════╡
+        │ [2.0, 3.0]  │
+        │ [6.0, 7.0]  │
+        │ [9.0, 10.0] │
+        └─────────────┘
+        """
+        if every < 1:
+            raise ValueError("every must be at least 1")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="list_chunked_mean",
+            is_elementwise=False,
+            changes_length=True,
+            kwargs={"every": every},
+        )
+
+    def mean_by_time(
+        self, timestamps: IntoExprColumn, every: str | timedelta
+    ) -> pl.Expr:
+        """
+        Vertical mean over the rows falling in each time bin.
+
+        Rows are grouped into bins of width ``every`` by their ``timestamps``
+        and averaged element-wise, giving one vector per bin without leaving
+        the expression engine (so it stays lazy).
+
+        Parameters
+        ----------
+        timestamps
+            Datetime column with one timestamp per row.
+        every
+            Bin width as a fixed-length duration string (``"500ms"``,
+            ``"1h30m"``, ``"2d"``; units ``ns``, ``us``, ``ms``, ``s``, ``m``,
+            ``h``, ``d``, ``w``) or a ``timedelta``. Calendar units such as
+            ``"1mo"`` are not supported.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning one row per non-empty bin, in time order: a
+            struct of the bin start ``time`` and the per-position ``mean``
+            (``List[f64]``). As the row count changes, use it in ``select`` or
+            ``agg`` rather than ``with_columns``.
+
+        Notes
+        -----
+        Bins are aligned to the Unix epoch in UTC. Rows with a null timestamp
+        are dropped, null elements are skipped, and a bin whose rows are all
+        null gives a null mean.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1, 2], [3, 4], [5, 6]],
+        ...         "t": [
+        ...             datetime(2024, 1, 1, 0, 10),
+        ...             datetime(2024, 1, 1, 0, 50),
+        ...             datetime(2024, 1, 1, 1, 20),
+        ...         ],
+        ...     }
+        ... )
+        >>> df.select(pl.col("a").vec.mean_by_time("t", "1h")).unnest("a")
+        shape: (2, 2)
+        ┌─────────────────────┬────────────┐
+        │ time                ┆ mean       │
+        │ ---                 ┆ ---        │
+        │ datetime[μs]        ┆ list[f64]  │
+        ╞═════════════════════╪════════════╡
+        │ 2024-01-01 00:00:00 ┆ [2.0, 3.0] │
+        │ 2024-01-01 01:00:00 ┆ [5.0, 6.0] │
+        └─────────────────────┴────────────┘
+        """
+        if isinstance(every, timedelta):
+            every = f"{every // timedelta(microseconds=1)}us"
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(timestamps))],
+            plugin_path=_LIB,
+            function_name="list_mean_by_time",
+            is_elementwise=False,
+            changes_length=True,
+            kwargs={"every": every},
+        )
+
+    def value_report(self) -> pl.Expr:
+        """
+        Count the elements of a list column and how many are null, NaN or inf.
+
+        Complements :meth:`shape_report`: where that describes list lengths,
+        this looks inside the non-null lists, e.g. to spot NaNs that would
+        propagate through a vertical reduction.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning a single row (one per group in ``group_by``)
+            holding a struct of ``UInt64`` counts:
+
+            - ``n_elements``: elements in the non-null lists
+            - ``n_null_elements``: null elements among them
+            - ``n_nan_elements``, ``n_inf_elements``: NaN and infinite
+              elements (always 0 for non-float lists)
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1.0, float("nan")], [None, 2.0], None]})
+        >>> df.select(pl.col("a").vec.value_report()).unnest("a")
+        shape: (1, 4)
+        ┌────────────┬─────────────────┬────────────────┬────────────────┐
+        │ n_elements ┆ n_null_elements ┆ n_nan_elements ┆ n_inf_elements │
+        │ ---        ┆ ---             ┆ ---            ┆ ---            │
+        │ u64        ┆ u64             ┆ u64            ┆ u64            │
+        ╞════════════╪═════════════════╪════════════════╪════════════════╡
+        │ 4          ┆ 1               ┆ 1              ┆ 0              │
+        └────────────┴─────────────────┴────────────────┴────────────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_value_report",
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
+    def to_matrix(self, width: int) -> pl.Expr:
+        """
+        Validate a dense matrix column and lay it out in one contiguous buffer.
+
+        Like :meth:`to_array`, but also requires a list in every row and no
+        null elements, and returns a single chunk. The values then form one
+        row-major buffer, so :func:`polars_vec_ops.to_numpy_matrix` (or
+        ``Series.to_numpy``) can view the column as a 2D numpy array without
+        copying, instead of a ``to_list()``/``np.stack`` round trip.
+
+        Parameters
+        ----------
+        width
+            Required length of every list.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning ``Array(inner, width)``.
+
+        Raises
+        ------
+        ValueError
+            If ``width`` is negative.
+        ComputeError
+            If a row is null, has a different length or contains null elements.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 4]]})
+        >>> df.select(pl.col("a").vec.to_matrix(2))["a"].to_numpy()
+        array([[1, 2],
+               [3, 4]])
+        """
+        if width < 0:
+            raise ValueError("width must be non-negative")
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_to_matrix",
+            is_elementwise=False,
+            kwargs={"width": width},
+        )
+
+    def reverse(self) -> pl.Expr:
+        """
+        Reverse the order of the elements within each row's list.
+
+        Unlike ``pl.col(...).reverse()``, which reverses the order of the rows,
+        this keeps the rows in place and flips each list.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning the reversed lists, preserving input type.
+            Null rows stay null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 5]]})
+        >>> df.select(pl.col("a").vec.reverse())
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [3, 2, 1] │
+        │ [5, 4]    │
+        └───────────┘
+        """
+        return register_plugin_function(
+            args=[self._expr],
+            plugin_path=_LIB,
+            function_name="vec_reverse",
+            is_elementwise=True,
+        )
+
+    def roll(
+        self,
+        n: int | IntoExprColumn,
+        mode: str = "wrap",
+        fill_value: float | None = None,
+    ) -> pl.Expr:
+        """
+        Shift the elements of each row's list by ``n`` positions.
+
+        Positive ``n`` moves elements towards the end of the list, negative
+        ``n`` towards the start, e.g. to bring periodic signals into phase.
+
+        Parameters
+        ----------
+        n
+            Number of positions to shift by: an integer for every row, or an
+            integer column giving a shift per row.
+        mode
+            ``"wrap"`` (default) rolls circularly like ``numpy.roll``, so
+            elements shifted past one end come back in at the other.
+            ``"fill"`` drops them and fills the vacated positions instead.
+        fill_value
+            Value for the vacated positions with ``mode="fill"``. ``None``
+            (default) leaves them null.
+
+        Returns
+        -------
+        pl.Expr
+            Expression returning the shifted lists, preserving input type.
+            Null lists or a null ``n`` give null rows.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4], [5, 6, 7]], "n": [1, -1]})
+        >>> df.select(pl.col("a").vec.roll("n"))
+        shape: (2, 1)
+        ┌──────────────┐
+        │ a            │
+        │ ---          │
+        │ list[i64]    │
+        ╞══════════════╡
+        │ [4, 1, 2, 3] │
+        │ [6, 7, 5]    │
+        └──────────────┘
+        """
+        if mode not in ("wrap", "fill"):
+            raise ValueError(f"mode must be one of 'wrap', 'fill', got {mode!r}")
+        return register_plugin_function(
+            args=[self._expr, wrap_expr(parse_into_expression(n))],
+            plugin_path=_LIB,
+            function_name="vec_roll",
+            is_elementwise=True,
+            kwargs={
+                "mode": mode,
+                "fill_value": None if fill_value is None else float(fill_value),
+            },
+        )
+
+
+def sum(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    nan_policy: str = "propagate",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Sum across rows for list columns (vertical aggregation).
+
+    Returns a single row with a list where each element is the sum
+    of elements at that position across all input lists.
+
+    All lists must have the same length.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of equal length.
+    null_policy
+        ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+        position null if any row has a null there.
+    min_count
+        Positions with fewer than ``min_count`` non-null values are null.
+    keep_rows
+        If True, repeat the aggregate for every input row.
+    validate
+        ``"strict"`` (default) checks list lengths; ``"fast"`` skips the check.
+    empty_policy
+        ``"error"`` (default), ``"skip"`` or ``"treat_as_null"`` for empty lists.
+    nan_policy
+        ``"propagate"`` (default), ``"ignore"`` or ``"as_null"`` for NaN elements.
+    offset
+        First row to aggregate; negative values count from the end.
+        With ``length`` this reduces a vertical slice of the rows
+        without slicing the frame first.
+    length
+        Number of rows to aggregate from ``offset``; None (default)
+        takes every remaining row. With ``keep_rows`` the output still
+        has one row per row of the whole column.
+    every
+        Aggregate only every ``every``-th row of the slice, starting
+        with its first (e.g. ``offset=1, every=2`` for the odd rows of
+        a split-half analysis).
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[0, 1, 2], [1, 2, 3]]})
+    >>> df.select(vec.sum("a"))
+    shape: (1, 1)
+    ┌───────────┐
+    │ a         │
+    │ ---       │
+    │ list[i64] │
+    ╞═══════════╡
+    │ [1, 3, 5] │
+    └───────────┘
+
+    Can be called with multiple columns:
+    >>> df = pl.DataFrame({"a": [[0, 1], [1, 2]], "b": [[10, 20], [30, 40]]})
+    >>> df.select(vec.sum("a", "b"))
+    shape: (1, 2)
+    ┌───────────┬───────────┐
+    │ a         ┆ b         │
+    │ ---       ┆ ---       │
+    │ list[i64] ┆ list[i64] │
+    ╞═══════════╪═══════════╡
+    │ [1, 3]    ┆ [40, 60]  │
+    └───────────┴───────────┘
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).sum(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            nan_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def mean(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    nan_policy: str = "propagate",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Calculate mean across rows for list columns (vertical aggregation).
+
+    Returns a single row with a list where each element is the mean
+    of elements at that position across all input lists.
+
+    All lists must have the same length.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of equal length.
+    null_policy
+        ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+        position null if any row has a null there.
+    min_count
+        Positions with fewer than ``min_count`` non-null values are null.
+    keep_rows
+        If True, repeat the aggregate for every input row.
+    validate
+        ``"strict"`` (default) checks list lengths; ``"fast"`` skips the check.
+    empty_policy
+        ``"error"`` (default), ``"skip"`` or ``"treat_as_null"`` for empty lists.
+    nan_policy
+        ``"propagate"`` (default), ``"ignore"`` or ``"as_null"`` for NaN elements.
+    offset
+        First row to aggregate; negative values count from the end.
+        With ``length`` this reduces a vertical slice of the rows
+        without slicing the frame first.
+    length
+        Number of rows to aggregate from ``offset``; None (default)
+        takes every remaining row. With ``keep_rows`` the output still
+        has one row per row of the whole column.
+    every
+        Aggregate only every ``every``-th row of the slice, starting
+        with its first (e.g. ``offset=1, every=2`` for the odd rows of
+        a split-half analysis).
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a list of Float64 values.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1, 2, 3], [3, 4, 5]]})
+    >>> df.select(vec.mean("a"))
+    shape: (1, 1)
+    ┌─────────────────┐
+    │ a               │
+    │ ---             │
+    │ list[f64]       │
+    ╞═════════════════╡
+    │ [2.0, 3.0, 4.0] │
+    └─────────────────┘
+
+    Can be called with multiple columns:
+    >>> df = pl.DataFrame({"a": [[1, 2], [3, 4]], "b": [[10, 20], [30, 40]]})
+    >>> df.select(vec.mean("a", "b"))
+    shape: (1, 2)
+    ┌────────────┬──────────────┐
+    │ a          ┆ b            │
+    │ ---        ┆ ---          │
+    │ list[f64]  ┆ list[f64]    │
+    ╞════════════╪══════════════╡
+    │ [2.0, 3.0] ┆ [20.0, 30.0] │
+    └────────────┴──────────────┘
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).mean(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            nan_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def avg(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    nan_policy: str = "propagate",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Alias for mean(). Calculate average across rows for list columns.
+
+    See mean() for full documentation.
+    """
+    return mean(
+        *exprs,
+        null_policy=null_policy,
+        min_count=min_count,
+        keep_rows=keep_rows,
+        validate=validate,
+        empty_policy=empty_policy,
+        nan_policy=nan_policy,
+        offset=offset,
+        length=length,
+        every=every,
+    )
+
+
+def min(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    nan_policy: str = "propagate",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Find minimum element at each position across rows (vertical aggregation).
+
+    Returns a single row with a list where each element is the minimum
+    of elements at that position across all input lists.
+
+    All lists must have the same length.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of equal length.
+    null_policy
+        ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+        position null if any row has a null there.
+    min_count
+        Positions with fewer than ``min_count`` non-null values are null.
+    keep_rows
+        If True, repeat the aggregate for every input row.
+    validate
+        ``"strict"`` (default) checks list lengths; ``"fast"`` skips the check.
+    empty_policy
+        ``"error"`` (default), ``"skip"`` or ``"treat_as_null"`` for empty lists.
+    nan_policy
+        ``"propagate"`` (default), ``"ignore"`` or ``"as_null"`` for NaN elements.
+    offset
+        First row to aggregate; negative values count from the end.
+        With ``length`` this reduces a vertical slice of the rows
+        without slicing the frame first.
+    length
+        Number of rows to aggregate from ``offset``; None (default)
+        takes every remaining row. With ``keep_rows`` the output still
+        has one row per row of the whole column.
+    every
+        Aggregate only every ``every``-th row of the slice, starting
+        with its first (e.g. ``offset=1, every=2`` for the odd rows of
+        a split-half analysis).
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a list with the same type as input.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[3, 5, 2], [1, 7, 4]]})
+    >>> df.select(vec.min("a"))
+    shape: (1, 1)
+    ┌───────────┐
+    │ a         │
+    │ ---       │
+    │ list[i64] │
+    ╞═══════════╡
+    │ [1, 5, 2] │
+    └───────────┘
+
+    Can be called with multiple columns:
+    >>> df = pl.DataFrame({"a": [[3, 5], [1, 7]], "b": [[10, 20], [5, 15]]})
+    >>> df.select(vec.min("a", "b"))
+    shape: (1, 2)
+    ┌───────────┬───────────┐
+    │ a         ┆ b         │
+    │ ---       ┆ ---       │
+    │ list[i64] ┆ list[i64] │
+    ╞═══════════╪═══════════╡
+    │ [1, 5]    ┆ [5, 15]   │
+    └───────────┴───────────┘
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).min(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            nan_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def max(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    nan_policy: str = "propagate",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Find maximum element at each position across rows (vertical aggregation).
+
+    Returns a single row with a list where each element is the maximum
+    of elements at that position across all input lists.
+
+    All lists must have the same length.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of equal length.
+    null_policy
+        ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+        position null if any row has a null there.
+    min_count
+        Positions with fewer than ``min_count`` non-null values are null.
+    keep_rows
+        If True, repeat the aggregate for every input row.
+    validate
+        ``"strict"`` (default) checks list lengths; ``"fast"`` skips the check.
+    empty_policy
+        ``"error"`` (default), ``"skip"`` or ``"treat_as_null"`` for empty lists.
+    nan_policy
+        ``"propagate"`` (default), ``"ignore"`` or ``"as_null"`` for NaN elements.
+    offset
+        First row to aggregate; negative values count from the end.
+        With ``length`` this reduces a vertical slice of the rows
+        without slicing the frame first.
+    length
+        Number of rows to aggregate from ``offset``; None (default)
+        takes every remaining row. With ``keep_rows`` the output still
+        has one row per row of the whole column.
+    every
+        Aggregate only every ``every``-th row of the slice, starting
+        with its first (e.g. ``offset=1, every=2`` for the odd rows of
+        a split-half analysis).
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a list with the same type as input.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[3, 5, 2], [1, 7, 4]]})
+    >>> df.select(vec.max("a"))
+    shape: (1, 1)
+    ┌───────────┐
+    │ a         │
+    │ ---       │
+    │ list[i64] │
+    ╞═══════════╡
+    │ [3, 7, 4] │
+    └───────────┘
+
+    Can be called with multiple columns:
+    >>> df = pl.DataFrame({"a": [[3, 5], [1, 7]], "b": [[10, 20], [30, 15]]})
+    >>> df.select(vec.max("a", "b"))
+    shape: (1, 2)
+    ┌───────────┬───────────┐
+    │ a         ┆ b         │
+    │ ---       ┆ ---       │
+    │ list[i64] ┆ list[i64] │
+    ╞═══════════╪═══════════╡
+    │ [3, 7]    ┆ [30, 20]  │
+    └───────────┴───────────┘
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).max(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            nan_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def diff(
+    *exprs: IntoExprColumn, n: int = 1, reverse_rows: bool = False
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Calculate differences between consecutive rows at each position.
+
+    Returns the same number of rows as input. The first row contains
+    a list of nulls (no previous row to compare). Each subsequent row
+    contains the element-wise difference from the previous row: row[i] - row[i-1].
+
+    If either the current or previous row is null, the result is a list of nulls.
+
+    All lists must have the same length.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of equal length.
+    n
+        Order of the difference, applied in a single pass.
+    reverse_rows
+        Difference against the following rows, ``row[i] - row[i+1]``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists with differences; unsigned and 8/16-bit
+        integers give a wider signed dtype. The first ``n`` rows (last with
+        ``reverse_rows``) are always lists of nulls.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[5, 10, 15], [2, 15, 5], [0, 0, 0]]})
+    >>> df.select(vec.diff("a"))
+    shape: (3, 1)
+    ┌────────────────────┐
+    │ a                  │
+    │ ---                │
+    │ list[i64]          │
+    ╞════════════════════╡
+    │ [null, null, null] │
+    │ [-3, 5, -10]       │
+    │ [-2, -15, -5]      │
+    └────────────────────┘
+
+    Can be called with multiple columns:
+    >>> df = pl.DataFrame({"a": [[5, 10], [2, 15], [0, 0]], "b": [[50, 100], [20, 150], [0, 0]]})
+    >>> df.select(vec.diff("a", "b"))
+    shape: (3, 2)
+    ┌──────────────┬──────────────┐
+    │ a            ┆ b            │
+    │ ---          ┆ ---          │
+    │ list[i64]    ┆ list[i64]    │
+    ╞══════════════╪══════════════╡
+    │ [null, null] ┆ [null, null] │
+    │ [-3, 5]      ┆ [-30, 50]    │
+    │ [-2, -15]    ┆ [-20, -150]  │
+    └──────────────┴──────────────┘
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).diff(n, reverse_rows)
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def convolve(
+    expr: IntoExprColumn,
+    kernel: list[float] | pl.Series,
+    fill_value: float = 0.0,
+    mode: str = "same",
+) -> pl.Expr:
+    """
+    Convolve each list in the column with the given kernel.
+
+    Performs 1D convolution on each row's list independently.
+    The convolution operation applies the kernel as a sliding window
+    over each signal, computing weighted sums.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays to convolve.
+    kernel
+        The convolution kernel (filter). Can be a list of floats or
+        a Polars Series. Non-finite values are filtered out.
+    fill_value
+        Value to use for null elements in the signal. Default is 0.0.
+    mode
+        Convolution mode, one of:
+        - "full": Full convolution, output length = signal_len + kernel_len - 1
+        - "same": Same length as signal, centered (default)
+        - "valid": Only where signal and kernel fully overlap,
+                  output length = signal_len - kernel_len + 1
+        - "left": Same length as signal, left-aligned
+        - "right": Same length as signal, right-aligned
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 values.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"signal": [[1, 2, 3, 4, 5]]})
+    >>> kernel = [0.25, 0.5, 0.25]
+    >>> df.select(vec.convolve("signal", kernel, mode="same"))
+    shape: (1, 1)
+    ┌───────────────────┐
+    │ signal            │
+    │ ---               │
+    │ list[f64]         │
+    ╞═══════════════════╡
+    │ [1.0, 2.0, … 3.5] │
+    └───────────────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).convolve(kernel, fill_value, mode)
+
+
+def histogram(
+    expr: IntoExprColumn,
+    bins: int | list[float] | pl.Series | pl.Expr | str | None = None,
+    *,
+    start: float | pl.Expr | None = None,
+    stop: float | pl.Expr | None = None,
+    spacing: float | pl.Expr | None = None,
+    count_dtype: pl.DataType | type[bool] | type[int] | None = None,
+    include_breakpoints: bool = False,
+) -> pl.Expr:
+    """
+    Compute a histogram for each list in the column.
+
+    Bin specification supports two mutually exclusive modes:
+
+    1. ``bins``: an integer number of bins (auto-ranged from data) or
+       explicit bin edges as a list.
+    2. ``start`` / ``stop`` / ``spacing``: evenly spaced bins.
+
+    Any scalar parameter can be a ``pl.Expr`` to derive values per-row
+    from other columns.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    bins
+        Number of bins (int) or explicit bin edges (list/Series).
+        Can be a ``pl.Expr`` resolving to an integer column.
+    start
+        Left edge of the first bin.
+    stop
+        Right edge of the last bin.
+    spacing
+        Width of each bin.
+    count_dtype
+        Data type for the counts field. Smaller types save memory.
+        Accepts ``pl.Boolean`` / ``bool``, ``pl.UInt8``, ``pl.UInt16``,
+        or ``pl.UInt32`` / ``int`` (default).
+    include_breakpoints
+        If ``True``, return a Struct with ``breakpoints`` and ``counts``.
+        If ``False`` (default), return ``List[count_dtype]`` directly.
+
+    Returns
+    -------
+    pl.Expr
+        If ``include_breakpoints=True``, returns a Struct with fields:
+        - ``breakpoints``: ``List[Float64]`` — n+1 bin edges
+        - ``counts``: ``List[count_dtype]`` — n bin counts
+
+        If ``include_breakpoints=False`` (default), returns
+        ``List[count_dtype]`` directly.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1, 2, 3, 4, 5]]})
+    >>> df.select(vec.histogram("a", bins=3))
+    shape: (1, 1)
+    ┌───────────┐
+    │ a         │
+    │ ---       │
+    │ list[u32] │
+    ╞═══════════╡
+    │ [2, 1, 2] │
+    └───────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).histogram(
+        bins, start=start, stop=stop, spacing=spacing,
+        count_dtype=count_dtype, include_breakpoints=include_breakpoints,
+    )
+
+
+hist = histogram
+
+
+def zero_crossings(expr: IntoExprColumn, return_indices: bool = False) -> pl.Expr:
+    """
+    Count the sign changes within each list.
+
+    Nulls, NaNs and exact zeros carry no sign and are skipped.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    return_indices
+        If ``True``, return the index of the first element after each
+        crossing instead of the number of crossings. Default ``False``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``UInt32`` counts, or ``List[UInt32]`` indices.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1, -1, 2, 3]]})
+    >>> df.select(vec.zero_crossings("a"))
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 2   │
+    └─────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).zero_crossings(return_indices)
+
+
+def baseline_normalize(
+    expr: IntoExprColumn,
+    baseline_start: int = 0,
+    baseline_end: int | None = None,
+    mode: str = "subtract",
+) -> pl.Expr:
+    """
+    Normalize each list against a baseline computed from a slice of itself.
+
+    The baseline is the mean of ``list[baseline_start:baseline_end]``.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    baseline_start
+        Index of the first baseline element. Default ``0``.
+    baseline_end
+        Index one past the last baseline element. ``None`` (default) uses
+        the whole list.
+    mode
+        One of "subtract" (default), "divide" (dF/F) or "zscore".
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 values.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[2.0, 2.0, 4.0]]})
+    >>> df.select(vec.baseline_normalize("a", baseline_end=2, mode="divide"))
+    shape: (1, 1)
+    ┌─────────────────┐
+    │ a               │
+    │ ---             │
+    │ list[f64]       │
+    ╞═════════════════╡
+    │ [0.0, 0.0, 1.0] │
+    └─────────────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).baseline_normalize(
+        baseline_start, baseline_end, mode
+    )
+
+
+def detrend(expr: IntoExprColumn, method: str = "linear", axis: int = 1) -> pl.Expr:
+    """
+    Remove a constant or linear trend from each list.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    method
+        "linear" (default) or "constant".
+    axis
+        ``1`` (default) detrends within each list, ``0`` detrends each
+        position across rows.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 values.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1.0, 2.0], [3.0, 2.0], [5.0, 2.0]]})
+    >>> df.select(vec.detrend("a", axis=0))
+    shape: (3, 1)
+    ┌────────────┐
+    │ a          │
+    │ ---        │
+    │ list[f64]  │
+    ╞════════════╡
+    │ [0.0, 0.0] │
+    │ [0.0, 0.0] │
+    │ [0.0, 0.0] │
+    └────────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).detrend(method, axis)
+
+
+def trapz(
+    expr: IntoExprColumn,
+    x: IntoExprColumn | None = None,
+    dx: float = 1.0,
+) -> pl.Expr:
+    """
+    Integrate each list using the trapezoidal rule.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of y values.
+    x
+        Optional List or Array column of sample positions.
+    dx
+        Spacing between samples when ``x`` is not given. Default ``1.0``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one Float64 area per row.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1.0, 2.0, 3.0]]})
+    >>> df.select(vec.trapz("a", dx=0.5))
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ f64 │
+    ╞═════╡
+    │ 2.0 │
+    └─────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).trapz(x, dx)
+
+
+def event_triggered_average(
+    expr: IntoExprColumn,
+    events: IntoExprColumn,
+    pre: int,
+    post: int,
+    *,
+    return_stats: bool = False,
+) -> pl.Expr:
+    """
+    Average windows of a continuous trace aligned on event indices.
+
+    Parameters
+    ----------
+    expr
+        Column name containing the trace lists/arrays.
+    events
+        Column of event indices into each row's trace (integer or List of
+        integers per row).
+    pre
+        Number of samples before each event to include.
+    post
+        Number of samples from each event onwards to include.
+    return_stats
+        If ``True``, return a Struct with ``mean``, ``count`` and ``sem``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single ``List[Float64]`` of length ``pre + post``.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"trace": [[0, 1, 5, 1], [2, 6, 2, 0]], "events": [2, 1]})
+    >>> df.select(vec.event_triggered_average("trace", "events", pre=1, post=2))
+    shape: (1, 1)
+    ┌─────────────────┐
+    │ trace           │
+    │ ---             │
+    │ list[f64]       │
+    ╞═════════════════╡
+    │ [1.5, 5.5, 1.5] │
+    └─────────────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).event_triggered_average(
+        events, pre, post, return_stats=return_stats
+    )
+
+
+def slice_around(
+    expr: IntoExprColumn,
+    indices: IntoExprColumn,
+    before: int,
+    after: int,
+    pad: float | None = None,
+) -> pl.Expr:
+    """
+    Extract a fixed-width window from each list around a per-row index.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    indices
+        Integer column giving the index into each row's list.
+    before
+        Number of elements before the index to include.
+    after
+        Number of elements from the index onwards to include.
+    pad
+        Value for window positions outside the list. ``None`` (default)
+        leaves them null.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``Array[inner, before + after]``.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1, 2, 3, 4, 5]], "i": [2]})
+    >>> df.select(vec.slice_around("a", "i", before=1, after=2))
+    shape: (1, 1)
+    ┌───────────────┐
+    │ a             │
+    │ ---           │
+    │ array[i64, 3] │
+    ╞═══════════════╡
+    │ [2, 3, 4]     │
+    └───────────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).slice_around(
+        indices, before, after, pad
+    )
+
+
+def rebin_by_time(
+    expr: IntoExprColumn,
+    timestamps: IntoExprColumn,
+    bin_width: float,
+    agg: str = "mean",
+    start: float | None = None,
+    stop: float | None = None,
+) -> pl.Expr:
+    """
+    Rebin each list onto a uniform time grid using per-element timestamps.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of values.
+    timestamps
+        List column with one timestamp per value.
+    bin_width
+        Width of each bin, in the same units as the timestamps.
+    agg
+        Aggregation within each bin: ``"mean"`` (default), ``"sum"``,
+        ``"min"``, ``"max"``, ``"first"``, ``"last"`` or ``"count"``.
+    start
+        Left edge of the first bin. Defaults to each row's earliest timestamp.
+    stop
+        Right edge of the grid. Defaults to covering each row's latest
+        timestamp.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` (``List[u32]`` for ``agg="count"``).
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"v": [[1.0, 2.0, 4.0]], "t": [[0.0, 0.5, 1.2]]})
+    >>> df.select(vec.rebin_by_time("v", "t", bin_width=1.0, agg="sum"))
+    shape: (1, 1)
+    ┌────────────┐
+    │ v          │
+    │ ---        │
+    │ list[f64]  │
+    ╞════════════╡
+    │ [3.0, 4.0] │
+    └────────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).rebin_by_time(
+        timestamps, bin_width, agg, start, stop
+    )
+
+
+def align(
+    a: IntoExprColumn,
+    b: IntoExprColumn,
+    max_lag: int,
+    return_lag: bool = False,
+) -> pl.Expr:
+    """
+    Align each row's ``b`` list to its ``a`` list by cross-correlation.
+
+    Parameters
+    ----------
+    a
+        Reference column containing lists/arrays.
+    b
+        Column containing the lists to align.
+    max_lag
+        Largest lag, in elements, to consider in either direction.
+    return_lag
+        If True, return the estimated lag instead of the aligned lists.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``b`` shifted by the lag as ``List[f64]``, or an
+        ``Int64`` lag if ``return_lag=True``.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[0, 1, 3]], "b": [[1, 3, 0]]})
+    >>> df.select(vec.align("a", "b", max_lag=1))
+    shape: (1, 1)
+    ┌──────────────────┐
+    │ a                │
+    │ ---              │
+    │ list[f64]        │
+    ╞══════════════════╡
+    │ [null, 1.0, 3.0] │
+    └──────────────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).align(
+        b, max_lag, return_lag
+    )
+
+
+def transpose(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Transpose a list column, swapping rows and list positions.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[inner]`` with one row per list position.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1, 2], [3, 4], [5, 6]]})
+    >>> df.select(vec.transpose("a"))
+    shape: (2, 1)
+    ┌───────────┐
+    │ a         │
+    │ ---       │
+    │ list[i64] │
+    ╞═══════════╡
+    │ [1, 3, 5] │
+    │ [2, 4, 6] │
+    └───────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).transpose()
+
+
+def stack(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Collapse all rows of a list column into a single matrix row.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single row of ``List[List[inner]]`` (or
+        ``List[Array[inner, width]]`` for Array input).
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1, 2], [3, 4]]})
+    >>> df.select(vec.stack("a"))
+    shape: (1, 1)
+    ┌──────────────────┐
+    │ a                │
+    │ ---              │
+    │ list[list[i64]]  │
+    ╞══════════════════╡
+    │ [[1, 2], [3, 4]] │
+    └──────────────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).stack()
+
+
+def matmul(expr: IntoExprColumn, matrix: Sequence[Sequence[float]]) -> pl.Expr:
+    """
+    Multiply each row's vector by a constant matrix.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of length ``m``.
+    matrix
+        Nested sequence (or 2-D numpy array) of shape ``(m, k)``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` rows of length ``k``.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[1, 2], [3, 4]]})
+    >>> df.select(vec.matmul("a", [[1], [1]]))
+    shape: (2, 1)
+    ┌───────────┐
+    │ a         │
+    │ ---       │
+    │ list[f64] │
+    ╞═══════════╡
+    │ [3.0]     │
+    │ [7.0]     │
+    └───────────┘
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).matmul(matrix)
+
+
+def cov_matrix(expr: IntoExprColumn, ddof: int = 1) -> pl.Expr:
+    """
+    Covariance matrix between list positions across rows.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    ddof
+        Delta degrees of freedom (default 1).
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the positions x positions covariance matrix as a
+        single ``List[List[f64]]`` row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).cov_matrix(ddof)
+
+
+def corr_matrix(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Pearson correlation matrix between list positions across rows.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the positions x positions correlation matrix as a
+        single ``List[List[f64]]`` row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).corr_matrix()
+
+
+def trend(expr: IntoExprColumn, return_stats: bool = False) -> pl.Expr:
+    """
+    Per-position linear trend (OLS slope against row index) across rows.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    return_stats
+        If True, return a struct with ``slope``, ``intercept`` and ``r2``
+        lists instead of just the slopes.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single ``List[f64]`` row of slopes, or a struct
+        of such lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).trend(return_stats)
+
+
+def regress(y: IntoExprColumn, x: IntoExprColumn) -> pl.Expr:
+    """
+    Per-position linear regression of list values against a covariate column.
+
+    Parameters
+    ----------
+    y
+        Column name containing lists/arrays of equal length.
+    x
+        Numeric column with one covariate value per row.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single row struct with ``slope``,
+        ``intercept`` and ``p_value`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(y))).regress(x)
+
+
+def ttest(a: IntoExprColumn, b: IntoExprColumn, equal_var: bool = False) -> pl.Expr:
+    """
+    Per-position two-sample t-test between two list columns.
+
+    Parameters
+    ----------
+    a
+        Column name containing the first sample's lists.
+    b
+        Column name containing the second sample's lists.
+    equal_var
+        If False (default), use Welch's t-test; otherwise Student's.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single row struct with ``statistic`` and
+        ``p_value`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).ttest(b, equal_var)
+
+
+def mannwhitneyu(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Per-position Mann-Whitney U test between two list columns.
+
+    Parameters
+    ----------
+    a
+        Column name containing the first sample's lists.
+    b
+        Column name containing the second sample's lists.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single row struct with ``statistic`` and
+        ``p_value`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).mannwhitneyu(b)
+
+
+def ttest_1samp(expr: IntoExprColumn, popmean: float = 0.0) -> pl.Expr:
+    """
+    Per-position one-sample t-test against a constant.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    popmean
+        Expected mean under the null hypothesis (default 0).
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single row struct with ``statistic`` and
+        ``p_value`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).ttest_1samp(popmean)
+
+
+def mean_ci(
+    expr: IntoExprColumn, n_boot: int = 1000, ci: float = 0.95, seed: int | None = None
+) -> pl.Expr:
+    """
+    Vertical mean with bootstrap confidence intervals per position.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    n_boot
+        Number of bootstrap replicates.
+    ci
+        Confidence level, between 0 and 1 (default 0.95).
+    seed
+        Seed for the random generator, for reproducible intervals.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single row struct with ``mean``, ``lower`` and
+        ``upper`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).mean_ci(
+        n_boot, ci, seed
+    )
+
+
+def permutation_test(
+    expr: IntoExprColumn,
+    other: IntoExprColumn | None = None,
+    n_perm: int = 1000,
+    statistic: str = "mean",
+    seed: int | None = None,
+) -> pl.Expr:
+    """
+    Per-position permutation test across rows.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    other
+        Optional second list column; without it a sign-flip test against zero
+        is used.
+    n_perm
+        Number of permutations.
+    statistic
+        ``"mean"`` (default) or ``"t"``.
+    seed
+        Seed for the random generator.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single row struct with ``statistic`` and
+        ``p_value`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).permutation_test(
+        other, n_perm, statistic, seed
+    )
+
+
+def mann_kendall(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Per-position Mann-Kendall trend test across rows.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single row struct with ``statistic``, ``tau``
+        and ``p_value`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).mann_kendall()
+
+
+def entropy(expr: IntoExprColumn, bins: int = 10, base: float | None = None) -> pl.Expr:
+    """
+    Per-position Shannon entropy of the values across rows.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    bins
+        Number of equal-width bins for float columns (integers are counted
+        exactly).
+    base
+        Logarithm base; ``None`` (default) uses the natural log.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single ``List[f64]`` row of entropies.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).entropy(bins, base)
+
+
+def percentile_rank(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Percentile of each value among all rows at the same position.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` percentiles (0-100) in the shape of
+        the input.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).percentile_rank()
+
+
+def top_k_rows(expr: IntoExprColumn, k: int, return_indices: bool = False) -> pl.Expr:
+    """
+    Per-position k largest values across rows.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    k
+        Number of values to keep per position.
+    return_indices
+        If True, return a struct with ``values`` and row ``indices``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single ``List[List[inner]]`` row with one
+        descending list per position.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).top_k_rows(
+        k, return_indices
+    )
+
+
+def is_max(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Mark elements equal to the vertical maximum at their position.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[bool]`` in the shape of the input.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).is_max()
+
+
+def is_min(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Mark elements equal to the vertical minimum at their position.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[bool]`` in the shape of the input.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).is_min()
+
+
+def eq(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Element-wise test of whether ``a`` is equal to ``b``, per row.
+
+    Parameters
+    ----------
+    a
+        Column name containing lists/arrays.
+    b
+        Column name containing lists/arrays of matching length per row, or
+        numeric values to compare against every element.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[bool]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).eq(b)
+
+
+def ne(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Element-wise test of whether ``a`` is not equal to ``b``, per row.
+
+    Parameters
+    ----------
+    a
+        Column name containing lists/arrays.
+    b
+        Column name containing lists/arrays of matching length per row, or
+        numeric values to compare against every element.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[bool]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).ne(b)
+
+
+def gt(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Element-wise test of whether ``a`` is greater than ``b``, per row.
+
+    Parameters
+    ----------
+    a
+        Column name containing lists/arrays.
+    b
+        Column name containing lists/arrays of matching length per row, or
+        numeric values to compare against every element.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[bool]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).gt(b)
+
+
+def ge(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Element-wise test of whether ``a`` is greater than or equal to ``b``, per row.
+
+    Parameters
+    ----------
+    a
+        Column name containing lists/arrays.
+    b
+        Column name containing lists/arrays of matching length per row, or
+        numeric values to compare against every element.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[bool]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).ge(b)
+
+
+def lt(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Element-wise test of whether ``a`` is less than ``b``, per row.
+
+    Parameters
+    ----------
+    a
+        Column name containing lists/arrays.
+    b
+        Column name containing lists/arrays of matching length per row, or
+        numeric values to compare against every element.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[bool]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).lt(b)
+
+
+def le(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Element-wise test of whether ``a`` is less than or equal to ``b``, per row.
+
+    Parameters
+    ----------
+    a
+        Column name containing lists/arrays.
+    b
+        Column name containing lists/arrays of matching length per row, or
+        numeric values to compare against every element.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[bool]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).le(b)
+
+
+def where(mask: IntoExprColumn, a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Choose elements from ``a`` or ``b`` per position by a Boolean list mask.
+
+    Parameters
+    ----------
+    mask
+        Column name containing lists/arrays of booleans.
+    a
+        Column name containing the elements taken where ``mask`` is True, or
+        a numeric scalar.
+    b
+        Column name containing the elements taken where ``mask`` is False, or
+        a numeric scalar.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).where(mask, b)
+
+
+def min_horizontal(*exprs: IntoExprColumn) -> pl.Expr:
+    """
+    Element-wise minimum across two or more list columns, per row.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of matching length per row; all
+        but the first may also be numeric scalars.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists in the shape of the first input.
+    """
+    first, *others = parse_into_list_of_expressions(*exprs)
+    return VecOpsNamespace(wrap_expr(first)).min_horizontal(*(wrap_expr(e) for e in others))
+
+
+def max_horizontal(*exprs: IntoExprColumn) -> pl.Expr:
+    """
+    Element-wise maximum across two or more list columns, per row.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of matching length per row; all
+        but the first may also be numeric scalars.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists in the shape of the first input.
+    """
+    first, *others = parse_into_list_of_expressions(*exprs)
+    return VecOpsNamespace(wrap_expr(first)).max_horizontal(*(wrap_expr(e) for e in others))
+
+
+def fma(a: IntoExprColumn, b: IntoExprColumn, c: IntoExprColumn) -> pl.Expr:
+    """
+    Fused multiply-add ``a * b + c`` element-wise within each row.
+
+    Parameters
+    ----------
+    a
+        Column name containing lists/arrays.
+    b
+        Column name containing the multipliers, or a numeric scalar.
+    c
+        Column name containing the addends, or a numeric scalar.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).fma(b, c)
+
+
+def add_const(expr: IntoExprColumn, values: Sequence[float]) -> pl.Expr:
+    """
+    Add a constant vector element-wise to each row's list.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    values
+        Sequence with one value per list position.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` in the shape of the input.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).add_const(values)
+
+
+def mul_const(expr: IntoExprColumn, values: Sequence[float]) -> pl.Expr:
+    """
+    Multiply each row's list element-wise by a constant vector.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    values
+        Sequence with one value per list position.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` in the shape of the input.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).mul_const(values)
+
+
+def normalize_vertical(
+    expr: IntoExprColumn, method: str = "mean", mode: str = "divide"
+) -> pl.Expr:
+    """
+    Normalize each row by the vertical aggregate at each position.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    method
+        Vertical aggregate to normalize by: ``"mean"``, ``"max"`` or ``"sum"``.
+    mode
+        ``"divide"`` or ``"subtract"``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` in the shape of the input.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).normalize_vertical(
+        method, mode
+    )
+
+
+def cum_std(expr: IntoExprColumn, ddof: int = 1) -> pl.Expr:
+    """
+    Expanding standard deviation down the rows at each position.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    ddof
+        Delta degrees of freedom.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` in the shape of the input.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).cum_std(ddof)
+
+
+def cum_count(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Expanding count of non-null values down the rows at each position.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[u32]`` in the shape of the input.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).cum_count()
+
+
+def mean_std(expr: IntoExprColumn, ddof: int = 1) -> pl.Expr:
+    """
+    Vertical mean and standard deviation per position in one pass.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    ddof
+        Delta degrees of freedom for the standard deviation.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a struct with ``mean`` and ``std`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).mean_std(ddof)
+
+
+def cv(
+    expr: IntoExprColumn,
+    ddof: int = 1,
+    zero_tol: float = 1e-12,
+    on_zero_mean: str = "null",
+) -> pl.Expr:
+    """
+    Coefficient of variation (std / mean) across rows at each position.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    ddof
+        Delta degrees of freedom for the standard deviation.
+    zero_tol
+        Means with an absolute value at or below this are treated as zero.
+    on_zero_mean
+        ``"null"``, ``"nan"`` or ``"raise"`` at positions with a zero mean.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` with one value per position.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).cv(
+        ddof, zero_tol, on_zero_mean
+    )
+
+
+def split_half_reliability(
+    expr: IntoExprColumn, n_splits: int = 100, seed: int | None = None
+) -> pl.Expr:
+    """
+    Split-half reliability of the per-position profile across rows.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    n_splits
+        Number of random splits to average over.
+    seed
+        Seed for the random generator.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the Spearman-Brown corrected reliability.
+    """
+    return VecOpsNamespace(
+        wrap_expr(parse_into_expression(expr))
+    ).split_half_reliability(n_splits, seed)
+
+
+def icc(expr: IntoExprColumn, kind: str = "icc2") -> pl.Expr:
+    """
+    Intraclass correlation across rows, or Cronbach's alpha.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    kind
+        ``"icc1"``, ``"icc2"``, ``"icc3"`` or ``"alpha"``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the reliability coefficient.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).icc(kind)
+
+
+def pairwise_distance(
+    expr: IntoExprColumn, metric: str = "euclidean", long: bool = False
+) -> pl.Expr:
+    """
+    Distance between every pair of rows, treating each list as a vector.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    metric
+        Distance metric, as in ``scipy.spatial.distance``.
+    long
+        If True, return ``{i, j, distance}`` rows instead of the matrix.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the distance matrix (or its long form).
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).pairwise_distance(
+        metric, long
+    )
+
+
+def distance_to(
+    expr: IntoExprColumn,
+    reference: Sequence[float] | IntoExprColumn,
+    metric: str = "euclidean",
+) -> pl.Expr:
+    """
+    Distance from each row's vector to a reference vector.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    reference
+        Constant sequence, or a List/Array expression with one row (or one
+        row per input row).
+    metric
+        Distance metric, as in ``scipy.spatial.distance``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``f64`` per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).distance_to(
+        reference, metric
+    )
+
+
+def assign_clusters(
+    expr: IntoExprColumn,
+    centroids: Sequence[Sequence[float]],
+    metric: str = "euclidean",
+) -> pl.Expr:
+    """
+    Label each row with the index of its nearest centroid.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    centroids
+        ``k`` centroid vectors, each with one value per list position.
+    metric
+        Distance metric, as in ``scipy.spatial.distance``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``UInt32`` label per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).assign_clusters(
+        centroids, metric
+    )
+
+
+def corr(a: IntoExprColumn, b: IntoExprColumn, method: str = "pearson") -> pl.Expr:
+    """
+    Correlation between two list columns within each row.
+
+    Parameters
+    ----------
+    a
+        First list column.
+    b
+        Second list column with the same length per row.
+    method
+        ``"pearson"`` or ``"spearman"``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``f64`` per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).corr(b, method)
+
+
+def rmse(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Root-mean-square error between two list columns within each row.
+
+    Parameters
+    ----------
+    a
+        First list column.
+    b
+        Second list column with the same length per row.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``f64`` per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).rmse(b)
+
+
+def mae(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Mean absolute error between two list columns within each row.
+
+    Parameters
+    ----------
+    a
+        First list column.
+    b
+        Second list column with the same length per row.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``f64`` per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).mae(b)
+
+
+def r2(observed: IntoExprColumn, predicted: IntoExprColumn) -> pl.Expr:
+    """
+    Coefficient of determination of predictions within each row.
+
+    Parameters
+    ----------
+    observed
+        List column of observations.
+    predicted
+        List column of predictions with the same length per row.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``f64`` per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(observed))).r2(predicted)
+
+
+def self_similarity(expr: IntoExprColumn, long: bool = False) -> pl.Expr:
+    """
+    Cosine similarity of every row against every other row.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    long
+        If True, return ``{i, j, similarity}`` rows instead of the matrix.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the similarity matrix (or its long form).
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).self_similarity(long)
+
+
+def cross(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    3D cross product of two ``Array(_, 3)`` columns in each row.
+
+    Parameters
+    ----------
+    a
+        First ``Array(_, 3)`` column.
+    b
+        Second ``Array(_, 3)`` column.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``Array[f64, 3]``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).cross(b)
+
+
+def outer(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Outer product of two list columns in each row.
+
+    Parameters
+    ----------
+    a
+        First list column (rows of the result).
+    b
+        Second list column (columns of the result).
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[List[f64]]``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).outer(b)
+
+
+def project(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Vector projection of ``a`` onto ``b`` in each row.
+
+    Parameters
+    ----------
+    a
+        List column to project.
+    b
+        List column to project onto.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).project(b)
+
+
+def reject(a: IntoExprColumn, b: IntoExprColumn) -> pl.Expr:
+    """
+    Vector rejection of ``a`` from ``b`` in each row.
+
+    Parameters
+    ----------
+    a
+        List column to decompose.
+    b
+        List column whose direction is removed.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` in the shape of ``a``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).reject(b)
+
+
+def angle(a: IntoExprColumn, b: IntoExprColumn, degrees: bool = False) -> pl.Expr:
+    """
+    Angle between two list columns' vectors in each row.
+
+    Parameters
+    ----------
+    a
+        First list column.
+    b
+        Second list column with the same length per row.
+    degrees
+        If True, return degrees instead of radians.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``f64`` per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(a))).angle(b, degrees)
+
+
+def stats(expr: IntoExprColumn, ddof: int = 1) -> pl.Expr:
+    """
+    Summary statistics of each row's own list in one pass.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    ddof
+        Delta degrees of freedom for the standard deviation.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a ``{mean, std, min, max, median}`` struct per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).stats(ddof)
+
+
+def weighted_mean(values: IntoExprColumn, weights: IntoExprColumn) -> pl.Expr:
+    """
+    Weighted mean of each row's list.
+
+    Parameters
+    ----------
+    values
+        List column of values.
+    weights
+        List column with one weight per element.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``f64`` per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(values))).weighted_mean(
+        weights
+    )
+
+
+def softmax(expr: IntoExprColumn, temperature: float = 1.0) -> pl.Expr:
+    """
+    Softmax within each row's list.
+
+    Parameters
+    ----------
+    expr
+        List column of logits.
+    temperature
+        Positive temperature. Default ``1.0``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 probabilities.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).softmax(temperature)
+
+
+def sigmoid(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Logistic sigmoid of every list element.
+
+    Parameters
+    ----------
+    expr
+        List column.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 values.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).sigmoid()
+
+
+def tanh(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Hyperbolic tangent of every list element.
+
+    Parameters
+    ----------
+    expr
+        List column.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 values.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).tanh()
+
+
+def minmax_scale(
+    expr: IntoExprColumn, feature_range: tuple[float, float] = (0.0, 1.0)
+) -> pl.Expr:
+    """
+    Min-max scale each row's list to a target range.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    feature_range
+        ``(min, max)`` of the output range. Default ``(0.0, 1.0)``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 values.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).minmax_scale(
+        feature_range
+    )
+
+
+def robust_scale(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Robust scale each row's list by its median and interquartile range.
+
+    Parameters
+    ----------
+    expr
+        List column.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 values.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).robust_scale()
+
+
+def zscore(expr: IntoExprColumn, ddof: int = 1) -> pl.Expr:
+    """
+    Standardize each row's list against its own mean and standard deviation.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    ddof
+        Delta degrees of freedom for the standard deviation. Default ``1``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 values.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).zscore(ddof)
+
+
+def rank(
+    expr: IntoExprColumn, method: str = "average", descending: bool = False
+) -> pl.Expr:
+    """
+    Rank each element within its own list.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    method
+        Tie handling: "average" (default), "min", "max", "dense" or
+        "ordinal".
+    descending
+        Rank the largest value first. Default ``False``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning lists of Float64 ranks.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).rank(
+        method, descending
+    )
+
+
+def top_k(expr: IntoExprColumn, k: int, return_indices: bool = False) -> pl.Expr:
+    """
+    The k largest elements of each row's list.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    k
+        Number of elements to keep per row.
+    return_indices
+        If True, return a struct with ``values`` and list ``indices``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a descending list per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).top_k(
+        k, return_indices
+    )
+
+
+def arg_sort(
+    expr: IntoExprColumn, descending: bool = False, nulls_last: bool = True
+) -> pl.Expr:
+    """
+    Indices that would sort each row's list.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    descending
+        Sort from largest to smallest. Default ``False``.
+    nulls_last
+        Place null elements at the end. Default ``True``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``UInt32`` positions within each list.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).arg_sort(
+        descending, nulls_last
+    )
+
+
+def sort_by(
+    values: IntoExprColumn,
+    keys: IntoExprColumn,
+    descending: bool = False,
+    nulls_last: bool = True,
+) -> pl.Expr:
+    """
+    Sort each row's list by the elements of a companion keys list.
+
+    Parameters
+    ----------
+    values
+        List column to reorder.
+    keys
+        Numeric list column with the same length as the values per row.
+    descending
+        Sort keys from largest to smallest. Default ``False``.
+    nulls_last
+        Place elements with null keys at the end. Default ``True``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the reordered values.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(values))).sort_by(
+        keys, descending, nulls_last
+    )
+
+
+def gather(
+    values: IntoExprColumn, indices: IntoExprColumn, oob: str = "raise"
+) -> pl.Expr:
+    """
+    Take elements of each row's list at per-row positions from another column.
+
+    Parameters
+    ----------
+    values
+        List column to take elements from.
+    indices
+        Integer list column of positions, one list per row.
+    oob
+        Out-of-bounds policy: "raise" (default), "null" or "clamp".
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one list of gathered elements per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(values))).gather(
+        indices, oob
+    )
+
+
+def scatter(
+    values: IntoExprColumn, indices: IntoExprColumn, updates: IntoExpr
+) -> pl.Expr:
+    """
+    Replace elements of each row's list at per-row positions.
+
+    Parameters
+    ----------
+    values
+        List column to update.
+    indices
+        Integer list column of positions, one list per row.
+    updates
+        List column with one new value per index, or a scalar for every index.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the updated values.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(values))).scatter(
+        indices, updates
+    )
+
+
+def search_sorted(
+    sorted_list: IntoExprColumn, queries: IntoExpr, side: str = "left"
+) -> pl.Expr:
+    """
+    Insertion indices of query values into each row's sorted list.
+
+    Parameters
+    ----------
+    sorted_list
+        List column sorted ascending within each row.
+    queries
+        Scalar or list column of query values per row.
+    side
+        "left" (default) or "right".
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``UInt32`` indices (a list for list queries).
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(sorted_list))).search_sorted(
+        queries, side
+    )
+
+
+def rle(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Run-length encode each row's list.
+
+    Parameters
+    ----------
+    expr
+        List column.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a struct of run ``values`` and ``lengths`` lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).rle()
+
+
+def rle_decode(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Expand a run-length encoding back into one list per row.
+
+    Parameters
+    ----------
+    expr
+        Struct column with ``values`` and ``lengths`` list fields.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the decoded lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).rle_decode()
+
+
+def pad_to(
+    expr: IntoExprColumn, width: int, fill: float | None = None, align: str = "left"
+) -> pl.Expr:
+    """
+    Pad each row's list up to a target length.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    width
+        Target length.
+    fill
+        Padding value. ``None`` (default) pads with nulls.
+    align
+        "left" (default) pads the end, "right" pads the start.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the padded lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).pad_to(
+        width, fill, align
+    )
+
+
+def truncate(expr: IntoExprColumn, width: int) -> pl.Expr:
+    """
+    Keep at most the first ``width`` elements of each row's list.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    width
+        Maximum length.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the truncated lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).truncate(width)
+
+
+def reshape_windows(expr: IntoExprColumn, window: int, step: int = 1) -> pl.Expr:
+    """
+    Split each row's list into (possibly overlapping) sub-windows.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    window
+        Number of elements per window.
+    step
+        Offset between consecutive window starts. Default ``1``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one list of windows per row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).reshape_windows(
+        window, step
+    )
+
+
+def to_array(expr: IntoExprColumn, width: int) -> pl.Expr:
+    """
+    Validate list lengths and convert to a fixed-width Array column.
+
+    Parameters
+    ----------
+    expr
+        List column.
+    width
+        Required length of every non-null list.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``Array(inner, width)``.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).to_array(width)
+
+
+def shape_report(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Summarize the shape of a list column in one pass.
+
+    Parameters
+    ----------
+    expr
+        List/Array column.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single-row struct of row counts, list length
+        range, number of distinct lengths and inner dtype.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).shape_report()
+
+
+def nansum(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Sum across rows, skipping NaN elements (like ``numpy.nansum``).
+
+    Same as ``sum(..., nan_policy="ignore")``; see sum() for the
+    parameters. Positions where every value is NaN or null sum to 0.
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).nansum(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def nanmean(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Mean across rows, skipping NaN elements (like ``numpy.nanmean``).
+
+    Same as ``mean(..., nan_policy="ignore")``; see mean() for the
+    parameters. Positions where every value is NaN or null are NaN.
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).nanmean(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def nanmin(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Minimum across rows, skipping NaN elements (like ``numpy.nanmin``).
+
+    Same as ``min(..., nan_policy="ignore")``; see min() for the
+    parameters. Positions where every value is NaN or null are null.
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).nanmin(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def nanmax(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Maximum across rows, skipping NaN elements (like ``numpy.nanmax``).
+
+    Same as ``max(..., nan_policy="ignore")``; see max() for the
+    parameters. Positions where every value is NaN or null are null.
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).nanmax(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def ptp(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    nan_policy: str = "propagate",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Peak-to-peak range (max - min) at each position across rows.
+
+    Returns a single row with a list where each element is the difference
+    between the largest and smallest element at that position, computed in
+    one pass rather than as ``max() - min()``.
+
+    All lists must have the same length.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of equal length.
+    null_policy
+        ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+        position null if any row has a null there.
+    min_count
+        Positions with fewer than ``min_count`` non-null values are null.
+    keep_rows
+        If True, repeat the aggregate for every input row.
+    validate
+        ``"strict"`` (default) checks list lengths; ``"fast"`` skips the check.
+    empty_policy
+        ``"error"`` (default), ``"skip"`` or ``"treat_as_null"`` for empty lists.
+    nan_policy
+        ``"propagate"`` (default), ``"ignore"`` or ``"as_null"`` for NaN elements.
+    offset
+        First row to aggregate; negative values count from the end.
+        With ``length`` this reduces a vertical slice of the rows
+        without slicing the frame first.
+    length
+        Number of rows to aggregate from ``offset``; None (default)
+        takes every remaining row. With ``keep_rows`` the output still
+        has one row per row of the whole column.
+    every
+        Aggregate only every ``every``-th row of the slice, starting
+        with its first (e.g. ``offset=1, every=2`` for the odd rows of
+        a split-half analysis).
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a list with the same type as input.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[3, 5, 2], [1, 7, 4]]})
+    >>> df.select(vec.ptp("a"))
+    shape: (1, 1)
+    ┌───────────┐
+    │ a         │
+    │ ---       │
+    │ list[i64] │
+    ╞═══════════╡
+    │ [2, 2, 2] │
+    └───────────┘
+
+    Can be called with multiple columns:
+    >>> df = pl.DataFrame({"a": [[3, 5], [1, 7]], "b": [[10, 20], [30, 15]]})
+    >>> df.select(vec.ptp("a", "b"))
+    shape: (1, 2)
+    ┌───────────┬───────────┐
+    │ a         ┆ b         │
+    │ ---       ┆ ---       │
+    │ list[i64] ┆ list[i64] │
+    ╞═══════════╪═══════════╡
+    │ [2, 2]    ┆ [20, 5]   │
+    └───────────┴───────────┘
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).ptp(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            nan_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def abs_max(
+    *exprs: IntoExprColumn,
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    nan_policy: str = "propagate",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr | list[pl.Expr]:
+    """
+    Value with the largest magnitude at each position across rows.
+
+    Returns a single row with a list where each element is the value with
+    the largest absolute value at that position, keeping its sign, e.g. the
+    peak deflection regardless of polarity. Ties keep the earliest row.
+
+    All lists must have the same length.
+
+    Parameters
+    ----------
+    *exprs
+        Column names containing lists/arrays of equal length.
+    null_policy
+        ``"ignore"`` (default) skips null elements; ``"propagate"`` makes a
+        position null if any row has a null there.
+    min_count
+        Positions with fewer than ``min_count`` non-null values are null.
+    keep_rows
+        If True, repeat the aggregate for every input row.
+    validate
+        ``"strict"`` (default) checks list lengths; ``"fast"`` skips the check.
+    empty_policy
+        ``"error"`` (default), ``"skip"`` or ``"treat_as_null"`` for empty lists.
+    nan_policy
+        ``"propagate"`` (default), ``"ignore"`` or ``"as_null"`` for NaN elements.
+    offset
+        First row to aggregate; negative values count from the end.
+        With ``length`` this reduces a vertical slice of the rows
+        without slicing the frame first.
+    length
+        Number of rows to aggregate from ``offset``; None (default)
+        takes every remaining row. With ``keep_rows`` the output still
+        has one row per row of the whole column.
+    every
+        Aggregate only every ``every``-th row of the slice, starting
+        with its first (e.g. ``offset=1, every=2`` for the odd rows of
+        a split-half analysis).
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning a list with the same type as input.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> df = pl.DataFrame({"a": [[3, -5, 2], [-4, 1, 2]]})
+    >>> df.select(vec.abs_max("a"))
+    shape: (1, 1)
+    ┌─────────────┐
+    │ a           │
+    │ ---         │
+    │ list[i64]   │
+    ╞═════════════╡
+    │ [-4, -5, 2] │
+    └─────────────┘
+
+    Can be called with multiple columns:
+    >>> df = pl.DataFrame({"a": [[3, -5], [-4, 1]], "b": [[10, -20], [30, 15]]})
+    >>> df.select(vec.abs_max("a", "b"))
+    shape: (1, 2)
+    ┌───────────┬───────────┐
+    │ a         ┆ b         │
+    │ ---       ┆ ---       │
+    │ list[i64] ┆ list[i64] │
+    ╞═══════════╪═══════════╡
+    │ [-4, -5]  ┆ [30, -20] │
+    └───────────┴───────────┘
+    """
+    results = [
+        VecOpsNamespace(wrap_expr(e)).abs_max(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            nan_policy,
+            offset,
+            length,
+            every,
+        )
+        for e in parse_into_list_of_expressions(*exprs)
+    ]
+    return results[0] if len(results) == 1 else results
+
+
+def scaled_mean(
+    expr: IntoExprColumn, method: str = "minmax", ddof: int = 1
+) -> pl.Expr:
+    """
+    Mean across rows after rescaling each row on its own.
+
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    method
+        ``"minmax"`` (default) or ``"zscore"``.
+    ddof
+        Delta degrees of freedom for ``method="zscore"``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning ``List[f64]`` with one value per position.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).scaled_mean(
+        method, ddof
+    )
+
+
+def cum_sum_from(expr: IntoExprColumn, initial: IntoExpr) -> pl.Expr:
+    """
+    Rebuild absolute rows from row-to-row differences, the inverse of diff().
+
+    Parameters
+    ----------
+    expr
+        Column name containing the differences, as produced by diff().
+    initial
+        The absolute first row, as a single list.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the reconstructed rows.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).cum_sum_from(
+        initial
+    )
+
+
+def delta_encode(
+    expr: IntoExprColumn, dtype: PolarsDataType | str | None = None
+) -> pl.Expr:
+    """
+    Delta-encode each row's list along the list axis.
+
+    Parameters
+    ----------
+    expr
+        Column name containing numeric lists/arrays.
+    dtype
+        Element dtype of the result, e.g. ``pl.Int16``; ``None`` keeps Int64
+        or Float64.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning the first element and successive differences.
     """
-    Find maximum element at each position across rows (vertical aggregation).
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).delta_encode(dtype)
 
-    Returns a single row with a list where each element is the maximum
-    of elements at that position across all input lists.
 
-    All lists must have the same length.
+def delta_decode(
+    expr: IntoExprColumn, dtype: PolarsDataType | str | None = None
+) -> pl.Expr:
+    """
+    Decode delta_encode() output back to absolute values.
+
+    Parameters
+    ----------
+    expr
+        Column name containing delta-encoded lists/arrays.
+    dtype
+        Element dtype of the result; ``None`` gives Int64 or Float64.
 
     Returns
     -------
     pl.Expr
-        Expression returning a list with the same type as input.
+        Expression returning the running sums of each row.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).delta_decode(dtype)
 
-    Examples
-    --------
-    >>> import polars_vec_ops as vec
-    >>> df = pl.DataFrame({"a": [[3, 5, 2], [1, 7, 4]]})
-    >>> df.select(vec.max("a"))
-    shape: (1, 1)
-    ┌───────────┐
-    │ a         │
-    │ ---       │
-    │ list[i64] │
-    ╞═══════════╡
-    │ [3, 7, 4] │
-    └───────────┘
 
-    Can be called with multiple columns:
-    >>> df = pl.DataFrame({"a": [[3, 5], [1, 7]], "b": [[10, 20], [30, 15]]})
-    >>> df.select(vec.max("a", "b"))
-    shape: (1, 2)
-    ┌───────────┬───────────┐
-    │ a         ┆ b         │
-    │ ---       ┆ ---       │
-    │ list[i64] ┆ list[i64] │
-    ╞═══════════╪═══════════╡
-    │ [3, 7]    ┆ [30, 20]  │
-    └───────────┴───────────┘
+def quantize(
+    expr: IntoExprColumn,
+    scale: float | None = None,
+    offset: float = 0.0,
+    dtype: PolarsDataType | str = pl.Int16,
+) -> pl.Expr:
     """
-    results = [VecOpsNamespace(wrap_expr(e)).max() for e in parse_into_list_of_expressions(*exprs)]
-    return results[0] if len(results) == 1 else results
+    Quantize float lists to integer levels to reduce storage.
 
+    Parameters
+    ----------
+    expr
+        Column name containing float lists/arrays.
+    scale
+        Step between levels; ``None`` scales each row on its own.
+    offset
+        Value mapped to level 0 when ``scale`` is given.
+    dtype
+        Integer dtype of the levels: ``pl.Int8``, ``pl.Int16`` or ``pl.Int32``.
 
-def diff(*exprs: IntoExprColumn) -> pl.Expr | list[pl.Expr]:
+    Returns
+    -------
+    pl.Expr
+        Integer lists, or a struct of ``values``, ``scale`` and ``offset``.
     """
-    Calculate differences between consecutive rows at each position.
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).quantize(
+        scale, offset, dtype
+    )
 
-    Returns the same number of rows as input. The first row contains
-    a list of nulls (no previous row to compare). Each subsequent row
-    contains the element-wise difference from the previous row: row[i] - row[i-1].
 
-    If either the current or previous row is null, the result is a list of nulls.
+def dequantize(
+    expr: IntoExprColumn, scale: float | None = None, offset: float = 0.0
+) -> pl.Expr:
+    """
+    Map quantized levels back to floats as ``level * scale + offset``.
 
-    All lists must have the same length.
+    Parameters
+    ----------
+    expr
+        Column name containing quantize() output.
+    scale
+        Step between levels, required for plain integer lists.
+    offset
+        Value of level 0 for plain integer lists.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning Float64 lists.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).dequantize(
+        scale, offset
+    )
+
+
+def group_mean(values: IntoExprColumn, keys: IntoExprColumn) -> pl.Expr:
+    """
+    Per-position vertical mean computed separately for each key.
+
+    Parameters
+    ----------
+    values
+        Column name containing lists/arrays of equal length.
+    keys
+        List column of integer, string or categorical labels with the same
+        shape as ``values``.
 
     Returns
     -------
     pl.Expr
-        Expression returning lists with differences, preserving input type.
-        First row is always a list of nulls.
+        Expression returning a struct of the sorted ``keys`` and one ``mean``
+        trace per key.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(values))).group_mean(keys)
 
-    Examples
-    --------
-    >>> import polars_vec_ops as vec
-    >>> df = pl.DataFrame({"a": [[5, 10, 15], [2, 15, 5], [0, 0, 0]]})
-    >>> df.select(vec.diff("a"))
-    shape: (3, 1)
-    ┌────────────────────┐
-    │ a                  │
-    │ ---                │
-    │ list[i64]          │
-    ╞════════════════════╡
-    │ [null, null, null] │
-    │ [-3, 5, -10]       │
-    │ [-2, -15, -5]      │
-    └────────────────────┘
 
-    Can be called with multiple columns:
-    >>> df = pl.DataFrame({"a": [[5, 10], [2, 15], [0, 0]], "b": [[50, 100], [20, 150], [0, 0]]})
-    >>> df.select(vec.diff("a", "b"))
-    shape: (3, 2)
-    ┌──────────────┬──────────────┐
-    │ a            ┆ b            │
-    │ ---          ┆ ---          │
-    │ list[i64]    ┆ list[i64]    │
-    ╞══════════════╪══════════════╡
-    │ [null, null] ┆ [null, null] │
-    │ [-3, 5]      ┆ [-30, 50]    │
-    │ [-2, -15]    ┆ [-20, -150]  │
-    └──────────────┴──────────────┘
+def chunked_mean(expr: IntoExprColumn, every: int) -> pl.Expr:
     """
-    results = [VecOpsNamespace(wrap_expr(e)).diff() for e in parse_into_list_of_expressions(*exprs)]
-    return results[0] if len(results) == 1 else results
+    Average element-wise over blocks of ``every`` consecutive rows.
 
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays of equal length.
+    every
+        Number of consecutive rows per block.
 
-def convolve(
-    expr: IntoExprColumn,
-    kernel: list[float] | pl.Series,
-    fill_value: float = 0.0,
-    mode: str = "same",
+    Returns
+    -------
+    pl.Expr
+        Expression returning one averaged row per block.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).chunked_mean(every)
+
+
+def mean_by_time(
+    values: IntoExprColumn, timestamps: IntoExprColumn, every: str | timedelta
 ) -> pl.Expr:
     """
-    Convolve each list in the column with the given kernel.
+    Vertical mean over the rows falling in each time bin.
+
+    Parameters
+    ----------
+    values
+        Column name containing lists/arrays of equal length.
+    timestamps
+        Datetime column with one timestamp per row.
+    every
+        Bin width as a fixed-length duration string (e.g. ``"1h"``) or a
+        ``timedelta``.
+
+    Returns
+    -------
+    pl.Expr
+        Expression returning one ``{time, mean}`` struct row per bin.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(values))).mean_by_time(
+        timestamps, every
+    )
 
-    Performs 1D convolution on each row's list independently.
-    The convolution operation applies the kernel as a sliding window
-    over each signal, computing weighted sums.
+
+def value_report(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Count the elements of a list column and how many are null, NaN or inf.
 
     Parameters
     ----------
     expr
-        Column name containing lists/arrays to convolve.
-    kernel
-        The convolution kernel (filter). Can be a list of floats or
-        a Polars Series. Non-finite values are filtered out.
-    fill_value
-        Value to use for null elements in the signal. Default is 0.0.
-    mode
-        Convolution mode, one of:
-        - "full": Full convolution, output length = signal_len + kernel_len - 1
-        - "same": Same length as signal, centered (default)
-        - "valid": Only where signal and kernel fully overlap,
-                  output length = signal_len - kernel_len + 1
-        - "left": Same length as signal, left-aligned
-        - "right": Same length as signal, right-aligned
+        Column name containing lists/arrays.
 
     Returns
     -------
     pl.Expr
-        Expression returning lists of Float64 values.
+        Expression returning a single-row struct of element counts.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).value_report()
+
+
+def reduce_columns(
+    *exprs: IntoExprColumn,
+    op: str = "mean",
+    null_policy: str = "ignore",
+    min_count: int = 0,
+    keep_rows: bool = False,
+    validate: str = "strict",
+    empty_policy: str = "error",
+    nan_policy: str = "propagate",
+    offset: int = 0,
+    length: int | None = None,
+    every: int = 1,
+) -> pl.Expr:
+    """
+    Vertically reduce several list columns in a single plugin call.
+
+    Selectors such as ``cs.by_dtype(pl.List(pl.Float64))`` already work with
+    every expression (``cs.by_dtype(...).vec.mean()``), which runs one plugin
+    call per column. This instead passes all matched columns to one call that
+    reduces them in parallel, and yields one output column per input, named
+    after it.
+
+    Parameters
+    ----------
+    *exprs
+        Column names, expressions or selectors resolving to List/Array columns.
+    op
+        Reduction to apply: ``"sum"``, ``"mean"`` (default), ``"min"`` or
+        ``"max"``.
+    null_policy, min_count, keep_rows, validate, empty_policy, nan_policy
+        As for :func:`sum`.
+    offset, length, every
+        As for :func:`sum`.
+
+    Returns
+    -------
+    pl.Expr
+        Multi-output expression with one reduced column per input.
 
     Examples
     --------
+    >>> import polars.selectors as cs
     >>> import polars_vec_ops as vec
-    >>> df = pl.DataFrame({"signal": [[1, 2, 3, 4, 5]]})
-    >>> kernel = [0.25, 0.5, 0.25]
-    >>> df.select(vec.convolve("signal", kernel, mode="same"))
-    shape: (1, 1)
-    ┌───────────────────┐
-    │ signal            │
-    │ ---               │
-    │ list[f64]         │
-    ╞═══════════════════╡
-    │ [1.0, 2.0, … 3.5] │
-    └───────────────────┘
+    >>> df = pl.DataFrame({"a": [[1.0, 2.0], [3.0, 4.0]], "b": [[0.5], [1.5]]})
+    >>> df.select(vec.reduce_columns(cs.by_dtype(pl.List(pl.Float64))))
+    shape: (1, 2)
+    ┌────────────┬───────────┐
+    │ a          ┆ b         │
+    │ ---        ┆ ---       │
+    │ list[f64]  ┆ list[f64] │
+    ╞════════════╪═══════════╡
+    │ [2.0, 3.0] ┆ [1.0]     │
+    └────────────┴───────────┘
     """
-    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).convolve(kernel, fill_value, mode)
+    if op not in ("sum", "mean", "min", "max"):
+        raise ValueError(f"op must be 'sum', 'mean', 'min' or 'max', got {op!r}")
+    return register_plugin_function(
+        args=[wrap_expr(e) for e in parse_into_list_of_expressions(*exprs)],
+        plugin_path=_LIB,
+        function_name=f"list_{op}_columns",
+        is_elementwise=False,
+        returns_scalar=not keep_rows,
+        input_wildcard_expansion=True,
+        kwargs=_reduce_kwargs(
+            null_policy,
+            min_count,
+            keep_rows,
+            validate,
+            empty_policy,
+            nan_policy,
+            offset,
+            length,
+            every,
+        ),
+    ).struct.unnest()
 
 
-def histogram(
-    expr: IntoExprColumn,
-    bins: int | list[float] | pl.Series | pl.Expr | str | None = None,
-    *,
-    start: float | pl.Expr | None = None,
-    stop: float | pl.Expr | None = None,
-    spacing: float | pl.Expr | None = None,
-    count_dtype: pl.DataType | type[bool] | type[int] | None = None,
-    include_breakpoints: bool = False,
-) -> pl.Expr:
+def to_matrix(expr: IntoExprColumn, width: int) -> pl.Expr:
     """
-    Compute a histogram for each list in the column.
+    Validate a dense matrix column and lay it out in one contiguous buffer.
 
-    Bin specification supports two mutually exclusive modes:
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    width
+        Required length of every list.
 
-    1. ``bins``: an integer number of bins (auto-ranged from data) or
-       explicit bin edges as a list.
-    2. ``start`` / ``stop`` / ``spacing``: evenly spaced bins.
+    Returns
+    -------
+    pl.Expr
+        Expression returning a single-chunk ``Array(inner, width)`` column.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).to_matrix(width)
 
-    Any scalar parameter can be a ``pl.Expr`` to derive values per-row
-    from other columns.
+
+def reverse(expr: IntoExprColumn) -> pl.Expr:
+    """
+    Reverse the order of the elements within each row's list.
 
     Parameters
     ----------
     expr
         Column name containing lists/arrays.
-    bins
-        Number of bins (int) or explicit bin edges (list/Series).
-        Can be a ``pl.Expr`` resolving to an integer column.
-    start
-        Left edge of the first bin.
-    stop
-        Right edge of the last bin.
-    spacing
-        Width of each bin.
-    count_dtype
-        Data type for the counts field. Smaller types save memory.
-        Accepts ``pl.Boolean`` / ``bool``, ``pl.UInt8``, ``pl.UInt16``,
-        or ``pl.UInt32`` / ``int`` (default).
-    include_breakpoints
-        If ``True``, return a Struct with ``breakpoints`` and ``counts``.
-        If ``False`` (default), return ``List[count_dtype]`` directly.
 
     Returns
     -------
     pl.Expr
-        If ``include_breakpoints=True``, returns a Struct with fields:
-        - ``breakpoints``: ``List[Float64]`` — n+1 bin edges
-        - ``counts``: ``List[count_dtype]`` — n bin counts
+        Expression returning the reversed lists, preserving input type.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).reverse()
 
-        If ``include_breakpoints=False`` (default), returns
-        ``List[count_dtype]`` directly.
 
-    Examples
-    --------
-    >>> import polars_vec_ops as vec
-    >>> df = pl.DataFrame({"a": [[1, 2, 3, 4, 5]]})
-    >>> df.select(vec.histogram("a", bins=3))
-    shape: (1, 1)
-    ┌───────────┐
-    │ a         │
-    │ ---       │
-    │ list[u32] │
-    ╞═══════════╡
-    │ [2, 1, 2] │
-    └───────────┘
+def roll(
+    expr: IntoExprColumn,
+    n: int | IntoExprColumn,
+    mode: str = "wrap",
+    fill_value: float | None = None,
+) -> pl.Expr:
     """
-    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).histogram(
-        bins, start=start, stop=stop, spacing=spacing,
-        count_dtype=count_dtype, include_breakpoints=include_breakpoints,
-    )
+    Shift the elements of each row's list by ``n`` positions.
 
+    Parameters
+    ----------
+    expr
+        Column name containing lists/arrays.
+    n
+        Number of positions to shift by, for every row or per row.
+    mode
+        ``"wrap"`` (default) or ``"fill"``.
+    fill_value
+        Value for the vacated positions with ``mode="fill"``.
 
-hist = histogram
+    Returns
+    -------
+    pl.Expr
+        Expression returning the shifted lists, preserving input type.
+    """
+    return VecOpsNamespace(wrap_expr(parse_into_expression(expr))).roll(
+        n, mode, fill_value
+    )
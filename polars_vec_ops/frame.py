@@ -9,8 +9,39 @@ import polars as pl
 from polars._typing import IntoExpr, IntoExprColumn, FrameType, PolarsDataType
 from polars.plugins import register_plugin_function
 
+from polars_vec_ops._internal import array_from_flat
+
 _LIB = Path(__file__).parent
 
+# Vertical reductions that summarize() can compute for each list column
+_SUMMARY_STATS = (
+    "sum",
+    "mean",
+    "std",
+    "min",
+    "max",
+    "ptp",
+    "abs_max",
+    "nansum",
+    "nanmean",
+    "nanmin",
+    "nanmax",
+)
+
+
+def _list_columns(df: FrameType, columns: str | Sequence[str] | None) -> list[str]:
+    """Resolve ``columns`` to names, defaulting to every List/Array column."""
+    if columns is None:
+        schema = df.collect_schema()
+        return [
+            name
+            for name, dtype in schema.items()
+            if isinstance(dtype, (pl.List, pl.Array))
+        ]
+    if isinstance(columns, str):
+        return [columns]
+    return list(columns)
+
 
 @pl.api.register_dataframe_namespace("vec")
 @pl.api.register_lazyframe_namespace("vec")
@@ -552,3 +583,200 @@ class VecOpsNamespace:
         if is_lazy:
             return result.lazy()
         return result
+
+    def summarize(
+        self,
+        columns: str | Sequence[str] | None = None,
+        stats: Sequence[str] = ("mean", "std", "min", "max"),
+    ) -> FrameType:
+        """
+        Compute vertical summary statistics for several list columns at once.
+
+        Expands to one plugin expression per column and statistic, evaluated
+        in a single ``select``.
+
+        Parameters
+        ----------
+        columns
+            Column name(s) to summarize. Defaults to every List/Array column.
+        stats
+            Vertical reductions to compute: any of ``"sum"``, ``"mean"``,
+            ``"std"`` (sample, from ``mean_std``), ``"min"``, ``"max"``,
+            ``"ptp"``, ``"abs_max"``, ``"nansum"``, ``"nanmean"``,
+            ``"nanmin"`` and ``"nanmax"``.
+
+        Returns
+        -------
+        DataFrame or LazyFrame
+            One row with a ``{column}_{stat}`` list column per column and
+            statistic, in that order.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 6]], "b": [[0.5], [1.5]]})
+        >>> df.vec.summarize(stats=["mean", "max"])
+        shape: (1, 4)
+        ┌────────────┬───────────┬───────────┬───────────┐
+        │ a_mean     ┆ a_max     ┆ b_mean    ┆ b_max     │
+        │ ---        ┆ ---       ┆ ---       ┆ ---       │
+        │ list[f64]  ┆ list[i64] ┆ list[f64] ┆ list[f64] │
+        ╞════════════╪═══════════╪═══════════╪═══════════╡
+        │ [2.0, 4.0] ┆ [3, 6]    ┆ [1.0]     ┆ [1.5]     │
+        └────────────┴───────────┴───────────┴───────────┘
+        """
+        unknown = [stat for stat in stats if stat not in _SUMMARY_STATS]
+        if unknown:
+            raise ValueError(
+                f"Unknown stats {unknown}. Must be among: {', '.join(_SUMMARY_STATS)}"
+            )
+        exprs = []
+        for name in _list_columns(self._df, columns):
+            for stat in stats:
+                if stat == "std":
+                    expr = pl.col(name).vec.mean_std().struct.field("std")
+                else:
+                    expr = getattr(pl.col(name).vec, stat)()
+                exprs.append(expr.alias(f"{name}_{stat}"))
+        return self._df.select(exprs)
+
+    def validate(self, columns: str | Sequence[str] | None = None) -> FrameType:
+        """
+        Report the shape and element health of list columns.
+
+        Runs ``shape_report`` and ``value_report`` on each column, so length
+        mismatches and stray nulls or NaNs can be spotted before running
+        vertical reductions.
+
+        Parameters
+        ----------
+        columns
+            Column name(s) to check. Defaults to every List/Array column.
+
+        Returns
+        -------
+        DataFrame or LazyFrame
+            One row per column: its name in ``column``, followed by the
+            ``shape_report`` fields (``n_rows`` ... ``inner_dtype``) and the
+            ``value_report`` counts (``n_elements`` ... ``n_inf_elements``).
+
+        Raises
+        ------
+        ValueError
+            If there are no columns to check.
+        """
+        names = _list_columns(self._df, columns)
+        if not names:
+            raise ValueError("No List or Array columns to validate")
+        reports = [
+            self._df.select(
+                pl.col(name).vec.shape_report().alias("shape"),
+                pl.col(name).vec.value_report().alias("values"),
+            )
+            .unnest("shape", "values")
+            .select(pl.lit(name).alias("column"), pl.all())
+            for name in names
+        ]
+        return pl.concat(reports, how="vertical")
+
+
+def summarize(
+    df: FrameType,
+    columns: str | Sequence[str] | None = None,
+    stats: Sequence[str] = ("mean", "std", "min", "max"),
+) -> FrameType:
+    """
+    Compute vertical summary statistics for several list columns at once.
+
+    See :meth:`VecOpsNamespace.summarize`.
+    """
+    return VecOpsNamespace(df).summarize(columns, stats)
+
+
+def validate(df: FrameType, columns: str | Sequence[str] | None = None) -> FrameType:
+    """
+    Report the shape and element health of list columns.
+
+    See :meth:`VecOpsNamespace.validate`.
+    """
+    return VecOpsNamespace(df).validate(columns)
+
+
+def to_numpy_matrix(series: pl.Series) -> np.ndarray:
+    """
+    View a dense List/Array column as a 2D numpy array without copying.
+
+    List columns are first validated and converted with ``vec.to_matrix``
+    (taking the width from the longest list); Array columns are checked the
+    same way. The result shares memory with the polars buffer, so it is
+    read-only.
+
+    Parameters
+    ----------
+    series
+        List/Array column with a list in every row and no null elements, e.g.
+        the output of a ``group_by`` aggregation.
+
+    Returns
+    -------
+    numpy.ndarray
+        Array of shape ``(len(series), width)``.
+
+    Raises
+    ------
+    ComputeError
+        If a row is null, the lengths differ or there are null elements.
+
+    Examples
+    --------
+    >>> import polars_vec_ops as vec
+    >>> vec.to_numpy_matrix(pl.Series("a", [[1.0, 2.0], [3.0, 4.0]]))
+    array([[1., 2.],
+           [3., 4.]])
+    """
+    if isinstance(series.dtype, pl.Array):
+        width = series.dtype.size
+    else:
+        width = series.list.len().max() or 0
+    matrix = series.to_frame().select(pl.col(series.name).vec.to_matrix(width))
+    return matrix.to_series().to_numpy(allow_copy=False)
+
+
+def from_matrix(name: str, matrix: np.ndarray) -> pl.Series:
+    """
+    Wrap a 2D numpy array as an ``Array(inner, width)`` Series.
+
+    A C-contiguous array of a numeric dtype polars supports is wrapped
+    without copying the values; other layouts (e.g. Fortran order or strided
+    views) are copied once into row-major order first. This avoids building
+    per-row lists when loading trial matrices.
+
+    Parameters
+    ----------
+    name
+        Name of the resulting Series.
+    matrix
+        Array of shape ``(n_rows, width)``; each row becomes one list.
+
+    Returns
+    -------
+    pl.Series
+        Series of ``n_rows`` fixed-width arrays.
+
+    Examples
+    --------
+    >>> import numpy as np
+    >>> import polars_vec_ops as vec
+    >>> vec.from_matrix("a", np.arange(6).reshape(2, 3))
+    shape: (2,)
+    Series: 'a' [array[i64, 3]]
+    [
+    	[0, 1, 2]
+    	[3, 4, 5]
+    ]
+    """
+    matrix = np.asarray(matrix)
+    if matrix.ndim != 2:
+        raise ValueError(f"Expected a 2D array, got {matrix.ndim} dimension(s)")
+    n_rows, width = matrix.shape
+    flat = pl.Series(name, np.ascontiguousarray(matrix).reshape(-1))
+    return array_from_flat(flat, width, n_rows)
@@ -0,0 +1,57 @@
+//! Vector distance metrics shared by the distance and clustering operations.
+use serde::Deserialize;
+
+/// Distance between two equal-length vectors, named as in `scipy.spatial.distance`.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum Metric {
+    Euclidean,
+    Sqeuclidean,
+    Cityblock,
+    Chebyshev,
+    Cosine,
+    Correlation,
+}
+
+impl Metric {
+    /// Distance between `a` and `b`, or `None` if either holds a null.
+    /// Cosine and correlation distances are NaN when a vector has zero norm (or variance).
+    pub fn distance(self, a: &[Option<f64>], b: &[Option<f64>]) -> Option<f64> {
+        Some(self.distance_f64(&complete_pairs(a, b)?))
+    }
+
+    fn distance_f64(self, pairs: &[(f64, f64)]) -> f64 {
+        match self {
+            Metric::Euclidean => Metric::Sqeuclidean.distance_f64(pairs).sqrt(),
+            Metric::Sqeuclidean => pairs.iter().map(|(x, y)| (x - y).powi(2)).sum(),
+            Metric::Cityblock => pairs.iter().map(|(x, y)| (x - y).abs()).sum(),
+            Metric::Chebyshev => pairs.iter().map(|(x, y)| (x - y).abs()).fold(0.0, f64::max),
+            Metric::Cosine => 1.0 - cosine(pairs.iter().copied()),
+            Metric::Correlation => {
+                let n = pairs.len() as f64;
+                let mx = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+                let my = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+                1.0 - cosine(pairs.iter().map(|(x, y)| (x - mx, y - my)))
+            },
+        }
+    }
+}
+
+/// Element pairs of `a` and `b`, or `None` if either holds a null.
+fn complete_pairs(a: &[Option<f64>], b: &[Option<f64>]) -> Option<Vec<(f64, f64)>> {
+    a.iter().zip(b).map(|(x, y)| Some(((*x)?, (*y)?))).collect()
+}
+
+/// Cosine similarity of `a` and `b`, or `None` if either holds a null; NaN for a zero
+/// vector.
+pub(super) fn cosine_similarity(a: &[Option<f64>], b: &[Option<f64>]) -> Option<f64> {
+    Some(cosine(complete_pairs(a, b)?.into_iter()))
+}
+
+/// Cosine of the angle between the two vectors given as `(x, y)` pairs.
+fn cosine(pairs: impl Iterator<Item = (f64, f64)>) -> f64 {
+    let (dot, xx, yy) = pairs.fold((0.0, 0.0, 0.0), |(d, xx, yy), (x, y)| {
+        (d + x * y, xx + x * x, yy + y * y)
+    });
+    dot / (xx * yy).sqrt()
+}
@@ -0,0 +1,119 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_list_type, Welford};
+
+#[derive(serde::Deserialize)]
+struct EventTriggeredAverageKwargs {
+    pre: usize,
+    post: usize,
+    return_stats: bool,
+}
+
+fn event_triggered_average_output_type(
+    input_fields: &[Field],
+    kwargs: EventTriggeredAverageKwargs,
+) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let mean = DataType::List(Box::new(DataType::Float64));
+    let dtype = if kwargs.return_stats {
+        DataType::Struct(vec![
+            Field::new("mean".into(), mean),
+            Field::new("count".into(), DataType::List(Box::new(DataType::UInt32))),
+            Field::new("sem".into(), DataType::List(Box::new(DataType::Float64))),
+        ])
+    } else {
+        mean
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Average windows of each row's trace aligned on that row's events.
+/// inputs[0] = trace (List/Array of numeric, one continuous trace per row)
+/// inputs[1] = event indices into the same row's trace (integer scalar or List of integers)
+/// Each event contributes `trace[event - pre .. event + post]`; samples outside the trace,
+/// nulls and NaNs contribute nothing, so counts may differ between window positions.
+#[polars_expr(output_type_func_with_kwargs=event_triggered_average_output_type)]
+fn event_triggered_average(
+    inputs: &[Series],
+    kwargs: EventTriggeredAverageKwargs,
+) -> PolarsResult<Series> {
+    let trace_series =
+        ensure_list_type(&inputs[0])?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let trace_ca = trace_series.list()?;
+    // Event indices per row, from either a List of integers or one integer per row
+    let events: Vec<Option<Vec<i64>>> = match inputs[1].dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {
+            let events_series = ensure_list_type(&inputs[1])?
+                .cast(&DataType::List(Box::new(DataType::Int64)))?;
+            let mut rows = Vec::with_capacity(events_series.len());
+            for row in events_series.list()?.amortized_iter() {
+                rows.push(match row {
+                    Some(s) => Some(s.as_ref().i64()?.iter().flatten().collect()),
+                    None => None,
+                });
+            }
+            rows
+        },
+        dt if dt.is_integer() => {
+            let events_series = inputs[1].cast(&DataType::Int64)?;
+            events_series.i64()?.iter().map(|e| e.map(|e| vec![e])).collect()
+        },
+        dt => polars_bail!(InvalidOperation: "Expected integer or List of integer event indices, got {:?}", dt),
+    };
+    if events.len() != trace_ca.len() {
+        polars_bail!(
+            ComputeError:
+            "events must have the same number of rows as the trace. Expected {}, got {}",
+            trace_ca.len(), events.len()
+        );
+    }
+
+    let width = kwargs.pre + kwargs.post;
+    let mut acc = vec![Welford::default(); width];
+
+    for (trace, events) in trace_ca.amortized_iter().zip(events.iter()) {
+        let (Some(trace), Some(events)) = (trace, events) else {
+            continue;
+        };
+        let trace = trace.as_ref().f64()?;
+        let n = trace.len() as i64;
+        for &event in events {
+            let first = event - kwargs.pre as i64;
+            for (state, idx) in acc.iter_mut().zip(first..) {
+                if idx < 0 || idx >= n {
+                    continue;
+                }
+                if let Some(v) = trace.get(idx as usize).filter(|v| !v.is_nan()) {
+                    state.push(v);
+                }
+            }
+        }
+    }
+
+    // Positions with no contributing samples are null
+    let mean: Vec<Option<f64>> = acc.iter().map(Welford::mean).collect();
+    let name = trace_series.name().clone();
+    let mean_series = Series::new("mean".into(), mean);
+
+    if !kwargs.return_stats {
+        return Ok(ListChunked::full(name, &mean_series, 1).into_series());
+    }
+
+    let sem: Vec<Option<f64>> =
+        acc.iter().map(|w| w.std(1).map(|std| std / (w.n as f64).sqrt())).collect();
+    let count: Vec<u32> = acc.iter().map(|w| w.n).collect();
+    let count_series = Series::new("count".into(), count);
+    let sem_series = Series::new("sem".into(), sem);
+    let fields = [
+        ListChunked::full("mean".into(), &mean_series, 1).into_series(),
+        ListChunked::full("count".into(), &count_series, 1).into_series(),
+        ListChunked::full("sem".into(), &sem_series, 1).into_series(),
+    ];
+    let out = StructChunked::from_series(name, 1, fields.iter())?;
+    Ok(out.into_series())
+}
@@ -1,8 +1,9 @@
 #![allow(clippy::unused_unit)]
 use polars::prelude::*;
+use polars_arrow::array::{Array, ListArray, ValueSize};
 
 // Helper function to convert Array to List if needed
-pub(super) fn ensure_list_type(series: &Series) -> PolarsResult<Series> {
+pub(crate) fn ensure_list_type(series: &Series) -> PolarsResult<Series> {
     match series.dtype() {
         DataType::Array(inner, _width) => {
             // Convert Array to List
@@ -14,3 +15,622 @@ pub(super) fn ensure_list_type(series: &Series) -> PolarsResult<Series> {
         dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
     }
 }
+
+/// Convert to a List column held in one contiguous chunk, for kernels that work directly on
+/// the flat offsets and values buffers. Columns scanned from multi-file sources can arrive
+/// in many chunks; rather than falling back to per-row lookups across chunks, this makes a
+/// single explicit copy up front, and is free when the column is already one chunk.
+pub(crate) fn ensure_contiguous_list(series: &Series) -> PolarsResult<Series> {
+    Ok(ensure_list_type(series)?.rechunk())
+}
+
+/// The single `ListArray` backing a column prepared by [`ensure_contiguous_list`].
+pub(crate) fn single_list_array(list_chunked: &ListChunked) -> &ListArray<i64> {
+    debug_assert_eq!(list_chunked.chunks().len(), 1);
+    list_chunked.chunks()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .expect("List chunk should be a ListArray<i64>")
+}
+
+/// Error for a List column whose non-null rows differ in length. Reports the first row that
+/// disagrees with the first non-empty row and how many rows disagree in total, so bad data
+/// can be located in large frames. `context` completes "must have the same length for";
+/// with `skip_empty`, empty lists are left out as if they were null.
+pub(crate) fn length_mismatch_error(
+    list_chunked: &ListChunked,
+    context: &str,
+    skip_empty: bool,
+) -> PolarsError {
+    length_mismatch_error_from(list_chunked, context, skip_empty, |i| i)
+}
+
+/// [`length_mismatch_error`] for rows selected from a larger column, reporting them by
+/// `row_number` (their row number in the whole column).
+pub(crate) fn length_mismatch_error_from(
+    list_chunked: &ListChunked,
+    context: &str,
+    skip_empty: bool,
+    row_number: impl Fn(usize) -> usize,
+) -> PolarsError {
+    let lengths: Vec<Option<usize>> = list_chunked
+        .downcast_iter()
+        .flat_map(|arr| {
+            let offsets = arr.offsets();
+            (0..arr.len())
+                .map(move |i| arr.is_valid(i).then(|| (offsets[i + 1] - offsets[i]) as usize))
+        })
+        .map(|len| len.filter(|&len| !(skip_empty && len == 0)))
+        .collect();
+    // The reference is the first non-empty row, so a leading empty list is reported as the
+    // odd one out rather than every later row.
+    let valid = || lengths.iter().enumerate().filter_map(|(i, len)| len.map(|len| (i, len)));
+    let expected = valid().find(|&(_, len)| len > 0).or_else(|| valid().next());
+    let (mut first_bad, mut n_bad, mut n_valid) = (None, 0usize, 0usize);
+    for (i, len) in valid() {
+        n_valid += 1;
+        if expected.is_some_and(|(_, w)| w != len) {
+            n_bad += 1;
+            first_bad.get_or_insert((i, len));
+        }
+    }
+    match (expected, first_bad) {
+        (Some((row, width)), Some((bad_row, len))) => polars_err!(
+            ComputeError:
+            "All lists must have the same length for {}. Expected {} (as in row {}), got {} at row {}; {} of {} non-null rows differ",
+            context, width, row_number(row), len, row_number(bad_row), n_bad, n_valid
+        ),
+        _ => polars_err!(ComputeError: "All lists must have the same length for {}", context),
+    }
+}
+
+// Output type for per-row transforms that return Float64 lists shaped like the input
+pub(super) fn float_list_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(Box::new(DataType::Float64)),
+        )),
+        DataType::Array(_, width) => Ok(Field::new(
+            field.name().clone(),
+            DataType::Array(Box::new(DataType::Float64), *width),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Apply `f` to every non-null row of a List or Array column, with the row cast to Float64.
+/// `f` writes the transformed row into the supplied buffer, which is cleared and reused
+/// between rows. Null rows stay null; Array inputs are returned as `Array(Float64, width)`.
+pub(super) fn map_rows_f64<F>(series: &Series, mut f: F) -> PolarsResult<Series>
+where
+    F: FnMut(&Float64Chunked, &mut Vec<Option<f64>>) -> PolarsResult<()>,
+{
+    let input_dtype = series.dtype().clone();
+    let list_series =
+        ensure_list_type(series)?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = list_series.list()?;
+    let n_lists = list_chunked.len();
+
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        series.name().clone(),
+        n_lists,
+        list_chunked.get_values_size(),
+        DataType::Float64,
+    );
+    let mut buf: Vec<Option<f64>> = Vec::new();
+    for row in list_chunked.amortized_iter() {
+        match row {
+            Some(s) => {
+                buf.clear();
+                f(s.as_ref().f64()?, &mut buf)?;
+                builder.append_iter(buf.iter().copied());
+            },
+            None => builder.append_null(),
+        }
+    }
+
+    let result_series = builder.finish().into_series();
+    match &input_dtype {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
+
+/// Resolve a Python-style `[start, end)` slice against a row of length `len`.
+/// Negative bounds count from the end; out-of-range bounds are clamped.
+pub(super) fn resolve_slice(start: i64, end: Option<i64>, len: usize) -> (usize, usize) {
+    let resolve = |idx: i64| -> usize {
+        if idx < 0 {
+            (len as i64 + idx).max(0) as usize
+        } else {
+            (idx as usize).min(len)
+        }
+    };
+    let lo = resolve(start);
+    let hi = end.map_or(len, resolve);
+    (lo, hi.max(lo))
+}
+
+/// Dense row-major Float64 view of a List or Array column whose non-null rows share a
+/// common width, used by the vertical (across-rows) operations.
+pub(super) struct F64Matrix {
+    pub n_rows: usize,
+    pub width: usize,
+    /// Row-major values; null elements (and every element of a null row) are `None`.
+    pub values: Vec<Option<f64>>,
+    pub row_valid: Vec<bool>,
+}
+
+impl F64Matrix {
+    /// Collect `series` into a matrix, failing if non-null rows differ in length.
+    /// `op_name` names the operation in the error message.
+    pub fn from_series(series: &Series, op_name: &str) -> PolarsResult<Self> {
+        let list_series =
+            ensure_list_type(series)?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+        let list_chunked = list_series.list()?;
+        let n_rows = list_chunked.len();
+
+        let mut width: Option<usize> = None;
+        let mut values: Vec<Option<f64>> = Vec::new();
+        let mut row_valid: Vec<bool> = Vec::with_capacity(n_rows);
+        let mut pending_null_rows = 0;
+        for row in list_chunked.amortized_iter() {
+            match row {
+                Some(s) => {
+                    let ca = s.as_ref().f64()?;
+                    match width {
+                        None => {
+                            width = Some(ca.len());
+                            values.reserve(n_rows * ca.len());
+                            // Back-fill null rows seen before the width was known
+                            values.extend(std::iter::repeat_n(None, pending_null_rows * ca.len()));
+                        },
+                        Some(w) if w != ca.len() => {
+                            let context = format!("vertical {op_name}");
+                            return Err(length_mismatch_error(list_chunked, &context, false));
+                        },
+                        _ => {},
+                    }
+                    values.extend(ca.iter());
+                    row_valid.push(true);
+                },
+                None => {
+                    match width {
+                        Some(w) => values.extend(std::iter::repeat_n(None, w)),
+                        None => pending_null_rows += 1,
+                    }
+                    row_valid.push(false);
+                },
+            }
+        }
+
+        Ok(Self {
+            n_rows,
+            width: width.unwrap_or(0),
+            values,
+            row_valid,
+        })
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<f64> {
+        self.values[i * self.width + j]
+    }
+}
+
+/// Start offset into the flat values of each non-null row of a column prepared by
+/// [`ensure_contiguous_list`], with the width those rows share. Lets vertical operations
+/// read the values in their own dtype; fails like [`F64Matrix::from_series`] if non-null
+/// rows differ in length.
+pub(super) fn vertical_row_starts(
+    list_chunked: &ListChunked,
+    op_name: &str,
+) -> PolarsResult<(Vec<Option<usize>>, usize)> {
+    let arr = single_list_array(list_chunked);
+    let offsets = arr.offsets().as_slice();
+    let mut width: Option<usize> = None;
+    let mut starts = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        if !arr.is_valid(i) {
+            starts.push(None);
+            continue;
+        }
+        let len = (offsets[i + 1] - offsets[i]) as usize;
+        if *width.get_or_insert(len) != len {
+            let context = format!("vertical {op_name}");
+            return Err(length_mismatch_error(list_chunked, &context, false));
+        }
+        starts.push(Some(offsets[i] as usize));
+    }
+    Ok((starts, width.unwrap_or(0)))
+}
+
+/// Evaluate `$body` with the type alias `$T` bound to the polars type of the physical
+/// numeric dtype `$dtype`, so a generic kernel runs in the column's own dtype.
+macro_rules! with_numeric_type {
+    ($dtype:expr, $T:ident => $body:expr) => {
+        match $dtype {
+            DataType::Int8 => { type $T = Int8Type; $body },
+            DataType::Int16 => { type $T = Int16Type; $body },
+            DataType::Int32 => { type $T = Int32Type; $body },
+            DataType::Int64 => { type $T = Int64Type; $body },
+            DataType::Int128 => { type $T = Int128Type; $body },
+            DataType::UInt8 => { type $T = UInt8Type; $body },
+            DataType::UInt16 => { type $T = UInt16Type; $body },
+            DataType::UInt32 => { type $T = UInt32Type; $body },
+            DataType::UInt64 => { type $T = UInt64Type; $body },
+            DataType::Float32 => { type $T = Float32Type; $body },
+            DataType::Float64 => { type $T = Float64Type; $body },
+            dt => polars_bail!(InvalidOperation: "Expected a numeric dtype, got {:?}", dt),
+        }
+    };
+}
+pub(super) use with_numeric_type;
+
+/// The flat values of a List column in their physical numeric dtype, for kernels that
+/// dispatch on it with [`with_numeric_type`] rather than casting to Float64. Elements of
+/// dtype Null (an all-null column) come back as Float64 nulls.
+pub(super) fn physical_numeric_values(list_chunked: &ListChunked) -> PolarsResult<Series> {
+    let values = match list_chunked.inner_dtype() {
+        DataType::Null => list_chunked.get_inner().cast(&DataType::Float64)?,
+        _ => list_chunked.get_inner().to_physical_repr().into_owned(),
+    };
+    polars_ensure!(
+        values.dtype().is_primitive_numeric(),
+        InvalidOperation: "Expected numeric list elements, got {:?}", list_chunked.inner_dtype()
+    );
+    Ok(values)
+}
+
+/// Welford's running count, mean and sum of squared deviations, updated one value at a
+/// time so mean and standard deviation come out of a single numerically stable pass.
+#[derive(Clone, Copy, Default)]
+pub(super) struct Welford {
+    pub n: u32,
+    mean: f64,
+    m2: f64,
+}
+
+impl Welford {
+    pub fn push(&mut self, v: f64) {
+        self.n += 1;
+        let delta = v - self.mean;
+        self.mean += delta / self.n as f64;
+        self.m2 += delta * (v - self.mean);
+    }
+
+    /// Mean of the values pushed so far, or `None` if there were none.
+    pub fn mean(&self) -> Option<f64> {
+        (self.n > 0).then_some(self.mean)
+    }
+
+    /// Standard deviation with divisor `n - ddof`, or `None` if `n <= ddof`.
+    pub fn std(&self, ddof: u8) -> Option<f64> {
+        let n = self.n as f64;
+        let ddof = ddof as f64;
+        (n > ddof).then(|| (self.m2 / (n - ddof)).sqrt())
+    }
+}
+
+/// Fold every non-null row of a List or Array column into one [`Welford`] state per
+/// position in a single streaming pass, skipping null elements. `op_name` names the
+/// operation in the length-mismatch error; an all-null column gives no states.
+pub(super) fn vertical_welford(series: &Series, op_name: &str) -> PolarsResult<Vec<Welford>> {
+    let list_series =
+        ensure_list_type(series)?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = list_series.list()?;
+
+    let mut states: Option<Vec<Welford>> = None;
+    for row in list_chunked.amortized_iter().flatten() {
+        let ca = row.as_ref().f64()?;
+        let states = states.get_or_insert_with(|| vec![Welford::default(); ca.len()]);
+        if ca.len() != states.len() {
+            return Err(length_mismatch_error(list_chunked, &format!("vertical {op_name}"), false));
+        }
+        for (state, v) in states.iter_mut().zip(ca.iter()) {
+            if let Some(v) = v {
+                state.push(v);
+            }
+        }
+    }
+
+    Ok(states.unwrap_or_default())
+}
+
+/// Build a Float64 list column from optional rows, returning `Array(Float64, width)` when
+/// the input dtype was an Array.
+pub(super) fn build_f64_list<'a>(
+    name: PlSmallStr,
+    n_rows: usize,
+    rows: impl Iterator<Item = Option<&'a [Option<f64>]>>,
+    input_dtype: &DataType,
+) -> PolarsResult<Series> {
+    let mut builder =
+        ListPrimitiveChunkedBuilder::<Float64Type>::new(name, n_rows, n_rows, DataType::Float64);
+    for row in rows {
+        match row {
+            Some(values) => builder.append_iter(values.iter().copied()),
+            None => builder.append_null(),
+        }
+    }
+    let result_series = builder.finish().into_series();
+    match input_dtype {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
+
+/// Wrap a flat values Series as an `Array(inner, width)` column of `n_rows` rows without
+/// copying the values. `validity` marks null rows (`None` means all rows are valid).
+pub(crate) fn array_from_flat_values(
+    name: PlSmallStr,
+    values: &Series,
+    width: usize,
+    n_rows: usize,
+    validity: Option<polars_arrow::bitmap::Bitmap>,
+) -> PolarsResult<Series> {
+    use polars_arrow::array::{FixedSizeListArray, LIST_VALUES_NAME};
+    use polars_arrow::datatypes::{ArrowDataType, Field as ArrowField};
+
+    polars_ensure!(
+        values.len() == width * n_rows,
+        ComputeError: "Expected {} values for {} rows of width {}, got {}",
+        width * n_rows, n_rows, width, values.len()
+    );
+    let values_arr = values.rechunk().to_arrow(0, CompatLevel::newest());
+    let inner_field = ArrowField::new(LIST_VALUES_NAME, values_arr.dtype().clone(), true);
+    let dtype = ArrowDataType::FixedSizeList(Box::new(inner_field), width);
+    let arr = FixedSizeListArray::try_new(dtype, n_rows, values_arr, validity)?;
+    Ok(ArrayChunked::with_chunk(name, arr).into_series())
+}
+
+/// Wrap a flat values Series as a List column of `offsets.len() - 1` rows without copying
+/// the values; row `i` holds `values[offsets[i]..offsets[i + 1]]`. `validity` marks null
+/// rows (`None` means all rows are valid).
+pub(crate) fn list_from_flat_values(
+    name: PlSmallStr,
+    values: &Series,
+    offsets: Vec<i64>,
+    validity: Option<polars_arrow::bitmap::Bitmap>,
+) -> PolarsResult<Series> {
+    let values_arr = values.rechunk().to_arrow(0, CompatLevel::newest());
+    let dtype = ListArray::<i64>::default_datatype(values_arr.dtype().clone());
+    let arr = ListArray::<i64>::try_new(dtype, offsets.try_into()?, values_arr, validity)?;
+    Ok(ListChunked::with_chunk(name, arr).into_series())
+}
+
+/// Convert a List column whose non-null rows all have length `width` into an
+/// `Array(inner, width)` column, building the fixed-size array directly instead of casting.
+/// When the rows are already laid out contiguously the flat values are reused as-is;
+/// otherwise they are gathered once, padding null rows.
+pub(crate) fn list_into_array(series: &Series, width: usize) -> PolarsResult<Series> {
+    let series = ensure_contiguous_list(series)?;
+    let list_chunked = series.list()?;
+    let n_rows = list_chunked.len();
+    let list_arr = single_list_array(list_chunked);
+    let offsets = list_arr.offsets();
+    let validity = list_arr.validity().cloned();
+    let row_is_valid = |i: usize| validity.as_ref().is_none_or(|v| v.get_bit(i));
+
+    let start = offsets[0] as usize;
+    let mut contiguous = true;
+    for i in 0..n_rows {
+        let len = (offsets[i + 1] - offsets[i]) as usize;
+        if row_is_valid(i) && len != width {
+            polars_bail!(
+                ComputeError:
+                "Expected every list to have length {} for Array output, got {} at row {}",
+                width, len, i
+            );
+        }
+        contiguous &= offsets[i] as usize == start + i * width && len == width;
+    }
+
+    let inner = list_chunked.get_inner();
+    let values = if contiguous {
+        inner.slice(start as i64, n_rows * width)
+    } else {
+        let idx_ca: IdxCa = (0..n_rows)
+            .flat_map(|i| {
+                let valid = row_is_valid(i);
+                (0..width).map(move |j| valid.then(|| (offsets[i] as usize + j) as IdxSize))
+            })
+            .collect();
+        inner.take(&idx_ca)?
+    };
+    array_from_flat_values(series.name().clone(), &values, width, n_rows, validity)
+}
+
+/// Ordinary least-squares fit of `y = intercept + slope * x`.
+pub(super) struct OlsFit {
+    pub n: usize,
+    pub slope: f64,
+    pub intercept: f64,
+    pub r2: f64,
+    /// Standard error of the slope (NaN when fewer than three points).
+    pub slope_se: f64,
+}
+
+/// Fit `y = intercept + slope * x` over the points where both x and y are finite.
+/// Returns `None` when fewer than two points are usable or x is constant.
+pub(super) fn ols_fit(points: impl Iterator<Item = (f64, f64)> + Clone) -> Option<OlsFit> {
+    let usable = points.filter(|(x, y)| x.is_finite() && y.is_finite());
+    let (n, sx, sy) = usable
+        .clone()
+        .fold((0usize, 0.0, 0.0), |(n, sx, sy), (x, y)| (n + 1, sx + x, sy + y));
+    if n < 2 {
+        return None;
+    }
+    let (mx, my) = (sx / n as f64, sy / n as f64);
+    let (sxx, syy, sxy) = usable.fold((0.0, 0.0, 0.0), |(sxx, syy, sxy), (x, y)| {
+        let (dx, dy) = (x - mx, y - my);
+        (sxx + dx * dx, syy + dy * dy, sxy + dx * dy)
+    });
+    if sxx == 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    let ss_res = (syy - slope * sxy).max(0.0);
+    let r2 = if syy > 0.0 { 1.0 - ss_res / syy } else { f64::NAN };
+    let slope_se = if n > 2 { (ss_res / (n - 2) as f64 / sxx).sqrt() } else { f64::NAN };
+    Some(OlsFit {
+        n,
+        slope,
+        intercept: my - slope * mx,
+        r2,
+        slope_se,
+    })
+}
+
+/// Pearson correlation of the pairs, or `None` when there are fewer than two; NaN when
+/// either side is constant.
+pub(super) fn pearson(pairs: impl Iterator<Item = (f64, f64)> + Clone) -> Option<f64> {
+    let (n, sx, sy) = pairs
+        .clone()
+        .fold((0.0, 0.0, 0.0), |(n, sx, sy), (x, y)| (n + 1.0, sx + x, sy + y));
+    if n < 2.0 {
+        return None;
+    }
+    let (mx, my) = (sx / n, sy / n);
+    let (cov, vx, vy) = pairs.fold((0.0, 0.0, 0.0), |(c, vx, vy), (x, y)| {
+        let (dx, dy) = (x - mx, y - my);
+        (c + dx * dy, vx + dx * dx, vy + dy * dy)
+    });
+    Some(cov / (vx * vy).sqrt())
+}
+
+/// Wrap each `(name, values)` pair as a one-row `List(Float64)` field of a single-row struct.
+pub(super) fn single_row_struct(
+    name: PlSmallStr,
+    fields: Vec<(&str, Vec<Option<f64>>)>,
+) -> PolarsResult<Series> {
+    let fields: Vec<Series> = fields
+        .into_iter()
+        .map(|(field_name, values)| {
+            let values = Series::new(field_name.into(), values);
+            ListChunked::full(field_name.into(), &values, 1).into_series()
+        })
+        .collect();
+    Ok(StructChunked::from_series(name, 1, fields.iter())?.into_series())
+}
+
+/// Element dtype of a binary-op operand: the inner dtype of a List/Array, or the dtype of a
+/// plain numeric (or Boolean) column that will be broadcast with [`broadcast_scalar`].
+pub(super) fn operand_inner_dtype(dtype: &DataType) -> PolarsResult<&DataType> {
+    match dtype {
+        DataType::List(inner) | DataType::Array(inner, _) => Ok(inner),
+        dt if dt.is_primitive_numeric() || dt.is_bool() => Ok(dt),
+        dt => polars_bail!(InvalidOperation: "Expected List, Array or numeric type, got {:?}", dt),
+    }
+}
+
+/// Turn a binary-op operand into a List column matching the row lengths of `like`.
+/// List/Array operands are passed through as Lists. A plain numeric or Boolean column has
+/// each row's value repeated to the length of the matching row of `like` (a null value
+/// gives a row of nulls), and a length-1 column such as a literal is used for every row.
+pub(super) fn broadcast_scalar(operand: &Series, like: &ListChunked) -> PolarsResult<Series> {
+    let dtype = operand.dtype();
+    if matches!(dtype, DataType::List(_) | DataType::Array(_, _)) {
+        return ensure_list_type(operand);
+    }
+    operand_inner_dtype(dtype)?;
+    let n_rows = like.len();
+    if operand.len() != n_rows && operand.len() != 1 {
+        polars_bail!(
+            ComputeError:
+            "Scalar operand must have one value or one per row. Expected {}, got {}",
+            n_rows, operand.len()
+        );
+    }
+    let lengths = like.lst_lengths();
+    let rows = (0..n_rows).map(|i| {
+        let len = lengths.get(i).unwrap_or(0) as usize;
+        let idx = if operand.len() == 1 { 0 } else { i };
+        Some(operand.new_from_index(idx, len))
+    });
+    ListChunked::from_iter(rows)
+        .with_name(operand.name().clone())
+        .into_series()
+        .cast(&DataType::List(Box::new(dtype.clone())))
+}
+
+/// Walk the rows of `a` together with the matching rows of `b`, both `List(Float64)`
+/// columns. `b` has one row per row of `a`, or a single row used for every row. `f`
+/// receives each row's pair of lists, or `None` when either row is null; paired rows of
+/// different lengths are an error naming `op_name` and the row.
+pub(super) fn for_each_row_pair(
+    a: &Series,
+    b: &Series,
+    op_name: &str,
+    mut f: impl FnMut(Option<(&Float64Chunked, &Float64Chunked)>) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    let (a_ca, b_ca) = (a.list()?, b.list()?);
+    let n_rows = a_ca.len();
+    let b_rows: Box<dyn Iterator<Item = Option<Series>>> = match b_ca.len() {
+        len if len == n_rows => Box::new(b_ca.into_iter()),
+        1 => Box::new(std::iter::repeat_n(b_ca.get_as_series(0), n_rows)),
+        len => polars_bail!(
+            ComputeError:
+            "b must have one row or the same number of rows as a for {}. Expected 1 or {}, got {}",
+            op_name, n_rows, len
+        ),
+    };
+    for (row, (a, b)) in a_ca.into_iter().zip(b_rows).enumerate() {
+        let (Some(a), Some(b)) = (a, b) else {
+            f(None)?;
+            continue;
+        };
+        let (a, b) = (a.f64()?, b.f64()?);
+        if a.len() != b.len() {
+            polars_bail!(
+                ComputeError:
+                "Lists must have the same length for {}. Row {}: a has {}, b has {}",
+                op_name, row, a.len(), b.len()
+            );
+        }
+        f(Some((a, b)))?;
+    }
+    Ok(())
+}
+
+/// Reduce each row's pair of lists `(a, b)` to one Float64 with `f`, which receives the
+/// positions where both values are non-null. NaN and infinite values are passed through so
+/// they propagate into the result instead of silently shrinking the sample.
+/// inputs[0] = a (List/Array)
+/// inputs[1] = b (List/Array, or a numeric column/literal broadcast across each row)
+/// Null rows in either input give null; `op_name` names the operation in error messages.
+pub(super) fn map_row_pairs_f64(
+    inputs: &[Series],
+    op_name: &str,
+    f: impl Fn(&[(f64, f64)]) -> Option<f64>,
+) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let a_series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let b_series = broadcast_scalar(&inputs[1], a_series.list()?)?.cast(&f64_list)?;
+
+    let mut out: Vec<Option<f64>> = Vec::with_capacity(a_series.len());
+    let mut pairs: Vec<(f64, f64)> = Vec::new();
+    for_each_row_pair(&a_series, &b_series, op_name, |pair| {
+        let Some((a, b)) = pair else {
+            out.push(None);
+            return Ok(());
+        };
+        pairs.clear();
+        pairs.extend(a.iter().zip(b.iter()).filter_map(|(x, y)| Some((x?, y?))));
+        out.push(f(&pairs));
+        Ok(())
+    })?;
+    Ok(Float64Chunked::from_iter_options(a_series.name().clone(), out.into_iter()).into_series())
+}
+
+/// Output field of a per-row Float64 reduction of a List/Array column.
+pub(super) fn float_scalar_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {
+            Ok(Field::new(field.name().clone(), DataType::Float64))
+        },
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
@@ -2,13 +2,25 @@
 use std::collections::HashMap;
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
-use super::helpers::ensure_list_type;
+use super::helpers::ensure_contiguous_list;
 
 // --- Histogram ---
 
+/// How the bin edges are specified.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum HistogramMode {
+    /// A bin count; edges span each row's finite min..max.
+    BinsInt,
+    /// Explicit, shared bin edges.
+    Edges,
+    /// `start`/`stop`/`spacing`, each possibly per-row.
+    Range,
+}
+
 #[derive(serde::Deserialize)]
 struct HistogramKwargs {
-    mode: String,                           // "bins_int", "edges", "range"
+    mode: HistogramMode,
     bins_int: Option<u32>,
     bins_edges: Option<Vec<f64>>,
     start: Option<f64>,
@@ -553,7 +565,8 @@ fn list_histogram_bins_int_fast(inputs: &[Series], kwargs: BinsIntFastKwargs) ->
     use polars_arrow::storage::SharedStorage;
 
     let series = &inputs[0];
-    let series = ensure_list_type(series)?;
+    // One up-front rechunk so chunked inputs still take the flat-buffer path
+    let series = ensure_contiguous_list(series)?;
     let list_chunked = series.list()?;
 
     let n_rows = list_chunked.len();
@@ -685,7 +698,10 @@ fn list_histogram_bins_int_fast(inputs: &[Series], kwargs: BinsIntFastKwargs) ->
                             let slice = &values_flat[start..end];
 
                             let (mut lo, mut hi) = (f64::INFINITY, f64::NEG_INFINITY);
-                            for &v in slice { if v < lo { lo = v; } if v > hi { hi = v; } }
+                            for &v in slice {
+                                if v < lo { lo = v; }
+                                if v > hi { hi = v; }
+                            }
                             let (lo, hi) = (lo, hi);
 
                             let has_non_finite = !lo.is_finite() || !hi.is_finite();
@@ -815,8 +831,8 @@ fn list_histogram(inputs: &[Series], kwargs: HistogramKwargs) -> PolarsResult<Se
     let series = &inputs[0];
     let include_breakpoints = kwargs.include_breakpoints.unwrap_or(true);
 
-    // Convert to List if it's an Array
-    let series = ensure_list_type(series)?;
+    // Convert to a single-chunk List so chunked inputs still take the flat-buffer path
+    let series = ensure_contiguous_list(series)?;
     let list_chunked = series.list()?;
 
     let n_rows = list_chunked.len();
@@ -834,12 +850,12 @@ fn list_histogram(inputs: &[Series], kwargs: HistogramKwargs) -> PolarsResult<Se
         return Ok(out.into_series());
     }
 
-    let mode = kwargs.mode.as_str();
+    let mode = kwargs.mode;
 
     // Parallel fast path: bins_int with constant n_bins, no breakpoints, no null rows.
     // Uses a single pre-allocated flat Vec<u32> split across threads — avoids the 2×
     // memory peak of the previous parallel attempt that used per-thread builders.
-    if mode == "bins_int"
+    if mode == HistogramMode::BinsInt
         && !include_breakpoints
         && kwargs.bins_int.is_some()
         && !kwargs.arg_positions.contains_key("bins_int")
@@ -849,7 +865,7 @@ fn list_histogram(inputs: &[Series], kwargs: HistogramKwargs) -> PolarsResult<Se
         return bins_int_parallel_flat(list_chunked, series.name().clone(), n_rows, n_bins);
     }
     // "bins_int" and "range" always produce uniformly-spaced edges — use O(1) bin assignment.
-    let use_uniform_bins = mode == "bins_int" || mode == "range";
+    let use_uniform_bins = matches!(mode, HistogramMode::BinsInt | HistogramMode::Range);
 
     // Single scratch buffer reused across all rows to avoid N heap allocations
     let mut scratch: Vec<u32> = Vec::new();
@@ -864,7 +880,7 @@ fn list_histogram(inputs: &[Series], kwargs: HistogramKwargs) -> PolarsResult<Se
     let mut values_cache: Vec<f64> = Vec::with_capacity(1024);
 
     // For "edges" mode, resolve edges once (they're the same for every row)
-    let static_edges: Option<Vec<f64>> = if mode == "edges" {
+    let static_edges: Option<Vec<f64>> = if mode == HistogramMode::Edges {
         let mut edges = kwargs.bins_edges.clone().unwrap_or_default();
         edges.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
         if edges.len() < 2 {
@@ -878,10 +894,10 @@ fn list_histogram(inputs: &[Series], kwargs: HistogramKwargs) -> PolarsResult<Se
 
     // Estimate bins for pre-allocation capacity (doesn't affect correctness)
     let n_bins_hint: usize = match mode {
-        "bins_int" if !kwargs.arg_positions.contains_key("bins_int") => {
+        HistogramMode::BinsInt if !kwargs.arg_positions.contains_key("bins_int") => {
             kwargs.bins_int.unwrap_or(50) as usize
         },
-        "edges" => static_edges.as_ref().map(|e| e.len().saturating_sub(1)).unwrap_or(50),
+        HistogramMode::Edges => static_edges.as_ref().map(|e| e.len().saturating_sub(1)).unwrap_or(50),
         _ => 50,
     };
 
@@ -925,10 +941,10 @@ fn list_histogram(inputs: &[Series], kwargs: HistogramKwargs) -> PolarsResult<Se
 
         // Determine edges for this row
         let edges = match mode {
-            "edges" => {
+            HistogramMode::Edges => {
                 static_edges.clone().unwrap()
             }
-            "bins_int" => {
+            HistogramMode::BinsInt => {
                 let n_bins = resolve_u32_param(
                     kwargs.bins_int,
                     "bins_int",
@@ -993,7 +1009,7 @@ fn list_histogram(inputs: &[Series], kwargs: HistogramKwargs) -> PolarsResult<Se
                     }
                 }
             }
-            "range" => {
+            HistogramMode::Range => {
                 let start = resolve_f64_param(
                     kwargs.start,
                     "start",
@@ -1028,9 +1044,6 @@ fn list_histogram(inputs: &[Series], kwargs: HistogramKwargs) -> PolarsResult<Se
                     }
                 }
             }
-            _ => {
-                polars_bail!(ComputeError: "Invalid histogram mode '{}'. Expected 'bins_int', 'edges', or 'range'", mode);
-            }
         };
 
         // Count into bins, reusing scratch buffer to avoid per-row allocations.
@@ -0,0 +1,57 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use crate::kwargs::ReduceKwargs;
+use crate::reduce::{reduce_output_type, vertical_reduce, Accumulator};
+
+/// Running per-position value with the largest magnitude, ignoring nulls. The magnitudes are
+/// kept alongside so each row only needs its own converted.
+struct AbsMax {
+    value: Series,
+    magnitude: Series,
+}
+
+/// Absolute values as Float64, for comparing magnitudes across integer and float inputs.
+fn magnitude(s: &Series) -> PolarsResult<Series> {
+    Ok(s.cast(&DataType::Float64)?.f64()?.apply_values(f64::abs).into_series())
+}
+
+impl Accumulator for AbsMax {
+    const NAME: &'static str = "abs_max";
+
+    // Preserve the input type: the result is one of the input values, sign included
+    fn output_dtype(inner: &DataType) -> DataType {
+        inner.clone()
+    }
+
+    fn init(first: &Series) -> PolarsResult<Self> {
+        Ok(AbsMax { value: first.clone(), magnitude: magnitude(first)? })
+    }
+
+    fn update(&mut self, row: &Series) -> PolarsResult<()> {
+        // Take the row where it is larger in magnitude or the result is still null; ties keep
+        // the earlier row
+        let row_magnitude = magnitude(row)?;
+        let both_not_null = self.value.is_not_null() & row.is_not_null();
+        let take_row = (self.magnitude.lt(&row_magnitude)? & both_not_null)
+            | self.value.is_null();
+        let take_row = take_row & row.is_not_null();
+        self.value = row.zip_with(&take_row, &self.value)?;
+        self.magnitude = row_magnitude.zip_with(&take_row, &self.magnitude)?;
+        Ok(())
+    }
+
+    fn finish(self) -> PolarsResult<Series> {
+        Ok(self.value)
+    }
+}
+
+fn list_abs_max_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    reduce_output_type::<AbsMax>(input_fields)
+}
+
+/// Per-position value with the largest absolute magnitude across rows, keeping its sign.
+#[polars_expr(output_type_func=list_abs_max_output_type)]
+fn list_abs_max(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    vertical_reduce::<AbsMax>(&inputs[0], kwargs)
+}
@@ -0,0 +1,44 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, float_list_output_type, F64Matrix};
+
+#[derive(serde::Deserialize)]
+struct ChunkedMeanKwargs {
+    every: usize,
+}
+
+/// Per-position mean over blocks of `every` consecutive rows, giving one row per block
+/// (`ceil(n_rows / every)` rows; the last block may be shorter). Null elements are skipped,
+/// positions without values in a block are null, and a block of only null rows is null.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn list_chunked_mean(inputs: &[Series], kwargs: ChunkedMeanKwargs) -> PolarsResult<Series> {
+    if kwargs.every == 0 {
+        polars_bail!(InvalidOperation: "every must be at least 1 for chunked_mean");
+    }
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "chunked_mean")?;
+    let width = matrix.width;
+    let n_blocks = matrix.n_rows.div_ceil(kwargs.every);
+
+    let mut means: Vec<Option<f64>> = Vec::with_capacity(n_blocks * width);
+    let mut block_valid: Vec<bool> = Vec::with_capacity(n_blocks);
+    let mut sums: Vec<(f64, u32)> = vec![(0.0, 0); width];
+    for start in (0..matrix.n_rows).step_by(kwargs.every) {
+        let rows = start..(start + kwargs.every).min(matrix.n_rows);
+        sums.fill((0.0, 0));
+        for i in rows.clone() {
+            for (j, (sum, count)) in sums.iter_mut().enumerate() {
+                if let Some(v) = matrix.get(i, j) {
+                    *sum += v;
+                    *count += 1;
+                }
+            }
+        }
+        means.extend(sums.iter().map(|&(sum, count)| (count > 0).then(|| sum / count as f64)));
+        block_valid.push(rows.into_iter().any(|i| matrix.row_valid[i]));
+    }
+
+    let rows = (0..n_blocks).map(|b| block_valid[b].then(|| &means[b * width..(b + 1) * width]));
+    build_f64_list(series.name().clone(), n_blocks, rows, series.dtype())
+}
@@ -1,7 +1,7 @@
 #![allow(clippy::unused_unit)]
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
-use super::helpers::ensure_list_type;
+use super::helpers::ensure_contiguous_list;
 
 #[derive(serde::Deserialize)]
 struct ListClipKwargs {
@@ -20,7 +20,7 @@ fn list_clip_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
 
 #[polars_expr(output_type_func=list_clip_output_type)]
 fn list_clip(inputs: &[Series], kwargs: ListClipKwargs) -> PolarsResult<Series> {
-    let values_series = ensure_list_type(&inputs[0])?;
+    let values_series = ensure_contiguous_list(&inputs[0])?;
     let start_series = inputs[1].cast(&DataType::Float64)?;
     let stop_series = inputs[2].cast(&DataType::Float64)?;
 
@@ -193,7 +193,7 @@ fn cross_clip_series(inputs: &[Series], kwargs: CrossClipSeriesKwargs) -> Polars
     use rayon::prelude::*;
     use polars_arrow::array::{Array, ListArray, PrimitiveArray};
 
-    let values_series = ensure_list_type(&inputs[0])?;
+    let values_series = ensure_contiguous_list(&inputs[0])?;
     let starts_f64 = inputs[1].cast(&DataType::Float64)?;
     let stops_f64 = inputs[2].cast(&DataType::Float64)?;
 
@@ -366,7 +366,7 @@ fn cross_clip(inputs: &[Series], kwargs: CrossClipKwargs) -> PolarsResult<Series
     use rayon::prelude::*;
     use polars_arrow::array::{Array, ListArray, PrimitiveArray};
 
-    let values_series = ensure_list_type(&inputs[0])?;
+    let values_series = ensure_contiguous_list(&inputs[0])?;
     let list_ca = values_series.list()?;
     let n_units = list_ca.len();
     let n_intervals = kwargs.starts.len();
@@ -1,9 +1,29 @@
 #![allow(clippy::unused_unit)]
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
-use super::helpers::ensure_list_type;
+use super::helpers::{ensure_list_type, list_into_array};
+
+/// Which part of the full convolution is returned, following numpy's modes.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum ConvolveMode {
+    Full,
+    Same,
+    Valid,
+    /// Causal: each output depends on the current and earlier samples.
+    Left,
+    /// Anti-causal: each output depends on the current and later samples.
+    Right,
+}
+
+#[derive(serde::Deserialize)]
+struct ConvolveKwargs {
+    kernel: Vec<f64>,
+    fill_value: f64,
+    mode: ConvolveMode,
+}
 
-fn list_convolve_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+fn list_convolve_output_type(input_fields: &[Field], kwargs: ConvolveKwargs) -> PolarsResult<Field> {
     let field = &input_fields[0];
     match field.dtype() {
         DataType::List(_) => {
@@ -14,24 +34,34 @@ fn list_convolve_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
             ))
         },
         DataType::Array(_, width) => {
-            // Convolution produces Float64 output, preserve Array type
+            // Convolution produces Float64 output, preserve Array type with the mode's width
+            let kernel_len = kwargs.kernel.iter().filter(|x| x.is_finite()).count();
             Ok(Field::new(
                 field.name().clone(),
-                DataType::Array(Box::new(DataType::Float64), *width),
+                DataType::Array(
+                    Box::new(DataType::Float64),
+                    convolved_len(*width, kernel_len, kwargs.mode),
+                ),
             ))
         },
         _ => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", field.dtype()),
     }
 }
 
-#[derive(serde::Deserialize)]
-struct ConvolveKwargs {
-    kernel: Vec<f64>,
-    fill_value: f64,
-    mode: String,
+/// Length of the convolution of a signal with a kernel in the given mode.
+fn convolved_len(signal_len: usize, kernel_len: usize, mode: ConvolveMode) -> usize {
+    if signal_len == 0 || kernel_len == 0 {
+        return 0;
+    }
+    match mode {
+        ConvolveMode::Full => signal_len + kernel_len - 1,
+        ConvolveMode::Same => signal_len.max(kernel_len),
+        ConvolveMode::Valid => signal_len.max(kernel_len) - signal_len.min(kernel_len) + 1,
+        ConvolveMode::Left | ConvolveMode::Right => signal_len,
+    }
 }
 
-#[polars_expr(output_type_func=list_convolve_output_type)]
+#[polars_expr(output_type_func_with_kwargs=list_convolve_output_type)]
 fn list_convolve(inputs: &[Series], kwargs: ConvolveKwargs) -> PolarsResult<Series> {
     let series = &inputs[0];
     let input_dtype = series.dtype().clone();
@@ -57,13 +87,13 @@ fn list_convolve(inputs: &[Series], kwargs: ConvolveKwargs) -> PolarsResult<Seri
         polars_bail!(ComputeError: "Kernel cannot be empty or contain only non-finite values");
     }
 
-    let mode = kwargs.mode.as_str();
+    let mode = kwargs.mode;
 
     // Build result: convolve each row's list with kernel
     let mut result_series_vec: Vec<Option<Series>> = Vec::with_capacity(n_lists);
 
-    for i in 0..n_lists {
-        if let Some(s) = list_chunked.get_as_series(i) {
+    for row in list_chunked.into_iter() {
+        if let Some(s) = row {
             // Convert series to f64 and handle nulls
             let signal = s.cast(&DataType::Float64)?;
             let signal_f64 = signal.f64()?;
@@ -94,14 +124,14 @@ fn list_convolve(inputs: &[Series], kwargs: ConvolveKwargs) -> PolarsResult<Seri
     let result_series = result_list.into_series();
     match &input_dtype {
         DataType::Array(_, width) => {
-            result_series.cast(&DataType::Array(Box::new(DataType::Float64), *width))
+            list_into_array(&result_series, convolved_len(*width, kernel.len(), mode))
         },
         _ => Ok(result_series),
     }
 }
 
 // Perform 1D convolution
-fn convolve_1d(signal: &[f64], kernel: &[f64], mode: &str) -> PolarsResult<Vec<f64>> {
+fn convolve_1d(signal: &[f64], kernel: &[f64], mode: ConvolveMode) -> PolarsResult<Vec<f64>> {
     let signal_len = signal.len();
     let kernel_len = kernel.len();
 
@@ -115,15 +145,15 @@ fn convolve_1d(signal: &[f64], kernel: &[f64], mode: &str) -> PolarsResult<Vec<f
 
     // Determine output length and offset for mapping to full convolution indices
     let (output_len, offset_to_full) = match mode {
-        "full" => (signal_len + kernel_len - 1, 0),
-        "same" => {
+        ConvolveMode::Full => (signal_len + kernel_len - 1, 0),
+        ConvolveMode::Same => {
             // NumPy's same mode: output has length max(signal_len, kernel_len)
             // The output is centered relative to the full convolution
             let out_len = signal_len.max(kernel_len);
             let offset = (kernel_len as isize - 1) / 2;
             (out_len, offset)
         },
-        "valid" => {
+        ConvolveMode::Valid => {
             // Valid mode: where one array fully overlaps the other
             // NumPy treats inputs symmetrically: result length is max(M, N) - min(M, N) + 1
             let output_length = if signal_len >= kernel_len {
@@ -134,11 +164,8 @@ fn convolve_1d(signal: &[f64], kernel: &[f64], mode: &str) -> PolarsResult<Vec<f
             let offset = kernel_len as isize - 1;
             (output_length, offset)
         },
-        "left" => (signal_len, 0),
-        "right" => (signal_len, kernel_len as isize - 1),
-        _ => {
-            polars_bail!(ComputeError: "Invalid mode '{}'. Must be one of: full, same, valid, left, right", mode)
-        },
+        ConvolveMode::Left => (signal_len, 0),
+        ConvolveMode::Right => (signal_len, kernel_len as isize - 1),
     };
 
     let mut result = vec![0.0; output_len];
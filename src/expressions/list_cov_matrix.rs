@@ -0,0 +1,87 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, F64Matrix};
+
+#[derive(serde::Deserialize)]
+struct CovMatrixKwargs {
+    ddof: u8,
+}
+
+fn matrix_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(Box::new(DataType::List(Box::new(DataType::Float64)))),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Covariance statistics for one pair of positions over the rows where both are finite.
+struct PairStats {
+    n: f64,
+    cov: f64,
+    var_p: f64,
+    var_q: f64,
+}
+
+/// Pairwise-complete sums of co-deviations between positions `p` and `q`.
+fn pair_stats(matrix: &F64Matrix, p: usize, q: usize) -> PairStats {
+    let pairs = || {
+        (0..matrix.n_rows).filter_map(move |i| match (matrix.get(i, p), matrix.get(i, q)) {
+            (Some(x), Some(y)) if x.is_finite() && y.is_finite() => Some((x, y)),
+            _ => None,
+        })
+    };
+    let (n, sx, sy) = pairs().fold((0.0, 0.0, 0.0), |(n, sx, sy), (x, y)| (n + 1.0, sx + x, sy + y));
+    let (mx, my) = (sx / n, sy / n);
+    let (cov, var_p, var_q) = pairs().fold((0.0, 0.0, 0.0), |(c, vp, vq), (x, y)| {
+        let (dx, dy) = (x - mx, y - my);
+        (c + dx * dy, vp + dx * dx, vq + dy * dy)
+    });
+    PairStats { n, cov, var_p, var_q }
+}
+
+/// Fill a symmetric `width x width` matrix from `f(p, q)` and wrap it as a single row.
+fn symmetric_matrix_row(
+    series: &Series,
+    matrix: &F64Matrix,
+    f: impl Fn(PairStats) -> Option<f64>,
+) -> PolarsResult<Series> {
+    let width = matrix.width;
+    let mut values: Vec<Option<f64>> = vec![None; width * width];
+    for p in 0..width {
+        for q in p..width {
+            let v = f(pair_stats(matrix, p, q));
+            values[p * width + q] = v;
+            values[q * width + p] = v;
+        }
+    }
+    let rows = values.chunks(width.max(1)).take(width).map(Some);
+    let row_dtype = DataType::List(Box::new(DataType::Float64));
+    let inner = build_f64_list(series.name().clone(), width, rows, &row_dtype)?;
+    Ok(ListChunked::full(series.name().clone(), &inner, 1).into_series())
+}
+
+/// Covariance matrix between list positions, treating the column as an
+/// (n_rows x n_positions) matrix. Each entry uses the rows where both positions are finite.
+#[polars_expr(output_type_func=matrix_output_type)]
+fn list_cov_matrix(inputs: &[Series], kwargs: CovMatrixKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "cov_matrix")?;
+    let ddof = kwargs.ddof as f64;
+    symmetric_matrix_row(series, &matrix, |s| (s.n > ddof).then(|| s.cov / (s.n - ddof)))
+}
+
+/// Pearson correlation matrix between list positions. Entries with fewer than two complete
+/// rows are null; positions with zero variance give NaN.
+#[polars_expr(output_type_func=matrix_output_type)]
+fn list_corr_matrix(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "corr_matrix")?;
+    symmetric_matrix_row(series, &matrix, |s| {
+        (s.n >= 2.0).then(|| s.cov / (s.var_p * s.var_q).sqrt())
+    })
+}
@@ -0,0 +1,84 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, float_list_output_type, list_into_array, F64Matrix, Welford};
+
+#[derive(serde::Deserialize)]
+struct CumStdKwargs {
+    ddof: u8,
+}
+
+fn count_list_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(Box::new(DataType::UInt32)),
+        )),
+        DataType::Array(_, width) => Ok(Field::new(
+            field.name().clone(),
+            DataType::Array(Box::new(DataType::UInt32), *width),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Fold the rows of `matrix` into per-position Welford states top to bottom, calling
+/// `emit` with each row's index and the states after including it.
+fn expanding(matrix: &F64Matrix, mut emit: impl FnMut(usize, &[Welford])) {
+    let mut states = vec![Welford::default(); matrix.width];
+    for i in 0..matrix.n_rows {
+        for (j, state) in states.iter_mut().enumerate() {
+            if let Some(v) = matrix.get(i, j) {
+                state.push(v);
+            }
+        }
+        emit(i, &states);
+    }
+}
+
+/// Expanding (cumulative down the rows) standard deviation at each position, over the
+/// non-null values seen so far. Positions with no more than `ddof` values are null, and
+/// null rows stay null.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn list_cum_std(inputs: &[Series], kwargs: CumStdKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "cum_std")?;
+    let width = matrix.width;
+
+    let mut values: Vec<Option<f64>> = Vec::with_capacity(matrix.values.len());
+    expanding(&matrix, |_, states| {
+        values.extend(states.iter().map(|s| s.std(kwargs.ddof)));
+    });
+
+    let rows = (0..matrix.n_rows)
+        .map(|i| matrix.row_valid[i].then(|| &values[i * width..(i + 1) * width]));
+    build_f64_list(series.name().clone(), matrix.n_rows, rows, series.dtype())
+}
+
+/// Expanding count of non-null values at each position, down the rows. Null rows stay
+/// null.
+#[polars_expr(output_type_func=count_list_output_type)]
+fn list_cum_count(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "cum_count")?;
+
+    let mut builder = ListPrimitiveChunkedBuilder::<UInt32Type>::new(
+        series.name().clone(),
+        matrix.n_rows,
+        matrix.values.len(),
+        DataType::UInt32,
+    );
+    expanding(&matrix, |i, states| {
+        if matrix.row_valid[i] {
+            builder.append_values_iter(states.iter().map(|s| s.n));
+        } else {
+            builder.append_null();
+        }
+    });
+    let result_series = builder.finish().into_series();
+    match series.dtype() {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
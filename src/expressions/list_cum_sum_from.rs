@@ -0,0 +1,106 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_core::chunked_array::builder::get_list_builder;
+use polars_core::utils::try_get_supertype;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_list_type, list_into_array};
+
+fn list_cum_sum_from_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let initial_inner = match input_fields[1].dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => inner,
+        dt => polars_bail!(InvalidOperation: "initial must be a List or Array row, got {:?}", dt),
+    };
+    let dtype = match field.dtype() {
+        DataType::List(inner) => {
+            DataType::List(Box::new(try_get_supertype(inner, initial_inner)?))
+        },
+        DataType::Array(inner, width) => {
+            DataType::Array(Box::new(try_get_supertype(inner, initial_inner)?), *width)
+        },
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Running sum of the rows of a diffed column starting from `initial`, the inverse of
+/// `list_diff`: the first row is `initial` (the diff's own first row is ignored, as `list_diff`
+/// makes it null) and row `i` is row `i - 1` plus diff row `i`.
+///
+/// inputs[0] = List/Array column of row-to-row differences
+/// inputs[1] = a single List/Array row, the absolute first row
+///
+/// Null elements propagate down their position; a null diff row gives a null row, and every
+/// later row is a list of nulls since the running values are then unknown. Integers are
+/// summed in Int128 with overflow checks and each row is narrowed back strictly, so the
+/// result is exact or an error.
+#[polars_expr(output_type_func=list_cum_sum_from_output_type)]
+fn list_cum_sum_from(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let input_dtype = series.dtype().clone();
+    let list_series = ensure_list_type(series)?;
+    let list_chunked = list_series.list()?;
+
+    let initial_series = ensure_list_type(&inputs[1])?;
+    if initial_series.len() != 1 {
+        polars_bail!(
+            ComputeError: "initial must be a single list, got {} rows", initial_series.len()
+        );
+    }
+    let Some(initial) = initial_series.list()?.get_as_series(0) else {
+        polars_bail!(ComputeError: "initial must not be null");
+    };
+    let inner_dtype = try_get_supertype(list_chunked.inner_dtype(), initial.dtype())?;
+    let width = initial.len();
+
+    let n_lists = list_chunked.len();
+    let mut builder =
+        get_list_builder(&inner_dtype, n_lists * width, n_lists, series.name().clone());
+    let integer = inner_dtype.is_integer();
+    let work_dtype = if integer { DataType::Int128 } else { inner_dtype.clone() };
+    let overflow = || {
+        polars_err!(
+            ComputeError:
+            "cum_sum_from overflows {}; cast to a wider signed integer type first", inner_dtype
+        )
+    };
+    let mut running = initial.cast(&work_dtype)?;
+    for (i, row) in list_chunked.into_iter().enumerate() {
+        if i == 0 {
+            builder.append_series(&running.cast(&inner_dtype)?)?;
+            continue;
+        }
+        let Some(row) = row else {
+            builder.append_null();
+            running = Series::full_null(running.name().clone(), width, &work_dtype);
+            continue;
+        };
+        if row.len() != width {
+            polars_bail!(
+                ComputeError:
+                "All lists must have the length of initial ({}) for cum_sum_from, got {} at row {}",
+                width, row.len(), i
+            );
+        }
+        let row = row.cast(&work_dtype)?;
+        if integer {
+            let sums = running.i128()?.into_iter().zip(row.i128()?).map(|pair| match pair {
+                (Some(a), Some(b)) => a.checked_add(b).map(Some),
+                _ => Some(None),
+            });
+            let sums = sums.collect::<Option<Vec<_>>>().ok_or_else(overflow)?;
+            running = Int128Chunked::from_iter_options(running.name().clone(), sums.into_iter())
+                .into_series();
+            builder.append_series(&running.strict_cast(&inner_dtype).map_err(|_| overflow())?)?;
+        } else {
+            running = (&running + &row)?;
+            builder.append_series(&running)?;
+        }
+    }
+    let result_series = builder.finish().into_series();
+
+    match &input_dtype {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
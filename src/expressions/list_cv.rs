@@ -0,0 +1,53 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, float_list_output_type, vertical_welford};
+
+/// What `list_cv` returns where the mean is zero.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum OnZeroMean {
+    Null,
+    Nan,
+    Raise,
+}
+
+#[derive(serde::Deserialize)]
+struct CvKwargs {
+    ddof: u8,
+    /// Means with an absolute value at or below this count as zero.
+    zero_tol: f64,
+    on_zero_mean: OnZeroMean,
+}
+
+/// Coefficient of variation (std / mean) across rows at each position, from one Welford
+/// pass. Null elements are skipped; positions with no more than `ddof` values are null.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn list_cv(inputs: &[Series], kwargs: CvKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let states = vertical_welford(series, "cv")?;
+    let mut values: Vec<Option<f64>> = Vec::with_capacity(states.len());
+    for (j, state) in states.iter().enumerate() {
+        let (Some(mean), Some(std)) = (state.mean(), state.std(kwargs.ddof)) else {
+            values.push(None);
+            continue;
+        };
+        if mean.abs() > kwargs.zero_tol {
+            values.push(Some(std / mean));
+            continue;
+        }
+        match kwargs.on_zero_mean {
+            OnZeroMean::Null => values.push(None),
+            OnZeroMean::Nan => values.push(Some(f64::NAN)),
+            OnZeroMean::Raise => polars_bail!(
+                ComputeError:
+                "Mean is zero at position {} (|mean| <= {}), coefficient of variation is undefined",
+                j, kwargs.zero_tol
+            ),
+        }
+    }
+
+    // An all-null column has no positions to report
+    let row = (!states.is_empty()).then_some(values.as_slice());
+    build_f64_list(series.name().clone(), 1, std::iter::once(row), series.dtype())
+}
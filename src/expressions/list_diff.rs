@@ -1,30 +1,122 @@
 #![allow(clippy::unused_unit)]
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
-use super::helpers::ensure_list_type;
+use super::helpers::{
+    array_from_flat_values, ensure_contiguous_list, length_mismatch_error, list_from_flat_values,
+    single_list_array,
+};
+
+#[derive(serde::Deserialize)]
+struct DiffKwargs {
+    /// Order of the difference: how many times the operator is applied.
+    n: usize,
+    /// Difference against the following rows instead, as if the rows were reversed.
+    #[serde(default)]
+    reverse_rows: bool,
+}
+
+/// Inner dtype of the differences: unsigned and 8/16-bit integers widen to a signed type
+/// that every first difference fits in, as polars' own `diff` does for unsigned columns.
+fn diff_dtype(inner: &DataType) -> DataType {
+    match inner {
+        DataType::UInt8 | DataType::Int8 => DataType::Int16,
+        DataType::UInt16 | DataType::Int16 => DataType::Int32,
+        DataType::UInt32 | DataType::UInt64 => DataType::Int64,
+        dt => dt.clone(),
+    }
+}
 
 fn list_diff_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
     let field = &input_fields[0];
     match field.dtype() {
         DataType::List(inner) => Ok(Field::new(
             field.name().clone(),
-            DataType::List(inner.clone()),
+            DataType::List(Box::new(diff_dtype(inner))),
         )),
         DataType::Array(inner, width) => Ok(Field::new(
             field.name().clone(),
-            DataType::Array(inner.clone(), *width),
+            DataType::Array(Box::new(diff_dtype(inner)), *width),
         )),
         _ => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", field.dtype()),
     }
 }
 
+/// Flat element index of the first element of each row in the window of row `i`, one per
+/// row `m = 0..=n` back, or `None` when the window runs past the column or holds a null row.
+type Windows<'a> = dyn Fn(usize) -> Option<Vec<usize>> + 'a;
+
+/// `sum_m coefficients[m] * row[i - m]` for every row and position, in `i128`, narrowed to
+/// the output native type `O`. Positions with a null element in the window are null.
+fn diff_integers<O>(
+    get: impl Fn(usize) -> Option<i128>,
+    windows: &Windows,
+    coefficients: &[i128],
+    (n_rows, width): (usize, usize),
+    out_dtype: &DataType,
+) -> PolarsResult<Series>
+where
+    O: PolarsIntegerType,
+    O::Native: TryFrom<i128>,
+    ChunkedArray<O>: IntoSeries,
+{
+    let overflow = || {
+        polars_err!(
+            ComputeError:
+            "Vertical diff overflows {}; cast to a wider signed integer type first", out_dtype
+        )
+    };
+    let mut builder = PrimitiveChunkedBuilder::<O>::new("".into(), n_rows * width);
+    for i in 0..n_rows {
+        let Some(starts) = windows(i) else {
+            (0..width).for_each(|_| builder.append_null());
+            continue;
+        };
+        for j in 0..width {
+            let mut total = Some(0i128);
+            for (&start, &c) in starts.iter().zip(coefficients) {
+                total = match (total, get(start + j)) {
+                    (Some(total), Some(v)) => {
+                        let term = c.checked_mul(v).ok_or_else(overflow)?;
+                        Some(total.checked_add(term).ok_or_else(overflow)?)
+                    },
+                    _ => None,
+                };
+            }
+            let narrowed = total.map(|v| O::Native::try_from(v).map_err(|_| overflow()));
+            builder.append_option(narrowed.transpose()?);
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
+/// Read element `k` of an integer values column as `i128`.
+fn int_getter<T>(values: &ChunkedArray<T>) -> impl Fn(usize) -> Option<i128> + '_
+where
+    T: PolarsIntegerType,
+    T::Native: Into<i128>,
+{
+    let arr = values.downcast_iter().next().expect("values should be a single chunk");
+    move |k| arr.get(k).map(Into::into)
+}
+
+/// `n`-th order row-to-row difference of each list, computed in one pass as
+/// `sum_m (-1)^m C(n, m) row[i - m]` over the current and `n` previous rows, straight from
+/// the list offsets. The first `n` rows (every row, when there are no more than `n`), and any
+/// row where it or one of the `n` previous rows is null, become lists of nulls. With
+/// `reverse_rows` the rows are taken in reverse order, `row[i + m]` in place of `row[i - m]`,
+/// so the last `n` rows become the lists of nulls instead. Unsigned and 8/16-bit integers
+/// give a wider signed dtype (see [`diff_dtype`]); other integer results must fit their dtype.
 #[polars_expr(output_type_func=list_diff_output_type)]
-fn list_diff(inputs: &[Series]) -> PolarsResult<Series> {
+fn list_diff(inputs: &[Series], kwargs: DiffKwargs) -> PolarsResult<Series> {
+    let order = kwargs.n;
+    if order == 0 {
+        polars_bail!(ComputeError: "diff order n must be at least 1");
+    }
     let series = &inputs[0];
     let input_dtype = series.dtype().clone();
 
-    // Convert to List if it's an Array
-    let series = ensure_list_type(series)?;
+    // Convert to a single-chunk List if it's an Array or chunked
+    let series = ensure_contiguous_list(series)?;
     let list_chunked = series.list()?;
 
     let n_lists = list_chunked.len();
@@ -32,78 +124,109 @@ fn list_diff(inputs: &[Series]) -> PolarsResult<Series> {
         return Ok(series.slice(0, 0));
     }
 
-    // Determine expected length and dtype from first non-null list
-    let mut expected_len = 0;
-    let mut inner_dtype = DataType::Null;
+    let list_arr = single_list_array(list_chunked);
+    let offsets = list_arr.offsets();
+    let validity = list_arr.validity();
+    let row_is_valid = |i: usize| validity.is_none_or(|v| v.get_bit(i));
 
-    for i in 0..n_lists {
-        if let Some(s) = list_chunked.get_as_series(i) {
-            expected_len = s.len();
-            inner_dtype = s.dtype().clone();
-            break;
+    // Determine expected length from first non-null list, validating the rest against it
+    let mut expected_len: Option<usize> = None;
+    for i in (0..n_lists).filter(|&i| row_is_valid(i)) {
+        let len = (offsets[i + 1] - offsets[i]) as usize;
+        match expected_len {
+            None => expected_len = Some(len),
+            Some(w) if w != len => {
+                return Err(length_mismatch_error(list_chunked, "vertical diff", false));
+            },
+            _ => {},
         }
     }
-
-    if inner_dtype == DataType::Null {
+    let inner_dtype = list_chunked.inner_dtype().clone();
+    let Some(width) = expected_len.filter(|_| inner_dtype != DataType::Null) else {
         // All rows are null
-        return Ok(series.clone());
-    }
-
-    // Build result: first row is null, then compute differences
-    let mut diff_chunks = Vec::with_capacity(n_lists);
-
-    // First row is always null (no previous row to compare)
-    // Create a null Series with the correct type and length, then wrap in list
-    let null_series = Series::full_null("".into(), expected_len, &inner_dtype);
-    diff_chunks.push(ListChunked::full(series.name().clone(), &null_series, 1));
-
-    // Calculate differences for remaining rows
-    for i in 1..n_lists {
-        let curr_opt = list_chunked.get_as_series(i);
-        let prev_opt = list_chunked.get_as_series(i - 1);
+        return Ok(inputs[0].clone());
+    };
+    let out_dtype = diff_dtype(&inner_dtype);
 
-        match (prev_opt, curr_opt) {
-            (Some(prev), Some(curr)) => {
-                // Both non-null: validate lengths and compute diff
-                if prev.len() != expected_len || curr.len() != expected_len {
-                    polars_bail!(
-                        ComputeError:
-                        "All lists must have the same length for vertical diff. Expected {}",
-                        expected_len
-                    );
-                }
-                let diff = (&curr - &prev)?;
-                let diff_casted = diff.cast(&inner_dtype)?;
-                let diff_list = ListChunked::full(series.name().clone(), &diff_casted, 1);
-                diff_chunks.push(diff_list);
-            },
-            _ => {
-                // Either current or previous is null: result is null list
-                let null_series = Series::full_null("".into(), expected_len, &inner_dtype);
-                diff_chunks.push(ListChunked::full(series.name().clone(), &null_series, 1));
-            },
-        }
+    // Signed binomial weights (-1)^m C(n, m), m = 0..=n
+    let mut coefficients: Vec<i128> = Vec::with_capacity(order + 1);
+    let mut coefficient: i128 = 1;
+    for m in 0..=order {
+        coefficients.push(if m % 2 == 0 { coefficient } else { -coefficient });
+        // C(n, m + 1) = C(n, m) * (n - m) / (m + 1)
+        let Some(next) = coefficient.checked_mul((order - m) as i128) else {
+            polars_bail!(
+                ComputeError: "diff order {} is too large: its binomial coefficients overflow", order
+            );
+        };
+        coefficient = next / (m + 1) as i128;
     }
 
-    // Concatenate all chunks vertically
-    let result_list = unsafe {
-        ListChunked::from_chunks(
-            series.name().clone(),
-            diff_chunks
-                .iter()
-                .flat_map(|c| c.chunks())
-                .cloned()
-                .collect(),
-        )
+    // The rows `m = 0..=n` back (or ahead, with `reverse_rows`) of row `i`, read in place
+    // through the offsets
+    let windows = |i: usize| -> Option<Vec<usize>> {
+        let rows: Vec<usize> = match kwargs.reverse_rows {
+            true => (i..=i + order).take_while(|&r| r < n_lists).collect(),
+            false => (0..=order).map_while(|m| i.checked_sub(m)).collect(),
+        };
+        (rows.len() == order + 1 && rows.iter().all(|&r| row_is_valid(r)))
+            .then(|| rows.into_iter().map(|r| offsets[r] as usize).collect())
+    };
+    let values = list_chunked.get_inner();
+    let shape = (n_lists, width);
+    macro_rules! integers {
+        ($getter:ident, $out:ty) => {
+            diff_integers::<$out>(
+                int_getter(values.$getter()?),
+                &windows,
+                &coefficients,
+                shape,
+                &out_dtype,
+            )?
+        };
+    }
+    let diff = match &inner_dtype {
+        dt if dt.is_float() => {
+            let values = values.cast(&DataType::Float64)?;
+            let arr = values.f64()?.downcast_iter().next();
+            let arr = arr.expect("values should be a single chunk");
+            let weights: Vec<f64> = coefficients.iter().map(|&c| c as f64).collect();
+            let mut builder =
+                PrimitiveChunkedBuilder::<Float64Type>::new("".into(), n_lists * width);
+            for i in 0..n_lists {
+                let Some(starts) = windows(i) else {
+                    (0..width).for_each(|_| builder.append_null());
+                    continue;
+                };
+                for j in 0..width {
+                    let mut terms = starts.iter().zip(&weights);
+                    let total = terms.try_fold(0.0, |total, (&start, &w)| {
+                        Some(total + w * arr.get(start + j)?)
+                    });
+                    builder.append_option(total);
+                }
+            }
+            builder.finish().into_series().cast(&out_dtype)?
+        },
+        DataType::Int8 => integers!(i8, Int16Type),
+        DataType::UInt8 => integers!(u8, Int16Type),
+        DataType::Int16 => integers!(i16, Int32Type),
+        DataType::UInt16 => integers!(u16, Int32Type),
+        DataType::Int32 => integers!(i32, Int32Type),
+        DataType::UInt32 => integers!(u32, Int64Type),
+        DataType::Int64 => integers!(i64, Int64Type),
+        DataType::UInt64 => integers!(u64, Int64Type),
+        DataType::Int128 => integers!(i128, Int128Type),
+        dt => polars_bail!(InvalidOperation: "vertical diff is not supported for {:?}", dt),
     };
 
-    // Cast back to Array if input was Array
-    let result_series = result_list.into_series();
+    // Every output row has `width` elements, so the flat differences are wrapped in place
+    let name = series.name().clone();
     match &input_dtype {
-        DataType::Array(_, width) => {
-            result_series.cast(&DataType::Array(Box::new(inner_dtype), *width))
+        DataType::Array(..) => array_from_flat_values(name, &diff, width, n_lists, None),
+        _ => {
+            let offsets = (0..=n_lists).map(|i| (i * width) as i64).collect();
+            list_from_flat_values(name, &diff, offsets, None)
         },
-        _ => Ok(result_series),
     }
 }
-
@@ -0,0 +1,91 @@
+#![allow(clippy::unused_unit)]
+use std::collections::HashMap;
+
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::F64Matrix;
+
+#[derive(serde::Deserialize)]
+struct EntropyKwargs {
+    bins: usize,
+    base: Option<f64>,
+}
+
+fn list_entropy_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(Box::new(DataType::Float64)),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Shannon entropy (natural log) of a set of counts.
+fn entropy_of_counts(counts: impl Iterator<Item = usize>, total: usize) -> f64 {
+    let total = total as f64;
+    counts
+        .filter(|&c| c > 0)
+        .fold(0.0, |h, c| {
+            let p = c as f64 / total;
+            h - p * p.ln()
+        })
+}
+
+/// Per-position Shannon entropy of the values across rows.
+/// Integer columns count each distinct value; float columns use `bins` equal-width bins
+/// between the position's min and max. Null and non-finite values are skipped.
+#[polars_expr(output_type_func=list_entropy_output_type)]
+fn list_entropy(inputs: &[Series], kwargs: EntropyKwargs) -> PolarsResult<Series> {
+    if kwargs.bins == 0 {
+        polars_bail!(ComputeError: "bins must be positive");
+    }
+    let log_base = match kwargs.base {
+        Some(base) if base <= 0.0 || base == 1.0 || base.is_nan() => {
+            polars_bail!(ComputeError: "base must be positive and not 1, got {}", base)
+        },
+        Some(base) => base.ln(),
+        None => 1.0,
+    };
+    let series = &inputs[0];
+    let exact = match series.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => inner.is_integer() || inner.is_bool(),
+        _ => false,
+    };
+    let matrix = F64Matrix::from_series(series, "entropy")?;
+
+    let entropy: Vec<Option<f64>> = (0..matrix.width)
+        .map(|j| {
+            let values: Vec<f64> = (0..matrix.n_rows)
+                .filter_map(|i| matrix.get(i, j).filter(|v| v.is_finite()))
+                .collect();
+            if values.is_empty() {
+                return None;
+            }
+            let h = if exact {
+                let mut counts: HashMap<u64, usize> = HashMap::new();
+                for v in &values {
+                    *counts.entry(v.to_bits()).or_default() += 1;
+                }
+                entropy_of_counts(counts.into_values(), values.len())
+            } else {
+                let (lo, hi) = values
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+                let mut counts = vec![0usize; kwargs.bins];
+                let scale = if hi > lo { kwargs.bins as f64 / (hi - lo) } else { 0.0 };
+                for v in &values {
+                    // The maximum falls into the last bin
+                    let k = (((v - lo) * scale) as usize).min(kwargs.bins - 1);
+                    counts[k] += 1;
+                }
+                entropy_of_counts(counts.into_iter(), values.len())
+            };
+            Some(h / log_base)
+        })
+        .collect();
+
+    let entropy = Series::new(series.name().clone(), entropy);
+    Ok(ListChunked::full(series.name().clone(), &entropy, 1).into_series())
+}
@@ -0,0 +1,138 @@
+#![allow(clippy::unused_unit)]
+use std::collections::BTreeMap;
+
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, ensure_list_type, F64Matrix};
+
+/// Inner dtype of the keys column, which must be a List/Array of integers, strings or
+/// categoricals.
+fn key_inner_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    let inner = match dtype {
+        DataType::List(inner) | DataType::Array(inner, _) => inner.as_ref(),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type for keys, got {:?}", dt),
+    };
+    if !(inner.is_integer() || inner.is_string() || inner.is_categorical() || inner.is_enum()) {
+        polars_bail!(
+            InvalidOperation: "Keys must be integer, string or categorical, got {:?}", inner
+        );
+    }
+    Ok(inner.clone())
+}
+
+fn list_group_mean_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    if !matches!(field.dtype(), DataType::List(_) | DataType::Array(_, _)) {
+        polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", field.dtype());
+    }
+    let key_dtype = key_inner_dtype(input_fields[1].dtype())?;
+    let dtype = DataType::Struct(vec![
+        Field::new("keys".into(), DataType::List(Box::new(key_dtype))),
+        Field::new(
+            "mean".into(),
+            DataType::List(Box::new(DataType::List(Box::new(DataType::Float64)))),
+        ),
+    ]);
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Collect each row of a keys list column with `get`, keeping null rows as `None`.
+fn key_rows<K>(
+    keys: &ListChunked,
+    get: impl Fn(&Series) -> PolarsResult<Vec<Option<K>>>,
+) -> PolarsResult<Vec<Option<Vec<Option<K>>>>> {
+    let mut rows = Vec::with_capacity(keys.len());
+    for row in keys.amortized_iter() {
+        rows.push(row.map(|s| get(s.as_ref())).transpose()?);
+    }
+    Ok(rows)
+}
+
+/// Per-position mean of the values sharing each key, ordered by key. A key gets null at
+/// positions where it never occurs (or only alongside null values).
+fn group_means<K: Ord>(
+    matrix: &F64Matrix,
+    keys: Vec<Option<Vec<Option<K>>>>,
+) -> PolarsResult<BTreeMap<K, Vec<Option<f64>>>> {
+    let width = matrix.width;
+    let mut groups: BTreeMap<K, Vec<(f64, u32)>> = BTreeMap::new();
+    for (i, row_keys) in keys.into_iter().enumerate() {
+        let Some(row_keys) = row_keys.filter(|_| matrix.row_valid[i]) else {
+            continue;
+        };
+        if row_keys.len() != width {
+            polars_bail!(
+                ComputeError:
+                "Keys must match the values' length for group_mean. Row {}: {} values, {} keys",
+                i, width, row_keys.len()
+            );
+        }
+        for (j, key) in row_keys.into_iter().enumerate() {
+            let (Some(key), Some(v)) = (key, matrix.get(i, j)) else {
+                continue;
+            };
+            let (sum, count) = &mut groups.entry(key).or_insert_with(|| vec![(0.0, 0); width])[j];
+            *sum += v;
+            *count += 1;
+        }
+    }
+    Ok(groups
+        .into_iter()
+        .map(|(key, sums)| {
+            let means = sums
+                .into_iter()
+                .map(|(sum, count)| (count > 0).then(|| sum / count as f64))
+                .collect();
+            (key, means)
+        })
+        .collect())
+}
+
+/// Vertical mean computed separately for each key at every position, for designs where
+/// conditions are interleaved element-wise (e.g. a per-sample condition label).
+/// inputs[0] = values (List/Array)
+/// inputs[1] = keys (List/Array of integers, strings or categoricals, same shape as values)
+/// Returns a single-row struct of the sorted distinct `keys` and a `mean` list holding one
+/// Float64 trace per key. Null values, null keys and null rows (in either input) are skipped.
+#[polars_expr(output_type_func=list_group_mean_output_type)]
+fn list_group_mean(inputs: &[Series]) -> PolarsResult<Series> {
+    let values = &inputs[0];
+    let matrix = F64Matrix::from_series(values, "group_mean")?;
+    let key_dtype = key_inner_dtype(inputs[1].dtype())?;
+    if inputs[1].len() != matrix.n_rows {
+        polars_bail!(
+            ComputeError:
+            "Values and keys must have the same number of rows for group_mean. Expected {}, got {}",
+            matrix.n_rows, inputs[1].len()
+        );
+    }
+    let keys = ensure_list_type(&inputs[1])?;
+
+    let (key_values, means) = if key_dtype.is_integer() {
+        let keys = keys.cast(&DataType::List(Box::new(DataType::Int64)))?;
+        let rows = key_rows(keys.list()?, |s| Ok(s.i64()?.iter().collect()))?;
+        let (key_values, means): (Vec<_>, Vec<_>) = group_means(&matrix, rows)?.into_iter().unzip();
+        (Series::new("keys".into(), key_values), means)
+    } else {
+        let keys = keys.cast(&DataType::List(Box::new(DataType::String)))?;
+        let rows = key_rows(keys.list()?, |s| {
+            Ok(s.str()?.iter().map(|k| k.map(str::to_string)).collect())
+        })?;
+        let (key_values, means): (Vec<_>, Vec<_>) = group_means(&matrix, rows)?.into_iter().unzip();
+        (Series::new("keys".into(), key_values), means)
+    };
+
+    let n_keys = means.len();
+    let key_values = key_values.cast(&key_dtype)?;
+    let means = build_f64_list(
+        "mean".into(),
+        n_keys,
+        means.iter().map(|m| Some(m.as_slice())),
+        &DataType::Null,
+    )?;
+    let fields = [
+        ListChunked::full("keys".into(), &key_values, 1).into_series(),
+        ListChunked::full("mean".into(), &means, 1).into_series(),
+    ];
+    Ok(StructChunked::from_series(values.name().clone(), 1, fields.iter())?.into_series())
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::F64Matrix;
+
+/// Which intraclass correlation `list_icc` computes.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum IccKind {
+    Icc1,
+    Icc2,
+    Icc3,
+    Alpha,
+}
+
+#[derive(serde::Deserialize)]
+struct IccKwargs {
+    kind: IccKind,
+}
+
+fn list_icc_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {
+            Ok(Field::new(field.name().clone(), DataType::Float64))
+        },
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Intraclass correlation treating each row as a repeated measurement (rater) of the
+/// per-position targets, from the two-way ANOVA mean squares (Shrout & Fleiss):
+/// `icc1` one-way random, `icc2` two-way random absolute agreement, `icc3` two-way mixed
+/// consistency, all single-measure; `alpha` is Cronbach's alpha (equal to ICC(3,k)).
+/// Null rows are skipped and only positions finite in every remaining row are used. The
+/// result is null with fewer than two rows or positions, or a zero denominator.
+#[polars_expr(output_type_func=list_icc_output_type)]
+fn list_icc(inputs: &[Series], kwargs: IccKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "icc")?;
+    let rows: Vec<usize> = (0..matrix.n_rows).filter(|&i| matrix.row_valid[i]).collect();
+    let targets: Vec<usize> = (0..matrix.width)
+        .filter(|&j| rows.iter().all(|&i| matrix.get(i, j).is_some_and(f64::is_finite)))
+        .collect();
+
+    let (k, n) = (rows.len() as f64, targets.len() as f64);
+    let icc = (k >= 2.0 && n >= 2.0).then(|| {
+        let x = |i: usize, j: usize| matrix.get(i, j).unwrap_or_default();
+        let grand = rows.iter().flat_map(|&i| targets.iter().map(move |&j| x(i, j))).sum::<f64>()
+            / (k * n);
+        let ss_targets: f64 = targets
+            .iter()
+            .map(|&j| (rows.iter().map(|&i| x(i, j)).sum::<f64>() / k - grand).powi(2))
+            .sum::<f64>()
+            * k;
+        let ss_raters: f64 = rows
+            .iter()
+            .map(|&i| (targets.iter().map(|&j| x(i, j)).sum::<f64>() / n - grand).powi(2))
+            .sum::<f64>()
+            * n;
+        let ss_total: f64 = rows
+            .iter()
+            .flat_map(|&i| targets.iter().map(move |&j| (x(i, j) - grand).powi(2)))
+            .sum();
+        let ss_error = ss_total - ss_targets - ss_raters;
+
+        let ms_targets = ss_targets / (n - 1.0);
+        let ms_raters = ss_raters / (k - 1.0);
+        let ms_error = ss_error / ((n - 1.0) * (k - 1.0));
+        let ms_within = (ss_raters + ss_error) / (n * (k - 1.0));
+        let (num, den) = match kwargs.kind {
+            IccKind::Icc1 => (ms_targets - ms_within, ms_targets + (k - 1.0) * ms_within),
+            IccKind::Icc2 => (
+                ms_targets - ms_error,
+                ms_targets + (k - 1.0) * ms_error + k * (ms_raters - ms_error) / n,
+            ),
+            IccKind::Icc3 => (ms_targets - ms_error, ms_targets + (k - 1.0) * ms_error),
+            IccKind::Alpha => (ms_targets - ms_error, ms_targets),
+        };
+        num / den
+    });
+
+    let icc = icc.filter(|v| v.is_finite());
+    Ok(Float64Chunked::from_slice_options(series.name().clone(), &[icc]).into_series())
+}
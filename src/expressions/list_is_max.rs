@@ -0,0 +1,94 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use std::cmp::Ordering;
+use polars::polars_utils::float::IsFloat;
+use polars::polars_utils::total_ord::TotalOrd;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    ensure_contiguous_list, list_from_flat_values, list_into_array, physical_numeric_values,
+    single_list_array, vertical_row_starts, with_numeric_type,
+};
+
+fn bool_list_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(Box::new(DataType::Boolean)),
+        )),
+        DataType::Array(_, width) => Ok(Field::new(
+            field.name().clone(),
+            DataType::Array(Box::new(DataType::Boolean), *width),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Mark the elements of the rows starting at `starts` that equal their position's vertical
+/// extreme, comparing in the values' own dtype. The extreme is the value that compares as
+/// `wanted` against every other one, so `Greater` finds the maximum. NaN elements are never
+/// extreme and null elements stay null. The mask is aligned with the flat `values`.
+fn extreme_mask<T: PolarsNumericType>(
+    values: &ChunkedArray<T>,
+    starts: &[Option<usize>],
+    width: usize,
+    wanted: Ordering,
+) -> BooleanChunked {
+    let arr = values.downcast_iter().next().expect("values should be a single chunk");
+    let get = |start: &Option<usize>, j: usize| start.and_then(|start| arr.get(start + j));
+    let extremes: Vec<Option<T::Native>> = (0..width)
+        .map(|j| {
+            starts
+                .iter()
+                .filter_map(|start| get(start, j).filter(|v| !v.is_nan()))
+                .fold(None, |acc, v| match acc {
+                    Some(e) if v.tot_cmp(&e) != wanted => Some(e),
+                    _ => Some(v),
+                })
+        })
+        .collect();
+
+    let mut mask: Vec<Option<bool>> = vec![None; values.len()];
+    for start in starts.iter().flatten() {
+        for (j, extreme) in extremes.iter().enumerate() {
+            mask[start + j] = arr
+                .get(start + j)
+                .map(|v| extreme.is_some_and(|e| v.tot_cmp(&e) == Ordering::Equal));
+        }
+    }
+    BooleanChunked::from_iter(mask)
+}
+
+/// [`extreme_mask`] of a List or Array column, shaped like the input.
+fn extreme_mask_series(series: &Series, op_name: &str, wanted: Ordering) -> PolarsResult<Series> {
+    let list_series = ensure_contiguous_list(series)?;
+    let list_chunked = list_series.list()?;
+    let (starts, width) = vertical_row_starts(list_chunked, op_name)?;
+    let physical = physical_numeric_values(list_chunked)?;
+    let mask = with_numeric_type!(physical.dtype(), T => {
+        extreme_mask(physical.unpack::<T>()?, &starts, width, wanted)
+    });
+    let arr = single_list_array(list_chunked);
+    let result_series = list_from_flat_values(
+        series.name().clone(),
+        &mask.into_series(),
+        arr.offsets().as_slice().to_vec(),
+        arr.validity().cloned(),
+    )?;
+    match series.dtype() {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
+
+/// Whether each element equals the vertical maximum at its position.
+#[polars_expr(output_type_func=bool_list_output_type)]
+fn list_is_max(inputs: &[Series]) -> PolarsResult<Series> {
+    extreme_mask_series(&inputs[0], "is_max", Ordering::Greater)
+}
+
+/// Whether each element equals the vertical minimum at its position.
+#[polars_expr(output_type_func=bool_list_output_type)]
+fn list_is_min(inputs: &[Series]) -> PolarsResult<Series> {
+    extreme_mask_series(&inputs[0], "is_min", Ordering::Less)
+}
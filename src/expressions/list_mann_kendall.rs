@@ -0,0 +1,84 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::distributions::normal_sf;
+use super::helpers::{single_row_struct, F64Matrix};
+
+fn list_mann_kendall_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let list_f64 = DataType::List(Box::new(DataType::Float64));
+    Ok(Field::new(
+        field.name().clone(),
+        DataType::Struct(vec![
+            Field::new("statistic".into(), list_f64.clone()),
+            Field::new("tau".into(), list_f64.clone()),
+            Field::new("p_value".into(), list_f64),
+        ]),
+    ))
+}
+
+/// Mann-Kendall `S`, Kendall's tau-a and the two-sided p-value from the tie-corrected
+/// normal approximation with continuity correction.
+fn mann_kendall(x: &[f64]) -> Option<(f64, f64, f64)> {
+    let n = x.len();
+    if n < 2 {
+        return None;
+    }
+    let mut s = 0.0;
+    for i in 0..n {
+        for j in i + 1..n {
+            if x[j] > x[i] {
+                s += 1.0;
+            } else if x[j] < x[i] {
+                s -= 1.0;
+            }
+        }
+    }
+
+    let mut sorted = x.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mut tie_term = 0.0;
+    for group in sorted.chunk_by(|a, b| a == b) {
+        let t = group.len() as f64;
+        tie_term += t * (t - 1.0) * (2.0 * t + 5.0);
+    }
+    let nf = n as f64;
+    let var = (nf * (nf - 1.0) * (2.0 * nf + 5.0) - tie_term) / 18.0;
+    let z = if s > 0.0 {
+        (s - 1.0) / var.sqrt()
+    } else if s < 0.0 {
+        (s + 1.0) / var.sqrt()
+    } else {
+        0.0
+    };
+    let p = if var > 0.0 { (2.0 * normal_sf(z.abs())).min(1.0) } else { f64::NAN };
+    Some((s, s / (nf * (nf - 1.0) / 2.0), p))
+}
+
+/// Per-position Mann-Kendall trend test of value against row order.
+/// Null rows and null or non-finite values are skipped; positions with fewer than two
+/// values are null.
+#[polars_expr(output_type_func=list_mann_kendall_output_type)]
+fn list_mann_kendall(inputs: &[Series]) -> PolarsResult<Series> {
+    let matrix = F64Matrix::from_series(&inputs[0], "mann_kendall")?;
+    let results: Vec<Option<(f64, f64, f64)>> = (0..matrix.width)
+        .map(|j| {
+            let x: Vec<f64> = (0..matrix.n_rows)
+                .filter_map(|i| matrix.get(i, j).filter(|v| v.is_finite()))
+                .collect();
+            mann_kendall(&x)
+        })
+        .collect();
+    single_row_struct(
+        inputs[0].name().clone(),
+        vec![
+            ("statistic", results.iter().map(|r| r.map(|r| r.0)).collect()),
+            ("tau", results.iter().map(|r| r.map(|r| r.1)).collect()),
+            ("p_value", results.iter().map(|r| r.map(|r| r.2)).collect()),
+        ],
+    )
+}
@@ -1,105 +1,68 @@
 #![allow(clippy::unused_unit)]
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
-use super::helpers::ensure_list_type;
+use crate::kwargs::{NanPolicy, ReduceKwargs};
+use crate::reduce::{
+    reduce_columns, reduce_columns_output_type, reduce_output_type, vertical_reduce, Accumulator,
+};
 
-fn list_max_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
-    let field = &input_fields[0];
-    match field.dtype() {
-        DataType::List(inner) => Ok(Field::new(
-            field.name().clone(),
-            DataType::List(inner.clone()),
-        )),
-        DataType::Array(inner, width) => Ok(Field::new(
-            field.name().clone(),
-            DataType::Array(inner.clone(), *width),
-        )),
-        _ => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", field.dtype()),
-    }
-}
+/// Running per-position maximum, ignoring nulls.
+struct Max(Series);
 
-#[polars_expr(output_type_func=list_max_output_type)]
-fn list_max(inputs: &[Series]) -> PolarsResult<Series> {
-    let series = &inputs[0];
-    let input_dtype = series.dtype().clone();
-
-    // Convert to List if it's an Array
-    let series = ensure_list_type(series)?;
-    let list_chunked = series.list()?;
+impl Accumulator for Max {
+    const NAME: &'static str = "max";
 
-    let n_lists = list_chunked.len();
-    if n_lists == 0 {
-        return Ok(series.slice(0, 0));
+    // Preserve the input type
+    fn output_dtype(inner: &DataType) -> DataType {
+        inner.clone()
     }
 
-    // Find first non-null list to determine length and type
-    let mut expected_len = 0;
-    let mut inner_dtype = DataType::Null;
-    let mut found_valid = false;
-
-    for i in 0..n_lists {
-        if let Some(s) = list_chunked.get_as_series(i) {
-            expected_len = s.len();
-            inner_dtype = s.dtype().clone();
-            found_valid = true;
-            break;
-        }
+    fn init(first: &Series) -> PolarsResult<Self> {
+        Ok(Max(first.clone()))
     }
 
-    if !found_valid {
-        // All rows are null
-        return Ok(ListChunked::full_null(series.name().clone(), n_lists).into_series());
+    fn update(&mut self, row: &Series) -> PolarsResult<()> {
+        self.0 = fold_max(&self.0, row)?;
+        Ok(())
     }
 
-    // Collect all non-null series references and validate
-    let mut all_series = Vec::new();
-
-    for i in 0..n_lists {
-        if let Some(s) = list_chunked.get_as_series(i) {
-            if s.len() != expected_len {
-                polars_bail!(
-                    ComputeError:
-                    "All lists must have the same length for vertical max. Expected {}, got {}",
-                    expected_len, s.len()
-                );
-            }
-            all_series.push(s);
-        }
-        // Skip null rows
-    }
-
-    if all_series.is_empty() {
-        return Ok(ListChunked::full_null(series.name().clone(), 1).into_series());
+    fn finish(self) -> PolarsResult<Series> {
+        Ok(self.0)
     }
+}
 
-    // Calculate element-wise maximum, ignoring nulls
-    // For max with null handling: if result is null, take s; if s is null, keep result; otherwise take maximum
-    let mut result = all_series[0].clone();
-    for s in all_series.iter().skip(1) {
-        let result_is_null = result.is_null();
-        let both_not_null = result.is_not_null() & s.is_not_null();
-
-        // Where both are not null, compare and take maximum
-        let comparison_mask = result.lt(s)? & both_not_null;
-        let take_s = &comparison_mask | &result_is_null;
-        let take_s_not_s_null = take_s & s.is_not_null();
+/// Per-position maximum of a running `result` and a `row`, ignoring nulls in either.
+pub(super) fn fold_max(result: &Series, row: &Series) -> PolarsResult<Series> {
+    // If the result is null, take the row; if the row is null, keep the result;
+    // otherwise take the maximum
+    let both_not_null = result.is_not_null() & row.is_not_null();
+    let take_row = (result.lt(row)? & both_not_null) | result.is_null();
+    row.zip_with(&(take_row & row.is_not_null()), result)
+}
 
-        result = s.zip_with(&take_s_not_s_null, &result)?;
-    }
+fn list_max_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    reduce_output_type::<Max>(input_fields)
+}
 
-    // Cast back to original inner dtype to preserve type
-    result = result.cast(&inner_dtype)?;
+#[polars_expr(output_type_func=list_max_output_type)]
+fn list_max(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    vertical_reduce::<Max>(&inputs[0], kwargs)
+}
 
-    // Wrap in a single-row list
-    let result_list = ListChunked::full(series.name().clone(), &result, 1);
+/// `list_max` skipping NaN elements, like `numpy.nanmax`.
+#[polars_expr(output_type_func=list_max_output_type)]
+fn list_nanmax(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    let kwargs = ReduceKwargs { nan_policy: NanPolicy::Ignore, ..kwargs };
+    vertical_reduce::<Max>(&inputs[0], kwargs)
+}
 
-    // Cast back to Array if input was Array
-    let result_series = result_list.into_series();
-    match &input_dtype {
-        DataType::Array(_, width) => {
-            result_series.cast(&DataType::Array(Box::new(inner_dtype), *width))
-        },
-        _ => Ok(result_series),
-    }
+fn list_max_columns_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    reduce_columns_output_type(input_fields, reduce_output_type::<Max>)
 }
 
+/// `list_max` over every input column in one call, returning a struct with a field per
+/// column.
+#[polars_expr(output_type_func=list_max_columns_output_type)]
+fn list_max_columns(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    reduce_columns(inputs, |s| vertical_reduce::<Max>(s, kwargs))
+}
@@ -1,113 +1,198 @@
 #![allow(clippy::unused_unit)]
 use polars::prelude::*;
+use polars_arrow::array::{Array, PrimitiveArray};
 use pyo3_polars::derive::polars_expr;
-use super::helpers::ensure_list_type;
-
-fn list_mean_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
-    let field = &input_fields[0];
-    match field.dtype() {
-        DataType::List(_) => {
-            // Mean always returns Float64
-            let float_inner = Box::new(DataType::Float64);
-            Ok(Field::new(
-                field.name().clone(),
-                DataType::List(float_inner),
-            ))
-        },
-        DataType::Array(_, width) => {
-            // Mean always returns Float64
-            let float_inner = Box::new(DataType::Float64);
-            Ok(Field::new(
-                field.name().clone(),
-                DataType::Array(float_inner, *width),
-            ))
-        },
-        _ => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", field.dtype()),
-    }
-}
-
-#[polars_expr(output_type_func=list_mean_output_type)]
-fn list_mean(inputs: &[Series]) -> PolarsResult<Series> {
-    let series = &inputs[0];
+use super::helpers::{
+    ensure_contiguous_list, ensure_list_type, float_list_output_type, length_mismatch_error_from,
+    list_into_array, single_list_array,
+};
+use crate::kwargs::{EmptyPolicy, NanPolicy, NullPolicy, ReduceKwargs, Validation};
+use crate::reduce::{
+    empty_row_error, reduce_columns, reduce_columns_output_type, POSITION_BLOCK,
+};
+
+/// Vertical mean computed directly on the Arrow buffers: the offsets are walked once per
+/// block of positions, accumulating per-position sums and counts in plain vectors, rather
+/// than adding up one row `Series` at a time. Follows the same contract as
+/// `vertical_reduce` (null rows, `empty_policy`, length validation, `ReduceKwargs`
+/// masking and row slice, Array in gives Array out).
+pub(crate) fn vertical_mean(series: &Series, kwargs: ReduceKwargs) -> PolarsResult<Series> {
     let input_dtype = series.dtype().clone();
-
-    // Convert to List if it's an Array
-    let series = ensure_list_type(series)?;
-    let list_chunked = series.list()?;
-
-    let n_lists = list_chunked.len();
+    let n_lists = series.len();
     if n_lists == 0 {
-        return Ok(series.slice(0, 0));
+        return Ok(ensure_list_type(series)?.slice(0, 0));
     }
-
-    // Find first non-null list to determine length
-    let mut expected_len = 0;
-    let mut found_valid = false;
-
-    for i in 0..n_lists {
-        if let Some(s) = list_chunked.get_as_series(i) {
-            expected_len = s.len();
-            found_valid = true;
-            break;
-        }
+    let out_rows = if kwargs.keep_rows { n_lists } else { 1 };
+    // Only the rows selected by offset/length/every are made contiguous and reduced; the
+    // output height still follows the whole column
+    let (row_number, selected) = kwargs.select_rows(series)?;
+    let n_rows = selected.len();
+    let series = ensure_contiguous_list(&selected)?
+        .cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = series.list()?;
+    let arr = single_list_array(list_chunked);
+    let offsets = arr.offsets().as_slice();
+    let len = |i: usize| (offsets[i + 1] - offsets[i]) as usize;
+
+    // The reference length comes from the first non-empty row, so a leading empty list
+    // does not decide the shape of the result.
+    let valid_rows = || (0..n_rows).filter(|&i| arr.is_valid(i));
+    let Some(first) = valid_rows().next() else {
+        // All rows are null: typed null rows, so the output matches the declared dtype and
+        // has the same height as a non-null result
+        let nulls =
+            ListChunked::full_null_with_dtype(series.name().clone(), out_rows, &DataType::Float64);
+        return match &input_dtype {
+            DataType::Array(_, width) => list_into_array(&nulls.into_series(), *width),
+            _ => Ok(nulls.into_series()),
+        };
+    };
+    let width = valid_rows().map(len).find(|&n| n > 0).unwrap_or(len(first));
+    let mismatch_error = || {
+        let skip_empty = kwargs.empty_policy != EmptyPolicy::Error;
+        length_mismatch_error_from(list_chunked, "vertical mean", skip_empty, row_number)
+    };
+    // Array input is uniform by construction, and `Validation::Fast` skips this pass. The
+    // row loops below still check each row's length before slicing it, so a mismatch then
+    // errors rather than reading another row's values.
+    let check =
+        kwargs.validate == Validation::Strict && !matches!(input_dtype, DataType::Array(..));
+    if check && valid_rows().any(|i| len(i) > 0 && len(i) != width) {
+        return Err(mismatch_error());
     }
-
-    if !found_valid {
-        // All rows are null
-        return Ok(ListChunked::full_null(series.name().clone(), n_lists).into_series());
+    if kwargs.empty_policy == EmptyPolicy::Error && width > 0 {
+        if let Some(i) = valid_rows().find(|&i| len(i) == 0) {
+            return Err(empty_row_error(row_number(i), "mean", width));
+        }
     }
 
-    // Collect all non-null series references and validate
-    let mut all_series = Vec::new();
-
-    for i in 0..n_lists {
-        if let Some(s) = list_chunked.get_as_series(i) {
-            if s.len() != expected_len {
-                polars_bail!(
-                    ComputeError:
-                    "All lists must have the same length for vertical mean. Expected {}, got {}",
-                    expected_len, s.len()
-                );
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .expect("List(Float64) values should be a PrimitiveArray<f64>");
+    let data = values.values().as_slice();
+    let dense = arr.null_count() == 0 && values.null_count() == 0;
+    let validity = values.validity().filter(|b| b.unset_bits() > 0);
+    let n_empty = valid_rows().filter(|&i| len(i) == 0).count();
+    let n_filled = valid_rows().count() - n_empty;
+    // Empty rows count as a row of nulls with `EmptyPolicy::TreatAsNull`
+    let empty_missing = match kwargs.empty_policy {
+        EmptyPolicy::TreatAsNull => n_empty,
+        _ => 0,
+    };
+
+    // Per-position state for one block of positions at a time, reused across blocks
+    let block_len = width.min(POSITION_BLOCK);
+    let mut sums = vec![0.0f64; block_len];
+    let mut counts = vec![0usize; block_len];
+    // Null elements (and NaN with `NanPolicy::AsNull`) per position, for `null_policy`
+    let mut missing = vec![0usize; block_len];
+    let mut result: Vec<Option<f64>> = Vec::with_capacity(width);
+    for block_start in (0..width).step_by(POSITION_BLOCK) {
+        let block = block_start..(block_start + POSITION_BLOCK).min(width);
+        let n = block.len();
+        sums[..n].fill(0.0);
+        counts[..n].fill(0);
+        missing[..n].fill(empty_missing);
+
+        if dense && kwargs.nan_policy == NanPolicy::Propagate {
+            // Dense column: no validity to consult and no NaN to look for, so each row is
+            // a plain slice added onto the sums and every position counts the same rows.
+            for i in (0..n_rows).filter(|&i| len(i) > 0) {
+                if len(i) != width {
+                    return Err(mismatch_error());
+                }
+                let start = offsets[i] as usize;
+                let row = &data[start + block.start..start + block.end];
+                for (sum, &v) in sums.iter_mut().zip(row) {
+                    *sum += v;
+                }
+            }
+            counts[..n].fill(n_filled);
+        } else {
+            // Branch-free per element, so the loop costs about the same whatever the share
+            // of nulls and NaN; a null slot's value is never added, even if it holds NaN
+            let skip_nan = kwargs.nan_policy != NanPolicy::Propagate;
+            let nan_missing = kwargs.nan_policy == NanPolicy::AsNull;
+            for i in valid_rows().filter(|&i| len(i) > 0) {
+                if len(i) != width {
+                    return Err(mismatch_error());
+                }
+                let start = offsets[i] as usize + block.start;
+                let row = &data[start..start + n];
+                let (sums, counts, missing) =
+                    (&mut sums[..n], &mut counts[..n], &mut missing[..n]);
+                // One 64-position lane of the row; bit `k` of `mask` says whether its
+                // element `k` is non-null
+                let mut add_lane = |lane: std::ops::Range<usize>, mask: u64| {
+                    let lanes = row[lane.clone()]
+                        .iter()
+                        .zip(&mut sums[lane.clone()])
+                        .zip(&mut counts[lane.clone()])
+                        .zip(&mut missing[lane]);
+                    for (k, (((&v, sum), count), missed)) in lanes.enumerate() {
+                        let valid = (mask >> k) & 1 == 1;
+                        let nan = v.is_nan();
+                        let counted = valid && !(skip_nan && nan);
+                        *sum += if counted { v } else { 0.0 };
+                        *count += counted as usize;
+                        *missed += (!valid || (nan_missing && nan)) as usize;
+                    }
+                };
+                let lanes = (0..n).step_by(64).map(|c| c..(c + 64).min(n));
+                match validity {
+                    Some(bits) => {
+                        // Read the validity 64 bits at a time rather than bit by bit
+                        let bits = bits.clone().sliced(start, n);
+                        let chunks = bits.chunks::<u64>();
+                        let remainder = chunks.remainder();
+                        for (lane, mask) in lanes.zip(chunks.chain(std::iter::once(remainder))) {
+                            add_lane(lane, mask);
+                        }
+                    },
+                    None => lanes.for_each(|lane| add_lane(lane, u64::MAX)),
+                }
             }
-            all_series.push(s);
         }
-        // Skip null rows
-    }
 
-    if all_series.is_empty() {
-        return Ok(ListChunked::full_null(series.name().clone(), 1).into_series());
+        result.extend((0..n).map(|j| {
+            let keep = counts[j] >= kwargs.min_count
+                && (kwargs.null_policy == NullPolicy::Ignore || missing[j] == 0);
+            keep.then(|| sums[j] / counts[j] as f64)
+        }));
     }
+    let result: Float64Chunked = result.into_iter().collect();
 
-    // Sum all series (nulls treated as 0), then divide by count of non-nulls per position
-    let mut sum_result = all_series[0]
-        .cast(&DataType::Float64)?
-        .fill_null(FillNullStrategy::Zero)?;
-    let mut count_result = all_series[0].is_not_null().cast(&DataType::UInt32)?;
-
-    for s in all_series.iter().skip(1) {
-        let s_float = s
-            .cast(&DataType::Float64)?
-            .fill_null(FillNullStrategy::Zero)?;
-        sum_result = (&sum_result + &s_float)?;
-
-        let s_not_null = s.is_not_null().cast(&DataType::UInt32)?;
-        count_result = (&count_result + &s_not_null)?;
+    // Wrap in a single-row list (or one per input row with keep_rows), cast back to Array
+    // if input was Array
+    let result_series =
+        ListChunked::full(series.name().clone(), &result.into_series(), out_rows).into_series();
+    match &input_dtype {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
     }
+}
 
-    // Divide sum by count to get mean (handle division by zero)
-    let count_float = count_result.cast(&DataType::Float64)?;
-    let result = sum_result.divide(&count_float)?;
+#[polars_expr(output_type_func=float_list_output_type)]
+fn list_mean(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    vertical_mean(&inputs[0], kwargs)
+}
 
-    // Wrap in a single-row list
-    let result_list = ListChunked::full(series.name().clone(), &result, 1);
+/// `list_mean` skipping NaN elements, like `numpy.nanmean`.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn list_nanmean(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    let kwargs = ReduceKwargs { nan_policy: NanPolicy::Ignore, ..kwargs };
+    vertical_mean(&inputs[0], kwargs)
+}
 
-    // Cast back to Array if input was Array
-    let result_series = result_list.into_series();
-    match &input_dtype {
-        DataType::Array(_, width) => {
-            result_series.cast(&DataType::Array(Box::new(DataType::Float64), *width))
-        },
-        _ => Ok(result_series),
-    }
+fn list_mean_columns_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    reduce_columns_output_type(input_fields, float_list_output_type)
 }
 
+/// `list_mean` over every input column in one call, returning a struct with a field per
+/// column.
+#[polars_expr(output_type_func=list_mean_columns_output_type)]
+fn list_mean_columns(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    reduce_columns(inputs, |s| vertical_mean(s, kwargs))
+}
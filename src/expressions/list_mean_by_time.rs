@@ -0,0 +1,156 @@
+#![allow(clippy::unused_unit)]
+use std::collections::BTreeMap;
+
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, F64Matrix};
+
+#[derive(serde::Deserialize)]
+struct MeanByTimeKwargs {
+    every: String,
+}
+
+fn list_mean_by_time_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    if !matches!(field.dtype(), DataType::List(_) | DataType::Array(_, _)) {
+        polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", field.dtype());
+    }
+    let time_dtype = match input_fields[1].dtype() {
+        dt @ DataType::Datetime(_, _) => dt.clone(),
+        dt => polars_bail!(InvalidOperation: "Expected Datetime timestamps, got {:?}", dt),
+    };
+    let dtype = DataType::Struct(vec![
+        Field::new("time".into(), time_dtype),
+        Field::new("mean".into(), DataType::List(Box::new(DataType::Float64))),
+    ]);
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Parse a fixed-length duration string such as `"500ms"`, `"1h30m"` or `"2d"` into
+/// nanoseconds. Calendar units (months, quarters, years) vary in length and are rejected.
+fn parse_every_ns(every: &str) -> PolarsResult<i64> {
+    let mut total: i64 = 0;
+    let mut rest = every.trim();
+    if rest.is_empty() {
+        polars_bail!(InvalidOperation: "every must not be empty");
+    }
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let unit_len = rest[digits..]
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len() - digits);
+        let (number, unit) = (&rest[..digits], &rest[digits..digits + unit_len]);
+        let Ok(n) = number.parse::<i64>() else {
+            polars_bail!(InvalidOperation: "Invalid duration '{}' for every", every);
+        };
+        let unit_ns: i64 = match unit {
+            "ns" => 1,
+            "us" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60 * 1_000_000_000,
+            "h" => 3_600 * 1_000_000_000,
+            "d" => 86_400 * 1_000_000_000,
+            "w" => 7 * 86_400 * 1_000_000_000,
+            "mo" | "q" | "y" => polars_bail!(
+                InvalidOperation:
+                "Calendar duration '{}' is not supported; use ns, us, ms, s, m, h, d or w",
+                every
+            ),
+            _ => polars_bail!(
+                InvalidOperation:
+                "Invalid duration unit '{}' in '{}'. Must be one of: ns, us, ms, s, m, h, d, w",
+                unit, every
+            ),
+        };
+        total = n
+            .checked_mul(unit_ns)
+            .and_then(|v| total.checked_add(v))
+            .ok_or_else(|| polars_err!(InvalidOperation: "Duration '{}' is too long", every))?;
+        rest = &rest[digits + unit_len..];
+    }
+    if total <= 0 {
+        polars_bail!(InvalidOperation: "every must be a positive duration, got '{}'", every);
+    }
+    Ok(total)
+}
+
+/// Vertical mean over the rows falling in each time bin, one output row per non-empty bin.
+/// inputs[0] = values (List/Array)
+/// inputs[1] = timestamps (Datetime, one per row)
+/// Bins are `every` wide and aligned to the Unix epoch; each output row is a struct of the
+/// bin start `time` and the per-position `mean` of its rows, in time order. Rows with a null
+/// timestamp are dropped, null elements are skipped, and a bin whose rows are all null
+/// gives a null mean.
+#[polars_expr(output_type_func=list_mean_by_time_output_type)]
+fn list_mean_by_time(inputs: &[Series], kwargs: MeanByTimeKwargs) -> PolarsResult<Series> {
+    let values = &inputs[0];
+    let (time_dtype, time_unit) = match inputs[1].dtype() {
+        dt @ DataType::Datetime(time_unit, _) => (dt.clone(), *time_unit),
+        dt => polars_bail!(InvalidOperation: "Expected Datetime timestamps, got {:?}", dt),
+    };
+    let unit_ns = match time_unit {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    };
+    let every_ns = parse_every_ns(&kwargs.every)?;
+    if every_ns % unit_ns != 0 {
+        polars_bail!(
+            InvalidOperation:
+            "every '{}' is not a whole number of the timestamps' unit ({})",
+            kwargs.every, time_unit
+        );
+    }
+    let every = every_ns / unit_ns;
+
+    let matrix = F64Matrix::from_series(values, "mean_by_time")?;
+    let width = matrix.width;
+    if inputs[1].len() != matrix.n_rows {
+        polars_bail!(
+            ComputeError:
+            "Values and timestamps must have the same number of rows. Expected {}, got {}",
+            matrix.n_rows, inputs[1].len()
+        );
+    }
+    let timestamps = inputs[1].to_physical_repr();
+
+    // Bin start -> (any non-null row, per-position (sum, count))
+    let mut bins: BTreeMap<i64, (bool, Vec<(f64, u32)>)> = BTreeMap::new();
+    for (i, t) in timestamps.i64()?.iter().enumerate() {
+        let Some(t) = t else {
+            continue;
+        };
+        let (valid, sums) = bins
+            .entry(t.div_euclid(every) * every)
+            .or_insert_with(|| (false, vec![(0.0, 0); width]));
+        *valid |= matrix.row_valid[i];
+        for (j, (sum, count)) in sums.iter_mut().enumerate() {
+            if let Some(v) = matrix.get(i, j) {
+                *sum += v;
+                *count += 1;
+            }
+        }
+    }
+
+    let n_bins = bins.len();
+    let mut starts: Vec<i64> = Vec::with_capacity(n_bins);
+    let mut means: Vec<Option<Vec<Option<f64>>>> = Vec::with_capacity(n_bins);
+    for (start, (valid, sums)) in bins {
+        starts.push(start);
+        means.push(valid.then(|| {
+            sums.iter().map(|&(sum, count)| (count > 0).then(|| sum / count as f64)).collect()
+        }));
+    }
+    let time = Int64Chunked::from_vec("time".into(), starts)
+        .into_series()
+        .cast(&time_dtype)?;
+    let mean = build_f64_list(
+        "mean".into(),
+        n_bins,
+        means.iter().map(|m| m.as_deref()),
+        &DataType::Null,
+    )?;
+    Ok(StructChunked::from_series(values.name().clone(), n_bins, [time, mean].iter())?
+        .into_series())
+}
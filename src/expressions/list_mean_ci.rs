@@ -0,0 +1,79 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use rand::Rng;
+use super::helpers::{single_row_struct, F64Matrix};
+use super::resampling::{column_mean, seeded_rng, sorted_quantile};
+
+#[derive(serde::Deserialize)]
+struct MeanCiKwargs {
+    n_boot: usize,
+    ci: f64,
+    seed: Option<u64>,
+}
+
+fn list_mean_ci_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let list_f64 = DataType::List(Box::new(DataType::Float64));
+    Ok(Field::new(
+        field.name().clone(),
+        DataType::Struct(vec![
+            Field::new("mean".into(), list_f64.clone()),
+            Field::new("lower".into(), list_f64.clone()),
+            Field::new("upper".into(), list_f64),
+        ]),
+    ))
+}
+
+/// Vertical mean with percentile bootstrap confidence intervals per position.
+/// Each bootstrap replicate resamples the non-null rows with replacement; the interval is
+/// the central `ci` fraction of the replicate means.
+#[polars_expr(output_type_func=list_mean_ci_output_type)]
+fn list_mean_ci(inputs: &[Series], kwargs: MeanCiKwargs) -> PolarsResult<Series> {
+    if kwargs.ci.is_nan() || kwargs.ci <= 0.0 || kwargs.ci >= 1.0 {
+        polars_bail!(ComputeError: "ci must be between 0 and 1, got {}", kwargs.ci);
+    }
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "mean_ci")?;
+    let width = matrix.width;
+    let rows: Vec<usize> = (0..matrix.n_rows).filter(|&i| matrix.row_valid[i]).collect();
+
+    let mean: Vec<Option<f64>> = (0..width)
+        .map(|j| column_mean(&matrix.values, width, j, rows.iter().copied()))
+        .collect();
+
+    // Replicate means, one Vec per position
+    let mut boot: Vec<Vec<f64>> = vec![Vec::with_capacity(kwargs.n_boot); width];
+    if !rows.is_empty() {
+        let mut rng = seeded_rng(kwargs.seed);
+        let mut sample = vec![0usize; rows.len()];
+        for _ in 0..kwargs.n_boot {
+            for s in sample.iter_mut() {
+                *s = rows[rng.random_range(0..rows.len())];
+            }
+            for (j, replicates) in boot.iter_mut().enumerate() {
+                if let Some(m) = column_mean(&matrix.values, width, j, sample.iter().copied()) {
+                    replicates.push(m);
+                }
+            }
+        }
+    }
+
+    let alpha = (1.0 - kwargs.ci) / 2.0;
+    let mut lower = Vec::with_capacity(width);
+    let mut upper = Vec::with_capacity(width);
+    for replicates in boot.iter_mut() {
+        replicates.sort_by(f64::total_cmp);
+        lower.push(sorted_quantile(replicates, alpha));
+        upper.push(sorted_quantile(replicates, 1.0 - alpha));
+    }
+
+    single_row_struct(
+        series.name().clone(),
+        vec![("mean", mean), ("lower", lower), ("upper", upper)],
+    )
+}
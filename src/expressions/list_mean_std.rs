@@ -0,0 +1,41 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{single_row_struct, vertical_welford, Welford};
+
+#[derive(serde::Deserialize)]
+struct MeanStdKwargs {
+    ddof: u8,
+}
+
+fn list_mean_std_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let list_f64 = DataType::List(Box::new(DataType::Float64));
+    Ok(Field::new(
+        field.name().clone(),
+        DataType::Struct(vec![
+            Field::new("mean".into(), list_f64.clone()),
+            Field::new("std".into(), list_f64),
+        ]),
+    ))
+}
+
+/// Vertical mean and standard deviation per position in a single streaming pass over the
+/// rows, without materializing the column. Null elements are skipped; positions with no
+/// values have a null mean, and positions with no more than `ddof` values a null std.
+#[polars_expr(output_type_func=list_mean_std_output_type)]
+fn list_mean_std(inputs: &[Series], kwargs: MeanStdKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let states = vertical_welford(series, "mean_std")?;
+    single_row_struct(
+        series.name().clone(),
+        vec![
+            ("mean", states.iter().map(Welford::mean).collect()),
+            ("std", states.iter().map(|s| s.std(kwargs.ddof)).collect()),
+        ],
+    )
+}
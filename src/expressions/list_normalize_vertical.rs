@@ -0,0 +1,79 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, float_list_output_type, F64Matrix};
+
+/// The vertical aggregate each position is normalized by.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum Aggregate {
+    Mean,
+    Max,
+    Sum,
+}
+
+/// How the aggregate is applied to each value.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum NormalizeMode {
+    Divide,
+    Subtract,
+}
+
+#[derive(serde::Deserialize)]
+struct NormalizeVerticalKwargs {
+    method: Aggregate,
+    mode: NormalizeMode,
+}
+
+/// Normalize every row by the vertical (across-rows) aggregate at each position, either
+/// dividing by it or subtracting it. The aggregate skips nulls; positions where every
+/// row is null stay null.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn list_normalize_vertical(
+    inputs: &[Series],
+    kwargs: NormalizeVerticalKwargs,
+) -> PolarsResult<Series> {
+    let subtract = kwargs.mode == NormalizeMode::Subtract;
+
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "normalize")?;
+    let width = matrix.width;
+
+    // Single pass for the per-position aggregate
+    let mut sum = vec![0.0; width];
+    let mut max = vec![f64::NEG_INFINITY; width];
+    let mut count = vec![0usize; width];
+    for row in matrix.values.chunks_exact(width.max(1)) {
+        for (j, v) in row.iter().enumerate() {
+            if let Some(v) = *v {
+                sum[j] += v;
+                max[j] = max[j].max(v);
+                count[j] += 1;
+            }
+        }
+    }
+    let aggregate: Vec<f64> = (0..width)
+        .map(|j| match kwargs.method {
+            _ if count[j] == 0 => f64::NAN,
+            Aggregate::Mean => sum[j] / count[j] as f64,
+            Aggregate::Max => max[j],
+            Aggregate::Sum => sum[j],
+        })
+        .collect();
+
+    let values: Vec<Option<f64>> = matrix
+        .values
+        .iter()
+        .enumerate()
+        .map(|(k, v)| {
+            let agg = aggregate[k % width];
+            v.filter(|_| !agg.is_nan())
+                .map(|v| if subtract { v - agg } else { v / agg })
+        })
+        .collect();
+
+    let rows = (0..matrix.n_rows)
+        .map(|i| matrix.row_valid[i].then(|| &values[i * width..(i + 1) * width]));
+    build_f64_list(series.name().clone(), matrix.n_rows, rows, series.dtype())
+}
@@ -0,0 +1,132 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::distance::{cosine_similarity, Metric};
+use super::helpers::{build_f64_list, F64Matrix};
+
+#[derive(serde::Deserialize)]
+struct PairwiseDistanceKwargs {
+    metric: Metric,
+    long: bool,
+}
+
+#[derive(serde::Deserialize)]
+struct SelfSimilarityKwargs {
+    long: bool,
+}
+
+/// Single-row `List(List(Float64))` matrix, or with `long` a struct of `{i, j, value_name}`.
+fn pairwise_output_type(
+    input_fields: &[Field],
+    long: bool,
+    value_name: &str,
+) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let dtype = if long {
+        DataType::Struct(vec![
+            Field::new("i".into(), DataType::UInt32),
+            Field::new("j".into(), DataType::UInt32),
+            Field::new(value_name.into(), DataType::Float64),
+        ])
+    } else {
+        DataType::List(Box::new(DataType::List(Box::new(DataType::Float64))))
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+fn list_pairwise_distance_output_type(
+    input_fields: &[Field],
+    kwargs: PairwiseDistanceKwargs,
+) -> PolarsResult<Field> {
+    pairwise_output_type(input_fields, kwargs.long, "distance")
+}
+
+fn list_self_similarity_output_type(
+    input_fields: &[Field],
+    kwargs: SelfSimilarityKwargs,
+) -> PolarsResult<Field> {
+    pairwise_output_type(input_fields, kwargs.long, "similarity")
+}
+
+/// Apply the symmetric `f` to every pair of rows, treating each row's list as a vector.
+/// The result is a single row holding the `n_rows x n_rows` matrix, or with `long` one
+/// struct row `{i, j, value_name}` per pair `i < j`. Row indices count every input row,
+/// and pairs involving a null row give null.
+fn pairwise_rows(
+    series: &Series,
+    op_name: &str,
+    value_name: &str,
+    long: bool,
+    f: impl Fn(&[Option<f64>], &[Option<f64>]) -> Option<f64>,
+) -> PolarsResult<Series> {
+    let matrix = F64Matrix::from_series(series, op_name)?;
+    let (n, width) = (matrix.n_rows, matrix.width);
+    let row = |i: usize| {
+        matrix.row_valid[i].then(|| &matrix.values[i * width..(i + 1) * width])
+    };
+    let pair_value = |i: usize, j: usize| match (row(i), row(j)) {
+        (Some(a), Some(b)) => f(a, b),
+        _ => None,
+    };
+
+    if long {
+        let n_pairs = n * n.saturating_sub(1) / 2;
+        let mut i_idx: Vec<u32> = Vec::with_capacity(n_pairs);
+        let mut j_idx: Vec<u32> = Vec::with_capacity(n_pairs);
+        let mut pair_values: Vec<Option<f64>> = Vec::with_capacity(n_pairs);
+        for i in 0..n {
+            for j in i + 1..n {
+                i_idx.push(i as u32);
+                j_idx.push(j as u32);
+                pair_values.push(pair_value(i, j));
+            }
+        }
+        let fields = [
+            Series::new("i".into(), i_idx),
+            Series::new("j".into(), j_idx),
+            Series::new(value_name.into(), pair_values),
+        ];
+        return Ok(StructChunked::from_series(series.name().clone(), n_pairs, fields.iter())?
+            .into_series());
+    }
+
+    let mut values: Vec<Option<f64>> = vec![None; n * n];
+    for i in 0..n {
+        values[i * n + i] = pair_value(i, i);
+        for j in i + 1..n {
+            let v = pair_value(i, j);
+            values[i * n + j] = v;
+            values[j * n + i] = v;
+        }
+    }
+    let rows = values.chunks(n.max(1)).take(n).map(Some);
+    let row_dtype = DataType::List(Box::new(DataType::Float64));
+    let inner = build_f64_list(series.name().clone(), n, rows, &row_dtype)?;
+    Ok(ListChunked::full(series.name().clone(), &inner, 1).into_series())
+}
+
+/// Distances between every pair of rows under `metric`, as a matrix or `{i, j, distance}`
+/// pairs. Pairs involving a null element have a null distance.
+#[polars_expr(output_type_func_with_kwargs=list_pairwise_distance_output_type)]
+fn list_pairwise_distance(
+    inputs: &[Series],
+    kwargs: PairwiseDistanceKwargs,
+) -> PolarsResult<Series> {
+    pairwise_rows(&inputs[0], "pairwise_distance", "distance", kwargs.long, |a, b| {
+        kwargs.metric.distance(a, b)
+    })
+}
+
+/// Cosine similarity between every pair of rows, as a matrix or `{i, j, similarity}`
+/// pairs. Pairs involving a null element are null; a zero vector gives NaN.
+#[polars_expr(output_type_func_with_kwargs=list_self_similarity_output_type)]
+fn list_self_similarity(
+    inputs: &[Series],
+    kwargs: SelfSimilarityKwargs,
+) -> PolarsResult<Series> {
+    pairwise_rows(&inputs[0], "self_similarity", "similarity", kwargs.long, cosine_similarity)
+}
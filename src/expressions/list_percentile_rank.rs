@@ -0,0 +1,35 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, float_list_output_type, F64Matrix};
+
+/// Percentile (0-100) of each value among all rows at the same position.
+/// Ties count half, i.e. `100 * (#less + #equal / 2) / n` over the position's finite
+/// values. Null and non-finite values give null.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn list_percentile_rank(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "percentile_rank")?;
+    let width = matrix.width;
+    let mut values = matrix.values.clone();
+
+    let mut sorted: Vec<f64> = Vec::with_capacity(matrix.n_rows);
+    for j in 0..width {
+        sorted.clear();
+        sorted.extend((0..matrix.n_rows).filter_map(|i| matrix.get(i, j).filter(|v| v.is_finite())));
+        sorted.sort_by(f64::total_cmp);
+        let n = sorted.len() as f64;
+        for i in 0..matrix.n_rows {
+            let v = &mut values[i * width + j];
+            *v = v.filter(|x| x.is_finite()).map(|x| {
+                let less = sorted.partition_point(|&s| s < x) as f64;
+                let less_equal = sorted.partition_point(|&s| s <= x) as f64;
+                100.0 * (less + less_equal) / 2.0 / n
+            });
+        }
+    }
+
+    let rows = (0..matrix.n_rows)
+        .map(|i| matrix.row_valid[i].then(|| &values[i * width..(i + 1) * width]));
+    build_f64_list(series.name().clone(), matrix.n_rows, rows, series.dtype())
+}
@@ -0,0 +1,162 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use super::helpers::{single_row_struct, F64Matrix};
+use super::list_ttest::{mean_var, ttest};
+use super::resampling::seeded_rng;
+
+/// Statistic compared between the observed and the permuted labelling.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum PermutationStatistic {
+    /// Difference of the group means.
+    Mean,
+    /// Welch's t statistic.
+    T,
+}
+
+#[derive(serde::Deserialize)]
+struct PermutationTestKwargs {
+    n_perm: usize,
+    statistic: PermutationStatistic,
+    seed: Option<u64>,
+}
+
+fn list_permutation_test_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let list_f64 = DataType::List(Box::new(DataType::Float64));
+    Ok(Field::new(
+        field.name().clone(),
+        DataType::Struct(vec![
+            Field::new("statistic".into(), list_f64.clone()),
+            Field::new("p_value".into(), list_f64),
+        ]),
+    ))
+}
+
+/// One-sample statistic of `x` against zero.
+fn one_sample_stat(x: &[f64], use_t: bool) -> Option<f64> {
+    if use_t {
+        if x.len() < 2 {
+            return None;
+        }
+        let (mean, var) = mean_var(x);
+        Some(mean / (var / x.len() as f64).sqrt())
+    } else {
+        (!x.is_empty()).then(|| x.iter().sum::<f64>() / x.len() as f64)
+    }
+}
+
+/// Two-sample statistic of `a` against `b`.
+fn two_sample_stat(a: &[f64], b: &[f64], use_t: bool) -> Option<f64> {
+    if use_t {
+        return ttest(a, b, false).map(|(t, _)| t);
+    }
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    Some(a.iter().sum::<f64>() / a.len() as f64 - b.iter().sum::<f64>() / b.len() as f64)
+}
+
+/// Per-position permutation test across rows.
+/// With one input, row signs are flipped at random to test a zero mean. With a second list
+/// input, rows are shuffled between the two groups to test equal means. Whole rows are
+/// permuted so the dependence between positions is preserved. The two-sided p-value is
+/// `(1 + #{|null| >= |observed|}) / (1 + n_perm)`.
+#[polars_expr(output_type_func=list_permutation_test_output_type)]
+fn list_permutation_test(
+    inputs: &[Series],
+    kwargs: PermutationTestKwargs,
+) -> PolarsResult<Series> {
+    let use_t = kwargs.statistic == PermutationStatistic::T;
+
+    let a = F64Matrix::from_series(&inputs[0], "permutation_test")?;
+    let b = inputs
+        .get(1)
+        .map(|s| F64Matrix::from_series(s, "permutation_test"))
+        .transpose()?;
+    let width = a.width;
+    if let Some(b) = &b {
+        if b.width != width && b.row_valid.iter().any(|&v| v) && a.row_valid.iter().any(|&v| v) {
+            polars_bail!(
+                ComputeError:
+                "Both list columns must have the same length for permutation_test. Expected {}, got {}",
+                width, b.width
+            );
+        }
+    }
+
+    // Pooled valid rows: group a first, then group b
+    let mut rows: Vec<&[Option<f64>]> = Vec::new();
+    for m in std::iter::once(&a).chain(b.as_ref()) {
+        rows.extend(
+            (0..m.n_rows)
+                .filter(|&i| m.row_valid[i])
+                .map(|i| &m.values[i * m.width..(i + 1) * m.width]),
+        );
+    }
+    let n_a = a.row_valid.iter().filter(|&&v| v).count();
+
+    let finite = |row: &[Option<f64>], j: usize| row.get(j).copied().flatten().filter(|v| v.is_finite());
+    // Statistic per position for a given row order (two-sample) or sign vector (one-sample)
+    let stats = |order: &[usize], signs: &[f64]| -> Vec<Option<f64>> {
+        (0..width)
+            .map(|j| {
+                if b.is_some() {
+                    let group = |idx: &[usize]| -> Vec<f64> {
+                        idx.iter().filter_map(|&r| finite(rows[r], j)).collect()
+                    };
+                    two_sample_stat(&group(&order[..n_a]), &group(&order[n_a..]), use_t)
+                } else {
+                    let x: Vec<f64> = rows
+                        .iter()
+                        .zip(signs)
+                        .filter_map(|(row, s)| finite(row, j).map(|v| v * s))
+                        .collect();
+                    one_sample_stat(&x, use_t)
+                }
+            })
+            .collect()
+    };
+
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    let mut signs = vec![1.0; rows.len()];
+    // An undefined observed statistic (e.g. t with zero variance) has no p-value
+    let observed: Vec<Option<f64>> =
+        stats(&order, &signs).into_iter().map(|s| s.filter(|s| !s.is_nan())).collect();
+    let mut exceed = vec![0usize; width];
+    let mut rng = seeded_rng(kwargs.seed);
+    for _ in 0..kwargs.n_perm {
+        if b.is_some() {
+            order.shuffle(&mut rng);
+        } else {
+            signs.iter_mut().for_each(|s| *s = if rng.random::<bool>() { 1.0 } else { -1.0 });
+        }
+        for (j, null) in stats(&order, &signs).into_iter().enumerate() {
+            if let (Some(null), Some(obs)) = (null, observed[j]) {
+                // Small tolerance so ties with the observed value count as exceeding it
+                if null.abs() >= obs.abs() * (1.0 - 1e-12) {
+                    exceed[j] += 1;
+                }
+            }
+        }
+    }
+
+    let p_value = observed
+        .iter()
+        .zip(&exceed)
+        .map(|(obs, &count)| {
+            obs.map(|_| (1 + count) as f64 / (1 + kwargs.n_perm) as f64)
+        })
+        .collect();
+    single_row_struct(
+        inputs[0].name().clone(),
+        vec![("statistic", observed), ("p_value", p_value)],
+    )
+}
@@ -0,0 +1,46 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::list_max::fold_max;
+use super::list_min::fold_min;
+use crate::kwargs::ReduceKwargs;
+use crate::reduce::{reduce_output_type, vertical_reduce, Accumulator};
+
+/// Running per-position minimum and maximum, ignoring nulls, so the range needs one pass.
+struct Ptp {
+    min: Series,
+    max: Series,
+}
+
+impl Accumulator for Ptp {
+    const NAME: &'static str = "ptp";
+
+    // Preserve the input type, like numpy.ptp
+    fn output_dtype(inner: &DataType) -> DataType {
+        inner.clone()
+    }
+
+    fn init(first: &Series) -> PolarsResult<Self> {
+        Ok(Ptp { min: first.clone(), max: first.clone() })
+    }
+
+    fn update(&mut self, row: &Series) -> PolarsResult<()> {
+        self.min = fold_min(&self.min, row)?;
+        self.max = fold_max(&self.max, row)?;
+        Ok(())
+    }
+
+    fn finish(self) -> PolarsResult<Series> {
+        &self.max - &self.min
+    }
+}
+
+fn list_ptp_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    reduce_output_type::<Ptp>(input_fields)
+}
+
+/// Per-position peak-to-peak range (max - min) across rows.
+#[polars_expr(output_type_func=list_ptp_output_type)]
+fn list_ptp(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    vertical_reduce::<Ptp>(&inputs[0], kwargs)
+}
@@ -0,0 +1,65 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::distributions::student_t_two_sided_p;
+use super::helpers::{ols_fit, single_row_struct, F64Matrix};
+
+fn list_regress_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let list_f64 = DataType::List(Box::new(DataType::Float64));
+    Ok(Field::new(
+        field.name().clone(),
+        DataType::Struct(vec![
+            Field::new("slope".into(), list_f64.clone()),
+            Field::new("intercept".into(), list_f64.clone()),
+            Field::new("p_value".into(), list_f64),
+        ]),
+    ))
+}
+
+/// Per-position OLS regression of list values against a scalar covariate across rows.
+/// inputs[0] = y (List/Array, equal length across rows)
+/// inputs[1] = x (numeric scalar per row)
+/// The p-value is the two-sided t-test of a zero slope with n - 2 degrees of freedom.
+/// Positions with fewer than two usable rows are null, and the p-value needs three.
+#[polars_expr(output_type_func=list_regress_output_type)]
+fn list_regress(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "regress")?;
+    let x_series = inputs[1].cast(&DataType::Float64)?;
+    let x = x_series.f64()?;
+    if x.len() != matrix.n_rows {
+        polars_bail!(
+            ComputeError:
+            "x must have the same number of rows as y. Expected {}, got {}",
+            matrix.n_rows, x.len()
+        );
+    }
+    let x: Vec<Option<f64>> = x.iter().collect();
+
+    let fits: Vec<_> = (0..matrix.width)
+        .map(|j| {
+            ols_fit((0..matrix.n_rows).filter_map(|i| Some((x[i]?, matrix.get(i, j)?))))
+        })
+        .collect();
+    let p_value = fits
+        .iter()
+        .map(|f| {
+            let f = f.as_ref().filter(|f| f.n > 2)?;
+            Some(student_t_two_sided_p(f.slope / f.slope_se, (f.n - 2) as f64))
+        })
+        .collect();
+
+    single_row_struct(
+        series.name().clone(),
+        vec![
+            ("slope", fits.iter().map(|f| f.as_ref().map(|f| f.slope)).collect()),
+            ("intercept", fits.iter().map(|f| f.as_ref().map(|f| f.intercept)).collect()),
+            ("p_value", p_value),
+        ],
+    )
+}
@@ -0,0 +1,63 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    build_f64_list, ensure_list_type, float_list_output_type, length_mismatch_error, Welford,
+};
+use super::vec_scale::{center_scale, minmax_stats, zscore_stats};
+
+/// How each row is rescaled before averaging.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum ScaleMethod {
+    /// Like `minmax_scale()`.
+    Minmax,
+    /// Like `zscore()`.
+    Zscore,
+}
+
+#[derive(serde::Deserialize)]
+struct ScaledMeanKwargs {
+    method: ScaleMethod,
+    ddof: u8,
+}
+
+/// Vertical mean of the rows after rescaling each one on its own, fusing `minmax_scale()` or
+/// `zscore()` with `mean()` so the scaled rows are never materialized. Rows are scaled
+/// exactly as by those expressions; null elements are skipped.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn list_scaled_mean(inputs: &[Series], kwargs: ScaledMeanKwargs) -> PolarsResult<Series> {
+    let ddof = kwargs.ddof;
+    let stats: &dyn Fn(&[f64]) -> (f64, f64) = match kwargs.method {
+        ScaleMethod::Minmax => &minmax_stats,
+        ScaleMethod::Zscore => &move |finite| zscore_stats(finite, ddof),
+    };
+
+    let series = &inputs[0];
+    let list_series =
+        ensure_list_type(series)?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = list_series.list()?;
+
+    let mut finite: Vec<f64> = Vec::new();
+    let mut states: Option<Vec<Welford>> = None;
+    for row in list_chunked.amortized_iter().flatten() {
+        let ca = row.as_ref().f64()?;
+        let states = states.get_or_insert_with(|| vec![Welford::default(); ca.len()]);
+        if ca.len() != states.len() {
+            return Err(length_mismatch_error(list_chunked, "vertical scaled_mean", false));
+        }
+        finite.clear();
+        finite.extend(ca.iter().flatten().filter(|v| v.is_finite()));
+        let (center, scale) = center_scale(&finite, stats);
+        for (state, v) in states.iter_mut().zip(ca.iter()) {
+            if let Some(v) = v {
+                state.push((v - center) / scale);
+            }
+        }
+    }
+
+    // An all-null column has no positions to report
+    let values: Option<Vec<Option<f64>>> =
+        states.map(|states| states.iter().map(Welford::mean).collect());
+    build_f64_list(series.name().clone(), 1, std::iter::once(values.as_deref()), series.dtype())
+}
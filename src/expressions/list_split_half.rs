@@ -0,0 +1,67 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use rand::seq::SliceRandom;
+use super::helpers::{pearson, F64Matrix};
+use super::resampling::{column_mean, seeded_rng};
+
+#[derive(serde::Deserialize)]
+struct SplitHalfKwargs {
+    n_splits: usize,
+    seed: Option<u64>,
+}
+
+fn list_split_half_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {
+            Ok(Field::new(field.name().clone(), DataType::Float64))
+        },
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Split-half reliability of the per-position profile across rows.
+/// Each split shuffles the non-null rows into two halves, averages each half per
+/// position and correlates the two mean profiles across positions; the result is the
+/// Spearman-Brown corrected correlation `2r / (1 + r)` averaged over the splits.
+/// Splits whose correlation is undefined (or exactly -1) are skipped, and the result is null when no
+/// split gives one.
+#[polars_expr(output_type_func=list_split_half_output_type)]
+fn list_split_half_reliability(
+    inputs: &[Series],
+    kwargs: SplitHalfKwargs,
+) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "split_half_reliability")?;
+    let width = matrix.width;
+    let mut rows: Vec<usize> = (0..matrix.n_rows).filter(|&i| matrix.row_valid[i]).collect();
+
+    let mut total = 0.0;
+    let mut n_valid = 0usize;
+    if rows.len() >= 2 {
+        let mut rng = seeded_rng(kwargs.seed);
+        let half = rows.len() / 2;
+        for _ in 0..kwargs.n_splits {
+            rows.shuffle(&mut rng);
+            let (a, b) = rows.split_at(half);
+            let means_a: Vec<Option<f64>> = (0..width)
+                .map(|j| column_mean(&matrix.values, width, j, a.iter().copied()))
+                .collect();
+            let means_b: Vec<Option<f64>> = (0..width)
+                .map(|j| column_mean(&matrix.values, width, j, b.iter().copied()))
+                .collect();
+            // Positions missing from either half are left out. r = -1 has no finite
+            // correction and is skipped like an undefined (NaN) r
+            let pairs = means_a.iter().zip(&means_b).filter_map(|(&x, &y)| Some((x?, y?)));
+            let r = pearson(pairs).filter(|&r| r > -1.0);
+            if let Some(r) = r {
+                total += 2.0 * r / (1.0 + r);
+                n_valid += 1;
+            }
+        }
+    }
+
+    let reliability = (n_valid > 0).then(|| total / n_valid as f64);
+    Ok(Float64Chunked::from_slice_options(series.name().clone(), &[reliability]).into_series())
+}
@@ -0,0 +1,26 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+
+fn list_stack_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(Box::new(field.dtype().clone())),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Collapse every row into a single row holding the whole column as one list of rows,
+/// i.e. `List(List(inner))` or `List(Array(inner, width))`. Null rows are kept in place.
+#[polars_expr(output_type_func=list_stack_output_type)]
+fn list_stack(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    match series.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    Ok(ListChunked::full(series.name().clone(), &series.rechunk(), 1).into_series())
+}
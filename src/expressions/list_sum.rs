@@ -1,95 +1,90 @@
 #![allow(clippy::unused_unit)]
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
-use super::helpers::ensure_list_type;
+use crate::kwargs::{NanPolicy, ReduceKwargs};
+use crate::reduce::{
+    reduce_columns, reduce_columns_output_type, reduce_output_type, vertical_reduce, Accumulator,
+};
 
-fn list_sum_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
-    let field = &input_fields[0];
-    match field.dtype() {
-        DataType::List(inner) => Ok(Field::new(
-            field.name().clone(),
-            DataType::List(inner.clone()),
-        )),
-        DataType::Array(inner, width) => Ok(Field::new(
-            field.name().clone(),
-            DataType::Array(inner.clone(), *width),
-        )),
-        _ => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", field.dtype()),
-    }
+/// Running per-position sum, treating null elements as 0 (ignoring them). Integer inputs are
+/// summed in Int128 with overflow checks and narrowed back to the input dtype at the end, so
+/// large UInt64/Int64 counts are neither wrapped nor routed through Float64.
+struct Sum {
+    total: Series,
+    dtype: DataType,
 }
 
-#[polars_expr(output_type_func=list_sum_output_type)]
-fn list_sum(inputs: &[Series]) -> PolarsResult<Series> {
-    let series = &inputs[0];
-    let input_dtype = series.dtype().clone();
-
-    // Convert to List if it's an Array
-    let series = ensure_list_type(series)?;
-    let list_chunked = series.list()?;
-
-    let n_lists = list_chunked.len();
-    if n_lists == 0 {
-        return Ok(series.slice(0, 0));
-    }
-
-    // Find first non-null list to determine length and type
-    let mut expected_len = 0;
-    let mut inner_dtype = DataType::Null;
+impl Accumulator for Sum {
+    const NAME: &'static str = "sum";
 
-    for i in 0..n_lists {
-        if let Some(s) = list_chunked.get_as_series(i) {
-            expected_len = s.len();
-            inner_dtype = s.dtype().clone();
-            break;
-        }
+    // Preserve the input type, including integers
+    fn output_dtype(inner: &DataType) -> DataType {
+        inner.clone()
     }
 
-    if expected_len == 0 {
-        // All rows are null, return a null series
-        return Ok(ListChunked::full_null(series.name().clone(), n_lists).into_series());
+    fn init(first: &Series) -> PolarsResult<Self> {
+        let dtype = first.dtype().clone();
+        let total = match dtype.is_integer() {
+            true => first.cast(&DataType::Int128)?,
+            false => first.clone(),
+        };
+        Ok(Sum { total: total.fill_null(FillNullStrategy::Zero)?, dtype })
     }
 
-    // Collect all non-null series references and validate
-    let mut all_series = Vec::new();
-
-    for i in 0..n_lists {
-        if let Some(s) = list_chunked.get_as_series(i) {
-            if s.len() != expected_len {
-                polars_bail!(
-                    ComputeError:
-                    "All lists must have the same length for vertical sum. Expected {}, got {}",
-                    expected_len, s.len()
-                );
-            }
-            all_series.push(s);
+    fn update(&mut self, row: &Series) -> PolarsResult<()> {
+        let row = row.fill_null(FillNullStrategy::Zero)?;
+        if !self.dtype.is_integer() {
+            self.total = (&self.total + &row)?;
+            return Ok(());
         }
-        // Skip null rows
+        let row = row.cast(&DataType::Int128)?;
+        let totals = self.total.i128()?.into_no_null_iter();
+        let total: Option<Vec<i128>> =
+            totals.zip(row.i128()?.into_no_null_iter()).map(|(a, b)| a.checked_add(b)).collect();
+        let Some(total) = total else {
+            polars_bail!(ComputeError: "Vertical sum overflows Int128");
+        };
+        self.total = Int128Chunked::from_vec(self.total.name().clone(), total).into_series();
+        Ok(())
     }
 
-    if all_series.is_empty() {
-        return Ok(ListChunked::full_null(series.name().clone(), 1).into_series());
+    fn finish(self) -> PolarsResult<Series> {
+        if !self.dtype.is_integer() {
+            return Ok(self.total);
+        }
+        self.total.strict_cast(&self.dtype).map_err(|_| {
+            polars_err!(
+                ComputeError:
+                "Vertical sum overflows {}; cast to a wider integer type (e.g. Int128) first",
+                self.dtype
+            )
+        })
     }
+}
 
-    // Sum all series, treating nulls as 0 (ignoring them)
-    let mut result = all_series[0].fill_null(FillNullStrategy::Zero)?;
-    for s in all_series.iter().skip(1) {
-        let s_filled = s.fill_null(FillNullStrategy::Zero)?;
-        result = (&result + &s_filled)?;
-    }
+fn list_sum_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    reduce_output_type::<Sum>(input_fields)
+}
 
-    // Cast back to original inner dtype to preserve integer types
-    result = result.cast(&inner_dtype)?;
+#[polars_expr(output_type_func=list_sum_output_type)]
+fn list_sum(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    vertical_reduce::<Sum>(&inputs[0], kwargs)
+}
 
-    // Wrap in a single-row list
-    let result_list = ListChunked::full(series.name().clone(), &result, 1);
+/// `list_sum` skipping NaN elements, like `numpy.nansum`.
+#[polars_expr(output_type_func=list_sum_output_type)]
+fn list_nansum(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    let kwargs = ReduceKwargs { nan_policy: NanPolicy::Ignore, ..kwargs };
+    vertical_reduce::<Sum>(&inputs[0], kwargs)
+}
 
-    // Cast back to Array if input was Array
-    let result_series = result_list.into_series();
-    match &input_dtype {
-        DataType::Array(_, width) => {
-            result_series.cast(&DataType::Array(Box::new(inner_dtype), *width))
-        },
-        _ => Ok(result_series),
-    }
+fn list_sum_columns_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    reduce_columns_output_type(input_fields, reduce_output_type::<Sum>)
 }
 
+/// `list_sum` over every input column in one call, returning a struct with a field per
+/// column.
+#[polars_expr(output_type_func=list_sum_columns_output_type)]
+fn list_sum_columns(inputs: &[Series], kwargs: ReduceKwargs) -> PolarsResult<Series> {
+    reduce_columns(inputs, |s| vertical_reduce::<Sum>(s, kwargs))
+}
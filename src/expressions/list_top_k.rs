@@ -0,0 +1,102 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars::polars_utils::float::IsFloat;
+use polars::polars_utils::total_ord::TotalOrd;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    ensure_contiguous_list, list_from_flat_values, physical_numeric_values, vertical_row_starts,
+    with_numeric_type,
+};
+
+#[derive(serde::Deserialize)]
+struct TopKKwargs {
+    k: usize,
+    return_indices: bool,
+}
+
+fn list_top_k_output_type(input_fields: &[Field], kwargs: TopKKwargs) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let inner = match field.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => inner.clone(),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    };
+    let values = DataType::List(Box::new(DataType::List(inner)));
+    let dtype = if kwargs.return_indices {
+        DataType::Struct(vec![
+            Field::new("values".into(), values),
+            Field::new(
+                "indices".into(),
+                DataType::List(Box::new(DataType::List(Box::new(DataType::UInt32)))),
+            ),
+        ])
+    } else {
+        values
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// For each of `width` positions, the k largest non-null, non-NaN values across the rows
+/// starting at `starts`, compared in their own dtype. Returns the flat index of each value,
+/// its row, and the offsets splitting both into one list per position. The stable sort keeps
+/// the earlier row first among equal values.
+fn top_k_by_position<T: PolarsNumericType>(
+    values: &ChunkedArray<T>,
+    starts: &[Option<usize>],
+    width: usize,
+    k: usize,
+) -> (Vec<IdxSize>, Vec<u32>, Vec<i64>) {
+    let arr = values.downcast_iter().next().expect("values should be a single chunk");
+    let mut take: Vec<IdxSize> = Vec::with_capacity(width * k);
+    let mut rows: Vec<u32> = Vec::with_capacity(width * k);
+    let mut offsets: Vec<i64> = Vec::with_capacity(width + 1);
+    offsets.push(0);
+    let mut candidates: Vec<(T::Native, u32, usize)> = Vec::with_capacity(starts.len());
+    for j in 0..width {
+        candidates.clear();
+        candidates.extend(starts.iter().enumerate().filter_map(|(i, start)| {
+            let idx = (*start)? + j;
+            let v = arr.get(idx).filter(|v| !v.is_nan())?;
+            Some((v, i as u32, idx))
+        }));
+        candidates.sort_by(|a, b| b.0.tot_cmp(&a.0));
+        candidates.truncate(k);
+        take.extend(candidates.iter().map(|c| c.2 as IdxSize));
+        rows.extend(candidates.iter().map(|c| c.1));
+        offsets.push(take.len() as i64);
+    }
+    (take, rows, offsets)
+}
+
+/// Per-position k largest values across rows, in descending order.
+/// The result is a single row holding one list per position; positions with fewer than k
+/// non-null, non-NaN values return what they have. Ties keep row order. Values are ranked
+/// in their own dtype and returned as the original elements.
+#[polars_expr(output_type_func_with_kwargs=list_top_k_output_type)]
+fn list_top_k(inputs: &[Series], kwargs: TopKKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let list_series = ensure_contiguous_list(series)?;
+    let list_chunked = list_series.list()?;
+    let (starts, width) = vertical_row_starts(list_chunked, "top_k")?;
+    let physical = physical_numeric_values(list_chunked)?;
+    let (take, rows, offsets) =
+        with_numeric_type!(physical.dtype(), T => {
+            top_k_by_position(physical.unpack::<T>()?, &starts, width, kwargs.k)
+        });
+    let name = series.name().clone();
+
+    // Take the physical values, then cast back so logical dtypes such as Date come through
+    let top = physical.take(&IdxCa::from_vec("".into(), take))?;
+    let values = list_from_flat_values(name.clone(), &top, offsets.clone(), None)?
+        .cast(&DataType::List(Box::new(list_chunked.inner_dtype().clone())))?;
+    let values = ListChunked::full(name.clone(), &values, 1).into_series();
+    if !kwargs.return_indices {
+        return Ok(values);
+    }
+    let rows = UInt32Chunked::from_vec("".into(), rows).into_series();
+    let indices = list_from_flat_values("indices".into(), &rows, offsets, None)?;
+    let fields = [
+        values.with_name("values".into()),
+        ListChunked::full("indices".into(), &indices, 1).into_series(),
+    ];
+    Ok(StructChunked::from_series(name, 1, fields.iter())?.into_series())
+}
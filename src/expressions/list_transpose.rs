@@ -0,0 +1,60 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    array_from_flat_values, ensure_contiguous_list, length_mismatch_error, single_list_array,
+};
+
+fn list_transpose_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        // The output width is the input row count, so Array input comes back as a List
+        DataType::List(inner) | DataType::Array(inner, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(inner.clone()),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Transpose an n-row column of length-m lists into an m-row column of length-n lists.
+/// Every non-null row must have the same length; a null input row becomes a null element
+/// at that position of every output list.
+#[polars_expr(output_type_func=list_transpose_output_type)]
+fn list_transpose(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = ensure_contiguous_list(&inputs[0])?;
+    let list_chunked = series.list()?;
+    let n_rows = list_chunked.len();
+    let inner_dtype = list_chunked.inner_dtype().clone();
+
+    let list_arr = single_list_array(list_chunked);
+    let offsets = list_arr.offsets();
+    let validity = list_arr.validity();
+    let row_is_valid = |i: usize| validity.is_none_or(|v| v.get_bit(i));
+
+    let mut width: Option<usize> = None;
+    for i in (0..n_rows).filter(|&i| row_is_valid(i)) {
+        let len = (offsets[i + 1] - offsets[i]) as usize;
+        match width {
+            None => width = Some(len),
+            Some(w) if w != len => {
+                return Err(length_mismatch_error(list_chunked, "transpose", false));
+            },
+            _ => {},
+        }
+    }
+    let width = width.unwrap_or(0);
+
+    // Output row j gathers element j of every input row
+    let mut take_idx: Vec<Option<IdxSize>> = Vec::with_capacity(width * n_rows);
+    for j in 0..width {
+        take_idx.extend(
+            (0..n_rows).map(|i| row_is_valid(i).then(|| (offsets[i] as usize + j) as IdxSize)),
+        );
+    }
+    let idx_ca = IdxCa::from_iter_options("".into(), take_idx.into_iter());
+    let values = list_chunked.get_inner().take(&idx_ca)?;
+
+    array_from_flat_values(series.name().clone(), &values, n_rows, width, None)?
+        .cast(&DataType::List(Box::new(inner_dtype)))
+}
@@ -0,0 +1,57 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ols_fit, single_row_struct, F64Matrix};
+
+#[derive(serde::Deserialize)]
+struct TrendKwargs {
+    return_stats: bool,
+}
+
+fn list_trend_output_type(input_fields: &[Field], kwargs: TrendKwargs) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let list_f64 = DataType::List(Box::new(DataType::Float64));
+    let dtype = if kwargs.return_stats {
+        DataType::Struct(vec![
+            Field::new("slope".into(), list_f64.clone()),
+            Field::new("intercept".into(), list_f64.clone()),
+            Field::new("r2".into(), list_f64),
+        ])
+    } else {
+        list_f64
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Per-position OLS fit of value against row index across rows.
+/// Null rows keep their index but contribute no point; positions with fewer than two
+/// finite values are null.
+#[polars_expr(output_type_func_with_kwargs=list_trend_output_type)]
+fn list_trend(inputs: &[Series], kwargs: TrendKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let matrix = F64Matrix::from_series(series, "trend")?;
+
+    let fits: Vec<_> = (0..matrix.width)
+        .map(|j| {
+            ols_fit((0..matrix.n_rows).filter_map(|i| matrix.get(i, j).map(|v| (i as f64, v))))
+        })
+        .collect();
+    let slope: Vec<Option<f64>> = fits.iter().map(|f| f.as_ref().map(|f| f.slope)).collect();
+
+    if !kwargs.return_stats {
+        let slope = Series::new(series.name().clone(), slope);
+        return Ok(ListChunked::full(series.name().clone(), &slope, 1).into_series());
+    }
+    single_row_struct(
+        series.name().clone(),
+        vec![
+            ("slope", slope),
+            ("intercept", fits.iter().map(|f| f.as_ref().map(|f| f.intercept)).collect()),
+            ("r2", fits.iter().map(|f| f.as_ref().map(|f| f.r2)).collect()),
+        ],
+    )
+}
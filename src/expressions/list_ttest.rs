@@ -0,0 +1,186 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::distributions::{normal_sf, student_t_two_sided_p};
+use super::helpers::{single_row_struct, F64Matrix};
+
+#[derive(serde::Deserialize)]
+struct TTestKwargs {
+    equal_var: bool,
+}
+
+fn two_sample_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let list_f64 = DataType::List(Box::new(DataType::Float64));
+    Ok(Field::new(
+        field.name().clone(),
+        DataType::Struct(vec![
+            Field::new("statistic".into(), list_f64.clone()),
+            Field::new("p_value".into(), list_f64),
+        ]),
+    ))
+}
+
+/// Collect both inputs as matrices of the same width.
+fn two_sample_matrices(inputs: &[Series], op_name: &str) -> PolarsResult<(F64Matrix, F64Matrix)> {
+    let a = F64Matrix::from_series(&inputs[0], op_name)?;
+    let b = F64Matrix::from_series(&inputs[1], op_name)?;
+    if a.width != b.width && a.row_valid.iter().any(|&v| v) && b.row_valid.iter().any(|&v| v) {
+        polars_bail!(
+            ComputeError:
+            "Both list columns must have the same length for {}. Expected {}, got {}",
+            op_name, a.width, b.width
+        );
+    }
+    Ok((a, b))
+}
+
+/// Finite values of column `j` of `matrix`.
+fn position_sample(matrix: &F64Matrix, j: usize) -> Vec<f64> {
+    if j >= matrix.width {
+        return Vec::new();
+    }
+    (0..matrix.n_rows)
+        .filter_map(|i| matrix.get(i, j).filter(|v| v.is_finite()))
+        .collect()
+}
+
+/// Run `test` on every position and pack the results as `{statistic, p_value}`.
+fn per_position_test(
+    name: PlSmallStr,
+    a: &F64Matrix,
+    b: &F64Matrix,
+    test: impl Fn(&[f64], &[f64]) -> Option<(f64, f64)>,
+) -> PolarsResult<Series> {
+    let width = a.width.max(b.width);
+    let results: Vec<Option<(f64, f64)>> = (0..width)
+        .map(|j| test(&position_sample(a, j), &position_sample(b, j)))
+        .collect();
+    single_row_struct(
+        name,
+        vec![
+            ("statistic", results.iter().map(|r| r.map(|r| r.0)).collect()),
+            ("p_value", results.iter().map(|r| r.map(|r| r.1)).collect()),
+        ],
+    )
+}
+
+pub(super) fn mean_var(x: &[f64]) -> (f64, f64) {
+    let n = x.len() as f64;
+    let mean = x.iter().sum::<f64>() / n;
+    let var = x.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+    (mean, var)
+}
+
+/// Two-sample t-test (Welch's by default, Student's with `equal_var`).
+pub(super) fn ttest(a: &[f64], b: &[f64], equal_var: bool) -> Option<(f64, f64)> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let (na, nb) = (a.len() as f64, b.len() as f64);
+    let ((ma, va), (mb, vb)) = (mean_var(a), mean_var(b));
+    let (se, df) = if equal_var {
+        let df = na + nb - 2.0;
+        let pooled = ((na - 1.0) * va + (nb - 1.0) * vb) / df;
+        ((pooled * (1.0 / na + 1.0 / nb)).sqrt(), df)
+    } else {
+        let (qa, qb) = (va / na, vb / nb);
+        let df = (qa + qb).powi(2) / (qa * qa / (na - 1.0) + qb * qb / (nb - 1.0));
+        ((qa + qb).sqrt(), df)
+    };
+    let t = (ma - mb) / se;
+    Some((t, student_t_two_sided_p(t, df)))
+}
+
+/// Mann-Whitney U test of `a` against `b`, returning `U` for `a` and the two-sided p-value
+/// from the tie-corrected normal approximation with continuity correction.
+fn mann_whitney(a: &[f64], b: &[f64]) -> Option<(f64, f64)> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let (na, nb) = (a.len() as f64, b.len() as f64);
+    let mut pooled: Vec<(f64, bool)> = a
+        .iter()
+        .map(|&v| (v, true))
+        .chain(b.iter().map(|&v| (v, false)))
+        .collect();
+    pooled.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    // Average ranks over ties, accumulating the tie correction term
+    let mut rank_sum_a = 0.0;
+    let mut tie_term = 0.0;
+    let mut i = 0;
+    while i < pooled.len() {
+        let mut j = i;
+        while j + 1 < pooled.len() && pooled[j + 1].0 == pooled[i].0 {
+            j += 1;
+        }
+        let rank = (i + j) as f64 / 2.0 + 1.0;
+        let ties = (j - i + 1) as f64;
+        tie_term += ties * ties * ties - ties;
+        rank_sum_a += rank * pooled[i..=j].iter().filter(|p| p.1).count() as f64;
+        i = j + 1;
+    }
+
+    let u_a = rank_sum_a - na * (na + 1.0) / 2.0;
+    let n = na + nb;
+    let mu = na * nb / 2.0;
+    let sigma = (na * nb / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)))).sqrt();
+    let u_max = u_a.max(na * nb - u_a);
+    let p = if sigma > 0.0 {
+        (2.0 * normal_sf((u_max - mu - 0.5) / sigma)).min(1.0)
+    } else {
+        f64::NAN
+    };
+    Some((u_a, p))
+}
+
+/// Per-position two-sample t-test between the rows of two list columns.
+/// inputs[0] = sample a (List/Array), inputs[1] = sample b (List/Array of the same length)
+#[polars_expr(output_type_func=two_sample_output_type)]
+fn list_ttest(inputs: &[Series], kwargs: TTestKwargs) -> PolarsResult<Series> {
+    let (a, b) = two_sample_matrices(inputs, "ttest")?;
+    per_position_test(inputs[0].name().clone(), &a, &b, |a, b| ttest(a, b, kwargs.equal_var))
+}
+
+/// Per-position Mann-Whitney U test between the rows of two list columns.
+#[polars_expr(output_type_func=two_sample_output_type)]
+fn list_mannwhitneyu(inputs: &[Series]) -> PolarsResult<Series> {
+    let (a, b) = two_sample_matrices(inputs, "mannwhitneyu")?;
+    per_position_test(inputs[0].name().clone(), &a, &b, mann_whitney)
+}
+
+#[derive(serde::Deserialize)]
+struct TTest1SampKwargs {
+    popmean: f64,
+}
+
+/// Per-position one-sample t-test of the rows against `popmean`.
+/// Positions with fewer than two finite values are null.
+#[polars_expr(output_type_func=two_sample_output_type)]
+fn list_ttest_1samp(inputs: &[Series], kwargs: TTest1SampKwargs) -> PolarsResult<Series> {
+    let matrix = F64Matrix::from_series(&inputs[0], "ttest_1samp")?;
+    let results: Vec<Option<(f64, f64)>> = (0..matrix.width)
+        .map(|j| {
+            let sample = position_sample(&matrix, j);
+            if sample.len() < 2 {
+                return None;
+            }
+            let n = sample.len() as f64;
+            let (mean, var) = mean_var(&sample);
+            let t = (mean - kwargs.popmean) / (var / n).sqrt();
+            Some((t, student_t_two_sided_p(t, n - 1.0)))
+        })
+        .collect();
+    single_row_struct(
+        inputs[0].name().clone(),
+        vec![
+            ("statistic", results.iter().map(|r| r.map(|r| r.0)).collect()),
+            ("p_value", results.iter().map(|r| r.map(|r| r.1)).collect()),
+        ],
+    )
+}
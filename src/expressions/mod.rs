@@ -1,4 +1,6 @@
 pub mod helpers;
+pub mod distributions;
+pub mod resampling;
 pub mod list_sum;
 pub mod list_mean;
 pub mod list_min;
@@ -7,3 +9,71 @@ pub mod list_diff;
 pub mod list_convolve;
 pub mod histogram;
 pub mod list_clip;
+pub mod vec_zero_crossings;
+pub mod vec_baseline_normalize;
+pub mod vec_detrend;
+pub mod vec_trapz;
+pub mod event_triggered_average;
+pub mod vec_slice_around;
+pub mod vec_rebin_by_time;
+pub mod vec_align;
+pub mod list_transpose;
+pub mod list_stack;
+pub mod vec_matmul;
+pub mod list_cov_matrix;
+pub mod list_trend;
+pub mod list_regress;
+pub mod list_ttest;
+pub mod list_mean_ci;
+pub mod list_permutation_test;
+pub mod list_mann_kendall;
+pub mod list_entropy;
+pub mod list_percentile_rank;
+pub mod list_top_k;
+pub mod list_is_max;
+pub mod vec_compare;
+pub mod vec_where;
+pub mod vec_horizontal;
+pub mod vec_fma;
+pub mod vec_const;
+pub mod list_normalize_vertical;
+pub mod list_cum_stats;
+pub mod list_mean_std;
+pub mod list_cv;
+pub mod list_split_half;
+pub mod list_icc;
+pub mod distance;
+pub mod list_pairwise_distance;
+pub mod vec_distance_to;
+pub mod vec_assign_clusters;
+pub mod vec_corr;
+pub mod vec_fit_metrics;
+pub mod vec_cross;
+pub mod vec_outer;
+pub mod vec_projection;
+pub mod vec_angle;
+pub mod vec_stats;
+pub mod vec_weighted_mean;
+pub mod vec_activations;
+pub mod vec_scale;
+pub mod vec_rank;
+pub mod vec_top_k;
+pub mod vec_sort_by;
+pub mod vec_gather;
+pub mod vec_search_sorted;
+pub mod vec_rle;
+pub mod vec_reverse;
+pub mod vec_roll;
+pub mod vec_reshape;
+pub mod vec_to_array;
+pub mod vec_shape_report;
+pub mod list_ptp;
+pub mod list_abs_max;
+pub mod list_scaled_mean;
+pub mod list_cum_sum_from;
+pub mod vec_delta;
+pub mod vec_quantize;
+pub mod list_group_mean;
+pub mod list_chunked_mean;
+pub mod list_mean_by_time;
+pub mod vec_value_report;
@@ -0,0 +1,34 @@
+//! Shared machinery for the bootstrap and permutation operations.
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Deterministic generator for a given seed, or one seeded from the OS when `seed` is None.
+pub(super) fn seeded_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    }
+}
+
+/// Linearly interpolated quantile `q` in `[0, 1]` of finite, sorted values.
+pub(super) fn sorted_quantile(sorted: &[f64], q: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    Some(sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64))
+}
+
+/// Mean of the finite values in column `j` over `rows` of a row-major matrix.
+pub(super) fn column_mean(
+    values: &[Option<f64>],
+    width: usize,
+    j: usize,
+    rows: impl Iterator<Item = usize>,
+) -> Option<f64> {
+    let (sum, count) = rows
+        .filter_map(|i| values[i * width + j].filter(|v| v.is_finite()))
+        .fold((0.0, 0usize), |(s, c), v| (s + v, c + 1));
+    (count > 0).then(|| sum / count as f64)
+}
@@ -0,0 +1,45 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{float_list_output_type, map_rows_f64};
+
+#[derive(serde::Deserialize)]
+struct SoftmaxKwargs {
+    temperature: f64,
+}
+
+/// Softmax of each row's list, `exp(x / T) / sum(exp(x / T))`. The row maximum is
+/// subtracted first for numerical stability. Null elements stay null and are left out
+/// of the normalization.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_softmax(inputs: &[Series], kwargs: SoftmaxKwargs) -> PolarsResult<Series> {
+    let temperature = kwargs.temperature;
+    if !(temperature.is_finite() && temperature > 0.0) {
+        polars_bail!(ComputeError: "temperature must be a positive finite number, got {}", temperature);
+    }
+    map_rows_f64(&inputs[0], |ca, out| {
+        let max = ca.iter().flatten().fold(f64::NEG_INFINITY, f64::max);
+        out.extend(ca.iter().map(|v| v.map(|v| ((v - max) / temperature).exp())));
+        let total: f64 = out.iter().flatten().sum();
+        out.iter_mut().flatten().for_each(|v| *v /= total);
+        Ok(())
+    })
+}
+
+/// Logistic sigmoid `1 / (1 + exp(-x))` of every element.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_sigmoid(inputs: &[Series]) -> PolarsResult<Series> {
+    map_rows_f64(&inputs[0], |ca, out| {
+        out.extend(ca.iter().map(|v| v.map(|v| 1.0 / (1.0 + (-v).exp()))));
+        Ok(())
+    })
+}
+
+/// Hyperbolic tangent of every element.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_tanh(inputs: &[Series]) -> PolarsResult<Series> {
+    map_rows_f64(&inputs[0], |ca, out| {
+        out.extend(ca.iter().map(|v| v.map(f64::tanh)));
+        Ok(())
+    })
+}
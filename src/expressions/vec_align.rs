@@ -0,0 +1,138 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_list_type, float_list_output_type, list_into_array};
+
+#[derive(serde::Deserialize)]
+struct AlignKwargs {
+    max_lag: usize,
+    return_lag: bool,
+}
+
+fn vec_align_output_type(input_fields: &[Field], kwargs: AlignKwargs) -> PolarsResult<Field> {
+    let name = input_fields[0].name().clone();
+    if kwargs.return_lag {
+        return Ok(Field::new(name, DataType::Int64));
+    }
+    // The aligned output has the shape of b under the name of a
+    let field = float_list_output_type(&input_fields[1..])?;
+    Ok(Field::new(name, field.dtype().clone()))
+}
+
+/// Pearson correlation between `a[i]` and `b[i + lag]` over the overlapping pairs where
+/// both values are finite. Returns `None` when fewer than two pairs overlap or either
+/// side is constant.
+fn lagged_corr(a: &[Option<f64>], b: &[Option<f64>], lag: i64) -> Option<f64> {
+    let (mut n, mut sa, mut sb, mut saa, mut sbb, mut sab) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    for (i, x) in a.iter().enumerate() {
+        let j = i as i64 + lag;
+        if j < 0 || j >= b.len() as i64 {
+            continue;
+        }
+        if let (Some(x), Some(y)) = (*x, b[j as usize]) {
+            if x.is_finite() && y.is_finite() {
+                n += 1.0;
+                sa += x;
+                sb += y;
+                saa += x * x;
+                sbb += y * y;
+                sab += x * y;
+            }
+        }
+    }
+    if n < 2.0 {
+        return None;
+    }
+    let cov = sab - sa * sb / n;
+    let var_a = saa - sa * sa / n;
+    let var_b = sbb - sb * sb / n;
+    if var_a <= 0.0 || var_b <= 0.0 {
+        return None;
+    }
+    Some(cov / (var_a * var_b).sqrt())
+}
+
+/// Lag in `[-max_lag, max_lag]` maximising the correlation, preferring the smallest |lag|
+/// on ties.
+fn best_lag(a: &[Option<f64>], b: &[Option<f64>], max_lag: usize) -> Option<i64> {
+    let mut best: Option<(i64, f64)> = None;
+    for step in 0..=max_lag as i64 {
+        for lag in if step == 0 { vec![0] } else { vec![-step, step] } {
+            if let Some(r) = lagged_corr(a, b, lag) {
+                if best.is_none_or(|(_, best_r)| r > best_r) {
+                    best = Some((lag, r));
+                }
+            }
+        }
+    }
+    best.map(|(lag, _)| lag)
+}
+
+/// Align each row's b list to its a list by cross-correlation.
+/// inputs[0] = reference lists a (List/Array)
+/// inputs[1] = lists b to align (List/Array)
+/// A lag of `k` means `b[i + k]` lines up with `a[i]`; the aligned output is b shifted by the
+/// lag, keeping b's length and filling positions shifted in from outside b with null.
+#[polars_expr(output_type_func_with_kwargs=vec_align_output_type)]
+fn vec_align(inputs: &[Series], kwargs: AlignKwargs) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let a_series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let b_series = ensure_list_type(&inputs[1])?.cast(&f64_list)?;
+    let a_ca = a_series.list()?;
+    let b_ca = b_series.list()?;
+    let n_rows = a_ca.len();
+    if b_ca.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "b must have the same number of rows as a. Expected {}, got {}",
+            n_rows, b_ca.len()
+        );
+    }
+
+    let name = a_series.name().clone();
+    let mut lags: Vec<Option<i64>> = Vec::with_capacity(n_rows);
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        name.clone(),
+        n_rows,
+        b_ca.get_inner().len(),
+        DataType::Float64,
+    );
+    let mut a_buf: Vec<Option<f64>> = Vec::new();
+    let mut b_buf: Vec<Option<f64>> = Vec::new();
+
+    for (a, b) in a_ca.amortized_iter().zip(b_ca.amortized_iter()) {
+        let lag = match (a, b) {
+            (Some(a), Some(b)) => {
+                a_buf.clear();
+                a_buf.extend(a.as_ref().f64()?.iter());
+                b_buf.clear();
+                b_buf.extend(b.as_ref().f64()?.iter());
+                best_lag(&a_buf, &b_buf, kwargs.max_lag)
+            },
+            _ => None,
+        };
+        lags.push(lag);
+        if kwargs.return_lag {
+            continue;
+        }
+        match lag {
+            Some(lag) => {
+                let n = b_buf.len() as i64;
+                builder.append_iter((0..n).map(|i| {
+                    let j = i + lag;
+                    if (0..n).contains(&j) { b_buf[j as usize] } else { None }
+                }));
+            },
+            None => builder.append_null(),
+        }
+    }
+
+    if kwargs.return_lag {
+        return Ok(Int64Chunked::from_iter_options(name, lags.into_iter()).into_series());
+    }
+    let out = builder.finish().into_series();
+    match inputs[1].dtype() {
+        DataType::Array(_, width) => list_into_array(&out, *width),
+        _ => Ok(out),
+    }
+}
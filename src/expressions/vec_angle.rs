@@ -0,0 +1,36 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::distance::cosine_similarity;
+use super::helpers::{ensure_list_type, float_scalar_output_type, for_each_row_pair};
+
+#[derive(serde::Deserialize)]
+struct AngleKwargs {
+    degrees: bool,
+}
+
+/// Angle between the two row vectors, in radians (or degrees).
+/// inputs[0] = a (List/Array)
+/// inputs[1] = b (List/Array), with one row per row of `a` or a single row used for all
+/// Rows where either vector is null or holds a null element are null; a zero vector
+/// gives NaN.
+#[polars_expr(output_type_func=float_scalar_output_type)]
+fn vec_angle(inputs: &[Series], kwargs: AngleKwargs) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let a_series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let b_series = ensure_list_type(&inputs[1])?.cast(&f64_list)?;
+    let mut out: Vec<Option<f64>> = Vec::with_capacity(a_series.len());
+    for_each_row_pair(&a_series, &b_series, "angle", |pair| {
+        let Some((a, b)) = pair else {
+            out.push(None);
+            return Ok(());
+        };
+        let a: Vec<Option<f64>> = a.iter().collect();
+        let b: Vec<Option<f64>> = b.iter().collect();
+        // Clamp so rounding just outside [-1, 1] still gives 0 or pi
+        let angle = cosine_similarity(&a, &b).map(|c| c.clamp(-1.0, 1.0).acos());
+        out.push(angle.map(|r| if kwargs.degrees { r.to_degrees() } else { r }));
+        Ok(())
+    })?;
+    Ok(Float64Chunked::from_iter_options(a_series.name().clone(), out.into_iter()).into_series())
+}
@@ -0,0 +1,79 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::distance::Metric;
+use super::helpers::ensure_list_type;
+
+#[derive(serde::Deserialize)]
+struct AssignClustersKwargs {
+    /// The k centroid vectors, each with one value per list position.
+    centroids: Vec<Vec<f64>>,
+    metric: Metric,
+}
+
+fn vec_assign_clusters_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {
+            Ok(Field::new(field.name().clone(), DataType::UInt32))
+        },
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Index of the nearest centroid to each row's list (k-means style inference).
+/// Ties go to the lower index. Null rows, rows holding a null element and rows whose
+/// distances are all NaN get a null label.
+#[polars_expr(output_type_func=vec_assign_clusters_output_type)]
+fn vec_assign_clusters(
+    inputs: &[Series],
+    kwargs: AssignClustersKwargs,
+) -> PolarsResult<Series> {
+    let Some(width) = kwargs.centroids.first().map(Vec::len) else {
+        polars_bail!(ComputeError: "assign_clusters needs at least one centroid");
+    };
+    if let Some(c) = kwargs.centroids.iter().find(|c| c.len() != width) {
+        polars_bail!(
+            ComputeError:
+            "All centroids must have the same length. Expected {}, got {}", width, c.len()
+        );
+    }
+    let centroids: Vec<Vec<Option<f64>>> = kwargs
+        .centroids
+        .iter()
+        .map(|c| c.iter().copied().map(Some).collect())
+        .collect();
+
+    let series =
+        ensure_list_type(&inputs[0])?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = series.list()?;
+    let mut labels: Vec<Option<u32>> = Vec::with_capacity(list_chunked.len());
+    let mut values: Vec<Option<f64>> = Vec::with_capacity(width);
+    for (i, row) in list_chunked.amortized_iter().enumerate() {
+        let Some(row) = row else {
+            labels.push(None);
+            continue;
+        };
+        values.clear();
+        values.extend(row.as_ref().f64()?.iter());
+        if values.len() != width {
+            polars_bail!(
+                ComputeError:
+                "centroids must have the same length as the lists for assign_clusters. Row {}: expected {}, got {}",
+                i, width, values.len()
+            );
+        }
+        let mut best: Option<(u32, f64)> = None;
+        for (k, centroid) in centroids.iter().enumerate() {
+            match kwargs.metric.distance(&values, centroid) {
+                Some(d) if !d.is_nan() && best.is_none_or(|(_, b)| d < b) => {
+                    best = Some((k as u32, d))
+                },
+                _ => {},
+            }
+        }
+        labels.push(best.map(|(k, _)| k));
+    }
+
+    Ok(UInt32Chunked::from_iter_options(series.name().clone(), labels.into_iter()).into_series())
+}
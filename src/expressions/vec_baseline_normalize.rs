@@ -0,0 +1,60 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{float_list_output_type, map_rows_f64, resolve_slice};
+
+/// How each value is scaled against the baseline window's statistics.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum BaselineMode {
+    /// `v - mean`
+    Subtract,
+    /// `(v - mean) / mean`
+    Divide,
+    /// `(v - mean) / std`
+    Zscore,
+}
+
+#[derive(serde::Deserialize)]
+struct BaselineNormalizeKwargs {
+    baseline_start: i64,
+    baseline_end: Option<i64>,
+    mode: BaselineMode,
+}
+
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_baseline_normalize(
+    inputs: &[Series],
+    kwargs: BaselineNormalizeKwargs,
+) -> PolarsResult<Series> {
+    map_rows_f64(&inputs[0], |ca, out| {
+        let (lo, hi) = resolve_slice(kwargs.baseline_start, kwargs.baseline_end, ca.len());
+
+        // Baseline statistics from the finite, non-null values in the window
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        let mut count = 0usize;
+        for v in ca.slice(lo as i64, hi - lo).iter().flatten() {
+            if v.is_finite() {
+                sum += v;
+                sum_sq += v * v;
+                count += 1;
+            }
+        }
+        let mean = if count > 0 { sum / count as f64 } else { f64::NAN };
+        let std = if count > 0 {
+            (sum_sq / count as f64 - mean * mean).max(0.0).sqrt()
+        } else {
+            f64::NAN
+        };
+
+        out.extend(ca.iter().map(|opt| {
+            opt.map(|v| match kwargs.mode {
+                BaselineMode::Subtract => v - mean,
+                BaselineMode::Divide => (v - mean) / mean,
+                BaselineMode::Zscore => (v - mean) / std,
+            })
+        }));
+        Ok(())
+    })
+}
@@ -0,0 +1,139 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::Array;
+use polars_core::utils::try_get_supertype;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    broadcast_scalar, ensure_contiguous_list, list_from_flat_values, list_into_array,
+    operand_inner_dtype, single_list_array,
+};
+
+fn compare_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(Box::new(DataType::Boolean)),
+        )),
+        DataType::Array(_, width) => Ok(Field::new(
+            field.name().clone(),
+            DataType::Array(Box::new(DataType::Boolean), *width),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Element-wise comparison of each row's list against the other input.
+/// inputs[0] = left lists (List/Array)
+/// inputs[1] = right lists of the same length per row, or a numeric column/literal broadcast
+///             across each row
+/// Both sides are cast to their supertype and compared with the polars kernels, so integers
+/// compare exactly and NaN sorts like in polars: equal to itself and above every number.
+/// Nulls on either side give null.
+fn compare(
+    inputs: &[Series],
+    op_name: &str,
+    op: impl Fn(&Series, &Series) -> PolarsResult<BooleanChunked>,
+) -> PolarsResult<Series> {
+    let inner = try_get_supertype(
+        operand_inner_dtype(inputs[0].dtype())?,
+        operand_inner_dtype(inputs[1].dtype())?,
+    )?;
+    let list_dtype = DataType::List(Box::new(inner));
+    let left_series = ensure_contiguous_list(&inputs[0])?.cast(&list_dtype)?.rechunk();
+    let left = left_series.list()?;
+    let n_rows = left.len();
+
+    let right_series = broadcast_scalar(&inputs[1], left)?.cast(&list_dtype)?.rechunk();
+    let right = right_series.list()?;
+    if right.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "Both inputs must have the same number of rows for {}. Expected {}, got {}",
+            op_name, n_rows, right.len()
+        );
+    }
+
+    // Compare the flat values in runs of rows that sit back to back on both sides, so a
+    // column without nulls is compared in one kernel call.
+    let (left_arr, right_arr) = (single_list_array(left), single_list_array(right));
+    let (left_inner, right_inner) = (left.get_inner(), right.get_inner());
+    let left_offsets = left_arr.offsets().as_slice();
+    let right_offsets = right_arr.offsets().as_slice();
+    let mut out = BooleanChunked::full_null(PlSmallStr::EMPTY, 0);
+    let mut flush = |l_start: usize, r_start: usize, len: usize| -> PolarsResult<()> {
+        if len > 0 {
+            let (l, r) = (left_inner.slice(l_start as i64, len), right_inner.slice(r_start as i64, len));
+            out.append(&op(&l, &r)?)?;
+        }
+        Ok(())
+    };
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    let mut validity: Vec<bool> = Vec::with_capacity(n_rows);
+    let (mut run_left, mut run_right, mut run_len) = (0usize, 0usize, 0usize);
+    for row in 0..n_rows {
+        let valid = left_arr.is_valid(row) && right_arr.is_valid(row);
+        validity.push(valid);
+        if !valid {
+            offsets.push(*offsets.last().unwrap());
+            continue;
+        }
+        let (l_start, l_end) = (left_offsets[row] as usize, left_offsets[row + 1] as usize);
+        let (r_start, r_end) = (right_offsets[row] as usize, right_offsets[row + 1] as usize);
+        if l_end - l_start != r_end - r_start {
+            polars_bail!(
+                ComputeError:
+                "Lists must have the same length for {}. Row {}: {} vs {}",
+                op_name, row, l_end - l_start, r_end - r_start
+            );
+        }
+        if l_start != run_left + run_len || r_start != run_right + run_len {
+            flush(run_left, run_right, run_len)?;
+            (run_left, run_right, run_len) = (l_start, r_start, 0);
+        }
+        run_len += l_end - l_start;
+        offsets.push(offsets.last().unwrap() + (l_end - l_start) as i64);
+    }
+    flush(run_left, run_right, run_len)?;
+
+    let validity = validity
+        .contains(&false)
+        .then(|| polars_arrow::bitmap::Bitmap::from_iter(validity));
+    let result_series =
+        list_from_flat_values(inputs[0].name().clone(), &out.into_series(), offsets, validity)?;
+    match inputs[0].dtype() {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
+
+#[polars_expr(output_type_func=compare_output_type)]
+fn vec_eq(inputs: &[Series]) -> PolarsResult<Series> {
+    compare(inputs, "eq", |a, b| a.equal(b))
+}
+
+#[polars_expr(output_type_func=compare_output_type)]
+fn vec_ne(inputs: &[Series]) -> PolarsResult<Series> {
+    compare(inputs, "ne", |a, b| a.not_equal(b))
+}
+
+#[polars_expr(output_type_func=compare_output_type)]
+fn vec_gt(inputs: &[Series]) -> PolarsResult<Series> {
+    compare(inputs, "gt", |a, b| a.gt(b))
+}
+
+#[polars_expr(output_type_func=compare_output_type)]
+fn vec_ge(inputs: &[Series]) -> PolarsResult<Series> {
+    compare(inputs, "ge", |a, b| a.gt_eq(b))
+}
+
+#[polars_expr(output_type_func=compare_output_type)]
+fn vec_lt(inputs: &[Series]) -> PolarsResult<Series> {
+    compare(inputs, "lt", |a, b| a.lt(b))
+}
+
+#[polars_expr(output_type_func=compare_output_type)]
+fn vec_le(inputs: &[Series]) -> PolarsResult<Series> {
+    compare(inputs, "le", |a, b| a.lt_eq(b))
+}
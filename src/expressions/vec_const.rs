@@ -0,0 +1,43 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{float_list_output_type, map_rows_f64};
+
+#[derive(serde::Deserialize)]
+struct ConstVectorKwargs {
+    /// Constant vector applied position-wise to every row; its length must match each row.
+    values: Vec<f64>,
+}
+
+/// Combine every row's list position-wise with the constant vector from kwargs.
+/// Null rows and null elements stay null.
+fn apply_const(
+    series: &Series,
+    values: &[f64],
+    op_name: &str,
+    op: impl Fn(f64, f64) -> f64,
+) -> PolarsResult<Series> {
+    map_rows_f64(series, |ca, out| {
+        if ca.len() != values.len() {
+            polars_bail!(
+                ComputeError:
+                "values must have the same length as the lists for {}. Expected {}, got {}",
+                op_name, values.len(), ca.len()
+            );
+        }
+        out.extend(ca.iter().zip(values).map(|(v, &c)| v.map(|v| op(v, c))));
+        Ok(())
+    })
+}
+
+/// Add a constant vector (e.g. per-channel offsets) element-wise to each row.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_add_const(inputs: &[Series], kwargs: ConstVectorKwargs) -> PolarsResult<Series> {
+    apply_const(&inputs[0], &kwargs.values, "add_const", |v, c| v + c)
+}
+
+/// Multiply each row element-wise by a constant vector (e.g. per-channel gains).
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_mul_const(inputs: &[Series], kwargs: ConstVectorKwargs) -> PolarsResult<Series> {
+    apply_const(&inputs[0], &kwargs.values, "mul_const", |v, c| v * c)
+}
@@ -0,0 +1,42 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{float_scalar_output_type, map_row_pairs_f64, pearson};
+use super::vec_rank::{rank, RankMethod};
+
+/// Which correlation coefficient `vec_corr` computes.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum CorrMethod {
+    Pearson,
+    /// Pearson correlation of the ranks.
+    Spearman,
+}
+
+#[derive(serde::Deserialize)]
+struct CorrKwargs {
+    method: CorrMethod,
+}
+
+/// Correlation between the two lists within each row, over the positions where both are
+/// non-null. Rows with fewer than two such positions are null; a constant list or a NaN
+/// gives NaN, as does an infinite value for Pearson (Spearman just ranks it highest).
+/// inputs[0] = a (List/Array)
+/// inputs[1] = b (List/Array, same length per row)
+#[polars_expr(output_type_func=float_scalar_output_type)]
+fn vec_corr(inputs: &[Series], kwargs: CorrKwargs) -> PolarsResult<Series> {
+    let spearman = kwargs.method == CorrMethod::Spearman;
+    map_row_pairs_f64(inputs, "corr", |pairs| {
+        if !spearman {
+            return pearson(pairs.iter().copied());
+        }
+        // Ranking would turn a NaN into an ordinary rank, so propagate it here
+        if pairs.len() >= 2 && pairs.iter().any(|(x, y)| x.is_nan() || y.is_nan()) {
+            return Some(f64::NAN);
+        }
+        let (x, y): (Vec<f64>, Vec<f64>) = pairs.iter().copied().unzip();
+        let rx = rank(&x, RankMethod::Average, false);
+        let ry = rank(&y, RankMethod::Average, false);
+        pearson(rx.into_iter().zip(ry))
+    })
+}
@@ -0,0 +1,64 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::bitmap::Bitmap;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::array_from_flat_values;
+
+fn vec_cross_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    for field in &input_fields[..2] {
+        match field.dtype() {
+            DataType::Array(inner, 3) if inner.is_primitive_numeric() => {},
+            dt => polars_bail!(
+                InvalidOperation:
+                "cross requires numeric Array(_, 3) columns, got {:?} for '{}'", dt, field.name()
+            ),
+        }
+    }
+    let field = &input_fields[0];
+    Ok(Field::new(field.name().clone(), DataType::Array(Box::new(DataType::Float64), 3)))
+}
+
+/// Row validity and flat Float64 values (three per row) of a width-3 Array column.
+fn vectors3(series: &Series) -> PolarsResult<(BooleanChunked, Float64Chunked)> {
+    let series = series.cast(&DataType::Array(Box::new(DataType::Float64), 3))?.rechunk();
+    let values = series.array()?.get_inner().f64()?.clone();
+    Ok((series.is_not_null(), values))
+}
+
+/// 3D cross product `a x b` per row.
+/// inputs[0] = a (Array(_, 3))
+/// inputs[1] = b (Array(_, 3)), with one row per row of `a` or a single row used for all
+/// A null row in either input gives a null row, and a null element nulls the components
+/// that depend on it.
+#[polars_expr(output_type_func=vec_cross_output_type)]
+fn vec_cross(inputs: &[Series]) -> PolarsResult<Series> {
+    let (a_valid, a) = vectors3(&inputs[0])?;
+    let (b_valid, b) = vectors3(&inputs[1])?;
+    let n_rows = a_valid.len();
+    if b_valid.len() != n_rows && b_valid.len() != 1 {
+        polars_bail!(
+            ComputeError:
+            "b must have one row or the same number of rows as a for cross. Expected 1 or {}, got {}",
+            n_rows, b_valid.len()
+        );
+    }
+
+    let mut out: Vec<Option<f64>> = Vec::with_capacity(3 * n_rows);
+    let mut validity: Vec<bool> = Vec::with_capacity(n_rows);
+    for i in 0..n_rows {
+        let j = if b_valid.len() == 1 { 0 } else { i };
+        let valid = a_valid.get(i) == Some(true) && b_valid.get(j) == Some(true);
+        validity.push(valid);
+        if !valid {
+            out.extend([None; 3]);
+            continue;
+        }
+        let (x, y) = (|k: usize| a.get(3 * i + k), |k: usize| b.get(3 * j + k));
+        let component = |p: usize, q: usize| Some(x(p)? * y(q)? - x(q)? * y(p)?);
+        out.extend([component(1, 2), component(2, 0), component(0, 1)]);
+    }
+
+    let values = Float64Chunked::from_iter_options("".into(), out.into_iter()).into_series();
+    let validity = (!validity.iter().all(|&v| v)).then(|| Bitmap::from_iter(validity));
+    array_from_flat_values(inputs[0].name().clone(), &values, 3, n_rows, validity)
+}
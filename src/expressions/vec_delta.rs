@@ -0,0 +1,152 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_list_type, list_into_array};
+
+#[derive(serde::Deserialize)]
+struct DeltaKwargs {
+    /// Element dtype to cast the result to, e.g. `Int16` to narrow the deltas of a smooth
+    /// integer trace. `None` keeps Int64 for integer input and Float64 for float input.
+    dtype: Option<DeltaDtype>,
+}
+
+/// Element dtype the result can be cast to.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum DeltaDtype {
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    #[serde(rename = "uint32")]
+    UInt32,
+    #[serde(rename = "uint64")]
+    UInt64,
+    Float32,
+    Float64,
+}
+
+impl DeltaDtype {
+    fn dtype(self) -> DataType {
+        match self {
+            DeltaDtype::Int8 => DataType::Int8,
+            DeltaDtype::Int16 => DataType::Int16,
+            DeltaDtype::Int32 => DataType::Int32,
+            DeltaDtype::Int64 => DataType::Int64,
+            DeltaDtype::UInt32 => DataType::UInt32,
+            DeltaDtype::UInt64 => DataType::UInt64,
+            DeltaDtype::Float32 => DataType::Float32,
+            DeltaDtype::Float64 => DataType::Float64,
+        }
+    }
+}
+
+/// Dtype the codec computes in: Int64 for integers (exact), Float64 for floats.
+fn work_dtype(inner: &DataType) -> PolarsResult<DataType> {
+    match inner {
+        dt if dt.is_integer() => Ok(DataType::Int64),
+        dt if dt.is_float() => Ok(DataType::Float64),
+        dt => polars_bail!(InvalidOperation: "Expected numeric list elements, got {:?}", dt),
+    }
+}
+
+fn delta_output_type(input_fields: &[Field], kwargs: DeltaKwargs) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let (DataType::List(inner) | DataType::Array(inner, _)) = field.dtype() else {
+        polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", field.dtype());
+    };
+    let out = match kwargs.dtype {
+        Some(dtype) => dtype.dtype(),
+        None => work_dtype(inner)?,
+    };
+    let dtype = match field.dtype() {
+        DataType::Array(_, width) => DataType::Array(Box::new(out), *width),
+        _ => DataType::List(Box::new(out)),
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Run `step` over the elements of each row in order, threading a state that starts at zero
+/// for every row. Null elements stay null and leave the state untouched.
+fn scan_rows<T: PolarsNumericType>(
+    list_chunked: &ListChunked,
+    step: impl Fn(&mut T::Native, T::Native) -> T::Native,
+) -> PolarsResult<Series> {
+    let n_values = list_chunked.get_inner().len();
+    let mut builder = ListPrimitiveChunkedBuilder::<T>::new(
+        list_chunked.name().clone(),
+        list_chunked.len(),
+        n_values,
+        T::get_static_dtype(),
+    );
+    let mut out: Vec<Option<T::Native>> = Vec::new();
+    for row in list_chunked.amortized_iter() {
+        let Some(row) = row else {
+            builder.append_null();
+            continue;
+        };
+        let mut state = T::Native::default();
+        out.clear();
+        out.extend(row.as_ref().unpack::<T>()?.iter().map(|v| v.map(|v| step(&mut state, v))));
+        builder.append_iter(out.iter().copied());
+    }
+    Ok(builder.finish().into_series())
+}
+
+/// Shared driver of the codecs: scan every row in the work dtype, then cast to the requested
+/// dtype and back to Array for Array input. Both casts fail if a value does not fit, so a
+/// UInt64 element above `i64::MAX` raises instead of turning null.
+fn delta_codec(
+    series: &Series,
+    kwargs: &DeltaKwargs,
+    step_int: impl Fn(&mut i64, i64) -> i64,
+    step_float: impl Fn(&mut f64, f64) -> f64,
+) -> PolarsResult<Series> {
+    let input_dtype = series.dtype().clone();
+    let list_series = ensure_list_type(series)?;
+    let work = work_dtype(list_series.list()?.inner_dtype())?;
+    let list_series = list_series.strict_cast(&DataType::List(Box::new(work.clone())))?;
+    let list_chunked = list_series.list()?;
+    let mut result = match work {
+        DataType::Int64 => scan_rows::<Int64Type>(list_chunked, step_int)?,
+        _ => scan_rows::<Float64Type>(list_chunked, step_float)?,
+    };
+    if let Some(dtype) = kwargs.dtype {
+        result = result.strict_cast(&DataType::List(Box::new(dtype.dtype())))?;
+    }
+    match &input_dtype {
+        DataType::Array(_, width) => list_into_array(&result, *width),
+        _ => Ok(result),
+    }
+}
+
+/// Delta-encode each row's list along the list axis: the first non-null element is kept and
+/// every later one is replaced by its difference from the previous non-null element. Null
+/// elements stay null, so `vec_delta_decode` reverses the encoding exactly for integers.
+#[polars_expr(output_type_func_with_kwargs=delta_output_type)]
+fn vec_delta_encode(inputs: &[Series], kwargs: DeltaKwargs) -> PolarsResult<Series> {
+    delta_codec(
+        &inputs[0],
+        &kwargs,
+        |prev, v| v.wrapping_sub(std::mem::replace(prev, v)),
+        |prev, v| v - std::mem::replace(prev, v),
+    )
+}
+
+/// Decode `vec_delta_encode` output: the running sum of each row's non-null elements, with
+/// null elements left null.
+#[polars_expr(output_type_func_with_kwargs=delta_output_type)]
+fn vec_delta_decode(inputs: &[Series], kwargs: DeltaKwargs) -> PolarsResult<Series> {
+    delta_codec(
+        &inputs[0],
+        &kwargs,
+        |sum, d| {
+            *sum = sum.wrapping_add(d);
+            *sum
+        },
+        |sum, d| {
+            *sum += d;
+            *sum
+        },
+    )
+}
@@ -0,0 +1,74 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, float_list_output_type, map_rows_f64, ols_fit, F64Matrix};
+
+/// The trend removed from each list (or position).
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum DetrendMethod {
+    /// The mean.
+    Constant,
+    /// The least-squares line.
+    Linear,
+}
+
+#[derive(serde::Deserialize)]
+struct DetrendKwargs {
+    method: DetrendMethod,
+    axis: i64,
+}
+
+/// Fit the trend to remove, returning `(intercept, slope)` over the index `x`. A linear
+/// fit with fewer than two finite points removes just their mean, like `Constant`.
+fn fit_trend(method: DetrendMethod, points: impl Iterator<Item = (f64, Option<f64>)>) -> (f64, f64) {
+    let points: Vec<(f64, f64)> = points.filter_map(|(x, y)| Some((x, y?))).collect();
+    if method == DetrendMethod::Linear {
+        if let Some(fit) = ols_fit(points.iter().copied()) {
+            return (fit.intercept, fit.slope);
+        }
+    }
+    let (sum, count) = points
+        .iter()
+        .filter(|(_, y)| y.is_finite())
+        .fold((0.0, 0usize), |(s, c), (_, y)| (s + y, c + 1));
+    let mean = if count > 0 { sum / count as f64 } else { f64::NAN };
+    (mean, 0.0)
+}
+
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_detrend(inputs: &[Series], kwargs: DetrendKwargs) -> PolarsResult<Series> {
+    let method = kwargs.method;
+    match kwargs.axis {
+        // Within each list: x is the element index
+        1 | -1 => map_rows_f64(&inputs[0], |ca, out| {
+            let (intercept, slope) =
+                fit_trend(method, ca.iter().enumerate().map(|(i, v)| (i as f64, v)));
+            out.extend(
+                ca.iter()
+                    .enumerate()
+                    .map(|(i, v)| v.map(|v| v - (intercept + slope * i as f64))),
+            );
+            Ok(())
+        }),
+        // Across rows at each position: x is the row index, null rows are excluded
+        0 => {
+            let series = &inputs[0];
+            let matrix = F64Matrix::from_series(series, "detrend")?;
+            let mut values = matrix.values.clone();
+            for j in 0..matrix.width {
+                let (intercept, slope) =
+                    fit_trend(method, (0..matrix.n_rows).map(|i| (i as f64, matrix.get(i, j))));
+                for i in 0..matrix.n_rows {
+                    let v = &mut values[i * matrix.width + j];
+                    *v = v.map(|v| v - (intercept + slope * i as f64));
+                }
+            }
+            let rows = (0..matrix.n_rows).map(|i| {
+                matrix.row_valid[i].then(|| &values[i * matrix.width..(i + 1) * matrix.width])
+            });
+            build_f64_list(series.name().clone(), matrix.n_rows, rows, series.dtype())
+        },
+        axis => polars_bail!(ComputeError: "Invalid axis {}. Must be 0 (across rows) or 1 (within lists)", axis),
+    }
+}
@@ -0,0 +1,77 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::distance::Metric;
+use super::helpers::ensure_list_type;
+
+#[derive(serde::Deserialize)]
+struct DistanceToKwargs {
+    metric: Metric,
+    /// Constant reference vector; when absent the reference comes from inputs[1].
+    reference: Option<Vec<f64>>,
+}
+
+fn vec_distance_to_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {
+            Ok(Field::new(field.name().clone(), DataType::Float64))
+        },
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Distance from each row's list to a reference vector.
+/// inputs[0] = vectors (List/Array)
+/// inputs[1] = optional reference (List/Array): one row used for every row (e.g. a vertical
+///             mean), or one reference per row; otherwise `kwargs.reference` is used
+/// Null rows, null references and rows holding a null element give null.
+#[polars_expr(output_type_func=vec_distance_to_output_type)]
+fn vec_distance_to(inputs: &[Series], kwargs: DistanceToKwargs) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let list_chunked = series.list()?;
+    let n_rows = list_chunked.len();
+
+    let references: Vec<Option<Vec<Option<f64>>>> = match (&kwargs.reference, inputs.get(1)) {
+        (Some(values), _) => vec![Some(values.iter().copied().map(Some).collect())],
+        (None, Some(reference)) => {
+            let reference = ensure_list_type(reference)?.cast(&f64_list)?;
+            let reference = reference.list()?;
+            if reference.len() != 1 && reference.len() != n_rows {
+                polars_bail!(
+                    ComputeError:
+                    "reference must have one row or the same number of rows as the vectors. Expected 1 or {}, got {}",
+                    n_rows, reference.len()
+                );
+            }
+            reference
+                .amortized_iter()
+                .map(|r| r.map(|r| r.as_ref().f64().map(|ca| ca.iter().collect())).transpose())
+                .collect::<PolarsResult<_>>()?
+        },
+        (None, None) => polars_bail!(ComputeError: "distance_to needs a reference vector"),
+    };
+
+    let mut out: Vec<Option<f64>> = Vec::with_capacity(n_rows);
+    let mut values: Vec<Option<f64>> = Vec::new();
+    for (i, row) in list_chunked.amortized_iter().enumerate() {
+        let reference = &references[if references.len() == 1 { 0 } else { i }];
+        let (Some(row), Some(reference)) = (row, reference) else {
+            out.push(None);
+            continue;
+        };
+        values.clear();
+        values.extend(row.as_ref().f64()?.iter());
+        if values.len() != reference.len() {
+            polars_bail!(
+                ComputeError:
+                "reference must have the same length as the lists for distance_to. Row {}: expected {}, got {}",
+                i, reference.len(), values.len()
+            );
+        }
+        out.push(kwargs.metric.distance(&values, reference));
+    }
+
+    Ok(Float64Chunked::from_iter_options(series.name().clone(), out.into_iter()).into_series())
+}
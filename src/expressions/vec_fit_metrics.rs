@@ -0,0 +1,48 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{float_scalar_output_type, map_row_pairs_f64};
+
+/// Mean of `f(a, b)` over the pairs, or `None` when there are none.
+fn mean_of(pairs: &[(f64, f64)], f: impl Fn(f64, f64) -> f64) -> Option<f64> {
+    (!pairs.is_empty())
+        .then(|| pairs.iter().map(|&(a, b)| f(a, b)).sum::<f64>() / pairs.len() as f64)
+}
+
+/// Root-mean-square error between the two lists within each row, over the positions
+/// where both are non-null. Rows without such positions are null; NaN and infinite values
+/// propagate.
+/// inputs[0] = a (List/Array)
+/// inputs[1] = b (List/Array, or a numeric column/literal broadcast across each row)
+#[polars_expr(output_type_func=float_scalar_output_type)]
+fn vec_rmse(inputs: &[Series]) -> PolarsResult<Series> {
+    map_row_pairs_f64(inputs, "rmse", |pairs| {
+        mean_of(pairs, |a, b| (a - b).powi(2)).map(f64::sqrt)
+    })
+}
+
+/// Mean absolute error between the two lists within each row, over the positions where
+/// both are non-null. Rows without such positions are null; NaN and infinite values
+/// propagate.
+/// inputs[0] = a (List/Array)
+/// inputs[1] = b (List/Array, or a numeric column/literal broadcast across each row)
+#[polars_expr(output_type_func=float_scalar_output_type)]
+fn vec_mae(inputs: &[Series]) -> PolarsResult<Series> {
+    map_row_pairs_f64(inputs, "mae", |pairs| mean_of(pairs, |a, b| (a - b).abs()))
+}
+
+/// Coefficient of determination `1 - SS_res / SS_tot` of predictions `b` for observations
+/// `a` within each row, over the positions where both are non-null. Rows without such
+/// positions are null; NaN and infinite values propagate, and constant observations give
+/// NaN.
+/// inputs[0] = a, observed (List/Array)
+/// inputs[1] = b, predicted (List/Array, or a numeric column/literal broadcast across each row)
+#[polars_expr(output_type_func=float_scalar_output_type)]
+fn vec_r2(inputs: &[Series]) -> PolarsResult<Series> {
+    map_row_pairs_f64(inputs, "r2", |pairs| {
+        let mean = mean_of(pairs, |a, _| a)?;
+        let ss_res: f64 = pairs.iter().map(|(a, b)| (a - b).powi(2)).sum();
+        let ss_tot: f64 = pairs.iter().map(|(a, _)| (a - mean).powi(2)).sum();
+        Some(if ss_tot > 0.0 { 1.0 - ss_res / ss_tot } else { f64::NAN })
+    })
+}
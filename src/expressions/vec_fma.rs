@@ -0,0 +1,66 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{broadcast_scalar, ensure_list_type, float_list_output_type, list_into_array};
+
+/// Fused multiply-add `a * b + c` per element, in a single pass over the three inputs.
+/// inputs[0] = a (List/Array)
+/// inputs[1] = b (List/Array, or a numeric column/literal broadcast across each row)
+/// inputs[2] = c (List/Array, or a numeric column/literal broadcast across each row)
+/// All lists must have the same length in each row. A null row in any input gives a
+/// null row, and a null element in any input gives a null element.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_fma(inputs: &[Series]) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let a_series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let a_ca = a_series.list()?;
+    let b_series = broadcast_scalar(&inputs[1], a_ca)?.cast(&f64_list)?;
+    let c_series = broadcast_scalar(&inputs[2], a_ca)?.cast(&f64_list)?;
+    let (b_ca, c_ca) = (b_series.list()?, c_series.list()?);
+    let n_rows = a_ca.len();
+    if b_ca.len() != n_rows || c_ca.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "All inputs must have the same number of rows for fma. Expected {}, got {} and {}",
+            n_rows, b_ca.len(), c_ca.len()
+        );
+    }
+
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        a_series.name().clone(),
+        n_rows,
+        a_ca.get_inner().len(),
+        DataType::Float64,
+    );
+    for (row, ((a, b), c)) in a_ca
+        .amortized_iter()
+        .zip(b_ca.amortized_iter())
+        .zip(c_ca.amortized_iter())
+        .enumerate()
+    {
+        let (Some(a), Some(b), Some(c)) = (a, b, c) else {
+            builder.append_null();
+            continue;
+        };
+        let (a, b, c) = (a.as_ref().f64()?, b.as_ref().f64()?, c.as_ref().f64()?);
+        if b.len() != a.len() || c.len() != a.len() {
+            polars_bail!(
+                ComputeError:
+                "Lists must have the same length for fma. Row {}: a has {}, b has {}, c has {}",
+                row, a.len(), b.len(), c.len()
+            );
+        }
+        builder.append_iter(
+            a.iter()
+                .zip(b.iter())
+                .zip(c.iter())
+                .map(|((a, b), c)| Some(a?.mul_add(b?, c?))),
+        );
+    }
+
+    let out = builder.finish().into_series();
+    match inputs[0].dtype() {
+        DataType::Array(_, width) => list_into_array(&out, *width),
+        _ => Ok(out),
+    }
+}
@@ -0,0 +1,216 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::{Array, ListArray, PrimitiveArray};
+use polars_arrow::bitmap::MutableBitmap;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    ensure_contiguous_list, list_from_flat_values, list_into_array, single_list_array,
+};
+
+/// What an out-of-bounds index does.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum OutOfBounds {
+    /// Raise an error naming the index and row.
+    Raise,
+    /// Give a null element.
+    Null,
+    /// Take the nearest end of the row (null for an empty row).
+    Clamp,
+}
+
+#[derive(serde::Deserialize)]
+struct GatherKwargs {
+    oob: OutOfBounds,
+}
+
+/// Position of `index` in a row of length `len`, with negative indices counting from the
+/// end; `None` if it falls outside the row.
+fn resolve_index(index: i64, len: i64) -> Option<i64> {
+    let pos = if index < 0 { index + len } else { index };
+    (0..len).contains(&pos).then_some(pos)
+}
+
+/// Index lists cast to Int64 in one contiguous chunk, checked against the values' row count.
+fn index_lists(series: &Series, n_rows: usize) -> PolarsResult<Series> {
+    let series = ensure_contiguous_list(series)?
+        .cast(&DataType::List(Box::new(DataType::Int64)))?
+        .rechunk();
+    if series.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "indices must have the same number of rows as the values. Expected {}, got {}",
+            n_rows, series.len()
+        );
+    }
+    Ok(series)
+}
+
+/// The flat Int64 buffer behind an index list array.
+fn flat_indices(indices_arr: &ListArray<i64>) -> &PrimitiveArray<i64> {
+    indices_arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .expect("indices were cast to Int64")
+}
+
+fn vec_gather_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(inner.clone()),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Take elements of each row's values list at the positions in the same row's indices list.
+/// inputs[0] = values (List/Array, any inner type)
+/// inputs[1] = indices (integer List/Array, any length per row)
+/// Negative indices count from the end of the row. Out-of-bounds indices raise, become
+/// null, or are clamped to the row, depending on `oob`. Null indices give null elements;
+/// a null values or indices row gives a null row.
+#[polars_expr(output_type_func=vec_gather_output_type)]
+fn vec_gather(inputs: &[Series], kwargs: GatherKwargs) -> PolarsResult<Series> {
+    let series = ensure_contiguous_list(&inputs[0])?;
+    let list_chunked = series.list()?;
+    let values_arr = single_list_array(list_chunked);
+    let n_rows = list_chunked.len();
+    let indices_series = index_lists(&inputs[1], n_rows)?;
+    let indices_arr = single_list_array(indices_series.list()?);
+    let indices = flat_indices(indices_arr);
+
+    let values_offsets = values_arr.offsets();
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    let mut take_idx: Vec<Option<IdxSize>> = Vec::with_capacity(indices.len());
+    let mut validity = MutableBitmap::with_capacity(n_rows);
+    for i in 0..n_rows {
+        let valid = values_arr.is_valid(i) && indices_arr.is_valid(i);
+        if valid {
+            let start = values_offsets[i];
+            let len = values_offsets[i + 1] - start;
+            let (lo, hi) = indices_arr.offsets().start_end(i);
+            for k in lo..hi {
+                let Some(index) = indices.get(k) else {
+                    take_idx.push(None);
+                    continue;
+                };
+                let pos = match (resolve_index(index, len), kwargs.oob) {
+                    (Some(pos), _) => Some(pos),
+                    (None, OutOfBounds::Clamp) if len > 0 => Some(index.clamp(0, len - 1)),
+                    (None, OutOfBounds::Raise) => polars_bail!(
+                        OutOfBounds:
+                        "gather index {} is out of bounds for a list of length {} at row {}",
+                        index, len, i
+                    ),
+                    (None, _) => None,
+                };
+                take_idx.push(pos.map(|pos| (start + pos) as IdxSize));
+            }
+        }
+        validity.push(valid);
+        offsets.push(take_idx.len() as i64);
+    }
+
+    let idx_ca = IdxCa::from_iter_options("".into(), take_idx.into_iter());
+    let values = list_chunked.get_inner().take(&idx_ca)?;
+    list_from_flat_values(series.name().clone(), &values, offsets, validity.into())
+}
+
+fn vec_scatter_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(input_fields[0].clone())
+}
+
+/// Copy of each row's values list with the elements at the same row's indices replaced.
+/// inputs[0] = values (List/Array, any inner type)
+/// inputs[1] = indices (integer List/Array, any length per row)
+/// inputs[2] = updates (List/Array with one element per index, or a scalar per row)
+/// Negative indices count from the end of the row; repeated indices keep the last update.
+/// Null indices are skipped, and a null indices or updates row leaves the values row as-is.
+#[polars_expr(output_type_func=vec_scatter_output_type)]
+fn vec_scatter(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = ensure_contiguous_list(&inputs[0])?;
+    let list_chunked = series.list()?;
+    let values_arr = single_list_array(list_chunked);
+    let n_rows = list_chunked.len();
+    let indices_series = index_lists(&inputs[1], n_rows)?;
+    let indices_arr = single_list_array(indices_series.list()?);
+    let indices = flat_indices(indices_arr);
+
+    let inner = list_chunked.get_inner();
+    let updates = &inputs[2];
+    let list_updates = matches!(updates.dtype(), DataType::List(_) | DataType::Array(..));
+    let broadcast = !list_updates && updates.len() == 1;
+    if updates.len() != n_rows && !broadcast {
+        polars_bail!(
+            ComputeError:
+            "updates must have the same number of rows as the values. Expected {}, got {}",
+            n_rows, updates.len()
+        );
+    }
+    let updates_lists = if list_updates { Some(ensure_contiguous_list(updates)?) } else { None };
+    let updates_arr = updates_lists.as_ref().map(|lists| lists.list().map(single_list_array));
+    let updates_arr = updates_arr.transpose()?;
+    let updates_series = match &updates_lists {
+        Some(lists) => lists.list()?.get_inner().cast(inner.dtype())?,
+        None => updates.cast(inner.dtype())?,
+    };
+
+    // Gather from the values followed by the updates, so replaced elements point past the end
+    let values_offsets = values_arr.offsets();
+    let n_values = inner.len();
+    let mut take_idx: Vec<IdxSize> = (0..n_values as IdxSize).collect();
+    for i in 0..n_rows {
+        if !values_arr.is_valid(i) || !indices_arr.is_valid(i) {
+            continue;
+        }
+        let (lo, hi) = indices_arr.offsets().start_end(i);
+        // Flat position of the update for the row's first index, and whether it advances
+        let (first_update, step) = match updates_arr {
+            Some(arr) if !arr.is_valid(i) => continue,
+            Some(arr) => {
+                let (u_lo, u_hi) = arr.offsets().start_end(i);
+                if u_hi - u_lo != hi - lo {
+                    polars_bail!(
+                        ComputeError:
+                        "indices and updates must have the same length for scatter. Expected {}, got {} at row {}",
+                        hi - lo, u_hi - u_lo, i
+                    );
+                }
+                (u_lo, 1)
+            },
+            None if broadcast => (0, 0),
+            None => (i, 0),
+        };
+        let start = values_offsets[i];
+        let len = values_offsets[i + 1] - start;
+        for k in lo..hi {
+            let Some(index) = indices.get(k) else {
+                continue;
+            };
+            let Some(pos) = resolve_index(index, len) else {
+                polars_bail!(
+                    OutOfBounds:
+                    "scatter index {} is out of bounds for a list of length {} at row {}",
+                    index, len, i
+                );
+            };
+            let update = first_update + step * (k - lo);
+            take_idx[(start + pos) as usize] = (n_values + update) as IdxSize;
+        }
+    }
+
+    let mut combined = inner.clone();
+    combined.append(&updates_series)?;
+    let values = combined.take(&IdxCa::from_vec("".into(), take_idx))?;
+    let offsets = values_offsets.as_slice().to_vec();
+    let validity = values_arr.validity().cloned();
+    let result = list_from_flat_values(series.name().clone(), &values, offsets, validity)?;
+    match inputs[0].dtype() {
+        DataType::Array(_, width) => list_into_array(&result, *width),
+        _ => Ok(result),
+    }
+}
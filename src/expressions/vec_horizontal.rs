@@ -0,0 +1,110 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_core::utils::try_get_supertype;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{broadcast_scalar, ensure_list_type, list_into_array, operand_inner_dtype};
+
+/// Common inner dtype of every input list column.
+fn common_inner(dtypes: impl Iterator<Item = DataType>) -> PolarsResult<DataType> {
+    let mut inner = DataType::Null;
+    for dtype in dtypes {
+        inner = try_get_supertype(&inner, operand_inner_dtype(&dtype)?)?;
+    }
+    Ok(inner)
+}
+
+fn horizontal_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let inner = common_inner(input_fields.iter().map(|f| f.dtype().clone()))?;
+    let dtype = match field.dtype() {
+        DataType::Array(_, width) => DataType::Array(Box::new(inner), *width),
+        _ => DataType::List(Box::new(inner)),
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Per-position reduction across two or more list columns within each row.
+/// `replaces(result, s)` marks positions where `s` should replace the running result when
+/// both are non-null. Null elements are ignored, so a position is null only when it is null
+/// in every input; a null row in any input gives a null row.
+fn horizontal(
+    inputs: &[Series],
+    op_name: &str,
+    replaces: impl Fn(&Series, &Series) -> PolarsResult<BooleanChunked>,
+) -> PolarsResult<Series> {
+    if inputs.len() < 2 {
+        polars_bail!(ComputeError: "{} needs at least two list columns, got {}", op_name, inputs.len());
+    }
+    let inner = common_inner(inputs.iter().map(|s| s.dtype().clone()))?;
+    let list_dtype = DataType::List(Box::new(inner.clone()));
+    let first = ensure_list_type(&inputs[0])?.cast(&list_dtype)?;
+    let mut columns = vec![first.clone()];
+    for s in &inputs[1..] {
+        columns.push(broadcast_scalar(s, first.list()?)?.cast(&list_dtype)?);
+    }
+    let n_rows = columns[0].len();
+    for s in &columns[1..] {
+        if s.len() != n_rows {
+            polars_bail!(
+                ComputeError:
+                "All inputs must have the same number of rows for {}. Expected {}, got {}",
+                op_name, n_rows, s.len()
+            );
+        }
+    }
+    // Walk every column in step with chunk-aware iterators rather than per-row lookups
+    let mut iters = columns
+        .iter()
+        .map(|s| Ok(s.list()?.into_iter()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let mut rows: Vec<Option<Series>> = Vec::with_capacity(n_rows);
+    for row in 0..n_rows {
+        let row_values: Vec<Option<Series>> = iters.iter_mut().map(|it| it.next().flatten()).collect();
+        let Some(row_series) = row_values.into_iter().collect::<Option<Vec<_>>>() else {
+            rows.push(None);
+            continue;
+        };
+        let mut result = row_series[0].clone();
+        for s in &row_series[1..] {
+            if s.len() != result.len() {
+                polars_bail!(
+                    ComputeError:
+                    "Lists must have the same length for {}. Row {}: expected {}, got {}",
+                    op_name, row, result.len(), s.len()
+                );
+            }
+            let both_not_null = result.is_not_null() & s.is_not_null();
+            let take_s = (replaces(&result, s)? & both_not_null) | result.is_null();
+            result = s.zip_with(&(take_s & s.is_not_null()), &result)?;
+        }
+        rows.push(Some(result));
+    }
+
+    let out = ListChunked::from_iter(rows)
+        .with_name(inputs[0].name().clone())
+        .into_series()
+        .cast(&list_dtype)?;
+    match inputs[0].dtype() {
+        DataType::Array(_, width) => list_into_array(&out, *width),
+        _ => Ok(out),
+    }
+}
+
+/// Element-wise minimum across two or more list columns.
+/// inputs[0] = list column (List/Array)
+/// inputs[1..] = list columns with the same length in each row, or numeric columns/literals
+///               broadcast across each row
+#[polars_expr(output_type_func=horizontal_output_type)]
+fn vec_min_horizontal(inputs: &[Series]) -> PolarsResult<Series> {
+    horizontal(inputs, "min_horizontal", |result, s| result.gt(s))
+}
+
+/// Element-wise maximum across two or more list columns.
+/// inputs[0] = list column (List/Array)
+/// inputs[1..] = list columns with the same length in each row, or numeric columns/literals
+///               broadcast across each row
+#[polars_expr(output_type_func=horizontal_output_type)]
+fn vec_max_horizontal(inputs: &[Series]) -> PolarsResult<Series> {
+    horizontal(inputs, "max_horizontal", |result, s| result.lt(s))
+}
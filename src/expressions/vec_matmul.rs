@@ -0,0 +1,88 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_list_type, list_into_array};
+
+#[derive(serde::Deserialize)]
+struct MatmulKwargs {
+    /// Row-major `m x k` matrix; each length-m row vector `v` maps to `v @ matrix`.
+    matrix: Vec<Vec<f64>>,
+}
+
+fn vec_matmul_output_type(input_fields: &[Field], kwargs: MatmulKwargs) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let k = kwargs.matrix.first().map_or(0, |r| r.len());
+    match field.dtype() {
+        DataType::List(_) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(Box::new(DataType::Float64)),
+        )),
+        DataType::Array(_, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::Array(Box::new(DataType::Float64), k),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Multiply each row's vector by a constant matrix passed in kwargs.
+/// A row of length m times an `m x k` matrix gives a row of length k. Rows containing a
+/// null element give a null row.
+#[polars_expr(output_type_func_with_kwargs=vec_matmul_output_type)]
+fn vec_matmul(inputs: &[Series], kwargs: MatmulKwargs) -> PolarsResult<Series> {
+    let matrix = &kwargs.matrix;
+    let m = matrix.len();
+    let k = matrix.first().map_or(0, |r| r.len());
+    if let Some(row) = matrix.iter().find(|r| r.len() != k) {
+        polars_bail!(
+            ComputeError:
+            "All matrix rows must have the same length. Expected {}, got {}",
+            k, row.len()
+        );
+    }
+
+    let series = &inputs[0];
+    let list_series =
+        ensure_list_type(series)?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = list_series.list()?;
+    let n_rows = list_chunked.len();
+
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        series.name().clone(),
+        n_rows,
+        n_rows * k,
+        DataType::Float64,
+    );
+    let mut out = vec![0.0; k];
+    for row in list_chunked.amortized_iter() {
+        let Some(row) = row else {
+            builder.append_null();
+            continue;
+        };
+        let ca = row.as_ref().f64()?;
+        if ca.len() != m {
+            polars_bail!(
+                ComputeError:
+                "List length must match the number of matrix rows. Expected {}, got {}",
+                m, ca.len()
+            );
+        }
+        if ca.null_count() > 0 {
+            builder.append_null();
+            continue;
+        }
+        out.fill(0.0);
+        for (v, matrix_row) in ca.into_no_null_iter().zip(matrix.iter()) {
+            for (o, w) in out.iter_mut().zip(matrix_row.iter()) {
+                *o += v * w;
+            }
+        }
+        builder.append_slice(&out);
+    }
+
+    let result_series = builder.finish().into_series();
+    match series.dtype() {
+        DataType::Array(_, _) => list_into_array(&result_series, k),
+        _ => Ok(result_series),
+    }
+}
@@ -0,0 +1,53 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_core::chunked_array::builder::get_list_builder;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{build_f64_list, ensure_list_type};
+
+fn vec_outer_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    for field in &input_fields[..2] {
+        match field.dtype() {
+            DataType::List(_) | DataType::Array(_, _) => {},
+            dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+        }
+    }
+    let nested = DataType::List(Box::new(DataType::List(Box::new(DataType::Float64))));
+    Ok(Field::new(input_fields[0].name().clone(), nested))
+}
+
+/// Outer product of the two lists in each row: a list of `len(a)` rows, row `p` holding
+/// `a[p] * b`. The lists may differ in length. A null row in either input gives a null
+/// row, and a null element gives null entries.
+/// inputs[0] = a (List/Array)
+/// inputs[1] = b (List/Array)
+#[polars_expr(output_type_func=vec_outer_output_type)]
+fn vec_outer(inputs: &[Series]) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let a_series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let b_series = ensure_list_type(&inputs[1])?.cast(&f64_list)?;
+    let (a_ca, b_ca) = (a_series.list()?, b_series.list()?);
+    let n_rows = a_ca.len();
+    if b_ca.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "Both inputs must have the same number of rows for outer. Expected {}, got {}",
+            n_rows, b_ca.len()
+        );
+    }
+
+    let mut builder = get_list_builder(&f64_list, n_rows, n_rows, a_series.name().clone());
+    let mut values: Vec<Option<f64>> = Vec::new();
+    for (a, b) in a_ca.amortized_iter().zip(b_ca.amortized_iter()) {
+        let (Some(a), Some(b)) = (a, b) else {
+            builder.append_null();
+            continue;
+        };
+        let (a, b) = (a.as_ref().f64()?, b.as_ref().f64()?);
+        let width = b.len();
+        values.clear();
+        values.extend(a.iter().flat_map(|x| b.iter().map(move |y| Some(x? * y?))));
+        let rows = (0..a.len()).map(|p| Some(&values[p * width..(p + 1) * width]));
+        builder.append_series(&build_f64_list("".into(), a.len(), rows, &f64_list)?)?;
+    }
+    Ok(builder.finish().into_series())
+}
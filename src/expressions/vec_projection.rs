@@ -0,0 +1,62 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    ensure_list_type, float_list_output_type, for_each_row_pair, list_into_array,
+};
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Split each row's `a` into its component along `b` (or, with `reject`, the component
+/// orthogonal to `b`). Rows where either vector is null or holds a null element are null,
+/// and a zero `b` gives a row of NaN.
+/// inputs[0] = a (List/Array)
+/// inputs[1] = b (List/Array), with one row per row of `a` or a single row used for all
+fn project_rows(inputs: &[Series], op_name: &str, reject: bool) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let a_series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let b_series = ensure_list_type(&inputs[1])?.cast(&f64_list)?;
+    let a_ca = a_series.list()?;
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        a_series.name().clone(),
+        a_ca.len(),
+        a_ca.get_inner().len(),
+        DataType::Float64,
+    );
+    // A row's values, or `None` if it holds a null element
+    let dense = |ca: &Float64Chunked| ca.iter().collect::<Option<Vec<f64>>>();
+    for_each_row_pair(&a_series, &b_series, op_name, |pair| {
+        let Some((a, b)) = pair.and_then(|(a, b)| Some((dense(a)?, dense(b)?))) else {
+            builder.append_null();
+            return Ok(());
+        };
+        let scale = dot(&a, &b) / dot(&b, &b);
+        let projection = b.iter().map(|y| scale * y);
+        if reject {
+            builder.append_values_iter(a.iter().zip(projection).map(|(x, p)| x - p));
+        } else {
+            builder.append_values_iter(projection);
+        }
+        Ok(())
+    })?;
+
+    let result_series = builder.finish().into_series();
+    match inputs[0].dtype() {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
+
+/// Vector projection of `a` onto `b` per row: `(a·b / b·b) b`.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_project(inputs: &[Series]) -> PolarsResult<Series> {
+    project_rows(inputs, "project", false)
+}
+
+/// Vector rejection of `a` from `b` per row: `a - project(a, b)`, orthogonal to `b`.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_reject(inputs: &[Series]) -> PolarsResult<Series> {
+    project_rows(inputs, "reject", true)
+}
@@ -0,0 +1,233 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_list_type, list_into_array};
+
+#[derive(serde::Deserialize)]
+struct QuantizeKwargs {
+    /// Step between quantization levels; `None` picks one per row from the row's range and
+    /// returns it alongside the values.
+    scale: Option<f64>,
+    offset: f64,
+    dtype: LevelDtype,
+}
+
+#[derive(serde::Deserialize)]
+struct DequantizeKwargs {
+    /// Step and offset for plain integer lists; ignored for `vec_quantize` structs, which
+    /// carry their own per row.
+    scale: Option<f64>,
+    offset: f64,
+}
+
+/// Integer dtype of the quantized levels.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum LevelDtype {
+    Int8,
+    Int16,
+    Int32,
+}
+
+impl LevelDtype {
+    /// The polars dtype and its largest level.
+    fn dtype_and_max(self) -> (DataType, f64) {
+        match self {
+            LevelDtype::Int8 => (DataType::Int8, i8::MAX as f64),
+            LevelDtype::Int16 => (DataType::Int16, i16::MAX as f64),
+            LevelDtype::Int32 => (DataType::Int32, i32::MAX as f64),
+        }
+    }
+}
+
+/// `inner` wrapped like `dtype`: an Array of the same width, otherwise a List.
+fn shaped_like(dtype: &DataType, inner: DataType) -> PolarsResult<DataType> {
+    match dtype {
+        DataType::List(_) => Ok(DataType::List(Box::new(inner))),
+        DataType::Array(_, width) => Ok(DataType::Array(Box::new(inner), *width)),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+fn vec_quantize_output_type(
+    input_fields: &[Field],
+    kwargs: QuantizeKwargs,
+) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let values = shaped_like(field.dtype(), kwargs.dtype.dtype_and_max().0)?;
+    let dtype = match kwargs.scale {
+        Some(_) => values,
+        None => DataType::Struct(vec![
+            Field::new("values".into(), values),
+            Field::new("scale".into(), DataType::Float64),
+            Field::new("offset".into(), DataType::Float64),
+        ]),
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Quantize float lists to integer levels as `round((x - offset) / scale)`.
+///
+/// With a fixed `scale` every level must fit the integer dtype. Without one, each row is
+/// scaled on its own so its finite range spans `[-max, max]` of the dtype, and the result is
+/// a struct of the `values` with the row's `scale` and `offset`. Non-finite elements become
+/// null, as do rows without finite values' scale and offset.
+#[polars_expr(output_type_func_with_kwargs=vec_quantize_output_type)]
+fn vec_quantize(inputs: &[Series], kwargs: QuantizeKwargs) -> PolarsResult<Series> {
+    let (dtype, max_level) = kwargs.dtype.dtype_and_max();
+    if let Some(scale) = kwargs.scale {
+        if !(scale.is_finite() && scale > 0.0) {
+            polars_bail!(ComputeError: "scale must be positive and finite, got {}", scale);
+        }
+    }
+    if !kwargs.offset.is_finite() {
+        polars_bail!(ComputeError: "offset must be finite, got {}", kwargs.offset);
+    }
+
+    let series = &inputs[0];
+    let list_series =
+        ensure_list_type(series)?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = list_series.list()?;
+    let n_rows = list_chunked.len();
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        "values".into(),
+        n_rows,
+        list_chunked.get_inner().len(),
+        DataType::Float64,
+    );
+    let mut scales: Vec<Option<f64>> = Vec::with_capacity(n_rows);
+    let mut offsets: Vec<Option<f64>> = Vec::with_capacity(n_rows);
+    let mut levels: Vec<Option<f64>> = Vec::new();
+    for (i, row) in list_chunked.amortized_iter().enumerate() {
+        let Some(row) = row else {
+            builder.append_null();
+            scales.push(None);
+            offsets.push(None);
+            continue;
+        };
+        let ca = row.as_ref().f64()?;
+        let finite = || ca.iter().flatten().filter(|v| v.is_finite());
+        let (scale, offset) = match kwargs.scale {
+            Some(scale) => (Some(scale), Some(kwargs.offset)),
+            None => {
+                let (lo, hi) = finite().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                    (lo.min(v), hi.max(v))
+                });
+                let half_range = (hi - lo) / 2.0;
+                if lo > hi {
+                    (None, None)
+                } else if half_range > 0.0 {
+                    (Some(half_range / max_level), Some(lo + half_range))
+                } else {
+                    // A constant row is all level 0
+                    (Some(1.0), Some(lo))
+                }
+            },
+        };
+        levels.clear();
+        if let (Some(scale), Some(offset)) = (scale, offset) {
+            for v in ca.iter() {
+                let level = v.filter(|v| v.is_finite()).map(|v| ((v - offset) / scale).round());
+                let fits = |l: &f64| (-max_level - 1.0..=max_level).contains(l);
+                if let Some(level) = level.filter(|l| !fits(l)) {
+                    polars_bail!(
+                        ComputeError:
+                        "Level {} at row {} does not fit {} with scale {} and offset {}",
+                        level, i, dtype, scale, offset
+                    );
+                }
+                levels.push(level);
+            }
+        } else {
+            levels.resize(ca.len(), None);
+        }
+        builder.append_iter(levels.iter().copied());
+        scales.push(scale);
+        offsets.push(offset);
+    }
+
+    let values = builder.finish().into_series().cast(&DataType::List(Box::new(dtype)))?;
+    let values = match series.dtype() {
+        DataType::Array(_, width) => list_into_array(&values, *width)?,
+        _ => values,
+    };
+    if kwargs.scale.is_some() {
+        return Ok(values.with_name(series.name().clone()));
+    }
+    let scales = Float64Chunked::from_iter_options("scale".into(), scales.into_iter());
+    let offsets = Float64Chunked::from_iter_options("offset".into(), offsets.into_iter());
+    let fields = [values, scales.into_series(), offsets.into_series()];
+    Ok(StructChunked::from_series(series.name().clone(), n_rows, fields.iter())?.into_series())
+}
+
+fn vec_dequantize_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let values = match field.dtype() {
+        DataType::Struct(fields) => match fields.iter().find(|f| f.name() == "values") {
+            Some(values) => values.dtype(),
+            None => polars_bail!(
+                InvalidOperation:
+                "Expected a struct with a 'values' field, got {:?}", field.dtype()
+            ),
+        },
+        dt => dt,
+    };
+    Ok(Field::new(field.name().clone(), shaped_like(values, DataType::Float64)?))
+}
+
+/// Map quantized levels back to floats as `level * scale + offset`.
+/// inputs[0] = struct {values, scale, offset} from `vec_quantize` with per-row scaling, or an
+///             integer List/Array quantized with the fixed `scale` and `offset` kwargs
+/// A null scale or offset gives a row of nulls.
+#[polars_expr(output_type_func=vec_dequantize_output_type)]
+fn vec_dequantize(inputs: &[Series], kwargs: DequantizeKwargs) -> PolarsResult<Series> {
+    let input = &inputs[0];
+    let n_rows = input.len();
+    let (values, scales, offsets) = match input.dtype() {
+        DataType::Struct(_) => {
+            let encoded = input.struct_()?;
+            let field = |name| -> PolarsResult<Series> {
+                encoded.field_by_name(name)?.cast(&DataType::Float64)
+            };
+            (encoded.field_by_name("values")?, field("scale")?, field("offset")?)
+        },
+        _ => {
+            let Some(scale) = kwargs.scale else {
+                polars_bail!(ComputeError: "scale is required to dequantize a plain list column");
+            };
+            let scales = Float64Chunked::full("scale".into(), scale, n_rows).into_series();
+            let offsets =
+                Float64Chunked::full("offset".into(), kwargs.offset, n_rows).into_series();
+            (input.clone(), scales, offsets)
+        },
+    };
+
+    let list_series =
+        ensure_list_type(&values)?.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = list_series.list()?;
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        input.name().clone(),
+        n_rows,
+        list_chunked.get_inner().len(),
+        DataType::Float64,
+    );
+    let params = scales.f64()?.iter().zip(offsets.f64()?.iter());
+    for (row, (scale, offset)) in list_chunked.amortized_iter().zip(params) {
+        let Some(row) = row else {
+            builder.append_null();
+            continue;
+        };
+        let ca = row.as_ref().f64()?;
+        match (scale, offset) {
+            (Some(scale), Some(offset)) => {
+                builder.append_iter(ca.iter().map(|v| v.map(|v| v * scale + offset)))
+            },
+            _ => builder.append_iter(ca.iter().map(|_| None)),
+        }
+    }
+    let result = builder.finish().into_series();
+    match values.dtype() {
+        DataType::Array(_, width) => list_into_array(&result, *width),
+        _ => Ok(result),
+    }
+}
@@ -0,0 +1,71 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use serde::Deserialize;
+use super::helpers::{float_list_output_type, map_rows_f64};
+
+/// How tied values are ranked, named as in polars' `rank`.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum RankMethod {
+    Average,
+    Min,
+    Max,
+    Dense,
+    Ordinal,
+}
+
+#[derive(Deserialize)]
+struct RankKwargs {
+    method: RankMethod,
+    descending: bool,
+}
+
+/// 1-based ranks of `values`, with ties resolved by `method`. Ordinal ranks break ties by
+/// position.
+pub(super) fn rank(values: &[f64], method: RankMethod, descending: bool) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    if descending {
+        order.sort_by(|&x, &y| values[y].total_cmp(&values[x]));
+    } else {
+        order.sort_by(|&x, &y| values[x].total_cmp(&values[y]));
+    }
+    let mut ranks = vec![0.0; order.len()];
+    let (mut i, mut dense) = (0, 0.0);
+    while i < order.len() {
+        let mut j = i;
+        while j + 1 < order.len() && values[order[j + 1]] == values[order[i]] {
+            j += 1;
+        }
+        dense += 1.0;
+        for (k, &idx) in order[i..=j].iter().enumerate() {
+            ranks[idx] = match method {
+                RankMethod::Average => (i + j) as f64 / 2.0 + 1.0,
+                RankMethod::Min => (i + 1) as f64,
+                RankMethod::Max => (j + 1) as f64,
+                RankMethod::Dense => dense,
+                RankMethod::Ordinal => (i + k + 1) as f64,
+            };
+        }
+        i = j + 1;
+    }
+    ranks
+}
+
+/// Replace each element with its rank within its own list. Nulls stay null and NaN stays
+/// NaN; both are left out of the ranking.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_rank(inputs: &[Series], kwargs: RankKwargs) -> PolarsResult<Series> {
+    let mut ranked: Vec<f64> = Vec::new();
+    map_rows_f64(&inputs[0], |ca, out| {
+        ranked.clear();
+        ranked.extend(ca.iter().flatten().filter(|v| !v.is_nan()));
+        let mut ranks = rank(&ranked, kwargs.method, kwargs.descending).into_iter();
+        out.extend(ca.iter().map(|v| match v {
+            Some(v) if v.is_nan() => Some(f64::NAN),
+            Some(_) => ranks.next(),
+            None => None,
+        }));
+        Ok(())
+    })
+}
@@ -0,0 +1,183 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::ensure_list_type;
+
+/// How the values falling in one bin are combined.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum RebinAgg {
+    Mean,
+    Sum,
+    Min,
+    Max,
+    First,
+    Last,
+    /// Number of values, as UInt32.
+    Count,
+}
+
+#[derive(serde::Deserialize)]
+struct RebinByTimeKwargs {
+    bin_width: f64,
+    agg: RebinAgg,
+    start: Option<f64>,
+    stop: Option<f64>,
+}
+
+fn vec_rebin_by_time_output_type(
+    input_fields: &[Field],
+    kwargs: RebinByTimeKwargs,
+) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let inner = if kwargs.agg == RebinAgg::Count {
+        DataType::UInt32
+    } else {
+        DataType::Float64
+    };
+    Ok(Field::new(field.name().clone(), DataType::List(Box::new(inner))))
+}
+
+/// Per-bin accumulator covering every supported aggregation.
+#[derive(Clone)]
+struct Bin {
+    count: u32,
+    sum: f64,
+    min: f64,
+    max: f64,
+    first: f64,
+    last: f64,
+}
+
+impl Bin {
+    const EMPTY: Bin = Bin {
+        count: 0,
+        sum: 0.0,
+        min: f64::INFINITY,
+        max: f64::NEG_INFINITY,
+        first: f64::NAN,
+        last: f64::NAN,
+    };
+
+    fn push(&mut self, v: f64) {
+        if self.count == 0 {
+            self.first = v;
+        }
+        self.count += 1;
+        self.sum += v;
+        self.min = self.min.min(v);
+        self.max = self.max.max(v);
+        self.last = v;
+    }
+
+    fn finish(&self, agg: RebinAgg) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(match agg {
+            RebinAgg::Mean => self.sum / self.count as f64,
+            RebinAgg::Sum => self.sum,
+            RebinAgg::Min => self.min,
+            RebinAgg::Max => self.max,
+            RebinAgg::First => self.first,
+            RebinAgg::Last => self.last,
+            RebinAgg::Count => self.count as f64,
+        })
+    }
+}
+
+/// Rebin each row's values onto a uniform time grid.
+/// inputs[0] = values (List/Array)
+/// inputs[1] = timestamps (List/Array, same length as values in each row)
+/// Bin k covers `[start + k * bin_width, start + (k + 1) * bin_width)`. `start` defaults to
+/// the row's earliest timestamp and `stop` to just past its latest one. Empty bins are null.
+#[polars_expr(output_type_func_with_kwargs=vec_rebin_by_time_output_type)]
+fn vec_rebin_by_time(inputs: &[Series], kwargs: RebinByTimeKwargs) -> PolarsResult<Series> {
+    let agg = kwargs.agg;
+    if kwargs.bin_width.is_nan() || kwargs.bin_width <= 0.0 {
+        polars_bail!(ComputeError: "bin_width must be positive, got {}", kwargs.bin_width);
+    }
+
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let values_series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let times_series = ensure_list_type(&inputs[1])?.to_physical_repr().cast(&f64_list)?;
+    let values_ca = values_series.list()?;
+    let times_ca = times_series.list()?;
+    let n_rows = values_ca.len();
+    if times_ca.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "timestamps must have the same number of rows as the values. Expected {}, got {}",
+            n_rows, times_ca.len()
+        );
+    }
+
+    let name = values_series.name().clone();
+    let mut f64_builder =
+        ListPrimitiveChunkedBuilder::<Float64Type>::new(name.clone(), n_rows, n_rows, DataType::Float64);
+    let mut count_builder =
+        ListPrimitiveChunkedBuilder::<UInt32Type>::new(name, n_rows, n_rows, DataType::UInt32);
+    let mut bins: Vec<Bin> = Vec::new();
+
+    for (row, (values, times)) in values_ca.amortized_iter().zip(times_ca.amortized_iter()).enumerate() {
+        let (Some(values), Some(times)) = (values, times) else {
+            f64_builder.append_null();
+            count_builder.append_null();
+            continue;
+        };
+        let (values, times) = (values.as_ref().f64()?, times.as_ref().f64()?);
+        if values.len() != times.len() {
+            polars_bail!(
+                ComputeError:
+                "values and timestamps must have the same length. Row {}: values has {}, timestamps has {}",
+                row, values.len(), times.len()
+            );
+        }
+
+        let finite_times = || times.iter().flatten().filter(|t| t.is_finite());
+        let start = kwargs
+            .start
+            .unwrap_or_else(|| finite_times().fold(f64::INFINITY, f64::min));
+        let n_bins = match kwargs.stop {
+            Some(stop) => ((stop - start) / kwargs.bin_width).ceil().max(0.0) as usize,
+            None => {
+                let last = finite_times().fold(f64::NEG_INFINITY, f64::max);
+                if last.is_finite() && start.is_finite() && last >= start {
+                    ((last - start) / kwargs.bin_width).floor() as usize + 1
+                } else {
+                    0
+                }
+            },
+        };
+        let stop = kwargs.stop.unwrap_or(f64::INFINITY);
+
+        bins.clear();
+        bins.resize(n_bins, Bin::EMPTY);
+        for (t, v) in times.iter().zip(values.iter()) {
+            let (Some(t), Some(v)) = (t, v) else { continue };
+            if !t.is_finite() || v.is_nan() || t < start || t >= stop {
+                continue;
+            }
+            let k = ((t - start) / kwargs.bin_width).floor() as usize;
+            if let Some(bin) = bins.get_mut(k) {
+                bin.push(v);
+            }
+        }
+
+        if agg == RebinAgg::Count {
+            count_builder.append_values_iter(bins.iter().map(|b| b.count));
+        } else {
+            f64_builder.append_iter(bins.iter().map(|b| b.finish(agg)));
+        }
+    }
+
+    if agg == RebinAgg::Count {
+        Ok(count_builder.finish().into_series())
+    } else {
+        Ok(f64_builder.finish().into_series())
+    }
+}
@@ -0,0 +1,143 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::Array;
+use polars_arrow::bitmap::MutableBitmap;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_contiguous_list, list_from_flat_values, single_list_array};
+
+/// Which end of a padded row the original values stay at.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum Align {
+    /// Values first, padding at the end.
+    Left,
+    /// Padding first, values at the end.
+    Right,
+}
+
+#[derive(serde::Deserialize)]
+struct PadToKwargs {
+    width: usize,
+    fill: Option<f64>,
+    align: Align,
+}
+
+#[derive(serde::Deserialize)]
+struct TruncateKwargs {
+    width: usize,
+}
+
+#[derive(serde::Deserialize)]
+struct WindowsKwargs {
+    window: usize,
+    step: usize,
+}
+
+fn same_inner_list_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::List(inner.clone()),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+fn vec_reshape_windows_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = same_inner_list_output_type(input_fields)?;
+    let dtype = DataType::List(Box::new(field.dtype().clone()));
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Rebuild every non-null row from flat positions chosen by `row_positions`, which receives
+/// the row's `(start, end)` in the flat values and pushes gather indices (`None` for padding).
+/// Returns the gathered values and the new offsets; null rows stay null and empty.
+fn regather_rows(
+    series: &Series,
+    mut row_positions: impl FnMut(usize, usize, &mut Vec<Option<IdxSize>>),
+) -> PolarsResult<(Series, Vec<i64>, Option<polars_arrow::bitmap::Bitmap>)> {
+    let series = ensure_contiguous_list(series)?;
+    let list_chunked = series.list()?;
+    let list_arr = single_list_array(list_chunked);
+    let n_rows = list_chunked.len();
+
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    let mut take_idx: Vec<Option<IdxSize>> = Vec::new();
+    for i in 0..n_rows {
+        if list_arr.is_valid(i) {
+            let (start, end) = list_arr.offsets().start_end(i);
+            row_positions(start, end, &mut take_idx);
+        }
+        offsets.push(take_idx.len() as i64);
+    }
+    let idx_ca = IdxCa::from_iter_options("".into(), take_idx.into_iter());
+    let values = list_chunked.get_inner().take(&idx_ca)?;
+    Ok((values, offsets, list_arr.validity().cloned()))
+}
+
+/// Pad each row's list with `fill` (null if not given) up to `width` elements, at the end
+/// (`align` "left") or the start ("right"). Rows already at least `width` long are
+/// unchanged; null rows stay null.
+#[polars_expr(output_type_func=same_inner_list_output_type)]
+fn vec_pad_to(inputs: &[Series], kwargs: PadToKwargs) -> PolarsResult<Series> {
+    let pad_start = kwargs.align == Align::Right;
+    let width = kwargs.width;
+    // Padding positions are remembered separately so in-list nulls are not filled
+    let mut is_pad = MutableBitmap::new();
+    let (mut values, offsets, validity) = regather_rows(&inputs[0], |start, end, idx| {
+        let n_pad = width.saturating_sub(end - start);
+        let row = (start..end).map(|k| Some(k as IdxSize));
+        let pad = std::iter::repeat_n(None, n_pad);
+        if pad_start {
+            idx.extend(pad.chain(row));
+            is_pad.extend_constant(n_pad, true);
+            is_pad.extend_constant(end - start, false);
+        } else {
+            idx.extend(row.chain(pad));
+            is_pad.extend_constant(end - start, false);
+            is_pad.extend_constant(n_pad, true);
+        }
+    })?;
+    if let Some(fill) = kwargs.fill {
+        let mask = BooleanChunked::from_bitmap("".into(), is_pad.freeze());
+        let fill =
+            Series::new("".into(), [fill]).cast(values.dtype())?.new_from_index(0, values.len());
+        values = fill.zip_with(&mask, &values)?;
+    }
+    list_from_flat_values(inputs[0].name().clone(), &values, offsets, validity)
+}
+
+/// Keep at most the first `width` elements of each row's list.
+#[polars_expr(output_type_func=same_inner_list_output_type)]
+fn vec_truncate(inputs: &[Series], kwargs: TruncateKwargs) -> PolarsResult<Series> {
+    let (values, offsets, validity) = regather_rows(&inputs[0], |start, end, idx| {
+        idx.extend((start..end.min(start + kwargs.width)).map(|k| Some(k as IdxSize)));
+    })?;
+    list_from_flat_values(inputs[0].name().clone(), &values, offsets, validity)
+}
+
+/// Split each row's list into sub-windows of `window` elements starting every `step`
+/// elements, as a list of lists. Only complete windows are kept.
+#[polars_expr(output_type_func=vec_reshape_windows_output_type)]
+fn vec_reshape_windows(inputs: &[Series], kwargs: WindowsKwargs) -> PolarsResult<Series> {
+    let (window, step) = (kwargs.window, kwargs.step);
+    if window == 0 || step == 0 {
+        polars_bail!(ComputeError: "window and step must be positive, got window={} step={}", window, step);
+    }
+    let (values, offsets, validity) = regather_rows(&inputs[0], |start, end, idx| {
+        let n_windows = if end - start >= window { (end - start - window) / step + 1 } else { 0 };
+        for w in 0..n_windows {
+            let first = start + w * step;
+            idx.extend((first..first + window).map(|k| Some(k as IdxSize)));
+        }
+    })?;
+
+    // Every row gathered a whole number of windows, so its offsets divide evenly
+    let n_windows = values.len() / window;
+    let window_offsets: Vec<i64> = (0..=n_windows).map(|w| (w * window) as i64).collect();
+    let windows = list_from_flat_values("".into(), &values, window_offsets, None)?;
+    let row_offsets = offsets.into_iter().map(|o| o / window as i64).collect();
+    list_from_flat_values(inputs[0].name().clone(), &windows, row_offsets, validity)
+}
@@ -0,0 +1,49 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::Array;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    ensure_contiguous_list, list_from_flat_values, list_into_array, single_list_array,
+};
+
+fn vec_reverse_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => Ok(field.clone()),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Each row's list in reverse order. Works on the flat buffers: the values are gathered once
+/// with every row's positions walked backwards, and the row lengths and validity are kept,
+/// so null rows stay null and Array input gives Array output.
+#[polars_expr(output_type_func=vec_reverse_output_type)]
+fn vec_reverse(inputs: &[Series]) -> PolarsResult<Series> {
+    let input_dtype = inputs[0].dtype().clone();
+    let series = ensure_contiguous_list(&inputs[0])?;
+    let list_chunked = series.list()?;
+    let list_arr = single_list_array(list_chunked);
+    let n_rows = list_chunked.len();
+
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    let mut take_idx: Vec<IdxSize> = Vec::with_capacity(list_arr.values().len());
+    for i in 0..n_rows {
+        if list_arr.is_valid(i) {
+            let (start, end) = list_arr.offsets().start_end(i);
+            take_idx.extend((start..end).rev().map(|k| k as IdxSize));
+        }
+        offsets.push(take_idx.len() as i64);
+    }
+
+    let idx_ca = IdxCa::from_vec("".into(), take_idx);
+    let values = list_chunked.get_inner().take(&idx_ca)?;
+    let validity = list_arr.validity().cloned();
+    let result_series = list_from_flat_values(series.name().clone(), &values, offsets, validity)?;
+
+    // Cast back to Array if input was Array
+    match &input_dtype {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
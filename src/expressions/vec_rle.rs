@@ -0,0 +1,140 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::{Array, PrimitiveArray};
+use polars_arrow::bitmap::MutableBitmap;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_contiguous_list, list_from_flat_values, single_list_array};
+
+fn vec_rle_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let inner = match field.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => inner.clone(),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    };
+    Ok(Field::new(
+        field.name().clone(),
+        DataType::Struct(vec![
+            Field::new("values".into(), DataType::List(inner)),
+            Field::new("lengths".into(), DataType::List(Box::new(DataType::UInt32))),
+        ]),
+    ))
+}
+
+/// Run-length encoding of each row's list as a struct of run `values` and run `lengths`.
+/// Consecutive equal elements form a run; consecutive nulls form a run of null. A null row
+/// gives null values and lengths.
+#[polars_expr(output_type_func=vec_rle_output_type)]
+fn vec_rle(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = ensure_contiguous_list(&inputs[0])?;
+    let list_chunked = series.list()?;
+    let list_arr = single_list_array(list_chunked);
+    let n_rows = list_chunked.len();
+    let inner = list_chunked.get_inner();
+
+    // changed[k] is true when flat element k + 1 differs from element k
+    let n_values = inner.len();
+    let changed = if n_values > 1 {
+        let tail = inner.slice(1, n_values - 1);
+        tail.not_equal_missing(&inner.slice(0, n_values - 1))?
+    } else {
+        BooleanChunked::full_null("".into(), 0)
+    };
+    let changed = changed.rechunk();
+    let changed = changed.downcast_as_array();
+
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    let mut run_starts: Vec<IdxSize> = Vec::new();
+    let mut lengths: Vec<u32> = Vec::new();
+    for i in 0..n_rows {
+        if list_arr.is_valid(i) {
+            let (start, end) = list_arr.offsets().start_end(i);
+            for k in start..end {
+                if k == start || changed.value(k - 1) {
+                    run_starts.push(k as IdxSize);
+                    lengths.push(0);
+                }
+                *lengths.last_mut().expect("a run was started") += 1;
+            }
+        }
+        offsets.push(run_starts.len() as i64);
+    }
+
+    let validity = list_arr.validity().cloned();
+    let values = inner.take(&IdxCa::from_vec("".into(), run_starts))?;
+    let values =
+        list_from_flat_values("values".into(), &values, offsets.clone(), validity.clone())?;
+    let lengths = UInt32Chunked::from_vec("".into(), lengths).into_series();
+    let lengths = list_from_flat_values("lengths".into(), &lengths, offsets, validity)?;
+    Ok(StructChunked::from_series(series.name().clone(), n_rows, [values, lengths].iter())?
+        .into_series())
+}
+
+fn vec_rle_decode_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let values = match field.dtype() {
+        DataType::Struct(fields) => fields.iter().find(|f| f.name() == "values"),
+        _ => None,
+    };
+    match values.map(|f| f.dtype()) {
+        Some(DataType::List(inner) | DataType::Array(inner, _)) => {
+            Ok(Field::new(field.name().clone(), DataType::List(inner.clone())))
+        },
+        _ => polars_bail!(
+            InvalidOperation:
+            "Expected a struct with 'values' and 'lengths' list fields, got {:?}", field.dtype()
+        ),
+    }
+}
+
+/// Expand a run-length encoding back into one list per row.
+/// inputs[0] = struct {values: List, lengths: integer List} as produced by `vec_rle`
+/// Null lengths count as zero; a null values or lengths row gives a null row.
+#[polars_expr(output_type_func=vec_rle_decode_output_type)]
+fn vec_rle_decode(inputs: &[Series]) -> PolarsResult<Series> {
+    let encoded = inputs[0].struct_()?;
+    let values_series = ensure_contiguous_list(&encoded.field_by_name("values")?)?;
+    let values_ca = values_series.list()?;
+    let values_arr = single_list_array(values_ca);
+    let lengths_series = ensure_contiguous_list(&encoded.field_by_name("lengths")?)?
+        .cast(&DataType::List(Box::new(DataType::Int64)))?
+        .rechunk();
+    let lengths_arr = single_list_array(lengths_series.list()?);
+    let lengths = lengths_arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .expect("lengths were cast to Int64");
+    let n_rows = values_ca.len();
+
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    let mut take_idx: Vec<IdxSize> = Vec::new();
+    let mut validity = MutableBitmap::with_capacity(n_rows);
+    for i in 0..n_rows {
+        let valid = values_arr.is_valid(i) && lengths_arr.is_valid(i);
+        if valid {
+            let (start, end) = values_arr.offsets().start_end(i);
+            let (l_start, l_end) = lengths_arr.offsets().start_end(i);
+            if end - start != l_end - l_start {
+                polars_bail!(
+                    ComputeError:
+                    "values and lengths must have the same length for rle_decode. Expected {}, got {} at row {}",
+                    end - start, l_end - l_start, i
+                );
+            }
+            for (k, l) in (start..end).zip(l_start..l_end) {
+                let length = lengths.get(l).unwrap_or(0);
+                if length < 0 {
+                    polars_bail!(ComputeError: "run lengths must be non-negative, got {} at row {}", length, i);
+                }
+                take_idx.extend(std::iter::repeat_n(k as IdxSize, length as usize));
+            }
+        }
+        validity.push(valid);
+        offsets.push(take_idx.len() as i64);
+    }
+
+    let values = values_ca.get_inner().take(&IdxCa::from_vec("".into(), take_idx))?;
+    list_from_flat_values(inputs[0].name().clone(), &values, offsets, validity.into())
+}
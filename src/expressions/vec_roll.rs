@@ -0,0 +1,99 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::Array;
+use polars_arrow::bitmap::MutableBitmap;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    ensure_contiguous_list, list_from_flat_values, list_into_array, single_list_array,
+};
+
+/// What happens to elements shifted past one end of a row.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum RollMode {
+    /// They come back in at the other end.
+    Wrap,
+    /// They are dropped and the vacated positions filled.
+    Fill,
+}
+
+#[derive(serde::Deserialize)]
+struct RollKwargs {
+    mode: RollMode,
+    fill_value: Option<f64>,
+}
+
+fn vec_roll_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => Ok(field.clone()),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Shift each row's list by `n` positions towards its end, like `numpy.roll` along the list.
+/// inputs[0] = values (List/Array)
+/// inputs[1] = n (integer scalar per row, or a length-1 column such as a literal)
+/// With `mode = "wrap"` elements shifted past one end come back in at the other; with
+/// `mode = "fill"` the vacated positions are null, or `fill_value` if given. Negative `n`
+/// shifts towards the start. Null lists or a null `n` give a null row.
+#[polars_expr(output_type_func=vec_roll_output_type)]
+fn vec_roll(inputs: &[Series], kwargs: RollKwargs) -> PolarsResult<Series> {
+    let wrap = kwargs.mode == RollMode::Wrap;
+    let input_dtype = inputs[0].dtype().clone();
+    let series = ensure_contiguous_list(&inputs[0])?;
+    let list_chunked = series.list()?;
+    let n_rows = list_chunked.len();
+    let shifts_series = inputs[1].cast(&DataType::Int64)?;
+    let shifts = shifts_series.i64()?;
+    if shifts.len() != n_rows && shifts.len() != 1 {
+        polars_bail!(
+            ComputeError:
+            "n must have one value or one per row. Expected {}, got {}",
+            n_rows, shifts.len()
+        );
+    }
+    let shift = |i: usize| shifts.get(if shifts.len() == 1 { 0 } else { i });
+
+    let list_arr = single_list_array(list_chunked);
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    // Gather indices into the flat values buffer; positions vacated in fill mode are None and
+    // remembered separately so they can be filled without touching in-list nulls
+    let mut take_idx: Vec<Option<IdxSize>> = Vec::with_capacity(list_arr.values().len());
+    let mut in_range = MutableBitmap::with_capacity(list_arr.values().len());
+    let mut validity = MutableBitmap::with_capacity(n_rows);
+    for i in 0..n_rows {
+        let n = shift(i).filter(|_| list_arr.is_valid(i));
+        if let Some(n) = n {
+            let (start, end) = list_arr.offsets().start_end(i);
+            let len = (end - start) as i64;
+            for pos in 0..len {
+                let src = if wrap { (pos - n).rem_euclid(len) } else { pos - n };
+                let inside = (0..len).contains(&src);
+                take_idx.push(inside.then(|| (start as i64 + src) as IdxSize));
+                in_range.push(inside);
+            }
+        }
+        validity.push(n.is_some());
+        offsets.push(take_idx.len() as i64);
+    }
+
+    let idx_ca = IdxCa::from_iter_options("".into(), take_idx.into_iter());
+    let mut values = list_chunked.get_inner().take(&idx_ca)?;
+    if let Some(fill_value) = kwargs.fill_value.filter(|_| !wrap) {
+        let mask = BooleanChunked::from_bitmap("".into(), in_range.freeze());
+        let fill_series = Series::new("".into(), [fill_value])
+            .cast(values.dtype())?
+            .new_from_index(0, values.len());
+        values = values.zip_with(&mask, &fill_series)?;
+    }
+    let result_series =
+        list_from_flat_values(series.name().clone(), &values, offsets, validity.into())?;
+
+    // Cast back to Array if input was Array
+    match &input_dtype {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
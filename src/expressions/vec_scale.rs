@@ -0,0 +1,90 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{float_list_output_type, map_rows_f64, Welford};
+use super::resampling::sorted_quantile;
+
+#[derive(serde::Deserialize)]
+struct MinMaxScaleKwargs {
+    range_min: f64,
+    range_max: f64,
+}
+
+#[derive(serde::Deserialize)]
+struct ZScoreKwargs {
+    ddof: u8,
+}
+
+/// Center and scale of a row from its finite values by `stats`. A zero scale is replaced by
+/// 1 so constant rows map to the center rather than NaN; rows without finite values give NaN.
+pub(super) fn center_scale(finite: &[f64], stats: impl Fn(&[f64]) -> (f64, f64)) -> (f64, f64) {
+    if finite.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+    let (center, scale) = stats(finite);
+    (center, if scale == 0.0 { 1.0 } else { scale })
+}
+
+/// `(min, max - min)`, the statistics of min-max scaling.
+pub(super) fn minmax_stats(finite: &[f64]) -> (f64, f64) {
+    let (min, max) = finite
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+    (min, max - min)
+}
+
+/// `(mean, std)` with divisor `n - ddof`, the statistics of z-scoring.
+pub(super) fn zscore_stats(finite: &[f64], ddof: u8) -> (f64, f64) {
+    let mut state = Welford::default();
+    finite.iter().for_each(|&v| state.push(v));
+    let mean = state.mean().unwrap_or(f64::NAN);
+    (mean, state.std(ddof).unwrap_or(f64::NAN))
+}
+
+/// Rescale every non-null element as `(x - center) / scale`, with the statistics taken
+/// by `stats` from the row's finite values, sorted (see [`center_scale`]).
+fn scale_rows(
+    series: &Series,
+    stats: impl Fn(&[f64]) -> (f64, f64),
+    target: impl Fn(f64) -> f64,
+) -> PolarsResult<Series> {
+    let mut finite: Vec<f64> = Vec::new();
+    map_rows_f64(series, |ca, out| {
+        finite.clear();
+        finite.extend(ca.iter().flatten().filter(|v| v.is_finite()));
+        finite.sort_unstable_by(f64::total_cmp);
+        let (center, scale) = center_scale(&finite, &stats);
+        out.extend(ca.iter().map(|v| v.map(|v| target((v - center) / scale))));
+        Ok(())
+    })
+}
+
+/// Min-max scale each row's list to `[range_min, range_max]`.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_minmax_scale(inputs: &[Series], kwargs: MinMaxScaleKwargs) -> PolarsResult<Series> {
+    let (lo, hi) = (kwargs.range_min, kwargs.range_max);
+    if !(lo.is_finite() && hi.is_finite()) || lo >= hi {
+        polars_bail!(ComputeError: "feature_range must be finite with min < max, got ({}, {})", lo, hi);
+    }
+    scale_rows(&inputs[0], minmax_stats, |unit| lo + unit * (hi - lo))
+}
+
+/// Robust scale each row's list as `(x - median) / IQR`, with the interquartile range
+/// between linearly interpolated 25th and 75th percentiles.
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_robust_scale(inputs: &[Series]) -> PolarsResult<Series> {
+    scale_rows(
+        &inputs[0],
+        |sorted| {
+            let q = |p| sorted_quantile(sorted, p).unwrap_or(f64::NAN);
+            (q(0.5), q(0.75) - q(0.25))
+        },
+        |v| v,
+    )
+}
+
+/// Standardize each row's list against its own mean and standard deviation (`ddof`).
+#[polars_expr(output_type_func=float_list_output_type)]
+fn vec_zscore(inputs: &[Series], kwargs: ZScoreKwargs) -> PolarsResult<Series> {
+    scale_rows(&inputs[0], |finite| zscore_stats(finite, kwargs.ddof), |v| v)
+}
@@ -0,0 +1,116 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::{Array, ListArray, PrimitiveArray};
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_contiguous_list, single_list_array};
+
+/// Which of several suitable insertion positions to return.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum Side {
+    /// The first, before any equal values.
+    Left,
+    /// The last, after any equal values.
+    Right,
+}
+
+#[derive(serde::Deserialize)]
+struct SearchSortedKwargs {
+    side: Side,
+}
+
+fn vec_search_sorted_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let dtype = match input_fields[1].dtype() {
+        DataType::List(_) | DataType::Array(..) => DataType::List(Box::new(DataType::UInt32)),
+        _ => DataType::UInt32,
+    };
+    Ok(Field::new(input_fields[0].name().clone(), dtype))
+}
+
+/// List column cast to Float64 in one contiguous chunk.
+fn f64_lists(series: &Series) -> PolarsResult<Series> {
+    Ok(ensure_contiguous_list(series)?
+        .cast(&DataType::List(Box::new(DataType::Float64)))?
+        .rechunk())
+}
+
+/// The flat Float64 buffer behind a list array prepared by [`f64_lists`].
+fn flat_f64(list_arr: &ListArray<i64>) -> &PrimitiveArray<f64> {
+    list_arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .expect("lists were cast to Float64")
+}
+
+/// Insertion indices of query values into each row's sorted list.
+/// inputs[0] = sorted (List/Array sorted ascending; null elements are treated as largest)
+/// inputs[1] = queries (numeric scalar per row, or List/Array of queries per row)
+/// `side` "left" gives the first suitable position, "right" the last. Null or NaN queries
+/// and null sorted rows give null.
+#[polars_expr(output_type_func=vec_search_sorted_output_type)]
+fn vec_search_sorted(inputs: &[Series], kwargs: SearchSortedKwargs) -> PolarsResult<Series> {
+    let right = kwargs.side == Side::Right;
+    let sorted_series = f64_lists(&inputs[0])?;
+    let sorted_arr = single_list_array(sorted_series.list()?);
+    let sorted = flat_f64(sorted_arr);
+    let n_rows = sorted_arr.len();
+    let name = inputs[0].name().clone();
+
+    let search = |i: usize, query: Option<f64>| -> Option<u32> {
+        let query = query.filter(|q| !q.is_nan())?;
+        if !sorted_arr.is_valid(i) {
+            return None;
+        }
+        // Binary search for the first element that the query goes before
+        let (start, end) = sorted_arr.offsets().start_end(i);
+        let (mut lo, mut hi) = (start, end);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let before = sorted.get(mid).is_some_and(|v| v < query || (right && v == query));
+            if before {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Some((lo - start) as u32)
+    };
+
+    let queries = &inputs[1];
+    let broadcast = queries.len() == 1;
+    if queries.len() != n_rows && !broadcast {
+        polars_bail!(
+            ComputeError:
+            "queries must have the same number of rows as the sorted lists. Expected {}, got {}",
+            n_rows, queries.len()
+        );
+    }
+    let row = |i: usize| if broadcast { 0 } else { i };
+
+    if !matches!(queries.dtype(), DataType::List(_) | DataType::Array(..)) {
+        let queries = queries.cast(&DataType::Float64)?;
+        let queries = queries.f64()?;
+        let out: UInt32Chunked = (0..n_rows).map(|i| search(i, queries.get(row(i)))).collect();
+        return Ok(out.with_name(name).into_series());
+    }
+
+    let queries_series = f64_lists(queries)?;
+    let queries_arr = single_list_array(queries_series.list()?);
+    let query_values = flat_f64(queries_arr);
+    let mut builder = ListPrimitiveChunkedBuilder::<UInt32Type>::new(
+        name,
+        n_rows,
+        query_values.len(),
+        DataType::UInt32,
+    );
+    for i in 0..n_rows {
+        if !queries_arr.is_valid(row(i)) || !sorted_arr.is_valid(i) {
+            builder.append_null();
+            continue;
+        }
+        let (start, end) = queries_arr.offsets().start_end(row(i));
+        builder.append_iter((start..end).map(|k| search(i, query_values.get(k))));
+    }
+    Ok(builder.finish().into_series())
+}
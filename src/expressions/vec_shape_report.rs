@@ -0,0 +1,61 @@
+#![allow(clippy::unused_unit)]
+use std::collections::HashSet;
+
+use polars::prelude::*;
+use polars_arrow::array::Array;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_contiguous_list, single_list_array};
+
+const COUNT_FIELDS: [&str; 5] = ["n_rows", "n_null_rows", "min_len", "max_len", "n_distinct_lens"];
+
+fn vec_shape_report_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let mut fields: Vec<Field> =
+        COUNT_FIELDS.iter().map(|name| Field::new((*name).into(), DataType::UInt64)).collect();
+    fields.push(Field::new("inner_dtype".into(), DataType::String));
+    Ok(Field::new(field.name().clone(), DataType::Struct(fields)))
+}
+
+/// One-row summary of a list column's shape, for diagnosing length-mismatch errors:
+/// row and null-row counts, the shortest and longest non-null list, the number of
+/// distinct list lengths, and the inner dtype. Min/max are null without non-null rows.
+#[polars_expr(output_type_func=vec_shape_report_output_type)]
+fn vec_shape_report(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let inner_dtype = match series.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => inner.to_string(),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    };
+    let list_series = ensure_contiguous_list(series)?;
+    let list_arr = single_list_array(list_series.list()?);
+
+    let n_rows = list_arr.len();
+    let mut lengths: HashSet<usize> = HashSet::new();
+    let (mut min_len, mut max_len) = (None::<usize>, None::<usize>);
+    for i in (0..n_rows).filter(|&i| list_arr.is_valid(i)) {
+        let (start, end) = list_arr.offsets().start_end(i);
+        let len = end - start;
+        lengths.insert(len);
+        min_len = Some(min_len.map_or(len, |m| m.min(len)));
+        max_len = Some(max_len.map_or(len, |m| m.max(len)));
+    }
+
+    let counts = [
+        Some(n_rows),
+        Some(list_arr.null_count()),
+        min_len,
+        max_len,
+        Some(lengths.len()),
+    ];
+    let mut fields: Vec<Series> = COUNT_FIELDS
+        .iter()
+        .zip(counts)
+        .map(|(name, count)| Series::new((*name).into(), [count.map(|c| c as u64)]))
+        .collect();
+    fields.push(Series::new("inner_dtype".into(), [inner_dtype]));
+    Ok(StructChunked::from_series(series.name().clone(), 1, fields.iter())?.into_series())
+}
@@ -0,0 +1,101 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::bitmap::MutableBitmap;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{array_from_flat_values, ensure_contiguous_list, single_list_array};
+
+#[derive(serde::Deserialize)]
+struct SliceAroundKwargs {
+    before: usize,
+    after: usize,
+    pad: Option<f64>,
+}
+
+fn vec_slice_around_output_type(
+    input_fields: &[Field],
+    kwargs: SliceAroundKwargs,
+) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::Array(inner.clone(), kwargs.before + kwargs.after),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Extract a fixed-width window from each row's list around a per-row index.
+/// inputs[0] = values (List/Array)
+/// inputs[1] = index into the same row's list (integer scalar per row)
+/// The window is `list[index - before .. index + after]`; samples outside the list are
+/// null, or `pad` if given. Null lists or null indices give a null row.
+#[polars_expr(output_type_func_with_kwargs=vec_slice_around_output_type)]
+fn vec_slice_around(inputs: &[Series], kwargs: SliceAroundKwargs) -> PolarsResult<Series> {
+    let series = ensure_contiguous_list(&inputs[0])?;
+    let list_chunked = series.list()?;
+    let indices_series = inputs[1].cast(&DataType::Int64)?;
+    let indices = indices_series.i64()?;
+    let n_rows = list_chunked.len();
+    if indices.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "indices must have the same number of rows as the values. Expected {}, got {}",
+            n_rows, indices.len()
+        );
+    }
+
+    let width = kwargs.before + kwargs.after;
+    let list_arr = single_list_array(list_chunked);
+    let offsets = list_arr.offsets();
+    let outer_validity = list_arr.validity();
+
+    // Gather indices into the flat values buffer; out-of-range samples are None and
+    // remembered separately so they can be padded without touching in-list nulls
+    let mut take_idx: Vec<Option<IdxSize>> = Vec::with_capacity(n_rows * width);
+    let mut in_range = MutableBitmap::with_capacity(n_rows * width);
+    let mut row_validity = MutableBitmap::with_capacity(n_rows);
+    for (i, index) in indices.iter().enumerate() {
+        let row_is_valid = outer_validity.is_none_or(|v| v.get_bit(i));
+        match index.filter(|_| row_is_valid) {
+            Some(index) => {
+                let start = offsets[i];
+                let len = offsets[i + 1] - start;
+                let first = index - kwargs.before as i64;
+                for pos in 0..width as i64 {
+                    let idx = first + pos;
+                    if idx >= 0 && idx < len {
+                        take_idx.push(Some((start + idx) as IdxSize));
+                        in_range.push(true);
+                    } else {
+                        take_idx.push(None);
+                        in_range.push(false);
+                    }
+                }
+                row_validity.push(true);
+            },
+            None => {
+                take_idx.extend(std::iter::repeat_n(None, width));
+                in_range.extend_constant(width, true);
+                row_validity.push(false);
+            },
+        }
+    }
+
+    let idx_ca = IdxCa::from_iter_options("".into(), take_idx.into_iter());
+    let mut values = list_chunked.get_inner().take(&idx_ca)?;
+    if let Some(pad) = kwargs.pad {
+        let mask = BooleanChunked::from_bitmap("".into(), in_range.freeze());
+        let pad_series =
+            Series::new("".into(), [pad]).cast(values.dtype())?.new_from_index(0, values.len());
+        values = values.zip_with(&mask, &pad_series)?;
+    }
+
+    array_from_flat_values(
+        series.name().clone(),
+        &values,
+        width,
+        n_rows,
+        row_validity.into(),
+    )
+}
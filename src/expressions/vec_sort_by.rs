@@ -0,0 +1,153 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::{Array, ListArray, PrimitiveArray};
+use polars_arrow::bitmap::MutableBitmap;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    ensure_contiguous_list, list_from_flat_values, list_into_array, single_list_array,
+};
+
+#[derive(serde::Deserialize)]
+struct SortKwargs {
+    descending: bool,
+    nulls_last: bool,
+}
+
+fn vec_arg_sort_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let idx = Box::new(DataType::UInt32);
+    let dtype = match field.dtype() {
+        DataType::List(_) => DataType::List(idx),
+        DataType::Array(_, width) => DataType::Array(idx, *width),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+fn vec_sort_by_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(input_fields[0].clone())
+}
+
+/// Per-row sort keys cast to Float64 in one contiguous chunk.
+struct SortKeys {
+    series: Series,
+}
+
+impl SortKeys {
+    fn new(series: &Series) -> PolarsResult<Self> {
+        let series = ensure_contiguous_list(series)?
+            .cast(&DataType::List(Box::new(DataType::Float64)))?
+            .rechunk();
+        Ok(Self { series })
+    }
+
+    fn list_array(&self) -> &ListArray<i64> {
+        single_list_array(self.series.list().expect("keys were cast to List"))
+    }
+
+    /// Stable ordering of row `i`'s positions by key, written to `perm`. NaN sorts as the
+    /// largest value; nulls go first or last regardless of `descending`.
+    fn permutation(&self, i: usize, kwargs: &SortKwargs, perm: &mut Vec<u32>) {
+        let list_arr = self.list_array();
+        let keys = list_arr
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .expect("keys were cast to Float64");
+        let start = list_arr.offsets()[i] as usize;
+        let len = list_arr.offsets()[i + 1] as usize - start;
+        let key = |k: u32| keys.get(start + k as usize);
+        perm.clear();
+        perm.extend(0..len as u32);
+        perm.sort_by(|&x, &y| match (key(x), key(y)) {
+            (Some(a), Some(b)) if kwargs.descending => b.total_cmp(&a),
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (a, b) if kwargs.nulls_last => a.is_none().cmp(&b.is_none()),
+            (a, b) => b.is_none().cmp(&a.is_none()),
+        });
+    }
+}
+
+/// Indices that would sort each row's list, as `UInt32` positions within the list.
+#[polars_expr(output_type_func=vec_arg_sort_output_type)]
+fn vec_arg_sort(inputs: &[Series], kwargs: SortKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let keys = SortKeys::new(series)?;
+    let list_arr = keys.list_array();
+    let n_rows = list_arr.len();
+
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    let mut indices: Vec<u32> = Vec::with_capacity(list_arr.values().len());
+    let mut perm: Vec<u32> = Vec::new();
+    for i in 0..n_rows {
+        if list_arr.is_valid(i) {
+            keys.permutation(i, &kwargs, &mut perm);
+            indices.extend_from_slice(&perm);
+        }
+        offsets.push(indices.len() as i64);
+    }
+
+    let values = UInt32Chunked::from_vec("".into(), indices).into_series();
+    let result =
+        list_from_flat_values(series.name().clone(), &values, offsets, list_arr.validity().cloned())?;
+    match series.dtype() {
+        DataType::Array(_, width) => list_into_array(&result, *width),
+        _ => Ok(result),
+    }
+}
+
+/// Sort each row's values by the corresponding elements of a companion keys list.
+/// inputs[0] = values (List/Array, any inner type)
+/// inputs[1] = keys (numeric List/Array, same length per row)
+/// A null values or keys row gives a null row.
+#[polars_expr(output_type_func=vec_sort_by_output_type)]
+fn vec_sort_by(inputs: &[Series], kwargs: SortKwargs) -> PolarsResult<Series> {
+    let series = ensure_contiguous_list(&inputs[0])?;
+    let list_chunked = series.list()?;
+    let values_arr = single_list_array(list_chunked);
+    let keys = SortKeys::new(&inputs[1])?;
+    let keys_arr = keys.list_array();
+    let n_rows = list_chunked.len();
+    if keys_arr.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "keys must have the same number of rows as the values. Expected {}, got {}",
+            n_rows, keys_arr.len()
+        );
+    }
+
+    let values_offsets = values_arr.offsets();
+    let mut offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    offsets.push(0);
+    let mut take_idx: Vec<IdxSize> = Vec::with_capacity(values_arr.values().len());
+    let mut validity = MutableBitmap::with_capacity(n_rows);
+    let mut perm: Vec<u32> = Vec::new();
+    for i in 0..n_rows {
+        let valid = values_arr.is_valid(i) && keys_arr.is_valid(i);
+        if valid {
+            let start = values_offsets[i];
+            let len = values_offsets[i + 1] - start;
+            let key_len = keys_arr.offsets()[i + 1] - keys_arr.offsets()[i];
+            if len != key_len {
+                polars_bail!(
+                    ComputeError:
+                    "values and keys must have the same length for sort_by. Expected {}, got {} at row {}",
+                    len, key_len, i
+                );
+            }
+            keys.permutation(i, &kwargs, &mut perm);
+            take_idx.extend(perm.iter().map(|&k| (start + k as i64) as IdxSize));
+        }
+        validity.push(valid);
+        offsets.push(take_idx.len() as i64);
+    }
+
+    let idx_ca = IdxCa::from_vec("".into(), take_idx);
+    let values = list_chunked.get_inner().take(&idx_ca)?;
+    let result = list_from_flat_values(series.name().clone(), &values, offsets, validity.into())?;
+    match inputs[0].dtype() {
+        DataType::Array(_, width) => list_into_array(&result, *width),
+        _ => Ok(result),
+    }
+}
@@ -0,0 +1,95 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_arrow::array::PrimitiveArray;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{ensure_contiguous_list, ensure_list_type, single_list_array, Welford};
+
+#[derive(serde::Deserialize)]
+struct StatsKwargs {
+    ddof: u8,
+}
+
+const STAT_NAMES: [&str; 5] = ["mean", "std", "min", "max", "median"];
+
+fn vec_stats_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let fields = STAT_NAMES
+        .iter()
+        .map(|name| Field::new((*name).into(), DataType::Float64))
+        .collect();
+    Ok(Field::new(field.name().clone(), DataType::Struct(fields)))
+}
+
+/// Median of `values`, reordering them in place.
+fn median(values: &mut [f64]) -> Option<f64> {
+    let n = values.len();
+    if n == 0 {
+        return None;
+    }
+    let (lower, &mut upper, _) = values.select_nth_unstable_by(n / 2, f64::total_cmp);
+    if n % 2 == 1 {
+        return Some(upper);
+    }
+    let below = lower.iter().copied().max_by(f64::total_cmp)?;
+    Some((below + upper) / 2.0)
+}
+
+/// Summary statistics of each row's own list in a single pass over the flat values buffer.
+/// Null elements are skipped; null rows, and statistics without enough values (e.g. std
+/// with no more than `ddof` values), are null.
+#[polars_expr(output_type_func=vec_stats_output_type)]
+fn vec_stats(inputs: &[Series], kwargs: StatsKwargs) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let series = ensure_contiguous_list(&ensure_list_type(&inputs[0])?.cast(&f64_list)?)?;
+    let list_chunked = series.list()?;
+    let n_rows = list_chunked.len();
+    let list_arr = single_list_array(list_chunked);
+    let offsets = list_arr.offsets();
+    let row_validity = list_arr.validity();
+    let values = list_arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .expect("List(Float64) values are a Float64 array");
+    let value_validity = values.validity();
+    let values = values.values().as_slice();
+
+    let mut columns: [Vec<Option<f64>>; 5] = std::array::from_fn(|_| Vec::with_capacity(n_rows));
+    let mut buf: Vec<f64> = Vec::new();
+    for i in 0..n_rows {
+        if row_validity.is_some_and(|v| !v.get_bit(i)) {
+            columns.iter_mut().for_each(|c| c.push(None));
+            continue;
+        }
+        let mut state = Welford::default();
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        buf.clear();
+        let (start, end) = (offsets[i] as usize, offsets[i + 1] as usize);
+        for (k, &v) in values.iter().enumerate().take(end).skip(start) {
+            if value_validity.is_some_and(|valid| !valid.get_bit(k)) {
+                continue;
+            }
+            state.push(v);
+            min = min.min(v);
+            max = max.max(v);
+            buf.push(v);
+        }
+        let any = state.n > 0;
+        columns[0].push(state.mean());
+        columns[1].push(state.std(kwargs.ddof));
+        columns[2].push(any.then_some(min));
+        columns[3].push(any.then_some(max));
+        columns[4].push(median(&mut buf));
+    }
+
+    let fields: Vec<Series> = STAT_NAMES
+        .iter()
+        .zip(columns)
+        .map(|(name, values)| Series::new((*name).into(), values))
+        .collect();
+    Ok(StructChunked::from_series(series.name().clone(), n_rows, fields.iter())?.into_series())
+}
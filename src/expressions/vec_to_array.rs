@@ -0,0 +1,60 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::list_into_array;
+
+#[derive(serde::Deserialize)]
+struct ToArrayKwargs {
+    width: usize,
+}
+
+fn vec_to_array_output_type(
+    input_fields: &[Field],
+    kwargs: ToArrayKwargs,
+) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => Ok(Field::new(
+            field.name().clone(),
+            DataType::Array(inner.clone(), kwargs.width),
+        )),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+fn to_array(series: &Series, width: usize) -> PolarsResult<Series> {
+    match series.dtype() {
+        DataType::Array(_, w) if *w == width => Ok(series.clone()),
+        DataType::Array(_, w) => polars_bail!(
+            ComputeError: "Expected Array of width {}, got width {}", width, w
+        ),
+        _ => list_into_array(series, width),
+    }
+}
+
+/// Convert a List column to `Array(inner, width)` after checking that every non-null row
+/// has exactly `width` elements; the error names the first row that does not.
+/// Array inputs must already have this width.
+#[polars_expr(output_type_func_with_kwargs=vec_to_array_output_type)]
+fn vec_to_array(inputs: &[Series], kwargs: ToArrayKwargs) -> PolarsResult<Series> {
+    to_array(&inputs[0], kwargs.width)
+}
+
+/// Like `vec_to_array`, but for a dense matrix: every row must be present and free of null
+/// elements, and the result is a single chunk, so its values form one contiguous
+/// row-major buffer that numpy can view as a 2D array without copying.
+#[polars_expr(output_type_func_with_kwargs=vec_to_array_output_type)]
+fn vec_to_matrix(inputs: &[Series], kwargs: ToArrayKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    if let Some(row) = series.is_null().iter().position(|is_null| is_null == Some(true)) {
+        polars_bail!(ComputeError: "Row {} is null; to_matrix needs a list in every row", row);
+    }
+    let array = to_array(series, kwargs.width)?.rechunk();
+    let n_nulls = array.array()?.get_inner().null_count();
+    if n_nulls > 0 {
+        polars_bail!(
+            ComputeError: "to_matrix needs lists without null elements, found {}", n_nulls
+        );
+    }
+    Ok(array)
+}
@@ -0,0 +1,113 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars::polars_utils::float::IsFloat;
+use polars::polars_utils::total_ord::TotalOrd;
+use polars_arrow::array::Array;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{
+    ensure_contiguous_list, list_from_flat_values, physical_numeric_values, single_list_array,
+    with_numeric_type,
+};
+
+#[derive(serde::Deserialize)]
+struct TopKKwargs {
+    k: usize,
+    return_indices: bool,
+}
+
+fn vec_top_k_output_type(input_fields: &[Field], kwargs: TopKKwargs) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let inner = match field.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => inner.clone(),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    };
+    let values = DataType::List(inner);
+    let dtype = if kwargs.return_indices {
+        DataType::Struct(vec![
+            Field::new("values".into(), values),
+            Field::new("indices".into(), DataType::List(Box::new(DataType::UInt32))),
+        ])
+    } else {
+        values
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// For each row starting at `offsets[i]`, the positions of its k largest non-null, non-NaN
+/// elements, compared in their own dtype: larger values first, then earlier positions.
+/// Returns each element's flat index, its position in the row, and the output offsets.
+/// Null rows (per `row_valid`) come out empty.
+fn top_k_by_row<T: PolarsNumericType>(
+    values: &ChunkedArray<T>,
+    offsets: &[i64],
+    row_valid: impl Fn(usize) -> bool,
+    k: usize,
+) -> (Vec<IdxSize>, Vec<u32>, Vec<i64>) {
+    let arr = values.downcast_iter().next().expect("values should be a single chunk");
+    let n_rows = offsets.len() - 1;
+    let mut take: Vec<IdxSize> = Vec::with_capacity(n_rows * k);
+    let mut positions: Vec<u32> = Vec::with_capacity(n_rows * k);
+    let mut out_offsets: Vec<i64> = Vec::with_capacity(n_rows + 1);
+    out_offsets.push(0);
+    let order = |a: &(T::Native, u32), b: &(T::Native, u32)| {
+        b.0.tot_cmp(&a.0).then(a.1.cmp(&b.1))
+    };
+    let mut candidates: Vec<(T::Native, u32)> = Vec::new();
+    for i in 0..n_rows {
+        if row_valid(i) {
+            let start = offsets[i] as usize;
+            candidates.clear();
+            candidates.extend((start..offsets[i + 1] as usize).filter_map(|idx| {
+                let v = arr.get(idx).filter(|v| !v.is_nan())?;
+                Some((v, (idx - start) as u32))
+            }));
+            if k < candidates.len() {
+                candidates.select_nth_unstable_by(k, order);
+                candidates.truncate(k);
+            }
+            candidates.sort_unstable_by(order);
+            take.extend(candidates.iter().map(|c| (start + c.1 as usize) as IdxSize));
+            positions.extend(candidates.iter().map(|c| c.1));
+        }
+        out_offsets.push(take.len() as i64);
+    }
+    (take, positions, out_offsets)
+}
+
+/// The k largest elements of each row's list in descending order, optionally with their
+/// positions in the list. Null and NaN elements are skipped, ties keep list order, and
+/// rows with fewer than k values return what they have. Elements are ranked in their own
+/// dtype and returned as the original values.
+#[polars_expr(output_type_func_with_kwargs=vec_top_k_output_type)]
+fn vec_top_k(inputs: &[Series], kwargs: TopKKwargs) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let list_series = ensure_contiguous_list(series)?;
+    let list_chunked = list_series.list()?;
+    let n_rows = list_chunked.len();
+    let name = series.name().clone();
+    let arr = single_list_array(list_chunked);
+    let physical = physical_numeric_values(list_chunked)?;
+    let (take, positions, offsets) = with_numeric_type!(physical.dtype(), T => {
+        top_k_by_row(
+            physical.unpack::<T>()?,
+            arr.offsets().as_slice(),
+            |i| arr.is_valid(i),
+            kwargs.k,
+        )
+    });
+    let validity = arr.validity().cloned();
+
+    // Take the physical values, then cast back so logical dtypes such as Date come through
+    let top = physical.take(&IdxCa::from_vec("".into(), take))?;
+    let values = list_from_flat_values(name.clone(), &top, offsets.clone(), validity.clone())?
+        .cast(&DataType::List(Box::new(list_chunked.inner_dtype().clone())))?;
+    if !kwargs.return_indices {
+        return Ok(values);
+    }
+    let positions = UInt32Chunked::from_vec("".into(), positions).into_series();
+    let fields = [
+        values.with_name("values".into()),
+        list_from_flat_values("indices".into(), &positions, offsets, validity)?,
+    ];
+    Ok(StructChunked::from_series(name, n_rows, fields.iter())?.into_series())
+}
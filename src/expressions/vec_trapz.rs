@@ -0,0 +1,90 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::ensure_list_type;
+
+#[derive(serde::Deserialize)]
+struct TrapzKwargs {
+    dx: f64,
+}
+
+fn vec_trapz_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {
+            Ok(Field::new(field.name().clone(), DataType::Float64))
+        },
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+}
+
+/// Trapezoidal area over the points whose x and y are both non-null and finite.
+fn trapz(points: impl Iterator<Item = (Option<f64>, Option<f64>)>) -> f64 {
+    let mut area = 0.0;
+    let mut prev: Option<(f64, f64)> = None;
+    for point in points {
+        if let (Some(x), Some(y)) = point {
+            if !x.is_finite() || !y.is_finite() {
+                continue;
+            }
+            if let Some((px, py)) = prev {
+                area += (x - px) * (y + py) / 2.0;
+            }
+            prev = Some((x, y));
+        }
+    }
+    area
+}
+
+/// Area under each row's list.
+/// inputs[0] = y values (List/Array)
+/// inputs[1] = optional x values (List/Array, same length per row); otherwise x = i * dx
+#[polars_expr(output_type_func=vec_trapz_output_type)]
+fn vec_trapz(inputs: &[Series], kwargs: TrapzKwargs) -> PolarsResult<Series> {
+    let f64_list = DataType::List(Box::new(DataType::Float64));
+    let y_series = ensure_list_type(&inputs[0])?.cast(&f64_list)?;
+    let y_ca = y_series.list()?;
+    let n_rows = y_ca.len();
+
+    let mut out: Vec<Option<f64>> = Vec::with_capacity(n_rows);
+    if let Some(x_input) = inputs.get(1) {
+        let x_series = ensure_list_type(x_input)?.cast(&f64_list)?;
+        let x_ca = x_series.list()?;
+        if x_ca.len() != n_rows {
+            polars_bail!(
+                ComputeError:
+                "x must have the same number of rows as y. Expected {}, got {}",
+                n_rows, x_ca.len()
+            );
+        }
+        for (row, (y, x)) in y_ca.amortized_iter().zip(x_ca.amortized_iter()).enumerate() {
+            match (y, x) {
+                (Some(y), Some(x)) => {
+                    let (y, x) = (y.as_ref().f64()?, x.as_ref().f64()?);
+                    if y.len() != x.len() {
+                        polars_bail!(
+                            ComputeError:
+                            "x and y lists must have the same length. Row {}: x has {}, y has {}",
+                            row, x.len(), y.len()
+                        );
+                    }
+                    out.push(Some(trapz(x.iter().zip(y.iter()))));
+                },
+                _ => out.push(None),
+            }
+        }
+    } else {
+        for y in y_ca.amortized_iter() {
+            out.push(match y {
+                Some(y) => {
+                    let y = y.as_ref().f64()?;
+                    let xs = (0..y.len()).map(|i| Some(i as f64 * kwargs.dx));
+                    Some(trapz(xs.zip(y.iter())))
+                },
+                None => None,
+            });
+        }
+    }
+
+    Ok(Float64Chunked::from_iter_options(y_series.name().clone(), out.into_iter()).into_series())
+}
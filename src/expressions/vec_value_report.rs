@@ -0,0 +1,55 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::ensure_list_type;
+
+const COUNT_FIELDS: [&str; 4] =
+    ["n_elements", "n_null_elements", "n_nan_elements", "n_inf_elements"];
+
+fn vec_value_report_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let fields: Vec<Field> =
+        COUNT_FIELDS.iter().map(|name| Field::new((*name).into(), DataType::UInt64)).collect();
+    Ok(Field::new(field.name().clone(), DataType::Struct(fields)))
+}
+
+/// One-row count of the elements in a list column's non-null rows, and how many of them
+/// are null, NaN or infinite (the last two are always 0 for non-float lists). Complements
+/// `vec_shape_report` when checking a column before a vertical reduction.
+#[polars_expr(output_type_func=vec_value_report_output_type)]
+fn vec_value_report(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = &inputs[0];
+    let is_float = match series.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => inner.is_float(),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    };
+    let list_series = ensure_list_type(series)?;
+    let list_series = match is_float {
+        true => list_series.cast(&DataType::List(Box::new(DataType::Float64)))?,
+        false => list_series,
+    };
+
+    let mut counts = [0u64; 4];
+    for row in list_series.list()?.amortized_iter().flatten() {
+        let row = row.as_ref();
+        counts[0] += row.len() as u64;
+        counts[1] += row.null_count() as u64;
+        if is_float {
+            for v in row.f64()?.into_iter().flatten() {
+                counts[2] += v.is_nan() as u64;
+                counts[3] += v.is_infinite() as u64;
+            }
+        }
+    }
+
+    let fields: Vec<Series> = COUNT_FIELDS
+        .iter()
+        .zip(counts)
+        .map(|(name, count)| Series::new((*name).into(), [count]))
+        .collect();
+    Ok(StructChunked::from_series(series.name().clone(), 1, fields.iter())?.into_series())
+}
@@ -0,0 +1,22 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{float_scalar_output_type, map_row_pairs_f64};
+
+/// Weighted mean `sum(w * v) / sum(w)` of each row's list, over the positions where both
+/// the value and its weight are non-null. Rows without such positions are null; NaN and
+/// infinite values propagate, and rows whose weights sum to zero give NaN.
+/// inputs[0] = values (List/Array)
+/// inputs[1] = weights (List/Array, same length per row)
+#[polars_expr(output_type_func=float_scalar_output_type)]
+fn vec_weighted_mean(inputs: &[Series]) -> PolarsResult<Series> {
+    map_row_pairs_f64(inputs, "weighted_mean", |pairs| {
+        if pairs.is_empty() {
+            return None;
+        }
+        let (weighted, total) = pairs
+            .iter()
+            .fold((0.0, 0.0), |(s, t), &(v, w)| (s + w * v, t + w));
+        Some(weighted / total)
+    })
+}
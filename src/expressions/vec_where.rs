@@ -0,0 +1,86 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use polars_core::utils::try_get_supertype;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::{broadcast_scalar, ensure_list_type, list_into_array, operand_inner_dtype};
+
+fn vec_where_output_type(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let inner = try_get_supertype(
+        operand_inner_dtype(field.dtype())?,
+        operand_inner_dtype(input_fields[2].dtype())?,
+    )?;
+    let dtype = match field.dtype() {
+        DataType::Array(_, width) => DataType::Array(Box::new(inner), *width),
+        _ => DataType::List(Box::new(inner)),
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Pick elements from a where the mask is true and from b where it is false.
+/// inputs[0] = a (List/Array, or a numeric column/literal broadcast across each row)
+/// inputs[1] = mask (List/Array of Boolean)
+/// inputs[2] = b (List/Array, or a numeric column/literal broadcast across each row)
+/// All lists must have the same length in each row. A null row in any input gives a
+/// null row, and a null mask element gives a null element.
+#[polars_expr(output_type_func=vec_where_output_type)]
+fn vec_where(inputs: &[Series]) -> PolarsResult<Series> {
+    let inner = try_get_supertype(
+        operand_inner_dtype(inputs[0].dtype())?,
+        operand_inner_dtype(inputs[2].dtype())?,
+    )?;
+    let list_dtype = DataType::List(Box::new(inner.clone()));
+    let mask_series =
+        ensure_list_type(&inputs[1])?.cast(&DataType::List(Box::new(DataType::Boolean)))?;
+    let mask_ca = mask_series.list()?;
+    let a_series = broadcast_scalar(&inputs[0], mask_ca)?.cast(&list_dtype)?;
+    let b_series = broadcast_scalar(&inputs[2], mask_ca)?.cast(&list_dtype)?;
+    let (a_ca, b_ca) = (a_series.list()?, b_series.list()?);
+    let n_rows = a_ca.len();
+    if mask_ca.len() != n_rows || b_ca.len() != n_rows {
+        polars_bail!(
+            ComputeError:
+            "All inputs must have the same number of rows for where. Expected {}, got {} and {}",
+            n_rows, mask_ca.len(), b_ca.len()
+        );
+    }
+
+    let mut rows: Vec<Option<Series>> = Vec::with_capacity(n_rows);
+    for (row, ((a, mask), b)) in a_ca
+        .amortized_iter()
+        .zip(mask_ca.amortized_iter())
+        .zip(b_ca.amortized_iter())
+        .enumerate()
+    {
+        let (Some(a), Some(mask), Some(b)) = (a, mask, b) else {
+            rows.push(None);
+            continue;
+        };
+        let (a, b) = (a.as_ref(), b.as_ref());
+        let mask = mask.as_ref().bool()?;
+        if a.len() != mask.len() || b.len() != mask.len() {
+            polars_bail!(
+                ComputeError:
+                "Lists must have the same length for where. Row {}: a has {}, mask has {}, b has {}",
+                row, a.len(), mask.len(), b.len()
+            );
+        }
+        let picked = a.zip_with(&mask.fill_null_with_values(false)?, b)?;
+        let picked = if mask.null_count() > 0 {
+            let nulls = Series::full_null(PlSmallStr::EMPTY, picked.len(), &inner);
+            picked.zip_with(&mask.is_not_null(), &nulls)?
+        } else {
+            picked
+        };
+        rows.push(Some(picked));
+    }
+
+    let out = ListChunked::from_iter(rows)
+        .with_name(inputs[0].name().clone())
+        .into_series()
+        .cast(&list_dtype)?;
+    match inputs[0].dtype() {
+        DataType::Array(_, width) => list_into_array(&out, *width),
+        _ => Ok(out),
+    }
+}
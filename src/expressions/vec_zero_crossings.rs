@@ -0,0 +1,87 @@
+#![allow(clippy::unused_unit)]
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use super::helpers::ensure_list_type;
+
+#[derive(serde::Deserialize)]
+struct ZeroCrossingsKwargs {
+    return_indices: bool,
+}
+
+fn vec_zero_crossings_output_type(
+    input_fields: &[Field],
+    kwargs: ZeroCrossingsKwargs,
+) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    match field.dtype() {
+        DataType::List(_) | DataType::Array(_, _) => {},
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    }
+    let dtype = if kwargs.return_indices {
+        DataType::List(Box::new(DataType::UInt32))
+    } else {
+        DataType::UInt32
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Find the positions at which the sign of a row changes.
+/// Nulls, NaNs and exact zeros carry no sign and are skipped, so `[1, 0, -1]` has a
+/// single crossing. The reported index is that of the first element with the new sign.
+fn zero_crossing_indices(values: impl Iterator<Item = Option<f64>>, out: &mut Vec<u32>) {
+    out.clear();
+    let mut prev_positive: Option<bool> = None;
+    for (idx, value) in values.enumerate() {
+        let v = match value {
+            Some(v) if v != 0.0 && !v.is_nan() => v,
+            _ => continue,
+        };
+        let positive = v > 0.0;
+        if prev_positive.is_some_and(|p| p != positive) {
+            out.push(idx as u32);
+        }
+        prev_positive = Some(positive);
+    }
+}
+
+#[polars_expr(output_type_func_with_kwargs=vec_zero_crossings_output_type)]
+fn vec_zero_crossings(inputs: &[Series], kwargs: ZeroCrossingsKwargs) -> PolarsResult<Series> {
+    let series = ensure_list_type(&inputs[0])?;
+    let series = series.cast(&DataType::List(Box::new(DataType::Float64)))?;
+    let list_chunked = series.list()?;
+    let n_lists = list_chunked.len();
+
+    // Scratch buffer reused across rows
+    let mut crossings: Vec<u32> = Vec::new();
+
+    if kwargs.return_indices {
+        let mut builder = ListPrimitiveChunkedBuilder::<UInt32Type>::new(
+            series.name().clone(),
+            n_lists,
+            n_lists * 4,
+            DataType::UInt32,
+        );
+        for row in list_chunked.amortized_iter() {
+            match row {
+                Some(s) => {
+                    zero_crossing_indices(s.as_ref().f64()?.iter(), &mut crossings);
+                    builder.append_slice(&crossings);
+                },
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into_series())
+    } else {
+        let mut counts: Vec<Option<u32>> = Vec::with_capacity(n_lists);
+        for row in list_chunked.amortized_iter() {
+            match row {
+                Some(s) => {
+                    zero_crossing_indices(s.as_ref().f64()?.iter(), &mut crossings);
+                    counts.push(Some(crossings.len() as u32));
+                },
+                None => counts.push(None),
+            }
+        }
+        Ok(UInt32Chunked::from_iter_options(series.name().clone(), counts.into_iter()).into_series())
+    }
+}
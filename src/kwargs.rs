@@ -0,0 +1,168 @@
+//! Options shared by several expressions, deserialized from the plugin kwargs.
+//!
+//! Options that only make sense for a single kernel stay in that kernel's own kwargs
+//! struct; anything reused across expressions lives here so the Python API spells it the
+//! same way everywhere.
+use polars::prelude::*;
+use serde::Deserialize;
+
+/// How null elements inside the lists affect a per-position result.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum NullPolicy {
+    /// Skip null elements and reduce over the remaining values.
+    #[default]
+    Ignore,
+    /// Any null element at a position makes the result at that position null.
+    Propagate,
+}
+
+/// How NaN elements in float lists affect a per-position result, independently of nulls.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum NanPolicy {
+    /// Any NaN at a position makes the result at that position NaN.
+    #[default]
+    Propagate,
+    /// Skip NaN elements, like numpy's nan-functions. They do not count towards
+    /// `min_count` and are not nulls for `null_policy`.
+    Ignore,
+    /// Treat NaN elements as nulls, so `null_policy` and `min_count` apply to them.
+    AsNull,
+}
+
+/// How thoroughly a vertical reduction checks that its lists share one length.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Validation {
+    /// Check every non-null row and report the offending rows on a mismatch.
+    #[default]
+    Strict,
+    /// Trust the caller that the lists are uniform and skip the per-row check.
+    Fast,
+}
+
+/// What a vertical reduction does with empty lists among non-empty ones.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EmptyPolicy {
+    /// Raise an error naming the first empty row.
+    #[default]
+    Error,
+    /// Leave empty rows out of the reduction, like null rows.
+    Skip,
+    /// Treat an empty row as a row of nulls, so it counts towards `null_policy` and
+    /// `min_count`.
+    TreatAsNull,
+}
+
+/// Options accepted by the vertical reductions (sum, mean, min, max).
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+#[serde(default)]
+pub(crate) struct ReduceKwargs {
+    pub null_policy: NullPolicy,
+    /// Positions with fewer non-null values than this are null in the result.
+    pub min_count: usize,
+    /// Repeat the aggregate for every input row instead of returning a single row.
+    pub keep_rows: bool,
+    pub validate: Validation,
+    pub empty_policy: EmptyPolicy,
+    pub nan_policy: NanPolicy,
+    /// First row of the vertical slice to reduce; negative values count from the end.
+    pub offset: i64,
+    /// Number of rows in the slice, or every row from `offset` on when `None`.
+    pub length: Option<usize>,
+    /// Reduce only every `every`-th row of the slice, starting with its first; 0 and 1 both
+    /// take every row.
+    pub every: usize,
+}
+
+impl ReduceKwargs {
+    /// The rows selected by `offset`, `length` and `every` (the slice is clamped to the
+    /// column like `Series.slice`), with a map from a selected row's index to its row
+    /// number in the whole column, for error messages.
+    pub(crate) fn select_rows(
+        &self,
+        series: &Series,
+    ) -> PolarsResult<(impl Fn(usize) -> usize + Copy, Series)> {
+        let n = series.len();
+        let start = match self.offset {
+            offset if offset < 0 => n.saturating_sub(offset.unsigned_abs() as usize),
+            offset => (offset as usize).min(n),
+        };
+        let len = self.length.map_or(n - start, |len| len.min(n - start));
+        let step = self.every.max(1);
+        let mut selected = series.slice(start as i64, len);
+        if step > 1 {
+            let idx: Vec<IdxSize> = (0..len).step_by(step).map(|i| i as IdxSize).collect();
+            selected = selected.take(&IdxCa::from_vec("".into(), idx))?;
+        }
+        Ok((move |i| start + i * step, selected))
+    }
+
+    /// The row as the accumulators should see it: with `NanPolicy::Ignore` or
+    /// `NanPolicy::AsNull` its NaN elements become nulls, which every accumulator skips.
+    pub(crate) fn accumulation_row(&self, row: &Series) -> PolarsResult<Series> {
+        if self.nan_policy == NanPolicy::Propagate || !row.dtype().is_float() {
+            return Ok(row.clone());
+        }
+        let is_nan = nan_mask(row)?;
+        if !is_nan.any() {
+            return Ok(row.clone());
+        }
+        let nulls = Series::full_null(row.name().clone(), row.len(), row.dtype());
+        row.zip_with(&!&is_nan, &nulls)
+    }
+
+    /// Null out the positions of a vertical reduction `result` that fail the policy, given
+    /// the non-null `rows` it was reduced from (before `accumulation_row`). With
+    /// `NanPolicy::Propagate`, positions holding a NaN in any row are NaN.
+    pub(crate) fn mask_result(&self, result: Series, rows: &[Series]) -> PolarsResult<Series> {
+        let n = result.len();
+        let (mut valid, mut missing) = (vec![0usize; n], vec![0usize; n]);
+        let mut has_nan = vec![false; n];
+        for s in rows {
+            let (not_null, is_nan) = (s.is_not_null(), nan_mask(s)?);
+            for (i, (not_null, nan)) in not_null.into_iter().zip(is_nan.into_iter()).enumerate() {
+                match (not_null.unwrap_or(false), nan.unwrap_or(false), self.nan_policy) {
+                    (false, ..) | (true, true, NanPolicy::AsNull) => missing[i] += 1,
+                    (true, true, NanPolicy::Ignore) => {},
+                    (true, true, NanPolicy::Propagate) => {
+                        valid[i] += 1;
+                        has_nan[i] = true;
+                    },
+                    (true, false, _) => valid[i] += 1,
+                }
+            }
+        }
+
+        let mut result = result;
+        if has_nan.contains(&true) && result.dtype().is_float() {
+            let nans = Series::new(result.name().clone(), vec![f64::NAN; n]).cast(result.dtype())?;
+            let keep: BooleanChunked = has_nan.iter().map(|&nan| !nan).collect();
+            result = result.zip_with(&keep, &nans)?;
+        }
+
+        let keep: BooleanChunked = valid
+            .iter()
+            .zip(&missing)
+            .map(|(&valid, &missing)| {
+                valid >= self.min_count
+                    && (self.null_policy == NullPolicy::Ignore || missing == 0)
+            })
+            .collect();
+        if keep.all() {
+            return Ok(result);
+        }
+        let nulls = Series::full_null(result.name().clone(), n, result.dtype());
+        result.zip_with(&keep, &nulls)
+    }
+}
+
+/// Per-element NaN flags of `s`, all false for non-float dtypes and null elements.
+fn nan_mask(s: &Series) -> PolarsResult<BooleanChunked> {
+    if !s.dtype().is_float() {
+        return Ok(BooleanChunked::full(s.name().clone(), false, s.len()));
+    }
+    s.is_nan()?.fill_null_with_values(false)
+}
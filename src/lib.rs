@@ -1,10 +1,49 @@
 mod expressions;
+mod kwargs;
+mod reduce;
+#[cfg(test)]
+mod proptests;
 use pyo3::prelude::*;
-use pyo3_polars::PolarsAllocator;
+use pyo3_polars::error::PyPolarsErr;
+use pyo3_polars::{PolarsAllocator, PySeries};
+
+/// Wrap the flat values of a row-major matrix as an `Array(inner, width)` Series of
+/// `n_rows` rows, reusing the values buffer instead of copying it.
+#[pyfunction]
+fn array_from_flat(values: PySeries, width: usize, n_rows: usize) -> PyResult<PySeries> {
+    let values = values.0;
+    let name = values.name().clone();
+    let array =
+        expressions::helpers::array_from_flat_values(name, &values, width, n_rows, None)
+            .map_err(PyPolarsErr::from)?;
+    Ok(PySeries(array))
+}
+
+/// Kernels exposed to the criterion benches in `benches/`, which link the crate as an rlib.
+/// Not part of the Python API.
+#[doc(hidden)]
+pub mod bench {
+    use polars::prelude::*;
+
+    pub use crate::expressions::list_mean::_polars_plugin_list_mean;
+    use crate::kwargs::{NanPolicy, ReduceKwargs};
+
+    /// `vec.mean()` with the default options.
+    pub fn mean(series: &Series) -> PolarsResult<Series> {
+        crate::expressions::list_mean::vertical_mean(series, ReduceKwargs::default())
+    }
+
+    /// `vec.nanmean()` with the default options.
+    pub fn nanmean(series: &Series) -> PolarsResult<Series> {
+        let kwargs = ReduceKwargs { nan_policy: NanPolicy::Ignore, ..Default::default() };
+        crate::expressions::list_mean::vertical_mean(series, kwargs)
+    }
+}
 
 #[pymodule]
 fn _internal(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_function(wrap_pyfunction!(array_from_flat, m)?)?;
     Ok(())
 }
 
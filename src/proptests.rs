@@ -0,0 +1,270 @@
+//! Property tests for the per-row kernels: round trips and plain-Rust references.
+//!
+//! Kernels are called through their plugin symbols, the same way polars calls them, with
+//! the kwargs pickled as the Python wrappers would. Rows are short ragged `Int64` lists
+//! with null rows and null elements, over a small value range so runs and ties are common.
+//! `tests/test_properties.py` checks the same kernels across dtypes from Python.
+
+use std::collections::BTreeMap;
+
+use polars::prelude::*;
+use proptest::collection::vec;
+use proptest::option::weighted;
+use proptest::prelude::*;
+use pyo3_polars::export::polars_ffi::version_0::{
+    export_series, import_series, CallerContext, SeriesExport,
+};
+
+use crate::expressions::{
+    vec_compare, vec_delta, vec_gather, vec_quantize, vec_reshape, vec_rle, vec_roll,
+    vec_sort_by,
+};
+
+type PluginFn = unsafe extern "C" fn(
+    *mut SeriesExport,
+    usize,
+    *const u8,
+    usize,
+    *mut SeriesExport,
+    *mut CallerContext,
+);
+
+type Row<T> = Option<Vec<Option<T>>>;
+
+/// A kwarg value, pickled like the Python value it stands for.
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+enum Kwarg {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(&'static str),
+    None,
+}
+
+/// Call a plugin function on `inputs`, returning the error message on failure.
+fn call(f: PluginFn, inputs: &[Series], kwargs: &[(&str, Kwarg)]) -> Result<Series, String> {
+    let kwargs: BTreeMap<_, _> = kwargs.iter().map(|(k, v)| (*k, v)).collect();
+    let pickled = serde_pickle::to_vec(&kwargs, Default::default()).unwrap();
+    let mut exports: Vec<SeriesExport> = inputs.iter().map(export_series).collect();
+    let mut ret = SeriesExport::empty();
+    let mut context = CallerContext::default();
+    // SAFETY: the plugin takes ownership of the exported inputs and fills `ret` on success.
+    unsafe {
+        f(
+            exports.as_mut_ptr(),
+            exports.len(),
+            pickled.as_ptr(),
+            pickled.len(),
+            &mut ret,
+            &mut context,
+        );
+        std::mem::forget(exports);
+        if ret.is_null() {
+            let message = pyo3_polars::derive::_polars_plugin_get_last_error_message();
+            return Err(std::ffi::CStr::from_ptr(message).to_string_lossy().into_owned());
+        }
+        Ok(import_series(ret).unwrap())
+    }
+}
+
+fn list_series<T>(rows: &[Row<T>], inner: DataType) -> Series
+where
+    T: Clone,
+    Series: NamedFrom<Vec<Option<T>>, [Option<T>]>,
+{
+    let rows = rows.iter().map(|row| row.as_ref().map(|row| Series::new("".into(), row.clone())));
+    let list = ListChunked::from_iter(rows).with_name("a".into()).into_series();
+    list.cast(&DataType::List(Box::new(inner))).unwrap()
+}
+
+fn rows_of<T: PolarsNumericType>(series: &Series) -> Vec<Row<T::Native>> {
+    let series = series.cast(&DataType::List(Box::new(T::get_static_dtype()))).unwrap();
+    let list = series.list().unwrap();
+    list.into_iter()
+        .map(|row| row.map(|row| row.unpack::<T>().unwrap().into_iter().collect()))
+        .collect()
+}
+
+fn int_rows(max_rows: usize) -> impl Strategy<Value = Vec<Row<i64>>> {
+    vec(weighted(0.9, vec(weighted(0.9, -3i64..3), 0..8)), 0..max_rows)
+}
+
+/// Rows paired with rows of the same lengths, e.g. keys or the right side of a comparison.
+fn paired_int_rows() -> impl Strategy<Value = (Vec<Row<i64>>, Vec<Row<i64>>)> {
+    int_rows(10).prop_flat_map(|rows| {
+        let shaped: Vec<_> = rows
+            .iter()
+            .map(|row| weighted(0.9, vec(weighted(0.9, -3i64..3), row.as_ref().map_or(0, Vec::len))))
+            .collect();
+        (Just(rows), shaped)
+    })
+}
+
+fn roll_row<T: Clone>(row: &[T], n: i64) -> Vec<T> {
+    if row.is_empty() {
+        return Vec::new();
+    }
+    let mut rolled = row.to_vec();
+    rolled.rotate_right(n.rem_euclid(row.len() as i64) as usize);
+    rolled
+}
+
+proptest! {
+    #[test]
+    fn rle_decode_inverts_rle(rows in int_rows(10)) {
+        let series = list_series(&rows, DataType::Int64);
+        let encoded = call(vec_rle::_polars_plugin_vec_rle, &[series], &[]).unwrap();
+        let values = rows_of::<Int64Type>(&encoded.struct_().unwrap().field_by_name("values").unwrap());
+        for row in values.iter().flatten() {
+            prop_assert!(row.windows(2).all(|pair| pair[0] != pair[1]), "adjacent equal runs in {:?}", row);
+        }
+        let decoded = call(vec_rle::_polars_plugin_vec_rle_decode, &[encoded], &[]).unwrap();
+        prop_assert_eq!(rows_of::<Int64Type>(&decoded), rows);
+    }
+
+    #[test]
+    fn delta_decode_inverts_delta_encode(rows in int_rows(10), narrow in any::<bool>()) {
+        let series = list_series(&rows, DataType::Int64);
+        let dtype = if narrow { Kwarg::Str("int16") } else { Kwarg::None };
+        let encoded = call(vec_delta::_polars_plugin_vec_delta_encode, &[series], &[("dtype", dtype)]).unwrap();
+        let decoded = call(vec_delta::_polars_plugin_vec_delta_decode, &[encoded], &[("dtype", Kwarg::None)]).unwrap();
+        prop_assert_eq!(rows_of::<Int64Type>(&decoded), rows);
+    }
+
+    #[test]
+    fn roll_wraps_like_rotate(rows in int_rows(10), n in -10i64..10) {
+        let series = list_series(&rows, DataType::Int64);
+        let shift = Series::new("n".into(), [n]);
+        let kwargs = [("mode", Kwarg::Str("wrap")), ("fill_value", Kwarg::None)];
+        let rolled = call(vec_roll::_polars_plugin_vec_roll, &[series, shift], &kwargs).unwrap();
+        let expected: Vec<_> = rows.iter().map(|row| row.as_ref().map(|row| roll_row(row, n))).collect();
+        prop_assert_eq!(rows_of::<Int64Type>(&rolled), expected);
+    }
+
+    #[test]
+    fn sort_by_matches_stable_sort((keys, _) in paired_int_rows(), descending in any::<bool>(), nulls_last in any::<bool>()) {
+        // Sorting the positions themselves gives the permutation the kernel applied
+        let positions: Vec<Row<i64>> = keys
+            .iter()
+            .map(|row| row.as_ref().map(|row| (0..row.len() as i64).map(Some).collect()))
+            .collect();
+        let inputs = [list_series(&positions, DataType::Int64), list_series(&keys, DataType::Int64)];
+        let kwargs = [("descending", Kwarg::Bool(descending)), ("nulls_last", Kwarg::Bool(nulls_last))];
+        let sorted = call(vec_sort_by::_polars_plugin_vec_sort_by, &inputs, &kwargs).unwrap();
+        let expected: Vec<Row<i64>> = keys
+            .iter()
+            .map(|row| {
+                row.as_ref().map(|row| {
+                    let mut order: Vec<usize> = (0..row.len()).collect();
+                    order.sort_by(|&x, &y| match (row[x], row[y]) {
+                        (Some(a), Some(b)) if descending => b.cmp(&a),
+                        (Some(a), Some(b)) => a.cmp(&b),
+                        (a, b) if nulls_last => a.is_none().cmp(&b.is_none()),
+                        (a, b) => b.is_none().cmp(&a.is_none()),
+                    });
+                    order.into_iter().map(|i| Some(i as i64)).collect()
+                })
+            })
+            .collect();
+        prop_assert_eq!(rows_of::<Int64Type>(&sorted), expected);
+    }
+
+    #[test]
+    fn gather_matches_indexing(values in int_rows(10), seed in vec(weighted(0.9, vec(weighted(0.9, -10i64..10), 0..5)), 10)) {
+        let indices = &seed[..values.len()];
+        let inputs = [list_series(&values, DataType::Int64), list_series(indices, DataType::Int64)];
+        let gathered = call(vec_gather::_polars_plugin_vec_gather, &inputs, &[("oob", Kwarg::Str("null"))]).unwrap();
+        let expected: Vec<Row<i64>> = values
+            .iter()
+            .zip(indices)
+            .map(|(row, idx)| {
+                let (row, idx) = (row.as_ref()?, idx.as_ref()?);
+                let len = row.len() as i64;
+                Some(
+                    idx.iter()
+                        .map(|&i| {
+                            let i = i?;
+                            let pos = if i < 0 { i + len } else { i };
+                            (0..len).contains(&pos).then(|| row[pos as usize]).flatten()
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+        prop_assert_eq!(rows_of::<Int64Type>(&gathered), expected);
+    }
+
+    #[test]
+    fn truncate_of_pad_to_has_exact_width(rows in int_rows(10), width in 0usize..8, fill in weighted(0.5, -5i64..5), right in any::<bool>()) {
+        let series = list_series(&rows, DataType::Int64);
+        let kwargs = [
+            ("width", Kwarg::Int(width as i64)),
+            ("fill", fill.map_or(Kwarg::None, |f| Kwarg::Float(f as f64))),
+            ("align", Kwarg::Str(if right { "right" } else { "left" })),
+        ];
+        let padded = call(vec_reshape::_polars_plugin_vec_pad_to, &[series], &kwargs).unwrap();
+        let truncated = call(vec_reshape::_polars_plugin_vec_truncate, &[padded], &[("width", Kwarg::Int(width as i64))]).unwrap();
+        let expected: Vec<Row<i64>> = rows
+            .iter()
+            .map(|row| {
+                row.as_ref().map(|row| {
+                    let padding = vec![fill; width.saturating_sub(row.len())];
+                    let mut padded = if right { padding.clone() } else { Vec::new() };
+                    padded.extend_from_slice(row);
+                    if !right {
+                        padded.extend(padding);
+                    }
+                    padded.truncate(width);
+                    padded
+                })
+            })
+            .collect();
+        prop_assert_eq!(rows_of::<Int64Type>(&truncated), expected);
+    }
+
+    #[test]
+    fn lt_matches_elementwise_comparison((left, right) in paired_int_rows()) {
+        let inputs = [list_series(&left, DataType::Int64), list_series(&right, DataType::Int64)];
+        let compared = call(vec_compare::_polars_plugin_vec_lt, &inputs, &[]).unwrap();
+        let compared = compared.list().unwrap();
+        let expected: Vec<Option<Vec<Option<bool>>>> = left
+            .iter()
+            .zip(&right)
+            .map(|(l, r)| {
+                let (l, r) = (l.as_ref()?, r.as_ref()?);
+                Some(l.iter().zip(r).map(|(a, b)| Some(a.as_ref()? < b.as_ref()?)).collect())
+            })
+            .collect();
+        let actual: Vec<Option<Vec<Option<bool>>>> = compared
+            .into_iter()
+            .map(|row| row.map(|row| row.bool().unwrap().into_iter().collect()))
+            .collect();
+        prop_assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn dequantize_is_within_half_a_step(rows in vec(weighted(0.9, vec(weighted(0.9, -1000i64..1000), 0..8)), 0..10), scale in 0.01f64..10.0) {
+        let rows: Vec<Row<f64>> = rows
+            .iter()
+            .map(|row| row.as_ref().map(|row| row.iter().map(|v| v.map(|v| v as f64 / 8.0)).collect()))
+            .collect();
+        let series = list_series(&rows, DataType::Float64);
+        let kwargs = [("scale", Kwarg::Float(scale)), ("offset", Kwarg::Float(0.0)), ("dtype", Kwarg::Str("int32"))];
+        let levels = call(vec_quantize::_polars_plugin_vec_quantize, &[series], &kwargs).unwrap();
+        let kwargs = [("scale", Kwarg::Float(scale)), ("offset", Kwarg::Float(0.0))];
+        let restored = call(vec_quantize::_polars_plugin_vec_dequantize, &[levels], &kwargs).unwrap();
+        let restored = rows_of::<Float64Type>(&restored);
+        prop_assert_eq!(restored.len(), rows.len());
+        for (actual, expected) in restored.iter().zip(&rows) {
+            prop_assert_eq!(actual.is_some(), expected.is_some());
+            for (a, e) in actual.iter().flatten().zip(expected.iter().flatten()) {
+                prop_assert_eq!(a.is_some(), e.is_some());
+                if let (Some(a), Some(e)) = (a, e) {
+                    prop_assert!((a - e).abs() <= scale / 2.0 * (1.0 + 1e-9), "{} vs {}", a, e);
+                }
+            }
+            prop_assert_eq!(actual.as_ref().map(Vec::len), expected.as_ref().map(Vec::len));
+        }
+    }
+}
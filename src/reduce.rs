@@ -0,0 +1,180 @@
+//! Shared engine for the vertical (across-rows) reductions.
+//!
+//! A reduction only supplies an [`Accumulator`]; [`vertical_reduce`] takes care of
+//! skipping null rows, handling empty lists, validating that every list has the same
+//! length (unless the caller opts out), applying the shared [`ReduceKwargs`] and
+//! converting Array input back to Array output. The mean, the hottest of these, has its
+//! own kernel over the Arrow buffers in `list_mean` that keeps the same contract and shares
+//! the error helpers and [`reduce_columns`].
+use polars::prelude::*;
+use crate::expressions::helpers::{ensure_list_type, length_mismatch_error_from, list_into_array};
+use crate::kwargs::{EmptyPolicy, ReduceKwargs, Validation};
+
+/// Positions reduced together. Very wide lists are processed in blocks of this many
+/// positions, so the per-position intermediates stay small enough to remain in cache while
+/// every row is folded in, and no full-width intermediates are built.
+pub(crate) const POSITION_BLOCK: usize = 1 << 16;
+
+/// Per-position state of a vertical reduction, folded over the non-null rows.
+pub(crate) trait Accumulator: Sized {
+    /// Operation name used in error messages, e.g. `"sum"`.
+    const NAME: &'static str;
+
+    /// Inner dtype of the result for lists with the given inner dtype.
+    fn output_dtype(inner: &DataType) -> DataType;
+
+    /// Start from the first non-null row.
+    fn init(first: &Series) -> PolarsResult<Self>;
+
+    /// Fold in another non-null row of the same length.
+    fn update(&mut self, row: &Series) -> PolarsResult<()>;
+
+    /// Produce the per-position result.
+    fn finish(self) -> PolarsResult<Series>;
+}
+
+/// Output field of a vertical reduction: the input's List/Array shape with the
+/// accumulator's inner dtype.
+pub(crate) fn reduce_output_type<A: Accumulator>(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let dtype = match field.dtype() {
+        DataType::List(inner) => DataType::List(Box::new(A::output_dtype(inner))),
+        DataType::Array(inner, width) => DataType::Array(Box::new(A::output_dtype(inner)), *width),
+        dt => polars_bail!(InvalidOperation: "Expected List or Array type, got {:?}", dt),
+    };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+/// Reduce a List/Array column across rows into a single-row list, position by position.
+/// Null rows are skipped; if every row is null the result is all null. Empty lists among
+/// non-empty ones are handled according to `kwargs.empty_policy`. With `keep_rows`
+/// the aggregate is repeated for every input row so the frame height is preserved.
+/// `kwargs.offset`/`length`/`every` restrict the reduction to a strided slice of the rows.
+/// Wide lists are reduced in blocks of [`POSITION_BLOCK`] positions.
+pub(crate) fn vertical_reduce<A: Accumulator>(
+    series: &Series,
+    kwargs: ReduceKwargs,
+) -> PolarsResult<Series> {
+    let input_dtype = series.dtype().clone();
+    let series = ensure_list_type(series)?;
+    let n_lists = series.len();
+    if n_lists == 0 {
+        return Ok(series.slice(0, 0));
+    }
+    let out_rows = if kwargs.keep_rows { n_lists } else { 1 };
+    // Only the rows selected by offset/length/every are reduced; the output height still
+    // follows the whole column
+    let (row_number, selected) = kwargs.select_rows(&series)?;
+    let list_chunked = selected.list()?;
+
+    // Collect the non-null rows. The reference length comes from the first non-empty row,
+    // so a leading empty list does not decide the shape of the result.
+    let mut rows: Vec<(usize, Series)> = list_chunked
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, s)| s.map(|s| (row_number(i), s)))
+        .collect();
+    if let Some(width) = rows.iter().map(|(_, s)| s.len()).find(|&len| len > 0) {
+        // Array input is uniform by construction, and `Validation::Fast` trusts the caller.
+        let check =
+            kwargs.validate == Validation::Strict && !matches!(input_dtype, DataType::Array(..));
+        if check && rows.iter().any(|(_, s)| !s.is_empty() && s.len() != width) {
+            let context = format!("vertical {}", A::NAME);
+            let skip_empty = kwargs.empty_policy != EmptyPolicy::Error;
+            return Err(length_mismatch_error_from(list_chunked, &context, skip_empty, row_number));
+        }
+
+        let dtype = rows[0].1.dtype().clone();
+        match kwargs.empty_policy {
+            EmptyPolicy::Error => {
+                if let Some((i, _)) = rows.iter().find(|(_, s)| s.is_empty()) {
+                    return Err(empty_row_error(*i, A::NAME, width));
+                }
+            },
+            EmptyPolicy::Skip => rows.retain(|(_, s)| !s.is_empty()),
+            EmptyPolicy::TreatAsNull => {
+                for (_, s) in rows.iter_mut().filter(|(_, s)| s.is_empty()) {
+                    *s = Series::full_null(s.name().clone(), width, &dtype);
+                }
+            },
+        }
+    }
+    let rows: Vec<Series> = rows.into_iter().map(|(_, s)| s).collect();
+
+    let Some(first) = rows.first() else {
+        // All rows are null: typed null rows, so the output matches the declared dtype and
+        // has the same height as a non-null result
+        let inner_dtype = A::output_dtype(series.list()?.inner_dtype());
+        let nulls =
+            ListChunked::full_null_with_dtype(series.name().clone(), out_rows, &inner_dtype);
+        return match &input_dtype {
+            DataType::Array(_, width) => list_into_array(&nulls.into_series(), *width),
+            _ => Ok(nulls.into_series()),
+        };
+    };
+    let output_dtype = A::output_dtype(first.dtype());
+
+    // Fold the rows in one block of positions at a time; slicing a row is zero-copy, so
+    // only the block-sized intermediates of the accumulator are allocated
+    let width = first.len();
+    let mut result = Series::new_empty(series.name().clone(), &output_dtype);
+    for block_start in (0..width.max(1)).step_by(POSITION_BLOCK) {
+        let block: Vec<Series> =
+            rows.iter().map(|s| s.slice(block_start as i64, POSITION_BLOCK)).collect();
+        let mut acc = A::init(&kwargs.accumulation_row(&block[0])?)?;
+        for s in &block[1..] {
+            acc.update(&kwargs.accumulation_row(s)?)?;
+        }
+        result.append(&kwargs.mask_result(acc.finish()?, &block)?.cast(&output_dtype)?)?;
+    }
+    let result = result.rechunk();
+
+    // Wrap in a single-row list (or one per input row with keep_rows), cast back to Array
+    // if input was Array
+    let result_series = ListChunked::full(series.name().clone(), &result, out_rows).into_series();
+    match &input_dtype {
+        DataType::Array(_, width) => list_into_array(&result_series, *width),
+        _ => Ok(result_series),
+    }
+}
+
+/// Error for an empty list at `row` under `EmptyPolicy::Error`, when other rows have `width`
+/// elements.
+pub(crate) fn empty_row_error(row: usize, name: &str, width: usize) -> PolarsError {
+    polars_err!(
+        ComputeError:
+        "Empty list at row {} for vertical {} while other rows have length {}. Use empty_policy='skip' or 'treat_as_null' to allow empty rows",
+        row, name, width
+    )
+}
+
+/// Output field of [`reduce_columns`]: a struct with one `output_type` field per input
+/// column, named after it.
+pub(crate) fn reduce_columns_output_type(
+    input_fields: &[Field],
+    output_type: fn(&[Field]) -> PolarsResult<Field>,
+) -> PolarsResult<Field> {
+    let mut fields: Vec<Field> = Vec::with_capacity(input_fields.len());
+    for field in input_fields {
+        if fields.iter().any(|f| f.name() == field.name()) {
+            polars_bail!(
+                Duplicate: "Column '{}' is passed twice to a multi-column reduction", field.name()
+            );
+        }
+        fields.push(output_type(std::slice::from_ref(field))?);
+    }
+    Ok(Field::new(input_fields[0].name().clone(), DataType::Struct(fields)))
+}
+
+/// Apply a vertical reduction to several columns at once, in parallel, returning a struct
+/// with one field per input column so a single plugin call covers a whole selector.
+pub(crate) fn reduce_columns(
+    inputs: &[Series],
+    reduce: impl Fn(&Series) -> PolarsResult<Series> + Send + Sync,
+) -> PolarsResult<Series> {
+    use rayon::prelude::*;
+
+    let fields: Vec<Series> = inputs.par_iter().map(reduce).collect::<PolarsResult<_>>()?;
+    let len = fields.first().map_or(0, |s| s.len());
+    Ok(StructChunked::from_series(inputs[0].name().clone(), len, fields.iter())?.into_series())
+}
@@ -1,4 +1,7 @@
+import re
 import time
+from datetime import datetime, timedelta
+from pathlib import Path
 
 import numpy as np
 import polars as pl
@@ -95,6 +98,48 @@ def test_vec_sum_type_preservation():
     assert float_vals == [2.0, 4.0, 6.0]
 
 
+def test_vec_sum_wide_integers():
+    """Test that UInt64 and Int128 sums are exact and report overflow."""
+    big = 2**63
+    df = pl.DataFrame({"a": [[big, 1], [big - 1, 2]]}, schema={"a": pl.List(pl.UInt64)})
+    result = df.select(pl.col("a").vec.sum())
+    assert result.schema["a"] == pl.List(pl.UInt64)
+    assert result["a"].to_list() == [[2**64 - 1, 3]]
+
+    with pytest.raises(Exception, match="overflows"):
+        pl.DataFrame({"a": [[big], [big]]}, schema={"a": pl.List(pl.UInt64)}).select(
+            pl.col("a").vec.sum()
+        )
+
+    df = pl.DataFrame(
+        {"a": [[2**100, -1], [2**100, 5]]}, schema={"a": pl.List(pl.Int128)}
+    )
+    result = df.select(pl.col("a").vec.sum())
+    assert result.schema["a"] == pl.List(pl.Int128)
+    assert result["a"].to_list() == [[2**101, 4]]
+
+
+def test_reduce_wider_than_one_block():
+    """Test reductions of lists spanning several position blocks."""
+    rng = np.random.default_rng(0)
+    values = rng.integers(-100, 100, size=(3, 2**16 * 2 + 5))
+    rows = [list(map(int, row)) for row in values]
+    rows[1][2**16] = None
+    df = pl.DataFrame({"a": rows}, schema={"a": pl.List(pl.Int64)})
+    masked = np.ma.array(values)
+    masked[1, 2**16] = np.ma.masked
+    result = df.select(
+        sum=pl.col("a").vec.sum(),
+        min=pl.col("a").vec.min(),
+        max=pl.col("a").vec.max(),
+        mean=pl.col("a").vec.mean(),
+    )
+    assert result["sum"][0].to_list() == masked.sum(axis=0).tolist()
+    assert result["min"][0].to_list() == masked.min(axis=0).tolist()
+    assert result["max"][0].to_list() == masked.max(axis=0).tolist()
+    np.testing.assert_allclose(result["mean"][0].to_numpy(), masked.mean(axis=0))
+
+
 def test_vec_sum_performance():
     """Compare performance of vec_ops.sum vs manual list comprehension approach.
 
@@ -482,6 +527,30 @@ def test_vec_mean_with_null_elements():
     assert result["a"][0].to_list() == [3.0, 4.5, 7.5]
 
 
+def test_vec_mean_from_slice_partials():
+    """Test the README recipe combining a mean from per-slice sums and counts."""
+    # The second slice ends in a null row, and position 1 is all null in the last one
+    lf = pl.LazyFrame({"a": [[1.0, None], [3.0, 5.0], [None, 2.0], None, [4.0, None]]})
+    n_rows, batch_size = 5, 2
+    partials = [
+        lf.slice(offset, batch_size)
+        .select(
+            pl.col("a").vec.sum().alias("sum"),
+            pl.col("a").vec.cum_count().drop_nulls().last().alias("count"),
+        )
+        .collect()
+        for offset in range(0, n_rows, batch_size)
+    ]
+    totals = pl.concat(partials).select(
+        pl.col("sum").vec.sum(), pl.col("count").vec.sum()
+    )
+    assert totals["count"][0].to_list() == [3, 2]
+    mean = np.array(totals["sum"][0].to_list()) / np.array(totals["count"][0].to_list())
+    expected = lf.select(pl.col("a").vec.mean()).collect()["a"][0].to_list()
+    assert mean.tolist() == pytest.approx(expected)
+    assert mean.tolist() == pytest.approx([8 / 3, 3.5])
+
+
 def test_vec_min_with_null_elements():
     """Test that min skips null elements within lists."""
     df = pl.DataFrame({"a": [[5, None, 3], [None, 8, 7], [2, 10, None]]})
@@ -1190,5 +1259,2586 @@ def test_histogram_count_dtype_bool_empty_bins():
     assert result["a"][0].to_list() == [False, False, True, False]
 
 
+def test_zero_crossings_count():
+    """Test counting sign changes within each list."""
+    df = pl.DataFrame({"a": [[1, -1, 2, 3], [0, 1, 0, -2], [1, 2, 3]]})
+    result = df.select(pl.col("a").vec.zero_crossings())
+    assert result["a"].dtype == pl.UInt32
+    assert result["a"].to_list() == [2, 1, 0]
+
+
+def test_zero_crossings_indices():
+    """Test returning the index of the first element after each crossing."""
+    df = pl.DataFrame({"a": [[1.0, -1.0, 2.0, 3.0], [0.0, 1.0, 0.0, -2.0]]})
+    result = df.select(pl.col("a").vec.zero_crossings(return_indices=True))
+    assert result["a"].dtype == pl.List(pl.UInt32)
+    assert result["a"].to_list() == [[1, 2], [3]]
+
+
+def test_zero_crossings_nulls():
+    """Test that null rows stay null and null/NaN elements are skipped."""
+    df = pl.DataFrame({"a": [[1.0, None, -1.0, float("nan"), 1.0], None]})
+    result = df.select(pl.col("a").vec.zero_crossings())
+    assert result["a"].to_list() == [2, None]
+
+
+def test_zero_crossings_with_arrays():
+    """Test zero crossings on Array columns."""
+    df = pl.DataFrame({"a": [[1, -1, 1], [-1, -1, -1]]}, schema={"a": pl.Array(pl.Int64, 3)})
+    result = df.select(polars_vec_ops.zero_crossings("a"))
+    assert result["a"].to_list() == [2, 0]
+
+
+def test_baseline_normalize_subtract():
+    """Test subtracting the mean of the baseline window."""
+    df = pl.DataFrame({"a": [[1, 3, 5, 7], [2, 2, 4, 6]]})
+    result = df.select(pl.col("a").vec.baseline_normalize(baseline_end=2))
+    assert result["a"].dtype == pl.List(pl.Float64)
+    assert result["a"].to_list() == [[-1.0, 1.0, 3.0, 5.0], [0.0, 0.0, 2.0, 4.0]]
+
+
+def test_baseline_normalize_divide():
+    """Test dF/F normalization."""
+    df = pl.DataFrame({"a": [[2.0, 2.0, 3.0, 4.0]]})
+    result = df.select(pl.col("a").vec.baseline_normalize(0, 2, mode="divide"))
+    assert result["a"][0].to_list() == [0.0, 0.0, 0.5, 1.0]
+
+
+def test_baseline_normalize_zscore():
+    """Test z-scoring against the baseline window, compared with numpy."""
+    values = [1.0, 2.0, 3.0, 10.0, 12.0]
+    df = pl.DataFrame({"a": [values]})
+    result = df.select(pl.col("a").vec.baseline_normalize(baseline_end=3, mode="zscore"))
+    baseline = np.array(values[:3])
+    expected = (np.array(values) - baseline.mean()) / baseline.std()
+    np.testing.assert_allclose(result["a"][0].to_list(), expected)
+
+
+def test_baseline_normalize_negative_indices():
+    """Test Python-style negative slice bounds."""
+    df = pl.DataFrame({"a": [[4.0, 1.0, 3.0]]})
+    result = df.select(pl.col("a").vec.baseline_normalize(baseline_start=-2))
+    assert result["a"][0].to_list() == [2.0, -1.0, 1.0]
+
+
+def test_baseline_normalize_nulls_and_arrays():
+    """Test null handling and Array dtype preservation."""
+    df = pl.DataFrame(
+        {"a": [[1.0, None, 3.0], None]}, schema={"a": pl.Array(pl.Float64, 3)}
+    )
+    result = df.select(polars_vec_ops.baseline_normalize("a", baseline_end=2))
+    assert result["a"].dtype == pl.Array(pl.Float64, 3)
+    assert result["a"].to_list() == [[0.0, None, 2.0], None]
+
+
+def test_baseline_normalize_invalid_mode():
+    """Test that an unknown mode raises."""
+    with pytest.raises(ValueError):
+        pl.col("a").vec.baseline_normalize(mode="bogus")
+
+
+def test_detrend_linear():
+    """Test removing a linear trend within each list."""
+    df = pl.DataFrame({"a": [[1, 3, 5, 7], [2.0, 1.0, 4.0, 3.0]]})
+    result = df.select(pl.col("a").vec.detrend())
+    np.testing.assert_allclose(result["a"][0].to_list(), [0.0, 0.0, 0.0, 0.0], atol=1e-12)
+    y = np.array([2.0, 1.0, 4.0, 3.0])
+    x = np.arange(4)
+    slope, intercept = np.polyfit(x, y, 1)
+    np.testing.assert_allclose(result["a"][1].to_list(), y - (intercept + slope * x))
+
+
+def test_detrend_constant():
+    """Test removing the mean within each list."""
+    df = pl.DataFrame({"a": [[1.0, 2.0, 6.0], None]})
+    result = df.select(pl.col("a").vec.detrend("constant"))
+    assert result["a"].to_list() == [[-2.0, -1.0, 3.0], None]
+
+
+def test_detrend_null_elements():
+    """Test that null elements are excluded from the fit and stay null."""
+    df = pl.DataFrame({"a": [[0.0, None, 2.0, 3.0]]})
+    result = df.select(pl.col("a").vec.detrend())
+    values = result["a"][0].to_list()
+    assert values[1] is None
+    np.testing.assert_allclose([values[0], values[2], values[3]], [0.0, 0.0, 0.0], atol=1e-12)
+
+
+def test_detrend_vertical():
+    """Test detrending each position across rows with axis=0."""
+    df = pl.DataFrame({"a": [[1.0, 5.0], [2.0, 5.0], None, [4.0, 5.0]]})
+    result = df.select(pl.col("a").vec.detrend(axis=0))
+    assert len(result) == 4
+    assert result["a"][2] is None
+    for row in (0, 1, 3):
+        np.testing.assert_allclose(result["a"][row].to_list(), [0.0, 0.0], atol=1e-12)
+
+
+def test_detrend_vertical_mismatch():
+    """Test that axis=0 requires equal list lengths."""
+    df = pl.DataFrame({"a": [[1, 2], [1]]})
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("a").vec.detrend(axis=0))
+
+
+def test_detrend_with_arrays():
+    """Test that Array inputs return Array(Float64)."""
+    df = pl.DataFrame({"a": [[1, 2, 3]]}, schema={"a": pl.Array(pl.Int32, 3)})
+    result = df.select(polars_vec_ops.detrend("a"))
+    assert result["a"].dtype == pl.Array(pl.Float64, 3)
+
+
+def test_trapz_uniform():
+    """Test trapezoidal integration with uniform spacing against numpy."""
+    values = [[1.0, 2.0, 3.0, 5.0], [0, 1, 0]]
+    df = pl.DataFrame({"a": values})
+    result = df.select(pl.col("a").vec.trapz(dx=0.5))
+    assert result["a"].dtype == pl.Float64
+    expected = [np.trapezoid(v, dx=0.5) for v in values]
+    np.testing.assert_allclose(result["a"].to_list(), expected)
+
+
+def test_trapz_with_x_column():
+    """Test non-uniform spacing from a second list column."""
+    df = pl.DataFrame(
+        {
+            "y": [[1.0, 2.0, 3.0], [4.0, 4.0]],
+            "x": [[0.0, 1.0, 3.0], [1.0, 1.5]],
+        }
+    )
+    result = df.select(polars_vec_ops.trapz("y", x="x"))
+    assert result["y"].to_list() == [6.5, 2.0]
+
+
+def test_trapz_nulls():
+    """Test that null rows give null and null elements are skipped."""
+    df = pl.DataFrame({"a": [[1.0, None, 3.0], None]})
+    result = df.select(pl.col("a").vec.trapz())
+    assert result["a"].to_list() == [4.0, None]
+
+
+def test_trapz_length_mismatch():
+    """Test that x and y lists must have equal lengths."""
+    df = pl.DataFrame({"y": [[1.0, 2.0]], "x": [[0.0]]})
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("y").vec.trapz(x=pl.col("x")))
+
+
+def test_event_triggered_average_list_events():
+    """Test averaging windows around a list of events per row."""
+    df = pl.DataFrame({"trace": [[0, 1, 5, 1, 0, 2, 6, 2]], "events": [[2, 6]]})
+    result = df.select(pl.col("trace").vec.event_triggered_average("events", pre=1, post=2))
+    assert len(result) == 1
+    assert result["trace"][0].to_list() == [1.5, 5.5, 1.5]
+
+
+def test_event_triggered_average_scalar_events():
+    """Test one integer event per row, averaging across rows."""
+    df = pl.DataFrame({"trace": [[0, 1, 5, 1], [2, 6, 2, 0]], "events": [2, 1]})
+    result = df.select(polars_vec_ops.event_triggered_average("trace", "events", pre=1, post=2))
+    assert result["trace"][0].to_list() == [1.5, 5.5, 1.5]
+
+
+def test_event_triggered_average_edges_and_stats():
+    """Test that out-of-range samples are skipped and stats are reported."""
+    df = pl.DataFrame({"trace": [[1.0, 2.0, 3.0, 4.0]], "events": [[0, 2]]})
+    result = df.select(
+        pl.col("trace").vec.event_triggered_average("events", pre=1, post=1, return_stats=True)
+    )
+    row = result["trace"][0]
+    # window position 0 only exists for the second event
+    assert row["mean"] == [2.0, 2.0]
+    assert row["count"] == [1, 2]
+    assert row["sem"][0] is None
+    np.testing.assert_allclose(row["sem"][1], np.std([1.0, 3.0], ddof=1) / np.sqrt(2))
+
+
+def test_event_triggered_average_group_by():
+    """Test per-group event-triggered averages."""
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "b"],
+            "trace": [[0, 2, 0], [0, 4, 0], [1, 1, 1]],
+            "events": [1, 1, None],
+        }
+    )
+    result = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.col("trace").vec.event_triggered_average("events", pre=1, post=2))
+    )
+    assert result["trace"].to_list() == [[0.0, 3.0, 0.0], [None, None, None]]
+
+
+def test_slice_around_basic():
+    """Test extracting windows around per-row indices."""
+    df = pl.DataFrame({"a": [[1, 2, 3, 4, 5], [10, 20, 30]], "i": [2, 1]})
+    result = df.select(pl.col("a").vec.slice_around("i", before=1, after=2))
+    assert result["a"].dtype == pl.Array(pl.Int64, 3)
+    assert result["a"].to_list() == [[2, 3, 4], [10, 20, 30]]
+
+
+def test_slice_around_out_of_range():
+    """Test null and explicit padding outside the list."""
+    df = pl.DataFrame({"a": [[1.0, None, 3.0]], "i": [0]})
+    result = df.select(pl.col("a").vec.slice_around("i", before=2, after=2))
+    assert result["a"].to_list() == [[None, None, 1.0, None]]
+    padded = df.select(pl.col("a").vec.slice_around("i", before=2, after=2, pad=-1))
+    # in-list nulls are kept, only out-of-range positions are padded
+    assert padded["a"].to_list() == [[-1.0, -1.0, 1.0, None]]
+
+
+def test_slice_around_null_rows():
+    """Test that null lists and null indices give null rows."""
+    df = pl.DataFrame({"a": [[1, 2], None, [3, 4]], "i": [0, 0, None]})
+    result = df.select(polars_vec_ops.slice_around("a", "i", before=0, after=2))
+    assert result["a"].to_list() == [[1, 2], None, None]
+
+
+def test_slice_around_then_vertical_mean():
+    """Test that the fixed-width output feeds the vertical reductions."""
+    df = pl.DataFrame({"a": [[0, 1, 5, 1], [2, 6, 2, 0]], "i": [2, 1]})
+    result = df.select(pl.col("a").vec.slice_around("i", before=1, after=2).vec.mean())
+    assert result["a"][0].to_list() == [1.5, 5.5, 1.5]
+
+
+def test_rebin_by_time_mean():
+    """Test rebinning irregularly sampled values onto a uniform grid."""
+    df = pl.DataFrame(
+        {
+            "v": [[1.0, 2.0, 3.0, 4.0], [5.0, None, 7.0]],
+            "t": [[0.0, 0.4, 1.1, 3.2], [10.0, 10.5, 12.0]],
+        }
+    )
+    result = df.select(pl.col("v").vec.rebin_by_time("t", bin_width=1.0))
+    # empty bins are null, null values are skipped
+    assert result["v"].to_list() == [[1.5, 3.0, None, 4.0], [5.0, None, 7.0]]
+
+
+def test_rebin_by_time_aggs():
+    """Test the sum, count, first and last aggregations."""
+    df = pl.DataFrame({"v": [[1.0, 2.0, 3.0, 4.0]], "t": [[0.0, 0.4, 1.1, 3.2]]})
+    expected = {
+        "sum": [3.0, 3.0, None, 4.0],
+        "count": [2, 1, 0, 1],
+        "first": [1.0, 3.0, None, 4.0],
+        "last": [2.0, 3.0, None, 4.0],
+    }
+    for agg, values in expected.items():
+        result = df.select(polars_vec_ops.rebin_by_time("v", "t", bin_width=1.0, agg=agg))
+        assert result["v"].to_list() == [values], agg
+    count = df.select(pl.col("v").vec.rebin_by_time("t", bin_width=1.0, agg="count"))
+    assert count["v"].dtype == pl.List(pl.UInt32)
+
+
+def test_rebin_by_time_fixed_grid():
+    """Test that an explicit start/stop gives every row the same number of bins."""
+    df = pl.DataFrame(
+        {
+            "v": [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0]],
+            "t": [[0.0, 0.4, 1.1, 3.2], [0.5, 5.0]],
+        }
+    )
+    result = df.select(
+        pl.col("v").vec.rebin_by_time("t", bin_width=1.0, start=0.0, stop=2.0)
+    )
+    assert result["v"].to_list() == [[1.5, 3.0], [5.0, None]]
+
+
+def test_rebin_by_time_nulls():
+    """Test null rows and mismatched value/timestamp lengths."""
+    df = pl.DataFrame({"v": [[1.0, 2.0], None], "t": [[0.0, 1.0], [0.0]]})
+    result = df.select(pl.col("v").vec.rebin_by_time("t", bin_width=1.0))
+    assert result["v"].to_list() == [[1.0, 2.0], None]
+    bad = pl.DataFrame({"v": [[1.0, 2.0]], "t": [[0.0]]})
+    with pytest.raises(Exception, match="same length"):
+        bad.select(pl.col("v").vec.rebin_by_time("t", bin_width=1.0))
+    with pytest.raises(ValueError):
+        pl.col("v").vec.rebin_by_time("t", bin_width=0)
+
+
+def test_align_lag():
+    """Test estimating the lag between two lists."""
+    df = pl.DataFrame(
+        {
+            "a": [[0, 0, 1, 3, 1, 0, 0], [0, 1, 3, 1, 0]],
+            "b": [[0, 0, 0, 0, 1, 3, 1], [1, 3, 1, 0, 0]],
+        }
+    )
+    result = df.select(pl.col("a").vec.align("b", max_lag=3, return_lag=True))
+    assert result["a"].dtype == pl.Int64
+    assert result["a"].to_list() == [2, -1]
+
+
+def test_align_shifted_values():
+    """Test that the aligned output is b shifted onto a, padded with null."""
+    df = pl.DataFrame({"a": [[0, 0, 1, 3, 1, 0, 0]], "b": [[0, 0, 0, 0, 1, 3, 1]]})
+    result = df.select(polars_vec_ops.align("a", "b", max_lag=3))
+    assert result["a"].to_list() == [[0.0, 0.0, 1.0, 3.0, 1.0, None, None]]
+
+
+def test_align_max_lag_limits_search():
+    """Test that lags beyond max_lag are not considered."""
+    df = pl.DataFrame({"a": [[0, 0, 1, 3, 1, 0, 0]], "b": [[0, 0, 0, 0, 1, 3, 1]]})
+    result = df.select(pl.col("a").vec.align("b", max_lag=1, return_lag=True))
+    assert abs(result["a"][0]) <= 1
+
+
+def test_align_nulls():
+    """Test null rows and rows too short or flat to correlate."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0], None, [1.0, 2.0, 3.0]],
+            "b": [[1.0, 1.0], [1.0], [3.0, 2.0, 1.0]],
+        }
+    )
+    result = df.select(pl.col("a").vec.align("b", max_lag=0, return_lag=True))
+    # constant b in row 0 has no defined correlation
+    assert result["a"].to_list() == [None, None, 0]
+
+
+def test_transpose_basic():
+    """Test transposing a list column."""
+    df = pl.DataFrame({"a": [[1, 2, 3], [4, 5, 6]]})
+    result = df.select(pl.col("a").vec.transpose())
+    assert result["a"].dtype == pl.List(pl.Int64)
+    assert result["a"].to_list() == [[1, 4], [2, 5], [3, 6]]
+
+
+def test_transpose_roundtrip_and_array():
+    """Test that transposing twice is the identity, including Array input."""
+    data = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]
+    df = pl.DataFrame({"a": data}, schema={"a": pl.Array(pl.Float64, 2)})
+    result = df.select(polars_vec_ops.transpose("a"))
+    assert result["a"].dtype == pl.List(pl.Float64)
+    assert result["a"].to_list() == [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]
+    back = result.select(pl.col("a").vec.transpose())
+    assert back["a"].to_list() == data
+
+
+def test_transpose_nulls():
+    """Test null rows and null elements."""
+    df = pl.DataFrame({"a": [[1, 2, 3], None, [4, None, 6]]})
+    result = df.select(pl.col("a").vec.transpose())
+    assert result["a"].to_list() == [[1, None, 4], [2, None, None], [3, None, 6]]
+
+
+def test_transpose_length_mismatch():
+    """Test that ragged lists are rejected."""
+    df = pl.DataFrame({"a": [[1, 2, 3], [4]]})
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("a").vec.transpose())
+
+
+def test_stack_basic():
+    """Test collapsing rows into a single matrix cell."""
+    df = pl.DataFrame({"a": [[1, 2, 3], [4, 5, 6]]})
+    result = df.select(pl.col("a").vec.stack())
+    assert result.shape == (1, 1)
+    assert result["a"].dtype == pl.List(pl.List(pl.Int64))
+    assert result["a"][0].to_list() == [[1, 2, 3], [4, 5, 6]]
+
+
+def test_stack_array_and_nulls():
+    """Test Array input keeps its width and null rows are kept."""
+    df = pl.DataFrame(
+        {"a": [[1.0, 2.0], None, [3.0, 4.0]]}, schema={"a": pl.Array(pl.Float64, 2)}
+    )
+    result = df.select(polars_vec_ops.stack("a"))
+    assert result["a"].dtype == pl.List(pl.Array(pl.Float64, 2))
+    assert result["a"][0].to_list() == [[1.0, 2.0], None, [3.0, 4.0]]
+
+
+def test_stack_group_by():
+    """Test one matrix per group."""
+    df = pl.DataFrame({"g": [1, 1, 2], "a": [[1, 2], [3, 4], [5, 6]]})
+    result = df.group_by("g").agg(pl.col("a").vec.stack()).sort("g")
+    assert result["a"].to_list() == [[[1, 2], [3, 4]], [[5, 6]]]
+
+
+def test_matmul_basic():
+    """Test projecting each row through a constant matrix."""
+    df = pl.DataFrame({"a": [[1, 2, 3], [4, 5, 6]]})
+    matrix = [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]
+    result = df.select(pl.col("a").vec.matmul(matrix))
+    expected = np.array(df["a"].to_list(), dtype=float) @ np.array(matrix)
+    np.testing.assert_allclose(np.array(result["a"].to_list()), expected)
+
+
+def test_matmul_numpy_and_array():
+    """Test a numpy matrix with Array input gives Array output of width k."""
+    df = pl.DataFrame(
+        {"a": [[1.0, 2.0], [3.0, 4.0]]}, schema={"a": pl.Array(pl.Float64, 2)}
+    )
+    result = df.select(polars_vec_ops.matmul("a", np.eye(2)[:, :1]))
+    assert result["a"].dtype == pl.Array(pl.Float64, 1)
+    assert result["a"].to_list() == [[1.0], [3.0]]
+
+
+def test_matmul_nulls():
+    """Test that null rows and rows with null elements are null."""
+    df = pl.DataFrame({"a": [[1.0, 2.0], None, [3.0, None]]})
+    result = df.select(pl.col("a").vec.matmul([[1.0], [1.0]]))
+    assert result["a"].to_list() == [[3.0], None, None]
+
+
+def test_matmul_shape_errors():
+    """Test ragged matrices and mismatched list lengths are rejected."""
+    df = pl.DataFrame({"a": [[1.0, 2.0, 3.0]]})
+    with pytest.raises(ValueError):
+        pl.col("a").vec.matmul([[1.0, 2.0], [1.0]])
+    with pytest.raises(Exception, match="matrix rows"):
+        df.select(pl.col("a").vec.matmul([[1.0], [1.0]]))
+
+
+def test_cov_matrix_matches_numpy():
+    """Test the covariance matrix against numpy."""
+    data = [[1.0, 2.0, 3.0], [2.0, 4.0, 1.0], [4.0, 3.0, 0.0], [0.0, 1.0, 5.0]]
+    df = pl.DataFrame({"a": data})
+    result = df.select(pl.col("a").vec.cov_matrix())
+    assert result.shape == (1, 1)
+    assert result["a"].dtype == pl.List(pl.List(pl.Float64))
+    np.testing.assert_allclose(
+        np.array(result["a"][0].to_list()), np.cov(np.array(data), rowvar=False)
+    )
+    result0 = df.select(polars_vec_ops.cov_matrix("a", ddof=0))
+    np.testing.assert_allclose(
+        np.array(result0["a"][0].to_list()), np.cov(np.array(data), rowvar=False, ddof=0)
+    )
+
+
+def test_corr_matrix_matches_numpy():
+    """Test the correlation matrix against numpy."""
+    data = [[1.0, 2.0, 3.0], [2.0, 4.0, 1.0], [4.0, 3.0, 0.0], [0.0, 1.0, 5.0]]
+    df = pl.DataFrame({"a": data})
+    result = df.select(polars_vec_ops.corr_matrix("a"))
+    np.testing.assert_allclose(
+        np.array(result["a"][0].to_list()), np.corrcoef(np.array(data), rowvar=False)
+    )
+
+
+def test_cov_matrix_pairwise_nulls():
+    """Test that null rows and elements are dropped pairwise."""
+    df = pl.DataFrame({"a": [[1.0, 2.0], None, [2.0, 4.0], [4.0, None]]})
+    result = df.select(pl.col("a").vec.cov_matrix())
+    # position 0 uses three rows, the (0, 1) pair and position 1 only two
+    np.testing.assert_allclose(result["a"][0].to_list(), [[7.0 / 3.0, 1.0], [1.0, 2.0]])
+
+
+def test_cov_matrix_group_by():
+    """Test one matrix per group and the length check."""
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "a": [[1, 2], [2, 4], [3, 1], [4, 0]]})
+    result = df.group_by("g").agg(pl.col("a").vec.corr_matrix()).sort("g")
+    assert result["a"].to_list() == [
+        [[1.0, 1.0], [1.0, 1.0]],
+        [[1.0, -1.0], [-1.0, 1.0]],
+    ]
+    bad = pl.DataFrame({"a": [[1, 2], [3]]})
+    with pytest.raises(Exception, match="same length"):
+        bad.select(pl.col("a").vec.cov_matrix())
+
+
+def test_trend_slopes():
+    """Test per-position slopes against row index."""
+    df = pl.DataFrame({"a": [[1.0, 5.0, 0.0], [2.0, 5.0, 2.0], [3.0, 5.0, 4.0]]})
+    result = df.select(pl.col("a").vec.trend())
+    assert result.shape == (1, 1)
+    assert result["a"][0].to_list() == [1.0, 0.0, 2.0]
+
+
+def test_trend_stats_match_numpy():
+    """Test slope, intercept and r2 against numpy.polyfit."""
+    data = np.array([[1.0, 2.0], [2.5, 1.0], [2.0, 0.5], [4.0, 0.0]])
+    df = pl.DataFrame({"a": data.tolist()})
+    result = df.select(polars_vec_ops.trend("a", return_stats=True)).unnest("a")
+    x = np.arange(len(data))
+    for j in range(data.shape[1]):
+        slope, intercept = np.polyfit(x, data[:, j], 1)
+        r2 = np.corrcoef(x, data[:, j])[0, 1] ** 2
+        assert result["slope"][0][j] == pytest.approx(slope)
+        assert result["intercept"][0][j] == pytest.approx(intercept)
+        assert result["r2"][0][j] == pytest.approx(r2)
+
+
+def test_trend_nulls():
+    """Test that null rows keep their index and sparse positions are null."""
+    df = pl.DataFrame({"a": [[0.0, 1.0], None, [4.0, None]]})
+    result = df.select(pl.col("a").vec.trend())
+    # position 0 fits rows 0 and 2, position 1 has a single value
+    assert result["a"][0].to_list() == [2.0, None]
+
+
+def test_regress_matches_polyfit():
+    """Test per-position slope and intercept against numpy.polyfit."""
+    x = np.array([0.0, 1.0, 2.0, 3.0])
+    y = np.array([[1.0, 2.0], [2.5, 2.0], [2.0, 4.0], [4.0, 8.0]])
+    df = pl.DataFrame({"a": y.tolist(), "x": x})
+    result = df.select(pl.col("a").vec.regress("x")).unnest("a")
+    for j in range(y.shape[1]):
+        slope, intercept = np.polyfit(x, y[:, j], 1)
+        assert result["slope"][0][j] == pytest.approx(slope)
+        assert result["intercept"][0][j] == pytest.approx(intercept)
+
+
+def test_regress_p_value():
+    """Test the slope t-test p-value against a reference value."""
+    df = pl.DataFrame({"a": [[1.0], [2.5], [2.0], [4.0]], "x": [0, 1, 2, 3]})
+    result = df.select(polars_vec_ops.regress("a", "x")).unnest("a")
+    # scipy.stats.linregress([0, 1, 2, 3], [1, 2.5, 2, 4]).pvalue
+    assert result["p_value"][0][0] == pytest.approx(0.122124, abs=1e-6)
+
+
+def test_regress_nulls_and_small_samples():
+    """Test null covariates are skipped and small samples give nulls."""
+    df = pl.DataFrame({"a": [[1.0, 1.0], [3.0, None], [9.0, 2.0]], "x": [0, 1, None]})
+    result = df.select(pl.col("a").vec.regress("x")).unnest("a")
+    # the third row has no x; position 1 then has a single usable row
+    assert result["slope"][0].to_list() == [2.0, None]
+    assert result["p_value"][0].to_list() == [None, None]
+
+
+def test_ttest_welch_and_student():
+    """Test per-position t statistics and p-values."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0], [2.0, 2.0], [3.0, 5.0], [4.0, 1.0]],
+            "b": [[3.0, 2.0], [5.0, 3.0], [6.0, 3.0], [8.0, 7.0]],
+        }
+    )
+    welch = df.select(pl.col("a").vec.ttest("b")).unnest("a")
+    a = np.array(df["a"].to_list())
+    b = np.array(df["b"].to_list())
+    se = np.sqrt(a.var(axis=0, ddof=1) / 4 + b.var(axis=0, ddof=1) / 4)
+    expected_t = (a.mean(axis=0) - b.mean(axis=0)) / se
+    np.testing.assert_allclose(welch["statistic"][0].to_list(), expected_t)
+    student = df.select(polars_vec_ops.ttest("a", "b", equal_var=True)).unnest("a")
+    # scipy.stats.ttest_ind([1, 2, 3, 4], [3, 5, 6, 8]).pvalue
+    assert student["p_value"][0][0] == pytest.approx(0.049825, abs=1e-6)
+
+
+def test_ttest_nulls_and_small_samples():
+    """Test that nulls are dropped per sample and tiny samples give nulls."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 1.0], [2.0, None], None],
+            "b": [[3.0, 0.0], [4.0, 1.0], [5.0, 2.0]],
+        }
+    )
+    result = df.select(pl.col("a").vec.ttest("b")).unnest("a")
+    assert result["statistic"][0][0] is not None
+    assert result["statistic"][0][1] is None
+
+
+def test_mannwhitneyu():
+    """Test the U statistic and its asymptotic p-value."""
+    df = pl.DataFrame({"a": [[1], [2], [3], [4]], "b": [[3], [5], [6], [8]]})
+    result = df.select(pl.col("a").vec.mannwhitneyu("b")).unnest("a")
+    assert result["statistic"][0].to_list() == [1.5]
+    assert 0.0 < result["p_value"][0][0] < 0.2
+    reverse = df.select(polars_vec_ops.mannwhitneyu("b", "a")).unnest("b")
+    assert reverse["statistic"][0].to_list() == [14.5]
+    assert reverse["p_value"][0][0] == pytest.approx(result["p_value"][0][0])
+
+
+def test_ttest_1samp():
+    """Test the one-sample statistic and p-value."""
+    df = pl.DataFrame({"a": [[1.0, 2.0], [2.0, 4.0], [3.0, 6.0], [6.0, 1.0]]})
+    result = df.select(pl.col("a").vec.ttest_1samp()).unnest("a")
+    a = np.array(df["a"].to_list())
+    expected = a.mean(axis=0) / (a.std(axis=0, ddof=1) / 2.0)
+    np.testing.assert_allclose(result["statistic"][0].to_list(), expected)
+    # scipy.stats.ttest_1samp([1, 2, 3, 6], 0).pvalue
+    assert result["p_value"][0][0] == pytest.approx(0.069137, abs=1e-5)
+
+
+def test_ttest_1samp_popmean_and_nulls():
+    """Test a non-zero popmean and positions with too few values."""
+    df = pl.DataFrame({"a": [[1.0, None], [3.0, 5.0], None]})
+    result = df.select(polars_vec_ops.ttest_1samp("a", popmean=2.0)).unnest("a")
+    assert result["statistic"][0].to_list() == [0.0, None]
+
+
+def test_mean_ci_basic():
+    """Test that the interval brackets the mean and is reproducible with a seed."""
+    df = pl.DataFrame({"a": [[float(i), 5.0] for i in range(20)]})
+    result = df.select(pl.col("a").vec.mean_ci(n_boot=500, seed=1)).unnest("a")
+    mean = result["mean"][0].to_list()
+    lower = result["lower"][0].to_list()
+    upper = result["upper"][0].to_list()
+    assert mean == [9.5, 5.0]
+    assert lower[0] < 9.5 < upper[0]
+    # a constant position has a degenerate interval
+    assert lower[1] == upper[1] == 5.0
+    again = df.select(polars_vec_ops.mean_ci("a", n_boot=500, seed=1)).unnest("a")
+    assert again["lower"][0].to_list() == lower
+    assert again["upper"][0].to_list() == upper
+
+
+def test_mean_ci_width_shrinks_with_ci():
+    """Test that a lower confidence level gives a narrower interval."""
+    df = pl.DataFrame({"a": [[float(i)] for i in range(30)]})
+    wide = df.select(pl.col("a").vec.mean_ci(ci=0.99, seed=3)).unnest("a")
+    narrow = df.select(pl.col("a").vec.mean_ci(ci=0.5, seed=3)).unnest("a")
+    assert wide["upper"][0][0] - wide["lower"][0][0] > (
+        narrow["upper"][0][0] - narrow["lower"][0][0]
+    )
+
+
+def test_mean_ci_nulls_and_validation():
+    """Test null rows are ignored and bad arguments are rejected."""
+    df = pl.DataFrame({"a": [[1.0], None, [3.0]]})
+    result = df.select(pl.col("a").vec.mean_ci(seed=0)).unnest("a")
+    assert result["mean"][0].to_list() == [2.0]
+    assert 1.0 <= result["lower"][0][0] <= result["upper"][0][0] <= 3.0
+    with pytest.raises(ValueError):
+        pl.col("a").vec.mean_ci(ci=1.5)
+
+
+def test_permutation_test_one_sample():
+    """Test the sign-flip test detects a shifted position but not a null one."""
+    rng = np.random.default_rng(0)
+    data = np.column_stack([rng.normal(2.0, 1.0, 20), rng.normal(0.0, 1.0, 20)])
+    df = pl.DataFrame({"a": data.tolist()})
+    result = df.select(pl.col("a").vec.permutation_test(n_perm=999, seed=1)).unnest("a")
+    np.testing.assert_allclose(result["statistic"][0].to_list(), data.mean(axis=0))
+    p = result["p_value"][0].to_list()
+    assert p[0] == pytest.approx(1 / 1000)
+    assert p[1] > 0.01
+
+
+def test_permutation_test_two_sample():
+    """Test shuffling rows between two columns."""
+    df = pl.DataFrame(
+        {
+            "a": [[float(i) + 5.0, 1.0] for i in range(10)],
+            "b": [[float(i), 1.0] for i in range(10)],
+        }
+    )
+    result = df.select(
+        polars_vec_ops.permutation_test("a", "b", n_perm=500, statistic="t", seed=2)
+    ).unnest("a")
+    assert result["p_value"][0][0] < 0.05
+    # identical constant samples give an undefined t statistic
+    assert result["statistic"][0][1] is None
+
+
+def test_permutation_test_seed_reproducible():
+    """Test that a seed makes the p-values reproducible."""
+    df = pl.DataFrame({"a": [[0.5], [-0.2], [0.9], [0.1], [0.4]]})
+    first = df.select(pl.col("a").vec.permutation_test(n_perm=200, seed=7))
+    second = df.select(pl.col("a").vec.permutation_test(n_perm=200, seed=7))
+    assert first.equals(second)
+    with pytest.raises(ValueError):
+        pl.col("a").vec.permutation_test(statistic="median")
+
+
+def test_mann_kendall():
+    """Test S, tau and the p-value for increasing, flat and decreasing positions."""
+    xs = [1.0, 2.0, 3.0, 2.5, 5.0, 6.0, 5.5, 8.0]
+    df = pl.DataFrame({"a": [[x, 1.0, -x] for x in xs]})
+    result = df.select(pl.col("a").vec.mann_kendall()).unnest("a")
+    assert result["statistic"][0].to_list() == [24.0, 0.0, -24.0]
+    np.testing.assert_allclose(result["tau"][0].to_list(), [24 / 28, 0.0, -24 / 28])
+    p = result["p_value"][0].to_list()
+    # z = (24 - 1) / sqrt(8 * 7 * 21 / 18)
+    assert p[0] == pytest.approx(0.004434, abs=1e-5)
+    assert p[2] == pytest.approx(p[0])
+
+
+def test_mann_kendall_nulls():
+    """Test null rows are skipped and short positions are null."""
+    df = pl.DataFrame({"a": [[1.0, None], None, [2.0, 4.0], [3.0, None]]})
+    result = df.select(polars_vec_ops.mann_kendall("a")).unnest("a")
+    assert result["statistic"][0].to_list() == [3.0, None]
+
+
+def test_entropy_integers():
+    """Test exact entropy of integer positions."""
+    df = pl.DataFrame({"a": [[1, 1, 0], [2, 1, 0], [3, 1, 1], [4, 1, 1]]})
+    result = df.select(pl.col("a").vec.entropy(base=2))
+    assert result["a"][0].to_list() == [2.0, 0.0, 1.0]
+    natural = df.select(polars_vec_ops.entropy("a"))
+    assert natural["a"][0][0] == pytest.approx(np.log(4))
+
+
+def test_entropy_binned_floats():
+    """Test float positions are binned between their min and max."""
+    df = pl.DataFrame({"a": [[0.0], [0.1], [0.9], [1.0]]})
+    assert df.select(pl.col("a").vec.entropy(bins=2, base=2))["a"][0].to_list() == [1.0]
+    assert df.select(pl.col("a").vec.entropy(bins=1))["a"][0].to_list() == [0.0]
+
+
+def test_entropy_nulls():
+    """Test null values are skipped and empty positions are null."""
+    df = pl.DataFrame({"a": [[1, None], None, [2, None]]})
+    result = df.select(pl.col("a").vec.entropy(base=2))
+    assert result["a"][0].to_list() == [1.0, None]
+
+
+def test_percentile_rank_basic():
+    """Test percentiles per position, with ties counting half."""
+    df = pl.DataFrame({"a": [[1.0, 5.0], [2.0, 5.0], [3.0, 1.0], [4.0, 7.0]]})
+    result = df.select(pl.col("a").vec.percentile_rank())
+    assert result["a"].to_list() == [
+        [12.5, 50.0],
+        [37.5, 50.0],
+        [62.5, 12.5],
+        [87.5, 87.5],
+    ]
+
+
+def test_percentile_rank_nulls_and_array():
+    """Test null handling and that Array input keeps its shape."""
+    df = pl.DataFrame(
+        {"a": [[1.0, 2.0], None, [3.0, None]]}, schema={"a": pl.Array(pl.Float64, 2)}
+    )
+    result = df.select(polars_vec_ops.percentile_rank("a"))
+    assert result["a"].dtype == pl.Array(pl.Float64, 2)
+    assert result["a"].to_list() == [[25.0, 50.0], None, [75.0, None]]
+
+
+def test_percentile_rank_group_by():
+    """Test ranks are computed within each group."""
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "a": [[1], [2], [10], [0]]})
+    result = df.with_columns(pl.col("a").vec.percentile_rank().over("g"))
+    assert result["a"].to_list() == [[25.0], [75.0], [75.0], [25.0]]
+
+
+def test_top_k_rows_basic():
+    """Test the k largest values per position, descending."""
+    df = pl.DataFrame({"a": [[1, 5], [4, 2], [3, 8], [9, 1]]})
+    result = df.select(pl.col("a").vec.top_k_rows(2))
+    assert result["a"].dtype == pl.List(pl.List(pl.Int64))
+    assert result["a"].to_list() == [[[9, 4], [8, 5]]]
+
+
+def test_top_k_rows_indices_and_nulls():
+    """Test row indices, null skipping and positions with fewer than k values."""
+    df = pl.DataFrame({"a": [[1.0, 5.0], None, [4.0, 5.0], [3.0, None]]})
+    result = df.select(polars_vec_ops.top_k_rows("a", 3, return_indices=True)).unnest("a")
+    assert result["values"].to_list() == [[[4.0, 3.0, 1.0], [5.0, 5.0]]]
+    # ties keep row order; indices count the null row
+    assert result["indices"].to_list() == [[[2, 3, 0], [0, 2]]]
+
+
+def test_top_k_rows_large_integers():
+    """Test that integers beyond float precision are ranked and returned exactly."""
+    big = 2**62
+    df = pl.DataFrame({"a": [[big], [big + 1], [big - 1]]})
+    result = df.select(pl.col("a").vec.top_k_rows(2, return_indices=True)).unnest("a")
+    assert result["values"].to_list() == [[[big + 1, big]]]
+    assert result["indices"].to_list() == [[[1, 0]]]
+
+
+def test_top_k_rows_group_by():
+    """Test one result per group."""
+    df = pl.DataFrame({"g": [1, 1, 2], "a": [[1], [2], [3]]})
+    result = df.group_by("g").agg(pl.col("a").vec.top_k_rows(1)).sort("g")
+    assert result["a"].to_list() == [[[2]], [[3]]]
+
+
+def test_is_max_is_min():
+    """Test vertical max/min membership masks, including ties."""
+    df = pl.DataFrame({"a": [[1, 5, 0], [4, 5, 2], [3, 1, 2]]})
+    assert df.select(pl.col("a").vec.is_max())["a"].to_list() == [
+        [False, True, False],
+        [True, True, True],
+        [False, False, True],
+    ]
+    assert df.select(polars_vec_ops.is_min("a"))["a"].to_list() == [
+        [True, False, True],
+        [False, False, False],
+        [False, True, False],
+    ]
+
+
+def test_is_max_nulls_and_array():
+    """Test null rows and elements, NaN, and Array input."""
+    df = pl.DataFrame(
+        {"a": [[1.0, float("nan")], None, [2.0, None]]},
+        schema={"a": pl.Array(pl.Float64, 2)},
+    )
+    result = df.select(pl.col("a").vec.is_max())
+    assert result["a"].dtype == pl.Array(pl.Boolean, 2)
+    assert result["a"].to_list() == [[False, False], None, [True, None]]
+
+
+def test_is_max_large_integers():
+    """Test that integers beyond float precision are compared exactly."""
+    big = 2**62
+    df = pl.DataFrame({"a": [[big], [big + 1]]})
+    assert df.select(pl.col("a").vec.is_max())["a"].to_list() == [[False], [True]]
+
+
+def test_vec_compare():
+    """Test element-wise comparisons between two list columns."""
+    df = pl.DataFrame({"a": [[1, 5, 3], [4, 2, 2]], "b": [[2, 5, 1], [3, 3, 2]]})
+    assert df.select(pl.col("a").vec.gt("b"))["a"].to_list() == [
+        [False, False, True],
+        [True, False, False],
+    ]
+    assert df.select(pl.col("a").vec.le("b"))["a"].to_list() == [
+        [True, True, False],
+        [False, True, True],
+    ]
+    assert df.select(polars_vec_ops.ne("a", "b"))["a"].to_list() == [
+        [True, False, True],
+        [True, True, False],
+    ]
+
+
+def test_vec_compare_scalar_nulls_and_array():
+    """Test broadcasting a numeric column, nulls, NaN and Array input."""
+    df = pl.DataFrame(
+        {"a": [[1.0, float("nan")], None, [2.0, None]], "t": [1.0, 0.0, None]},
+        schema={"a": pl.Array(pl.Float64, 2), "t": pl.Float64},
+    )
+    result = df.select(pl.col("a").vec.ge("t"))
+    assert result["a"].dtype == pl.Array(pl.Boolean, 2)
+    assert result["a"].to_list() == [[True, True], None, [None, None]]
+
+
+def test_vec_compare_large_integers():
+    """Test that integers beyond float precision compare exactly."""
+    big = 2**62
+    df = pl.DataFrame({"a": [[big, big + 1]], "b": [[big + 1, big + 1]]})
+    assert df.select(pl.col("a").vec.eq("b"))["a"].to_list() == [[False, True]]
+    assert df.select(pl.col("a").vec.lt(big + 1))["a"].to_list() == [[True, False]]
+
+
+def test_vec_compare_length_mismatch():
+    """Test that differing per-row lengths raise an error."""
+    df = pl.DataFrame({"a": [[1, 2, 3]], "b": [[1, 2]]})
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("a").vec.eq("b"))
+
+
+def test_vec_where():
+    """Test picking elements by a Boolean list mask."""
+    df = pl.DataFrame(
+        {
+            "a": [[1, 5, 3], [4, 2]],
+            "m": [[True, False, None], [False, True]],
+            "b": [[10, 20, 30], [7, 8]],
+        }
+    )
+    assert df.select(pl.col("a").vec.where("m", "b"))["a"].to_list() == [
+        [1, 20, None],
+        [7, 2],
+    ]
+    masked = df.select(polars_vec_ops.where(pl.col("a").vec.gt("b"), "a", "b"))
+    assert masked["a"].to_list() == [[10, 20, 30], [7, 8]]
+
+
+def test_vec_where_nulls_and_errors():
+    """Test null rows, supertype casting and length validation."""
+    df = pl.DataFrame(
+        {"a": [[1, 2], None], "m": [[True, False], [True]], "b": [[0.5, 1.5], [1.0]]}
+    )
+    result = df.select(pl.col("a").vec.where("m", "b"))
+    assert result["a"].dtype == pl.List(pl.Float64)
+    assert result["a"].to_list() == [[1.0, 1.5], None]
+    bad = pl.DataFrame({"a": [[1, 2]], "m": [[True]], "b": [[3, 4]]})
+    with pytest.raises(Exception, match="same length"):
+        bad.select(pl.col("a").vec.where("m", "b"))
+
+
+def test_vec_min_max_horizontal():
+    """Test element-wise min/max across several list columns."""
+    df = pl.DataFrame(
+        {
+            "a": [[1, None, 3], [4, 5]],
+            "b": [[10, None, 1], [7, 2]],
+            "c": [[0, 9, 2], [7, 8]],
+        }
+    )
+    assert df.select(pl.col("a").vec.min_horizontal("b", "c"))["a"].to_list() == [
+        [0, 9, 1],
+        [4, 2],
+    ]
+    assert df.select(polars_vec_ops.max_horizontal("a", "b", "c"))["a"].to_list() == [
+        [10, 9, 3],
+        [7, 8],
+    ]
+
+
+def test_vec_min_max_horizontal_nulls_and_errors():
+    """Test null rows, Array input and validation."""
+    df = pl.DataFrame(
+        {"a": [[1.0, 2.0], None], "b": [[0.5, 3.0], [1.0, 1.0]]},
+        schema={"a": pl.Array(pl.Float64, 2), "b": pl.Array(pl.Float64, 2)},
+    )
+    result = df.select(pl.col("a").vec.max_horizontal("b"))
+    assert result["a"].dtype == pl.Array(pl.Float64, 2)
+    assert result["a"].to_list() == [[1.0, 3.0], None]
+    with pytest.raises(ValueError):
+        pl.col("a").vec.min_horizontal()
+    bad = pl.DataFrame({"a": [[1, 2]], "b": [[3]]})
+    with pytest.raises(Exception, match="same length"):
+        bad.select(pl.col("a").vec.min_horizontal("b"))
+
+
+def test_vec_fma():
+    """Test fused multiply-add across three list columns."""
+    df = pl.DataFrame(
+        {
+            "a": [[1, None, 3], None, [4, 5]],
+            "b": [[2.0, 1.0, 3.0], [1.0], [0.5, 2.0]],
+            "c": [[1, 1, 1], [1], [7, 8]],
+        }
+    )
+    assert df.select(pl.col("a").vec.fma("b", "c"))["a"].to_list() == [
+        [3.0, None, 10.0],
+        None,
+        [9.0, 18.0],
+    ]
+    assert df.select(polars_vec_ops.fma("b", "b", "c"))["b"].to_list() == [
+        [5.0, 2.0, 10.0],
+        [2.0],
+        [7.25, 12.0],
+    ]
+
+
+def test_vec_fma_length_mismatch():
+    """Test that differing per-row lengths raise an error."""
+    df = pl.DataFrame({"a": [[1, 2]], "b": [[1, 2]], "c": [[1]]})
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("a").vec.fma("b", "c"))
+
+
+def test_binary_ops_scalar_broadcast():
+    """Test numeric columns and literals broadcast across list positions."""
+    df = pl.DataFrame({"a": [[1.0, None, 3.0], None, [4.0, 5.0]], "t": [10.0, 1.0, None]})
+    assert df.select(pl.col("a").vec.gt(2))["a"].to_list() == [
+        [False, None, True],
+        None,
+        [True, True],
+    ]
+    assert df.select(pl.col("a").vec.fma(2, "t"))["a"].to_list() == [
+        [12.0, None, 16.0],
+        None,
+        [None, None],
+    ]
+    assert df.select(pl.col("a").vec.max_horizontal(2))["a"].to_list() == [
+        [2.0, 2.0, 3.0],
+        None,
+        [4.0, 5.0],
+    ]
+    clipped = df.select(pl.col("a").vec.where(pl.col("a").vec.gt(2), 0))
+    assert clipped["a"].to_list() == [[0.0, None, 3.0], None, [4.0, 5.0]]
+
+
+def test_vec_add_mul_const():
+    """Test adding and multiplying by a constant vector."""
+    df = pl.DataFrame({"a": [[1, None, 3], None, [4, 5, 6]]})
+    assert df.select(pl.col("a").vec.add_const([1, 2, 3]))["a"].to_list() == [
+        [2.0, None, 6.0],
+        None,
+        [5.0, 7.0, 9.0],
+    ]
+    result = df.select(polars_vec_ops.mul_const("a", np.array([1.0, 2.0, 0.5])))
+    assert result["a"].to_list() == [[1.0, None, 1.5], None, [4.0, 10.0, 3.0]]
+
+
+def test_vec_const_length_mismatch():
+    """Test that a constant vector of the wrong length raises an error."""
+    df = pl.DataFrame({"a": [[1, 2, 3]]}, schema={"a": pl.Array(pl.Int64, 3)})
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("a").vec.mul_const([1, 2]))
+
+
+def test_reduce_null_policy_and_min_count():
+    """Test the shared null_policy and min_count reduction options."""
+    df = pl.DataFrame({"a": [[1.0, None, None], None, [4.0, 5.0, None]]})
+    assert df.select(pl.col("a").vec.sum())["a"].to_list() == [[5.0, 5.0, 0.0]]
+    assert df.select(pl.col("a").vec.sum(min_count=1))["a"].to_list() == [
+        [5.0, 5.0, None]
+    ]
+    assert df.select(pl.col("a").vec.mean(null_policy="propagate"))["a"].to_list() == [
+        [2.5, None, None]
+    ]
+    assert df.select(polars_vec_ops.max("a", min_count=2))["a"].to_list() == [
+        [4.0, None, None]
+    ]
+    with pytest.raises(ValueError):
+        pl.col("a").vec.min(null_policy="skip")
+
+
+def test_array_output_width_follows_result():
+    """Test Array outputs are built with the result width, including null rows."""
+    df = pl.DataFrame(
+        {"a": [[1, 2, 3], None, [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+    )
+    full = df.select(pl.col("a").vec.convolve([1.0, 1.0], mode="full"))
+    assert full["a"].dtype == pl.Array(pl.Float64, 4)
+    assert full["a"].to_list() == [[1.0, 3.0, 5.0, 3.0], None, [4.0, 9.0, 11.0, 6.0]]
+    valid = df.select(pl.col("a").vec.convolve([1.0, 1.0], mode="valid"))
+    assert valid["a"].dtype == pl.Array(pl.Float64, 2)
+    assert valid["a"].to_list() == [[3.0, 5.0], None, [9.0, 11.0]]
+    diff = df.select(pl.col("a").vec.diff())
+    assert diff["a"].dtype == pl.Array(pl.Int64, 3)
+    assert diff["a"].to_list() == [[None, None, None]] * 3
+
+    nulls = pl.DataFrame({"a": [None, None]}, schema={"a": pl.Array(pl.Int64, 3)})
+    for expr, dtype in [
+        (pl.col("a").vec.mean(), pl.Array(pl.Float64, 3)),
+        (pl.col("a").vec.sum(), pl.Array(pl.Int64, 3)),
+    ]:
+        result = nulls.select(expr)
+        assert result["a"].dtype == dtype
+        assert result["a"].to_list() == [None]
+
+
+def test_vec_diff_chunked_input_single_chunk():
+    """Test diff over a multi-chunk column returns one contiguous chunk."""
+    s = pl.concat(
+        [pl.Series("a", [[1, 2], [3, 5]]), pl.Series("a", [None, [4, 4], [6, 9]])],
+        rechunk=False,
+    )
+    assert s.n_chunks() == 2
+    result = s.to_frame().select(pl.col("a").vec.diff())["a"]
+    assert result.n_chunks() == 1
+    assert result.to_list() == [
+        [None, None],
+        [2, 3],
+        [None, None],
+        [None, None],
+        [2, 5],
+    ]
+
+
+def test_chunked_input_matches_contiguous():
+    """Test multi-chunk columns give the same results as a single chunk."""
+    chunks = [
+        pl.Series("a", [[0.5, 1.5, 2.5], [3.0, 1.0, 0.0]]),
+        pl.Series("a", [None, [2.0, 2.0, 2.0]]),
+        pl.Series("a", [[1.0, 0.0, 3.0]]),
+    ]
+    chunked = pl.concat(chunks, rechunk=False).to_frame()
+    contiguous = chunked.select(pl.col("a").rechunk())
+    assert chunked["a"].n_chunks() == 3
+    exprs = [
+        pl.col("a").vec.sum().alias("sum"),
+        pl.col("a").vec.transpose().alias("transpose"),
+    ]
+    for expr in exprs:
+        assert chunked.select(expr).equals(contiguous.select(expr))
+    hist = pl.col("a").vec.histogram(bins=3)
+    assert chunked.select(hist).equals(contiguous.select(hist))
+
+
+def test_reduce_keep_rows():
+    """Test keep_rows repeats the aggregate for every row."""
+    df = pl.DataFrame({"a": [[1.0, 2.0], [3.0, 6.0], None]})
+    result = df.with_columns(mean=pl.col("a").vec.mean(keep_rows=True))
+    assert result.height == 3
+    assert result["mean"].to_list() == [[2.0, 4.0]] * 3
+    assert df.select(polars_vec_ops.sum("a", keep_rows=True))["a"].to_list() == [
+        [4.0, 8.0]
+    ] * 3
+
+
+def test_normalize_vertical():
+    """Test normalizing rows by the vertical aggregate."""
+    df = pl.DataFrame({"a": [[1.0, 2.0], [3.0, None], None, [4.0, 6.0]]})
+    result = df.select(pl.col("a").vec.normalize_vertical(method="sum"))
+    assert result["a"].to_list() == [[0.125, 0.25], [0.375, None], None, [0.5, 0.75]]
+    by_max = df.select(polars_vec_ops.normalize_vertical("a", method="max"))
+    assert by_max["a"].to_list() == [[0.25, 2 / 6], [0.75, None], None, [1.0, 1.0]]
+    centered = pl.DataFrame({"a": [[1.0, 2.0], [3.0, None], [5.0, 6.0]]}).select(
+        pl.col("a").vec.normalize_vertical(mode="subtract")
+    )
+    assert centered["a"].to_list() == [[-2.0, -2.0], [0.0, None], [2.0, 2.0]]
+
+    arr = df.select(pl.col("a").cast(pl.Array(pl.Float64, 2)))
+    result = arr.select(pl.col("a").vec.normalize_vertical())
+    assert result.schema["a"] == pl.Array(pl.Float64, 2)
+
+    with pytest.raises(ValueError):
+        df.select(pl.col("a").vec.normalize_vertical(method="median"))
+    with pytest.raises(Exception, match="same length"):
+        pl.DataFrame({"a": [[1.0], [1.0, 2.0]]}).select(
+            pl.col("a").vec.normalize_vertical()
+        )
+
+
+def test_cum_std_and_count():
+    """Test expanding std and count down the rows."""
+    df = pl.DataFrame({"a": [[1.0, 2.0], [3.0, None], None, [5.0, 6.0]]})
+    result = df.select(pl.col("a").vec.cum_std())["a"].to_list()
+    assert result[0] == [None, None]
+    assert result[1][0] == pytest.approx(np.std([1.0, 3.0], ddof=1))
+    assert result[1][1] is None
+    assert result[2] is None
+    assert result[3] == pytest.approx([2.0, np.std([2.0, 6.0], ddof=1)])
+    population = df.select(polars_vec_ops.cum_std("a", ddof=0))["a"].to_list()
+    assert population[0] == [0.0, 0.0]
+
+    counts = df.select(pl.col("a").vec.cum_count())
+    assert counts["a"].to_list() == [[1, 1], [2, 1], None, [3, 2]]
+    assert counts.schema["a"] == pl.List(pl.UInt32)
+    arr = df.select(pl.col("a").cast(pl.Array(pl.Float64, 2)))
+    assert arr.select(pl.col("a").vec.cum_count()).schema["a"] == pl.Array(pl.UInt32, 2)
+
+    # Welford stays accurate with a large offset
+    big = pl.DataFrame({"a": [[1e9 + 1.0], [1e9 + 2.0], [1e9 + 3.0]]})
+    assert big.select(pl.col("a").vec.cum_std())["a"].to_list()[2] == [1.0]
+
+
+def test_mean_std():
+    """Test the single-pass vertical mean and std."""
+    data = [[1.0, 2.0], [3.0, None], None, [5.0, 6.0]]
+    df = pl.DataFrame({"a": data})
+    result = df.select(pl.col("a").vec.mean_std()).unnest("a")
+    assert result["mean"].to_list() == [[3.0, 4.0]]
+    assert result["std"].to_list()[0] == pytest.approx(
+        [np.std([1.0, 3.0, 5.0], ddof=1), np.std([2.0, 6.0], ddof=1)]
+    )
+    population = df.select(polars_vec_ops.mean_std("a", ddof=0)).unnest("a")
+    assert population["std"].to_list()[0] == pytest.approx(
+        [np.std([1.0, 3.0, 5.0]), 2.0]
+    )
+
+    grouped = (
+        pl.DataFrame({"g": [1, 1, 2], "a": [[1.0], [3.0], [7.0]]})
+        .group_by("g", maintain_order=True)
+        .agg(pl.col("a").vec.mean_std())
+        .unnest("a")
+    )
+    assert grouped["mean"].to_list() == [[2.0], [7.0]]
+    assert grouped["std"].to_list()[1] == [None]
+
+    with pytest.raises(Exception, match="same length"):
+        pl.DataFrame({"a": [[1.0], [1.0, 2.0]]}).select(pl.col("a").vec.mean_std())
+
+
+def test_cv():
+    """Test the per-position coefficient of variation."""
+    df = pl.DataFrame(
+        {"a": [[1.0, -1.0, 2.0], [3.0, 1.0, None], None, [5.0, 0.0, 4.0]]}
+    )
+    result = df.select(pl.col("a").vec.cv())["a"].to_list()[0]
+    assert result[0] == pytest.approx(np.std([1, 3, 5], ddof=1) / 3)
+    assert result[1] is None
+    assert result[2] == pytest.approx(np.std([2, 4], ddof=1) / 3)
+
+    nan = df.select(polars_vec_ops.cv("a", ddof=0, on_zero_mean="nan"))
+    assert np.isnan(nan["a"].to_list()[0][1])
+    # Every mean here is within zero_tol of zero
+    loose = df.select(pl.col("a").vec.cv(zero_tol=3.0))
+    assert loose["a"].to_list() == [[None, None, None]]
+
+    arr = df.select(pl.col("a").cast(pl.Array(pl.Float64, 3)))
+    assert arr.select(pl.col("a").vec.cv()).schema["a"] == pl.Array(pl.Float64, 3)
+
+    with pytest.raises(Exception, match="Mean is zero at position 1"):
+        df.select(pl.col("a").vec.cv(on_zero_mean="raise"))
+    with pytest.raises(ValueError):
+        df.select(pl.col("a").vec.cv(on_zero_mean="inf"))
+
+
+def test_split_half_reliability():
+    """Test split-half reliability across rows."""
+    rng = np.random.default_rng(0)
+    profile = np.linspace(0, 10, 20)
+    consistent = pl.DataFrame(
+        {"a": [(profile + rng.normal(0, 0.1, 20)).tolist() for _ in range(10)]}
+    )
+    result = consistent.select(pl.col("a").vec.split_half_reliability(seed=1))
+    assert result["a"][0] > 0.99
+    noisy = pl.DataFrame({"a": [rng.normal(0, 1, 20).tolist() for _ in range(10)]})
+    noisy_result = noisy.select(polars_vec_ops.split_half_reliability("a", seed=1))
+    assert noisy_result["a"][0] < 0.9
+
+    # Same seed, same result
+    again = consistent.select(pl.col("a").vec.split_half_reliability(seed=1))
+    assert again["a"][0] == result["a"][0]
+
+    single = pl.DataFrame({"a": [[1.0, 2.0], None]})
+    assert single.select(pl.col("a").vec.split_half_reliability())["a"][0] is None
+    with pytest.raises(ValueError):
+        consistent.select(pl.col("a").vec.split_half_reliability(n_splits=0))
+
+
+def test_icc():
+    """Test ICC and Cronbach's alpha against Shrout & Fleiss (1979)."""
+    df = pl.DataFrame(
+        {
+            "a": [
+                [9, 6, 8, 7, 10, 6],
+                [2, 1, 4, 1, 5, 2],
+                None,
+                [5, 3, 6, 2, 6, 4],
+                [8, 2, 8, 6, 9, 7],
+            ]
+        }
+    )
+    expected = {"icc1": 0.17, "icc2": 0.29, "icc3": 0.71, "alpha": 0.91}
+    for kind, value in expected.items():
+        result = df.select(pl.col("a").vec.icc(kind))["a"][0]
+        assert result == pytest.approx(value, abs=0.005)
+    default = df.select(polars_vec_ops.icc("a"))["a"][0]
+    assert default == pytest.approx(0.29, abs=0.005)
+
+    # Positions with a null in any row are left out
+    with_null = df.with_columns(
+        pl.col("a").list.concat(pl.lit([None], dtype=pl.List(pl.Int64)))
+    )
+    icc3 = with_null.select(pl.col("a").vec.icc("icc3"))["a"][0]
+    assert icc3 == pytest.approx(0.71, abs=0.005)
+
+    single = pl.DataFrame({"a": [[1.0, 2.0]]})
+    assert single.select(pl.col("a").vec.icc())["a"][0] is None
+    with pytest.raises(ValueError):
+        df.select(pl.col("a").vec.icc("icc4"))
+
+
+def test_pairwise_distance():
+    """Test the rows x rows distance matrix."""
+    df = pl.DataFrame({"a": [[0.0, 0.0], [3.0, 4.0], None, [1.0, None]]})
+    matrix = df.select(pl.col("a").vec.pairwise_distance())["a"].to_list()[0]
+    assert matrix[0] == [0.0, 5.0, None, None]
+    assert matrix[1] == [5.0, 0.0, None, None]
+    assert matrix[2] == [None] * 4
+
+    long = df.select(pl.col("a").vec.pairwise_distance(long=True)).unnest("a")
+    assert long.height == 6
+    assert long.row(0) == (0, 1, 5.0)
+
+    rng = np.random.default_rng(0)
+    x = rng.normal(size=(5, 3))
+    emb = pl.DataFrame({"a": x.tolist()})
+    for metric in ["cityblock", "chebyshev", "cosine", "correlation"]:
+        result = emb.select(polars_vec_ops.pairwise_distance("a", metric=metric))
+        got = np.array(result["a"].to_list()[0])
+        if metric == "cityblock":
+            expected = np.abs(x[:, None] - x[None]).sum(-1)
+        elif metric == "chebyshev":
+            expected = np.abs(x[:, None] - x[None]).max(-1)
+        else:
+            y = x - x.mean(1, keepdims=True) if metric == "correlation" else x
+            y = y / np.linalg.norm(y, axis=1, keepdims=True)
+            expected = 1 - y @ y.T
+        np.testing.assert_allclose(got, expected, atol=1e-12)
+
+    with pytest.raises(ValueError):
+        df.select(pl.col("a").vec.pairwise_distance(metric="hamming"))
+
+
+def test_distance_to():
+    """Test the distance of each row to a reference vector."""
+    df = pl.DataFrame({"a": [[0.0, 0.0], [3.0, 4.0], None, [1.0, None]]})
+    result = df.select(pl.col("a").vec.distance_to([3, 4]))
+    assert result["a"].to_list() == [5.0, 0.0, None, None]
+
+    ref = pl.DataFrame({"ref": [[0.0, 1.0]]})
+    cityblock = df.select(
+        polars_vec_ops.distance_to("a", ref["ref"], metric="cityblock")
+    )
+    assert cityblock["a"].to_list() == [1.0, 6.0, None, None]
+
+    centered = pl.DataFrame({"a": [[0, 0], [3, 4], [6, 8]]}).with_columns(
+        dist=pl.col("a").vec.distance_to(pl.col("a").vec.mean())
+    )
+    assert centered["dist"].to_list() == [5.0, 0.0, 5.0]
+
+    cosine = df.select(pl.col("a").vec.distance_to(np.array([0.0, 2.0]), "cosine"))
+    assert cosine["a"].to_list()[1] == pytest.approx(1 - 4 / 5)
+
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("a").vec.distance_to([1.0, 2.0, 3.0]))
+
+
+def test_assign_clusters():
+    """Test nearest-centroid labels."""
+    df = pl.DataFrame(
+        {"a": [[0.0, 0.0], [3.0, 4.0], None, [1.0, None], [9.0, 9.0]]}
+    )
+    centroids = [[0, 0], [4, 4], [10, 10]]
+    result = df.select(pl.col("a").vec.assign_clusters(centroids))
+    assert result["a"].to_list() == [0, 1, None, None, 2]
+    assert result.schema["a"] == pl.UInt32
+
+    # Direction only under cosine distance
+    axes = np.array([[0.0, 1.0], [1.0, 0.0]])
+    cosine = pl.DataFrame({"a": [[10.0, 0.1], [0.1, 5.0]]}).select(
+        polars_vec_ops.assign_clusters("a", axes, metric="cosine")
+    )
+    assert cosine["a"].to_list() == [1, 0]
+
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("a").vec.assign_clusters([[0.0, 0.0, 0.0]]))
+    with pytest.raises(ValueError):
+        df.select(pl.col("a").vec.assign_clusters([[0.0], [0.0, 1.0]]))
+
+
+def test_corr():
+    """Test the row-wise correlation between two list columns."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0, 3.0, 4.0], [1.0, 2.0, None, 4.0], None, [1.0] * 4],
+            "b": [
+                [1.0, 4.0, 9.0, 16.0],
+                [4.0, 2.0, 0.0, 1.0],
+                [1.0] * 4,
+                [1.0, 2.0] * 2,
+            ],
+        }
+    )
+    pearson = df.select(pl.col("a").vec.corr("b"))["a"].to_list()
+    assert pearson[0] == pytest.approx(np.corrcoef([1, 2, 3, 4], [1, 4, 9, 16])[0, 1])
+    assert pearson[1] == pytest.approx(np.corrcoef([1, 2, 4], [4, 2, 1])[0, 1])
+    assert pearson[2] is None
+    assert np.isnan(pearson[3])
+
+    spearman = df.select(polars_vec_ops.corr("a", "b", method="spearman"))
+    assert spearman["a"].to_list()[:3] == [1.0, -1.0, None]
+
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("a").vec.corr(pl.lit([1.0, 2.0])))
+    with pytest.raises(ValueError):
+        df.select(pl.col("a").vec.corr("b", method="kendall"))
+
+
+def test_rmse_mae():
+    """Test row-wise RMSE and MAE between two list columns."""
+    df = pl.DataFrame(
+        {
+            "obs": [[1.0, 2.0, 3.0], [None, None, 1.0], None, [None]],
+            "pred": [[2.0, 2.0, 5.0], [4.0, 2.0, 0.0], [1.0] * 3, [1.0]],
+        }
+    )
+    rmse = df.select(pl.col("obs").vec.rmse("pred"))["obs"].to_list()
+    assert rmse == [pytest.approx(np.sqrt(5 / 3)), 1.0, None, None]
+    mae = df.select(polars_vec_ops.mae("obs", "pred"))["obs"].to_list()
+    assert mae == [pytest.approx(1.0), 1.0, None, None]
+
+    # A literal is broadcast across the row
+    against_zero = df.select(pl.col("obs").vec.mae(0))["obs"].to_list()
+    assert against_zero[:2] == [2.0, 1.0]
+
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("obs").vec.rmse(pl.lit([1.0, 2.0])))
+
+
+def test_pair_metrics_propagate_non_finite():
+    """Test that NaN and infinite values propagate instead of being skipped."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, float("nan"), 3.0], [1.0, float("inf"), 3.0], [1.0, None, 3.0]],
+            "b": [[1.0, 2.0, 4.0], [1.0, 2.0, 4.0], [1.0, 2.0, 4.0]],
+        }
+    )
+    rmse = df.select(pl.col("a").vec.rmse("b"))["a"].to_list()
+    assert np.isnan(rmse[0])
+    assert rmse[1:] == [float("inf"), pytest.approx(np.sqrt(1 / 2))]
+    mae = df.select(pl.col("a").vec.mae("b"))["a"].to_list()
+    assert np.isnan(mae[0])
+    assert mae[1:] == [float("inf"), 0.5]
+    for method in ["pearson", "spearman"]:
+        corr = df.select(pl.col("a").vec.corr("b", method=method))["a"].to_list()
+        assert np.isnan(corr[0]) and corr[2] == pytest.approx(1.0)
+    weighted = df.select(pl.col("b").vec.weighted_mean("a"))["b"].to_list()
+    assert np.isnan(weighted[0]) and np.isnan(weighted[1])
+
+
+def test_r2():
+    """Test the row-wise coefficient of determination."""
+    df = pl.DataFrame(
+        {
+            "obs": [[1.0, 2.0, 3.0], [1.0, 1.0, 1.0], None, [3.0, None, 1.0, 2.0]],
+            "pred": [[1.0, 2.0, 4.0], [4.0, 2.0, 0.0], [1.0] * 3, [3.0, 0.0, 1.0, 2.0]],
+        }
+    )
+    result = df.select(pl.col("obs").vec.r2("pred"))["obs"].to_list()
+    assert result[0] == pytest.approx(0.5)
+    assert np.isnan(result[1])
+    assert result[2:] == [None, 1.0]
+    mean_model = df.select(polars_vec_ops.r2("obs", 2.0))["obs"].to_list()
+    assert mean_model[0] == pytest.approx(0.0)
+
+
+def test_self_similarity():
+    """Test the rows x rows cosine-similarity matrix."""
+    df = pl.DataFrame({"a": [[1.0, 0.0], [3.0, 4.0], None]})
+    matrix = df.select(pl.col("a").vec.self_similarity())["a"].to_list()[0]
+    assert matrix == [[1.0, 0.6, None], [0.6, 1.0, None], [None, None, None]]
+
+    long = df.select(polars_vec_ops.self_similarity("a", long=True)).unnest("a")
+    assert long.columns == ["i", "j", "similarity"]
+    assert long.row(0) == (0, 1, 0.6)
+
+    rng = np.random.default_rng(0)
+    x = rng.normal(size=(4, 3))
+    emb = pl.DataFrame({"a": x.tolist()})
+    got = np.array(emb.select(pl.col("a").vec.self_similarity())["a"].to_list()[0])
+    y = x / np.linalg.norm(x, axis=1, keepdims=True)
+    np.testing.assert_allclose(got, y @ y.T, atol=1e-12)
+
+
+def test_cross():
+    """Test the row-wise 3D cross product."""
+    vec3 = pl.Array(pl.Float64, 3)
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 0.0, 0.0], [1.0, 2.0, 3.0], None, [1.0, None, 0.0]],
+            "b": [[0.0, 1.0, 0.0], [4.0, 5.0, 6.0], [1.0] * 3, [0.0, 0.0, 1.0]],
+        },
+        schema={"a": vec3, "b": vec3},
+    )
+    result = df.select(pl.col("a").vec.cross("b"))
+    assert result.schema["a"] == vec3
+    assert result["a"].to_list() == [
+        [0.0, 0.0, 1.0],
+        [-3.0, 6.0, -3.0],
+        None,
+        [None, -1.0, None],
+    ]
+    np.testing.assert_allclose(
+        np.array(result["a"][1].to_list()), np.cross([1, 2, 3], [4, 5, 6])
+    )
+
+    # A single-row operand is used for every row
+    z_axis = pl.lit(pl.Series([[0.0, 0.0, 1.0]], dtype=vec3))
+    about_z = df.select(polars_vec_ops.cross("a", z_axis))
+    assert about_z["a"].to_list()[0] == [0.0, -1.0, 0.0]
+
+    with pytest.raises(Exception, match="Array"):
+        df.select(pl.col("a").cast(pl.List(pl.Float64)).vec.cross("b"))
+
+
+def test_outer():
+    """Test the per-row outer product."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0], [1.0, None], None, [3.0]],
+            "b": [[1.0, 10.0, 100.0], [2.0], [1.0], []],
+        }
+    )
+    result = df.select(pl.col("a").vec.outer("b"))
+    assert result.schema["a"] == pl.List(pl.List(pl.Float64))
+    assert result["a"].to_list() == [
+        [[1.0, 10.0, 100.0], [2.0, 20.0, 200.0]],
+        [[2.0], [None]],
+        None,
+        [[]],
+    ]
+    x, y = np.arange(3.0), np.arange(4.0)
+    arr = pl.DataFrame({"x": [x.tolist()], "y": [y.tolist()]}).select(
+        polars_vec_ops.outer("x", "y")
+    )
+    np.testing.assert_array_equal(np.array(arr["x"][0].to_list()), np.outer(x, y))
+
+
+def test_project_reject():
+    """Test per-row vector projection and rejection."""
+    df = pl.DataFrame(
+        {
+            "a": [[3.0, 4.0], [1.0, None], None, [2.0, 2.0]],
+            "b": [[2.0, 0.0], [2.0, 0.0], [1.0, 1.0], [0.0, 0.0]],
+        }
+    )
+    proj = df.select(pl.col("a").vec.project("b"))["a"].to_list()
+    assert proj[:3] == [[3.0, 0.0], None, None]
+    assert np.isnan(proj[3]).all()
+    rej = df.select(polars_vec_ops.reject("a", "b"))["a"].to_list()
+    assert rej[0] == [0.0, 4.0]
+
+    rng = np.random.default_rng(0)
+    a, b = rng.normal(size=(5, 4)), rng.normal(size=(5, 4))
+    vecs = pl.DataFrame({"a": a.tolist(), "b": b.tolist()})
+    result = vecs.select(
+        p=pl.col("a").vec.project("b"), r=pl.col("a").vec.reject("b")
+    )
+    p, r = np.array(result["p"].to_list()), np.array(result["r"].to_list())
+    np.testing.assert_allclose(p + r, a)
+    np.testing.assert_allclose((r * b).sum(axis=1), 0, atol=1e-12)
+
+    # A single-row axis is used for every row
+    x_axis = pl.lit(pl.Series([[1.0, 0.0]]))
+    assert df.select(pl.col("a").vec.reject(x_axis))["a"].to_list()[3] == [0.0, 2.0]
+
+
+def test_angle():
+    """Test the per-row angle between vectors."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 0.0], [1.0, None], None, [0.0, 0.0], [1.0, 1.0]],
+            "b": [[0.0, 1.0], [2.0, 0.0], [1.0, 1.0], [1.0, 0.0], [-3.0, -3.0]],
+        }
+    )
+    degrees = df.select(pl.col("a").vec.angle("b", degrees=True))["a"].to_list()
+    assert degrees[:3] == [pytest.approx(90.0), None, None]
+    assert np.isnan(degrees[3])
+    assert degrees[4] == pytest.approx(180.0)
+    radians = df.select(polars_vec_ops.angle("a", "b"))["a"].to_list()
+    assert radians[0] == pytest.approx(np.pi / 2)
+
+    # Rounding the cosine above 1 must not give NaN for identical vectors
+    same = pl.DataFrame({"a": [[0.1, 0.2, 0.3]]})
+    zero = same.select(pl.col("a").vec.angle("a"))["a"][0]
+    assert zero == pytest.approx(0.0, abs=1e-7)
+
+
+def test_stats():
+    """Test the fused per-row summary statistics."""
+    df = pl.DataFrame(
+        {"a": [[1.0, 4.0, 2.0, 3.0], [5.0, None, 1.0], None, [], [7.0]]}
+    )
+    result = df.select(pl.col("a").vec.stats()).unnest("a")
+    assert result.columns == ["mean", "std", "min", "max", "median"]
+    for name, builtin in [
+        ("mean", pl.col("a").list.mean()),
+        ("std", pl.col("a").list.std()),
+        ("min", pl.col("a").list.min()),
+        ("max", pl.col("a").list.max()),
+        ("median", pl.col("a").list.median()),
+    ]:
+        expected = df.select(builtin)["a"].to_list()
+        got = result[name].to_list()
+        assert [v is None for v in got] == [v is None for v in expected]
+        assert [v for v in got if v is not None] == pytest.approx(
+            [v for v in expected if v is not None]
+        )
+
+    population = df.select(polars_vec_ops.stats("a", ddof=0)).unnest("a")
+    assert population["std"].to_list()[4] == 0.0
+    arr = pl.DataFrame({"a": [[1, 4]]}, schema={"a": pl.Array(pl.Int64, 2)})
+    assert arr.select(pl.col("a").vec.stats()).unnest("a")["median"][0] == 2.5
+
+
+def test_weighted_mean():
+    """Test the within-row weighted mean."""
+    df = pl.DataFrame(
+        {
+            "v": [[1.0, 2.0, 3.0], [4.0, 8.0, None], None, [1.0, 2.0], [5.0]],
+            "w": [[1.0, 1.0, 2.0], [3.0, 1.0, 5.0], [1.0], [1.0, -1.0], [None]],
+        }
+    )
+    result = df.select(pl.col("v").vec.weighted_mean("w"))["v"].to_list()
+    assert result[:3] == [2.25, 5.0, None]
+    assert np.isnan(result[3])
+    assert result[4] is None
+
+    rng = np.random.default_rng(0)
+    v, w = rng.normal(size=(3, 6)), rng.uniform(size=(3, 6))
+    frame = pl.DataFrame({"v": v.tolist(), "w": w.tolist()})
+    got = frame.select(polars_vec_ops.weighted_mean("v", "w"))["v"].to_numpy()
+    np.testing.assert_allclose(got, np.average(v, axis=1, weights=w))
+
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("v").vec.weighted_mean(pl.lit([1.0])))
+
+
+def test_activations():
+    """Test per-row softmax, sigmoid and tanh."""
+    df = pl.DataFrame({"a": [[1.0, 2.0, 3.0], [1000.0, None, 1000.0], None]})
+    result = df.select(pl.col("a").vec.softmax())["a"].to_list()
+    e = np.exp([1.0, 2.0, 3.0])
+    assert result[0] == pytest.approx((e / e.sum()).tolist())
+    assert result[1] == [0.5, None, 0.5]
+    assert result[2] is None
+
+    hot = df.select(polars_vec_ops.softmax("a", temperature=2.0))["a"][0].to_list()
+    e = np.exp(np.array([1.0, 2.0, 3.0]) / 2.0)
+    assert hot == pytest.approx((e / e.sum()).tolist())
+
+    x = [-2.0, 0.0, 3.0]
+    frame = pl.DataFrame({"a": [x]}, schema={"a": pl.Array(pl.Float64, 3)})
+    out = frame.select(
+        pl.col("a").vec.sigmoid().alias("s"), polars_vec_ops.tanh("a").alias("t")
+    )
+    assert out.schema["s"] == pl.Array(pl.Float64, 3)
+    expected = 1 / (1 + np.exp(-np.array(x)))
+    assert out["s"][0].to_list() == pytest.approx(expected.tolist())
+    assert out["t"][0].to_list() == pytest.approx(np.tanh(x).tolist())
+
+    with pytest.raises(ValueError, match="temperature"):
+        pl.col("a").vec.softmax(temperature=0)
+
+
+def test_minmax_and_robust_scale():
+    """Test per-row min-max and robust scaling."""
+    df = pl.DataFrame(
+        {"a": [[1.0, 3.0, None, 5.0], [2.0, 2.0], [1.0, 2.0, 3.0, 4.0, 100.0], None]}
+    )
+    result = df.select(pl.col("a").vec.minmax_scale())["a"].to_list()
+    assert result[0] == [0.0, 0.5, None, 1.0]
+    assert result[1] == [0.0, 0.0]
+    assert result[3] is None
+
+    ranged = df.select(polars_vec_ops.minmax_scale("a", feature_range=(-1, 1)))
+    assert ranged["a"][0].to_list() == [-1.0, 0.0, None, 1.0]
+
+    robust = df.select(pl.col("a").vec.robust_scale())["a"].to_list()
+    assert robust[0] == [-1.0, 0.0, None, 1.0]
+    assert robust[1] == [0.0, 0.0]
+    x = np.array([1.0, 2.0, 3.0, 4.0, 100.0])
+    q25, q50, q75 = np.percentile(x, [25, 50, 75])
+    assert robust[2] == pytest.approx(((x - q50) / (q75 - q25)).tolist())
+
+    with pytest.raises(ValueError, match="feature_range"):
+        pl.col("a").vec.minmax_scale(feature_range=(1, 0))
+
+
+def test_zscore():
+    """Test standardizing each list along its own axis."""
+    df = pl.DataFrame({"a": [[1.0, 2.0, None, 3.0], [4.0, 4.0], [7.0], None]})
+    result = df.select(pl.col("a").vec.zscore())["a"].to_list()
+    assert result[0] == [-1.0, 0.0, None, 1.0]
+    assert result[1] == [0.0, 0.0]
+    assert np.isnan(result[2][0])
+    assert result[3] is None
+
+    x = np.array([2.0, 5.0, 11.0, 3.0])
+    frame = pl.DataFrame({"a": [x.tolist()]}, schema={"a": pl.Array(pl.Float64, 4)})
+    out = frame.select(polars_vec_ops.zscore("a", ddof=0))
+    assert out.schema["a"] == pl.Array(pl.Float64, 4)
+    assert out["a"][0].to_list() == pytest.approx(((x - x.mean()) / x.std()).tolist())
+
+
+def test_rank():
+    """Test ranking elements within each list."""
+    df = pl.DataFrame(
+        {"a": [[3.0, 1.0, None, 3.0, float("nan"), 2.0], [5.0], [], None]}
+    )
+    result = df.select(pl.col("a").vec.rank())["a"].to_list()
+    assert result[0][:4] == [3.5, 1.0, None, 3.5]
+    assert np.isnan(result[0][4])
+    assert result[0][5] == 2.0
+    assert result[1:] == [[1.0], [], None]
+
+    x = pl.DataFrame({"a": [[3, 1, 3, 2]]})
+    for method in ("min", "max", "dense", "ordinal"):
+        for descending in (False, True):
+            got = x.select(polars_vec_ops.rank("a", method, descending))["a"][0]
+            expected = pl.Series([3, 1, 3, 2]).rank(method, descending=descending)
+            assert got.to_list() == expected.cast(pl.Float64).to_list()
+
+    with pytest.raises(ValueError, match="method must be one of"):
+        pl.col("a").vec.rank("first")
+
+
+def test_top_k():
+    """Test the per-row k largest elements."""
+    df = pl.DataFrame(
+        {"a": [[3.0, 1.0, None, 3.0, float("nan"), 2.0], [1.0], None]}
+    )
+    result = df.select(pl.col("a").vec.top_k(2))["a"].to_list()
+    assert result == [[3.0, 3.0], [1.0], None]
+
+    out = df.select(polars_vec_ops.top_k("a", 3, return_indices=True))
+    assert out["a"].struct.field("values").to_list() == [[3.0, 3.0, 2.0], [1.0], None]
+    assert out["a"].struct.field("indices").to_list() == [[0, 3, 5], [0], None]
+
+    ints = pl.DataFrame({"a": [[4, 9, 1]]}, schema={"a": pl.Array(pl.Int64, 3)})
+    top = ints.select(pl.col("a").vec.top_k(1))
+    assert top.schema["a"] == pl.List(pl.Int64)
+    assert top["a"].to_list() == [[9]]
+
+    with pytest.raises(ValueError, match="non-negative"):
+        pl.col("a").vec.top_k(-1)
+
+
+def test_top_k_large_integers():
+    """Test that integers beyond float precision are ranked and returned exactly."""
+    big = 2**62
+    df = pl.DataFrame({"a": [[big, big + 1, big - 1]]})
+    out = df.select(pl.col("a").vec.top_k(2, return_indices=True)).unnest("a")
+    assert out["values"].to_list() == [[big + 1, big]]
+    assert out["indices"].to_list() == [[1, 0]]
+
+
+def test_arg_sort_and_sort_by():
+    """Test per-row arg_sort and sorting values by a companion keys list."""
+    df = pl.DataFrame(
+        {
+            "v": [["a", "b", "c", "d", "e"], ["x"], None, []],
+            "k": [[3.0, None, 1.0, float("nan"), 2.0], [0.0], [1.0], []],
+        }
+    )
+    result = df.select(pl.col("k").vec.arg_sort())["k"].to_list()
+    assert result == [[2, 4, 0, 3, 1], [0], [0], []]
+    desc = df.select(polars_vec_ops.arg_sort("k", descending=True, nulls_last=False))
+    assert desc["k"][0].to_list() == [1, 3, 0, 4, 2]
+
+    sorted_v = df.select(pl.col("v").vec.sort_by("k"))["v"].to_list()
+    assert sorted_v == [["c", "e", "a", "d", "b"], ["x"], None, []]
+
+    arr = pl.DataFrame(
+        {"v": [[1, 2, 3]], "k": [[0.5, -1.0, 0.0]]},
+        schema={"v": pl.Array(pl.Int64, 3), "k": pl.List(pl.Float64)},
+    )
+    out = arr.select(polars_vec_ops.sort_by("v", "k", descending=True))
+    assert out.schema["v"] == pl.Array(pl.Int64, 3)
+    assert out["v"].to_list() == [[1, 3, 2]]
+
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("v").vec.sort_by(pl.col("k").list.head(1)))
+
+
+def test_gather():
+    """Test per-row gather with dynamic indices."""
+    df = pl.DataFrame(
+        {
+            "v": [[10.0, 11.0, 12.0], [], None, [1.0]],
+            "i": [[2, -1, None, 5, 0], [0], [0], []],
+        }
+    )
+    nulls = df.select(pl.col("v").vec.gather("i", oob="null"))["v"].to_list()
+    assert nulls == [[12.0, 12.0, None, None, 10.0], [None], None, []]
+    clamped = df.select(polars_vec_ops.gather("v", "i", oob="clamp"))["v"].to_list()
+    assert clamped[0] == [12.0, 12.0, None, 12.0, 10.0]
+
+    arr = pl.DataFrame(
+        {"v": [["a", "b"]], "i": [[1, 1, 0]]}, schema={"v": pl.Array(pl.String, 2)}
+    )
+    out = arr.select(pl.col("v").vec.gather("i"))
+    assert out.schema["v"] == pl.List(pl.String)
+    assert out["v"].to_list() == [["b", "b", "a"]]
+
+    with pytest.raises(Exception, match="out of bounds"):
+        df.select(pl.col("v").vec.gather("i"))
+    with pytest.raises(ValueError, match="oob must be one of"):
+        pl.col("v").vec.gather("i", oob="wrap")
+
+
+def test_scatter():
+    """Test per-row scatter of updates at index positions."""
+    df = pl.DataFrame(
+        {
+            "v": [[10.0, 11.0, 12.0], [5.0], None, [1.0, 2.0]],
+            "i": [[2, -3, None], [0], [0], [1, 1]],
+            "u": [[0.0, -1.0, 9.0], None, [0.0], [7.0, 8.0]],
+        }
+    )
+    result = df.select(pl.col("v").vec.scatter("i", "u"))["v"].to_list()
+    assert result == [[-1.0, 11.0, 0.0], [5.0], None, [1.0, 8.0]]
+
+    masked = df.select(polars_vec_ops.scatter("v", "i", None))["v"].to_list()
+    assert masked == [[None, 11.0, None], [None], None, [1.0, None]]
+
+    arr = pl.DataFrame(
+        {"v": [[1, 2, 3], [4, 5, 6]], "i": [[0], [1, 2]], "u": [-1, -2]},
+        schema_overrides={"v": pl.Array(pl.Int64, 3)},
+    )
+    out = arr.select(pl.col("v").vec.scatter("i", "u"))
+    assert out.schema["v"] == pl.Array(pl.Int64, 3)
+    assert out["v"].to_list() == [[-1, 2, 3], [4, -2, -2]]
+
+    with pytest.raises(Exception, match="out of bounds"):
+        df.select(pl.col("v").vec.scatter(pl.col("i").list.eval(pl.element() + 5), 0))
+    with pytest.raises(Exception, match="same length"):
+        df.select(pl.col("v").vec.scatter("i", pl.col("u").list.head(1)))
+
+
+def test_search_sorted():
+    """Test per-row searchsorted with scalar and list queries."""
+    grids = [[0.0, 1.0, 1.0, 2.0], [], None]
+    df = pl.DataFrame(
+        {"t": grids, "q": [1.0, 5.0, 0.0], "qs": [[-1.0, 1.0, 9.0, None], [1.0], [1.0]]}
+    )
+    left = df.select(pl.col("t").vec.search_sorted("q"))["t"].to_list()
+    assert left == [1, 0, None]
+    right = df.select(polars_vec_ops.search_sorted("t", "q", side="right"))["t"]
+    assert right.to_list() == [3, 0, None]
+
+    many = df.select(pl.col("t").vec.search_sorted("qs"))["t"].to_list()
+    assert many == [[0, 1, 4, None], [0], None]
+    expected = np.searchsorted(grids[0], [-1.0, 1.0, 9.0], side="right").tolist()
+    many_right = df.select(pl.col("t").vec.search_sorted("qs", side="right"))["t"]
+    assert many_right[0].to_list()[:3] == expected
+
+    assert df.select(pl.col("t").vec.search_sorted(1.0))["t"].to_list() == [1, 0, None]
+
+    with pytest.raises(ValueError, match="side"):
+        pl.col("t").vec.search_sorted("q", side="middle")
+
+
+def test_rle_round_trip():
+    """Test run-length encoding and decoding of each list."""
+    df = pl.DataFrame({"a": [["on", "on", None, None, "off"], [], None, ["on"]]})
+    encoded = df.select(pl.col("a").vec.rle()).unnest("a")
+    assert encoded["values"].to_list() == [["on", None, "off"], [], None, ["on"]]
+    assert encoded["lengths"].to_list() == [[2, 2, 1], [], None, [1]]
+
+    decoded = df.select(polars_vec_ops.rle_decode(polars_vec_ops.rle("a")))
+    assert decoded["a"].to_list() == df["a"].to_list()
+
+    runs = pl.DataFrame({"values": [[1.5, 2.5]], "lengths": [[3, 0]]})
+    manual = runs.select(pl.struct("values", "lengths").vec.rle_decode())
+    assert manual["values"].to_list() == [[1.5, 1.5, 1.5]]
+
+    with pytest.raises(Exception, match="same length"):
+        pl.DataFrame({"values": [[1]], "lengths": [[1, 2]]}).select(
+            pl.struct("values", "lengths").vec.rle_decode()
+        )
+
+
+def test_pad_truncate_and_windows():
+    """Test padding, truncating and windowing each list."""
+    df = pl.DataFrame({"a": [[1.0, None, 3.0], [], None, [1.0, 2.0, 3.0, 4.0, 5.0]]})
+    padded = df.select(pl.col("a").vec.pad_to(4, fill=0.0, align="right"))["a"]
+    assert padded.to_list() == [
+        [0.0, 1.0, None, 3.0],
+        [0.0, 0.0, 0.0, 0.0],
+        None,
+        [1.0, 2.0, 3.0, 4.0, 5.0],
+    ]
+    fixed = df.select(polars_vec_ops.pad_to(polars_vec_ops.truncate("a", 4), 4))
+    assert fixed["a"].to_list() == [
+        [1.0, None, 3.0, None],
+        [None, None, None, None],
+        None,
+        [1.0, 2.0, 3.0, 4.0],
+    ]
+
+    windows = df.select(pl.col("a").vec.reshape_windows(3, step=2))["a"].to_list()
+    assert windows == [[[1.0, None, 3.0]], [], None, [[1.0, 2.0, 3.0], [3.0, 4.0, 5.0]]]
+    chunks = df.select(polars_vec_ops.reshape_windows("a", 2, step=2))["a"][3]
+    assert chunks.to_list() == [[1.0, 2.0], [3.0, 4.0]]
+
+    ints = pl.DataFrame({"a": [[1, 2]]}, schema={"a": pl.Array(pl.Int32, 2)})
+    out = ints.select(pl.col("a").vec.pad_to(3, fill=7))
+    assert out.schema["a"] == pl.List(pl.Int32)
+    assert out["a"].to_list() == [[1, 2, 7]]
+
+    with pytest.raises(ValueError, match="positive"):
+        pl.col("a").vec.reshape_windows(0)
+
+
+def test_to_array():
+    """Test validated List to Array conversion."""
+    df = pl.DataFrame({"a": [[1.0, 2.0], None, [3.0, None]]})
+    out = df.select(pl.col("a").vec.to_array(2))
+    assert out.schema["a"] == pl.Array(pl.Float64, 2)
+    assert out["a"].to_list() == [[1.0, 2.0], None, [3.0, None]]
+
+    again = out.select(polars_vec_ops.to_array("a", 2))
+    assert again["a"].to_list() == out["a"].to_list()
+
+    ragged = pl.DataFrame({"a": [[1.0, 2.0], [1.0], [1.0, 2.0, 3.0]]})
+    with pytest.raises(Exception, match="got 1 at row 1"):
+        ragged.select(pl.col("a").vec.to_array(2))
+    with pytest.raises(Exception, match="width 3, got width 2"):
+        out.select(pl.col("a").vec.to_array(3))
+
+
+def test_shape_report():
+    """Test the one-pass list shape summary."""
+    df = pl.DataFrame(
+        {"a": [[1.0, None, 3.0], [], None, [1.0], [1.0]], "g": [0, 0, 1, 1, 1]}
+    )
+    report = df.select(pl.col("a").vec.shape_report()).unnest("a").row(0, named=True)
+    assert report == {
+        "n_rows": 5,
+        "n_null_rows": 1,
+        "min_len": 0,
+        "max_len": 3,
+        "n_distinct_lens": 3,
+        "inner_dtype": "f64",
+    }
+
+    grouped = (
+        df.group_by("g", maintain_order=True)
+        .agg(polars_vec_ops.shape_report("a"))
+        .unnest("a")
+    )
+    assert grouped["n_distinct_lens"].to_list() == [2, 1]
+    assert grouped["max_len"].to_list() == [3, 1]
+
+    arr = pl.DataFrame({"a": [[1, 2]]}, schema={"a": pl.Array(pl.Int32, 2)})
+    row = arr.select(pl.col("a").vec.shape_report()).unnest("a").row(0, named=True)
+    assert (row["min_len"], row["max_len"], row["inner_dtype"]) == (2, 2, "i32")
+
+
+def test_length_mismatch_reports_rows():
+    """Test that vertical length errors locate the offending rows."""
+    df = pl.DataFrame({"a": [None, [1.0, 3.0], [1.0], None, [1.0, 2.0], []]})
+    expected = r"Expected 2 \(as in row 1\), got 1 at row 2; 2 of 4 non-null rows differ"
+    for expr in (
+        pl.col("a").vec.sum(),
+        pl.col("a").vec.mean_std(),
+        pl.col("a").vec.diff(),
+        pl.col("a").vec.transpose(),
+    ):
+        with pytest.raises(Exception, match=expected):
+            df.select(expr)
+
+
+def test_reduce_validate():
+    """Test the strict and fast length validation of the reductions."""
+    df = pl.DataFrame({"a": [[1.0, 3.0], [2.0, 5.0], None]})
+    for func in (polars_vec_ops.sum, polars_vec_ops.mean, polars_vec_ops.max):
+        strict = df.select(func("a"))
+        fast = df.select(func("a", validate="fast"))
+        assert strict["a"].to_list() == fast["a"].to_list()
+    assert df.select(pl.col("a").vec.min(validate="fast"))["a"].to_list() == [[1.0, 3.0]]
+
+    ragged = pl.DataFrame({"a": [[1.0, 3.0], [2.0, 5.0], [1.0, 2.0, 3.0]]})
+    with pytest.raises(Exception, match="at row 2"):
+        ragged.select(pl.col("a").vec.sum(validate="strict"))
+    # The mean checks each row's length as it folds it in, so fast still errors cleanly
+    for rows in ([[1.0, 3.0], [2.0]], [[1.0, 3.0], [2.0, 5.0, 7.0]]):
+        with pytest.raises(Exception, match="vertical mean"):
+            pl.DataFrame({"a": rows}).select(pl.col("a").vec.mean(validate="fast"))
+    with pytest.raises(ValueError, match="validate must be"):
+        pl.col("a").vec.mean(validate="lenient")
+
+
+def test_reduce_empty_policy():
+    """Test the handling of empty lists in the vertical reductions."""
+    df = pl.DataFrame({"a": [[], [1.0, 2.0], None, [3.0, 4.0]]})
+    with pytest.raises(Exception, match="Empty list at row 0"):
+        df.select(pl.col("a").vec.sum())
+    assert df.select(pl.col("a").vec.sum(empty_policy="skip"))["a"].to_list() == [[4.0, 6.0]]
+    assert df.select(polars_vec_ops.mean("a", empty_policy="treat_as_null"))[
+        "a"
+    ].to_list() == [[2.0, 3.0]]
+    propagated = df.select(
+        pl.col("a").vec.max(null_policy="propagate", empty_policy="treat_as_null")
+    )
+    assert propagated["a"].to_list() == [[None, None]]
+    assert df.select(
+        pl.col("a").vec.min(min_count=3, empty_policy="treat_as_null")
+    )["a"].to_list() == [[None, None]]
+
+    all_empty = pl.DataFrame({"a": [[], []]}, schema={"a": pl.List(pl.Float64)})
+    assert all_empty.select(pl.col("a").vec.sum())["a"].to_list() == [[]]
+
+    ragged = pl.DataFrame({"a": [[], [1.0, 2.0], [1.0]]})
+    with pytest.raises(Exception, match=r"Expected 2 \(as in row 1\), got 1 at row 2"):
+        ragged.select(pl.col("a").vec.sum(empty_policy="skip"))
+    with pytest.raises(ValueError, match="empty_policy must be"):
+        pl.col("a").vec.sum(empty_policy="drop")
+
+
+def test_reduce_nan_policy():
+    """Test that NaN handling is configured separately from nulls."""
+    nan = float("nan")
+    df = pl.DataFrame({"a": [[1.0, nan, 5.0], [nan, 2.0, None], [3.0, 4.0, 1.0]]})
+    for func in (polars_vec_ops.sum, polars_vec_ops.mean, polars_vec_ops.min):
+        result = df.select(func("a"))["a"].to_list()[0]
+        assert np.isnan(result[0]) and np.isnan(result[1])
+
+    assert df.select(pl.col("a").vec.sum(nan_policy="ignore"))["a"].to_list() == [
+        [4.0, 6.0, 6.0]
+    ]
+    assert df.select(pl.col("a").vec.mean(nan_policy="ignore"))["a"].to_list() == [
+        [2.0, 3.0, 3.0]
+    ]
+    assert df.select(pl.col("a").vec.max(nan_policy="as_null"))["a"].to_list() == [
+        [3.0, 4.0, 5.0]
+    ]
+    # NaN only counts as null with "as_null"
+    ignored = df.select(pl.col("a").vec.min(null_policy="propagate", nan_policy="ignore"))
+    assert ignored["a"].to_list() == [[1.0, 2.0, None]]
+    as_null = df.select(pl.col("a").vec.min(null_policy="propagate", nan_policy="as_null"))
+    assert as_null["a"].to_list() == [[None, None, None]]
+    assert df.select(pl.col("a").vec.sum(min_count=3, nan_policy="ignore"))[
+        "a"
+    ].to_list() == [[None, None, None]]
+
+    with pytest.raises(ValueError, match="nan_policy must be"):
+        pl.col("a").vec.sum(nan_policy="omit")
+
+
+def test_nan_reductions():
+    """Test the numpy-style nansum/nanmean/nanmin/nanmax reductions."""
+    nan = float("nan")
+    data = [[1.0, nan, 5.0], [nan, 2.0, 1.0], [3.0, 4.0, nan]]
+    df = pl.DataFrame({"a": data})
+    arr = np.array(data)
+    for name in ("nansum", "nanmean", "nanmin", "nanmax"):
+        expected = getattr(np, name)(arr, axis=0).tolist()
+        assert df.select(getattr(pl.col("a").vec, name)())["a"].to_list() == [expected]
+        assert df.select(getattr(polars_vec_ops, name)("a"))["a"].to_list() == [expected]
+
+    assert df.select(polars_vec_ops.nanmean("a", min_count=2))["a"].to_list() == [
+        [2.0, 3.0, 3.0]
+    ]
+    all_nan = pl.DataFrame({"a": [[nan, 1.0], [nan, 2.0]]})
+    assert all_nan.select(pl.col("a").vec.nanmax())["a"].to_list() == [[None, 2.0]]
+    assert all_nan.select(pl.col("a").vec.nansum())["a"].to_list() == [[0.0, 3.0]]
+
+
+def test_ptp():
+    """Test the vertical peak-to-peak range."""
+    df = pl.DataFrame({"a": [[3, 5, 2], None, [1, 7, 4], [2, -1, None]]})
+    result = df.select(pl.col("a").vec.ptp())
+    assert result["a"].to_list() == [[2, 8, 2]]
+    assert result.schema["a"] == pl.List(pl.Int64)
+
+    arr = np.random.default_rng(0).normal(size=(6, 4))
+    floats = pl.DataFrame({"a": arr.tolist()}).select(polars_vec_ops.ptp("a"))
+    assert floats["a"].to_list()[0] == pytest.approx(np.ptp(arr, axis=0).tolist())
+
+    propagated = df.select(pl.col("a").vec.ptp(null_policy="propagate", keep_rows=True))
+    assert propagated["a"].to_list() == [[2, 8, None]] * 4
+
+
+def test_abs_max():
+    """Test the vertical absolute maximum keeps the sign of the peak."""
+    df = pl.DataFrame({"a": [[1.0, None, 5.0], None, [-2.0, -3.0, -5.0], [-1.5, 1.0, 4.0]]})
+    assert df.select(pl.col("a").vec.abs_max())["a"].to_list() == [[-2.0, -3.0, 5.0]]
+
+    arr = np.random.default_rng(1).normal(size=(5, 6))
+    expected = arr[np.abs(arr).argmax(axis=0), np.arange(6)]
+    result = pl.DataFrame({"a": arr.tolist()}).select(polars_vec_ops.abs_max("a"))
+    assert result["a"].to_list()[0] == pytest.approx(expected.tolist())
+
+    ints = pl.DataFrame({"a": [[1, 9], [-4, -1]]}).select(pl.col("a").vec.abs_max())
+    assert ints["a"].to_list() == [[-4, 9]]
+    assert ints.schema["a"] == pl.List(pl.Int64)
+
+
+def test_scaled_mean():
+    """Test the fused rescale-then-average reduction against the two-pass result."""
+    arr = np.random.default_rng(2).normal(size=(5, 7)) * np.arange(1, 6)[:, None]
+    df = pl.DataFrame({"a": arr.tolist() + [None]})
+
+    lo, hi = arr.min(axis=1, keepdims=True), arr.max(axis=1, keepdims=True)
+    expected = ((arr - lo) / (hi - lo)).mean(axis=0)
+    result = df.select(pl.col("a").vec.scaled_mean())["a"].to_list()[0]
+    assert result == pytest.approx(expected.tolist())
+
+    two_pass = df.select(pl.col("a").vec.zscore(ddof=0).vec.mean())["a"].to_list()[0]
+    fused = df.select(polars_vec_ops.scaled_mean("a", method="zscore", ddof=0))
+    assert fused["a"].to_list()[0] == pytest.approx(two_pass)
+
+    with pytest.raises(ValueError, match="method must be"):
+        pl.col("a").vec.scaled_mean(method="robust")
+
+
+def test_vec_diff_order():
+    """Test n-th order differences against numpy and chained first differences."""
+    arr = np.random.default_rng(3).integers(-50, 50, size=(8, 4))
+    df = pl.DataFrame({"a": arr.tolist()})
+    for n in (1, 2, 3):
+        result = df.select(pl.col("a").vec.diff(n=n))["a"].to_list()
+        assert result[:n] == [[None] * 4] * n
+        assert result[n:] == np.diff(arr, n=n, axis=0).tolist()
+
+    chained = df.select(pl.col("a").vec.diff().vec.diff())["a"].to_list()
+    assert df.select(polars_vec_ops.diff("a", n=2))["a"].to_list() == chained
+
+    gaps = pl.DataFrame({"a": [[1.0], [2.0], [4.0], None, [8.0], [16.0], [32.0]]})
+    second = gaps.select(pl.col("a").vec.diff(n=2))["a"].to_list()
+    assert second == [[None], [None], [1.0], [None], [None], [None], [8.0]]
+
+    # Integer terms are summed in a wider type, so only the result has to fit
+    small = pl.DataFrame({"a": [[100], [100], [100]]}, schema={"a": pl.List(pl.Int8)})
+    assert small.select(pl.col("a").vec.diff(n=2))["a"].to_list() == [[None], [None], [0]]
+    # Unsigned and narrow integers give a wider signed dtype, like polars' diff
+    for dtype, out in [(pl.Int8, pl.Int16), (pl.UInt8, pl.Int16), (pl.UInt64, pl.Int64)]:
+        swing = pl.DataFrame({"a": [[100], [5]]}, schema={"a": pl.List(dtype)})
+        result = swing.select(pl.col("a").vec.diff())["a"]
+        assert result.dtype == pl.List(out)
+        assert result.to_list() == [[None], [-95]]
+    wide = pl.DataFrame({"a": [[2**62], [-(2**62) - 1]]}, schema={"a": pl.List(pl.Int64)})
+    with pytest.raises(Exception, match="Vertical diff overflows"):
+        wide.select(pl.col("a").vec.diff())
+
+    with pytest.raises(ValueError, match="n must be at least 1"):
+        pl.col("a").vec.diff(n=0)
+    # Too few rows for the order gives lists of nulls, like polars' diff
+    assert df.select(pl.col("a").vec.diff(n=8))["a"].to_list() == [[None] * 4] * 8
+
+
+def test_cum_sum_from_inverts_diff():
+    """Test that cum_sum_from rebuilds the rows diff() was taken from."""
+    arr = np.random.default_rng(4).integers(-20, 20, size=(6, 3))
+    df = pl.DataFrame({"g": [0, 0, 0, 1, 1, 1], "a": arr.tolist()})
+    rebuilt = df.select(pl.col("a").vec.diff().vec.cum_sum_from(pl.col("a").first()))
+    assert rebuilt["a"].to_list() == arr.tolist()
+
+    per_group = df.group_by("g", maintain_order=True).agg(
+        polars_vec_ops.cum_sum_from(pl.col("a").vec.diff(), pl.col("a").first())
+    )
+    assert per_group["a"].explode().to_list() == arr.tolist()
+
+    diffs = pl.DataFrame({"d": [None, [1.0, None], None, [1.0, 1.0]]})
+    result = diffs.select(pl.col("d").vec.cum_sum_from([0.5, 0.0]))["d"].to_list()
+    assert result == [[0.5, 0.0], [1.5, None], None, [None, None]]
+
+    with pytest.raises(Exception, match="initial must be a single list"):
+        df.select(pl.col("a").vec.cum_sum_from(pl.col("a")))
+    # A scalar initial is rejected when the schema is resolved
+    with pytest.raises(Exception, match="initial must be a List or Array"):
+        df.lazy().select(pl.col("a").vec.cum_sum_from(pl.lit(0))).collect_schema()
+
+    big = pl.DataFrame({"d": [None, [2**62], [2**62]]})
+    with pytest.raises(Exception, match="cum_sum_from overflows i64"):
+        big.select(pl.col("d").vec.cum_sum_from([2**62]))
+    narrow = pl.DataFrame({"d": [None, [100], [-100]]}, schema={"d": pl.List(pl.Int8)})
+    initial = pl.lit([100], dtype=pl.List(pl.Int8))
+    with pytest.raises(Exception, match="cum_sum_from overflows i8"):
+        narrow.select(pl.col("d").vec.cum_sum_from(initial))
+
+
+def test_delta_encode_round_trip():
+    """Test delta encoding along the list axis, narrowing and decoding."""
+    df = pl.DataFrame(
+        {"a": [[1000, 1002, None, 1001, 1005], None, [], [-3, 120]]},
+        schema={"a": pl.List(pl.Int32)},
+    )
+    encoded = df.select(pl.col("a").vec.delta_encode(pl.Int16))
+    assert encoded.schema["a"] == pl.List(pl.Int16)
+    assert encoded["a"].to_list() == [[1000, 2, None, -1, 4], None, [], [-3, 123]]
+
+    decoded = encoded.select(polars_vec_ops.delta_decode("a", dtype="int32"))
+    assert decoded.schema["a"] == pl.List(pl.Int32)
+    assert decoded["a"].to_list() == df["a"].to_list()
+
+    arr = np.cumsum(np.random.default_rng(5).normal(size=(3, 50)), axis=1)
+    floats = pl.DataFrame({"a": arr.tolist()}, schema={"a": pl.Array(pl.Float64, 50)})
+    round_trip = floats.select(pl.col("a").vec.delta_encode().vec.delta_decode())
+    assert round_trip.schema["a"] == pl.Array(pl.Float64, 50)
+    assert np.allclose(round_trip["a"].to_numpy(), arr)
+
+    with pytest.raises(Exception, match="conversion"):
+        df.select(pl.col("a").vec.delta_encode(pl.Int8))
+    with pytest.raises(ValueError, match="dtype must be one of"):
+        pl.col("a").vec.delta_encode(pl.String)
+    big = pl.DataFrame({"a": [[2**64 - 1, 1]]}, schema={"a": pl.List(pl.UInt64)})
+    with pytest.raises(Exception, match="conversion"):
+        big.select(pl.col("a").vec.delta_encode())
+
+
+def test_quantize_round_trip():
+    """Test fixed and per-row quantization to integer levels and back."""
+    gains = np.array([[1.0], [10.0], [100.0], [0.1]])
+    arr = np.random.default_rng(6).normal(size=(4, 20)) * gains
+    df = pl.DataFrame({"a": arr.tolist() + [None]})
+
+    auto = df.select(pl.col("a").vec.quantize(dtype=pl.Int16))
+    assert auto.schema["a"] == pl.Struct(
+        {"values": pl.List(pl.Int16), "scale": pl.Float64, "offset": pl.Float64}
+    )
+    levels = auto.unnest("a")
+    assert levels["values"].to_list()[4] is None
+    assert max(abs(v) for row in levels["values"].to_list()[:4] for v in row) == 32767
+
+    restored = auto.select(polars_vec_ops.dequantize("a"))["a"].to_list()
+    scales = levels["scale"].to_numpy()[:4, None]
+    assert np.all(np.abs(np.array(restored[:4]) - arr) <= scales / 2 + 1e-12)
+    assert restored[4] is None
+
+    fixed = pl.DataFrame({"a": [[0.0, 0.25, None, float("nan"), -1.0]]})
+    levels = fixed.select(pl.col("a").vec.quantize(scale=0.01, dtype="int8"))
+    assert levels.schema["a"] == pl.List(pl.Int8)
+    assert levels["a"].to_list() == [[0, 25, None, None, -100]]
+    back = levels.select(pl.col("a").vec.dequantize(scale=0.01))["a"].to_list()
+    assert back == [[0.0, 0.25, None, None, -1.0]]
+
+    with pytest.raises(Exception, match="does not fit i8"):
+        fixed.select(pl.col("a").vec.quantize(scale=0.001, dtype=pl.Int8))
+    with pytest.raises(Exception, match="scale is required"):
+        levels.select(pl.col("a").vec.dequantize())
+    with pytest.raises(ValueError, match="dtype must be one of"):
+        pl.col("a").vec.quantize(dtype=pl.Int64)
+
+
+def test_float32_inner_dtype():
+    """Test that Float32 lists, the in-memory form of f16 exports, work throughout."""
+    arr = np.random.default_rng(7).normal(size=(5, 8)).astype(np.float32)
+    for dtype in (pl.List(pl.Float32), pl.Array(pl.Float32, 8)):
+        df = pl.DataFrame({"a": arr.tolist()}, schema={"a": dtype})
+        summed = df.select(pl.col("a").vec.sum())
+        assert summed.schema["a"] == dtype
+        expected = arr.sum(axis=0).tolist()
+        assert summed["a"].to_list()[0] == pytest.approx(expected, rel=1e-5)
+
+        mean_std = df.select(pl.col("a").vec.mean_std()).unnest("a")
+        expected = arr.mean(axis=0).tolist()
+        assert mean_std["mean"].to_list()[0] == pytest.approx(expected, rel=1e-5)
+
+        row_stats = df.select(pl.col("a").vec.stats()).unnest("a")
+        expected = arr.mean(axis=1).tolist()
+        assert row_stats["mean"].to_list() == pytest.approx(expected, rel=1e-5)
+
+
+def test_group_mean():
+    """Test per-position means split by a same-shape key list."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0, 3.0], [3.0, None, 5.0], None, [10.0, 20.0, 30.0]],
+            "cond": [[2, 1, 2], [2, 2, None], [1, 1, 1], [1, 1, 1]],
+        }
+    )
+    result = df.select(pl.col("a").vec.group_mean("cond")).unnest("a")
+    assert result["keys"].to_list() == [[1, 2]]
+    assert result["mean"].to_list() == [[[10.0, 11.0, 30.0], [2.0, None, 3.0]]]
+
+    labels = df.with_columns(
+        pl.col("cond").cast(pl.List(pl.String)).cast(pl.List(pl.Categorical))
+    )
+    result = labels.select(polars_vec_ops.group_mean("a", "cond")).unnest("a")
+    assert result.schema["keys"] == pl.List(pl.Categorical)
+    assert result["keys"].to_list() == [["1", "2"]]
+    assert result["mean"].to_list() == [[[10.0, 11.0, 30.0], [2.0, None, 3.0]]]
+
+    grouped = (
+        pl.DataFrame(
+            {
+                "g": [0, 0, 1],
+                "a": [[1, 2], [3, 4], [5, 6]],
+                "k": [[0, 1], [1, 0], [0, 0]],
+            }
+        )
+        .group_by("g", maintain_order=True)
+        .agg(pl.col("a").vec.group_mean("k"))
+        .unnest("a")
+    )
+    assert grouped["keys"].to_list() == [[0, 1], [0]]
+    assert grouped["mean"].to_list() == [[[1.0, 4.0], [3.0, 2.0]], [[5.0, 6.0]]]
+
+    with pytest.raises(Exception, match="length for group_mean"):
+        df.with_columns(cond=pl.Series([[1, 2]] * 4)).select(
+            pl.col("a").vec.group_mean("cond")
+        )
+
+
+def test_chunked_mean():
+    """Test averaging over blocks of consecutive rows."""
+    df = pl.DataFrame(
+        {"a": [[1.0, 2.0], [3.0, None], None, [10.0, 20.0], None, None]}
+    )
+    result = df.select(pl.col("a").vec.chunked_mean(2))
+    assert result["a"].to_list() == [[2.0, 2.0], [10.0, 20.0], None]
+
+    result = df.select(polars_vec_ops.chunked_mean("a", 4))
+    assert result["a"].to_list() == [
+        pytest.approx([14.0 / 3.0, 11.0]),
+        None,
+    ]
+
+    arr = pl.DataFrame(
+        {"a": [[1, 2], [3, 4], [5, 6]]}, schema={"a": pl.Array(pl.Int64, 2)}
+    )
+    result = arr.select(pl.col("a").vec.chunked_mean(2))
+    assert result.schema["a"] == pl.Array(pl.Float64, 2)
+    assert result["a"].to_list() == [[2.0, 3.0], [5.0, 6.0]]
+
+    with pytest.raises(ValueError, match="every"):
+        pl.col("a").vec.chunked_mean(0)
+
+
+def test_mean_by_time():
+    """Test averaging rows into fixed-width time bins."""
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0], [3.0, None], None, [10.0, 20.0], [5.0, 5.0]],
+            "t": [
+                datetime(2024, 1, 1, 0, 0, 0),
+                datetime(2024, 1, 1, 0, 0, 59),
+                datetime(2024, 1, 1, 0, 1, 1),
+                datetime(2024, 1, 1, 1, 0, 0),
+                None,
+            ],
+        }
+    )
+    result = df.select(pl.col("a").vec.mean_by_time("t", "1m")).unnest("a")
+    assert result.schema["time"] == df.schema["t"]
+    assert result["time"].to_list() == [
+        datetime(2024, 1, 1, 0, 0),
+        datetime(2024, 1, 1, 0, 1),
+        datetime(2024, 1, 1, 1, 0),
+    ]
+    assert result["mean"].to_list() == [[2.0, 2.0], None, [10.0, 20.0]]
+
+    result = df.select(polars_vec_ops.mean_by_time("a", "t", timedelta(hours=1)))
+    assert result.unnest("a")["mean"].to_list() == [[2.0, 2.0], [10.0, 20.0]]
+
+    with pytest.raises(Exception, match="Calendar duration"):
+        df.select(pl.col("a").vec.mean_by_time("t", "1mo"))
+
+
+def test_every_plugin_function_is_exposed():
+    """Test that each Rust plugin function is registered by the Python package."""
+    root = Path(__file__).parents[1]
+    rust = set()
+    for path in (root / "src").rglob("*.rs"):
+        pattern = r"#\[polars_expr[^\]]*\]\s*fn\s+(\w+)"
+        rust |= set(re.findall(pattern, path.read_text()))
+    python = set()
+    for path in (root / "polars_vec_ops").glob("*.py"):
+        text = path.read_text()
+        python |= set(re.findall(r'function_name="(\w+)"', text))
+        # f-string names such as f"list_{op}_columns" match any value of the field
+        for template in re.findall(r'function_name=f"([\w{}]+)"', text):
+            pattern = re.sub(r"\{\w+\}", r"\\w+", template)
+            python |= {name for name in rust if re.fullmatch(pattern, name)}
+    # Kwargs-based clip variants; join_between uses cross_clip_series instead
+    internal = {"list_clip", "cross_clip"}
+    assert python == rust - internal
+
+
+def test_value_report():
+    """Test counting null, NaN and infinite elements of a list column."""
+    df = pl.DataFrame(
+        {"a": [[1.0, float("nan"), None], [float("inf"), None], None, []]}
+    )
+    report = df.select(pl.col("a").vec.value_report()).unnest("a").row(0, named=True)
+    assert report == {
+        "n_elements": 5,
+        "n_null_elements": 2,
+        "n_nan_elements": 1,
+        "n_inf_elements": 1,
+    }
+
+    ints = pl.DataFrame({"a": [[1, None], None]})
+    report = (
+        ints.select(polars_vec_ops.value_report("a")).unnest("a").row(0, named=True)
+    )
+    assert report == {
+        "n_elements": 2,
+        "n_null_elements": 1,
+        "n_nan_elements": 0,
+        "n_inf_elements": 0,
+    }
+
+
+def test_summarize_and_validate():
+    """Test the frame-level helpers that expand to per-column expressions."""
+    df = pl.DataFrame(
+        {"a": [[1, 2], [3, 6]], "b": [[0.5], [float("nan")]], "label": ["x", "y"]}
+    )
+    summary = df.vec.summarize(stats=["mean", "std", "nanmax"])
+    assert summary.columns == [
+        "a_mean",
+        "a_std",
+        "a_nanmax",
+        "b_mean",
+        "b_std",
+        "b_nanmax",
+    ]
+    assert summary["a_mean"].to_list() == [[2.0, 4.0]]
+    assert summary["a_std"].to_list()[0] == pytest.approx([2**0.5, 8**0.5])
+    assert summary["b_nanmax"].to_list() == [[0.5]]
+
+    lazy = polars_vec_ops.summarize(df.lazy(), "a", stats=["sum"])
+    assert isinstance(lazy, pl.LazyFrame)
+    assert lazy.collect()["a_sum"].to_list() == [[4, 8]]
+
+    with pytest.raises(ValueError, match="Unknown stats"):
+        df.vec.summarize(stats=["median"])
+
+    report = polars_vec_ops.validate(df)
+    assert report["column"].to_list() == ["a", "b"]
+    assert report["n_distinct_lens"].to_list() == [1, 1]
+    assert report["n_nan_elements"].to_list() == [0, 1]
+    assert report["inner_dtype"].to_list() == ["i64", "f64"]
+    assert df.lazy().vec.validate("a").collect().height == 1
+
+    with pytest.raises(ValueError, match="No List or Array columns"):
+        df.select("label").vec.validate()
+
+
+def test_selector_expansion():
+    """Test expressions on selectors and the single-call multi-column reduction."""
+    import polars.selectors as cs
+
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0], [3.0, 4.0], None],
+            "b": [[0.5], [1.5], [2.5]],
+            "c": [[1, 2], [3, 4], [5, 6]],
+            "label": ["x", "y", "z"],
+        }
+    )
+    floats = cs.by_dtype(pl.List(pl.Float64))
+
+    per_column = df.select(floats.vec.mean())
+    assert per_column.columns == ["a", "b"]
+    assert per_column.row(0) == ([2.0, 3.0], [1.5])
+
+    combined = df.select(polars_vec_ops.reduce_columns(floats))
+    assert combined.columns == ["a", "b"]
+    assert combined.row(0) == ([2.0, 3.0], [1.5])
+
+    result = df.select(
+        polars_vec_ops.reduce_columns("a", "c", op="sum", keep_rows=True)
+    )
+    assert result.schema == {"a": pl.List(pl.Float64), "c": pl.List(pl.Int64)}
+    assert result["c"].to_list() == [[9, 12]] * 3
+
+    grouped = (
+        df.with_columns(g=pl.Series([0, 0, 1]))
+        .group_by("g", maintain_order=True)
+        .agg(polars_vec_ops.reduce_columns(cs.by_dtype(pl.List(pl.Int64)), op="max"))
+    )
+    assert grouped["c"].to_list() == [[3, 4], [5, 6]]
+
+    # An all-null column still gives one row, so it lines up with the others
+    with_nulls = df.with_columns(d=pl.Series([None] * 3, dtype=pl.List(pl.Float64)))
+    combined = with_nulls.select(polars_vec_ops.reduce_columns("a", "d"))
+    assert combined.schema == {"a": pl.List(pl.Float64), "d": pl.List(pl.Float64)}
+    assert combined.row(0) == ([2.0, 3.0], None)
+    kept = with_nulls.select(polars_vec_ops.reduce_columns("a", "d", keep_rows=True))
+    assert kept["d"].to_list() == [None] * 3
+
+    with pytest.raises(ValueError, match="op must be"):
+        polars_vec_ops.reduce_columns("a", op="median")
+
+
+def test_to_matrix_numpy_view():
+    """Test dense matrix validation and the copy-free numpy view."""
+    df = pl.DataFrame({"a": [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]})
+    result = df.select(pl.col("a").vec.to_matrix(3))
+    assert result.schema["a"] == pl.Array(pl.Float64, 3)
+    assert result["a"].n_chunks() == 1
+
+    chunked = pl.concat([df, df], rechunk=False)["a"]
+    matrix = polars_vec_ops.to_numpy_matrix(chunked)
+    assert matrix.shape == (4, 3)
+    np.testing.assert_array_equal(matrix[3], [4.0, 5.0, 6.0])
+
+    arr = pl.Series("a", [[1, 2], [3, 4]], dtype=pl.Array(pl.Int32, 2))
+    matrix = polars_vec_ops.to_numpy_matrix(arr)
+    assert matrix.dtype == np.int32
+    np.testing.assert_array_equal(matrix, [[1, 2], [3, 4]])
+
+    for bad, match in [
+        ([[1.0, 2.0], None], "Row 1 is null"),
+        ([[1.0, None], [3.0, 4.0]], "null elements"),
+        ([[1.0], [3.0, 4.0]], "length 2"),
+    ]:
+        with pytest.raises(Exception, match=match):
+            polars_vec_ops.to_numpy_matrix(pl.Series("a", bad))
+
+
+def test_from_matrix():
+    """Test wrapping 2D numpy arrays as Array columns."""
+    matrix = np.arange(12, dtype=np.float32).reshape(4, 3)
+    series = polars_vec_ops.from_matrix("a", matrix)
+    assert series.name == "a"
+    assert series.dtype == pl.Array(pl.Float32, 3)
+    assert series.to_list()[1] == [3.0, 4.0, 5.0]
+    np.testing.assert_array_equal(polars_vec_ops.to_numpy_matrix(series), matrix)
+
+    fortran = np.asfortranarray(np.arange(6).reshape(2, 3))
+    series = polars_vec_ops.from_matrix("b", fortran)
+    assert series.to_list() == [[0, 1, 2], [3, 4, 5]]
+
+    df = pl.DataFrame(polars_vec_ops.from_matrix("a", matrix))
+    assert df.select(pl.col("a").vec.sum())["a"].to_list() == [[18.0, 22.0, 26.0]]
+
+    with pytest.raises(ValueError, match="2D"):
+        polars_vec_ops.from_matrix("a", np.arange(3))
+
+
+def test_reductions_are_bit_identical():
+    """Test that vertical reductions give the same floats across runs and chunkings."""
+    rng = np.random.default_rng(0)
+    scales = 10.0 ** rng.integers(-8, 8, (500, 8))
+    df = pl.DataFrame({"a": (rng.standard_normal((500, 8)) * scales).tolist()})
+    chunked = pl.concat([df.slice(i, 37) for i in range(0, 500, 37)], rechunk=False)
+    assert chunked["a"].n_chunks() > 1
+
+    exprs = [
+        pl.col("a").vec.mean().alias("mean"),
+        pl.col("a").vec.sum().alias("sum"),
+        pl.col("a").vec.mean_std().struct.field("std").alias("std"),
+    ]
+    expected = df.select(exprs).row(0)
+    for frame in [df, df, chunked]:
+        assert frame.select(exprs).row(0) == expected
+    combined = chunked.select(polars_vec_ops.reduce_columns("a", op="sum"))
+    assert combined["a"][0].to_list() == expected[1]
+
+
+def test_reduction_row_slice():
+    """Test offset/length restricting the vertical reductions to a slice of rows."""
+    df = pl.DataFrame({"a": [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]})
+    result = df.select(pl.col("a").vec.mean(offset=1, length=2))
+    assert result["a"].to_list() == [[4.0, 5.0]]
+    result = df.select(pl.col("a").vec.sum(offset=-2))
+    assert result["a"].to_list() == [[12.0, 14.0]]
+    result = df.select(pl.col("a").vec.max(offset=1, length=1, keep_rows=True))
+    assert result["a"].to_list() == [[3.0, 4.0]] * 4
+    result = df.select(polars_vec_ops.reduce_columns("a", op="min", offset=2))
+    assert result["a"].to_list() == [[5.0, 6.0]]
+
+    # Rows outside the slice are not checked, and errors keep the column's row numbers
+    ragged = pl.DataFrame({"a": [[1.0], [2.0, 3.0], [4.0, 5.0], [6.0]]})
+    result = ragged.select(pl.col("a").vec.mean(offset=1, length=2))
+    assert result["a"].to_list() == [[3.0, 4.0]]
+    with pytest.raises(Exception, match="got 1 at row 3"):
+        ragged.select(pl.col("a").vec.mean(offset=1))
+    with pytest.raises(ValueError, match="length must be non-negative"):
+        pl.col("a").vec.mean(length=-1)
+
+
+def test_reduction_every_nth_row():
+    """Test the every kwarg for split-half (odd/even row) reductions."""
+    rows = [[0.0, 1.0], [10.0, 11.0], [2.0, 3.0], [12.0, 13.0], [4.0, 5.0]]
+    df = pl.DataFrame({"a": rows})
+    even = df.select(pl.col("a").vec.mean(every=2))
+    odd = df.select(pl.col("a").vec.mean(offset=1, every=2))
+    assert even["a"].to_list() == [[2.0, 3.0]]
+    assert odd["a"].to_list() == [[11.0, 12.0]]
+    result = df.select(pl.col("a").vec.sum(offset=1, length=3, every=2, keep_rows=True))
+    assert result["a"].to_list() == [[22.0, 24.0]] * 5
+
+    # Skipped rows are not validated; errors report rows by their position in the column
+    ragged = pl.DataFrame({"a": [[1.0], [2.0, 3.0], [4.0], [5.0, 6.0], [7.0, 8.0]]})
+    result = ragged.select(pl.col("a").vec.max(offset=1, every=2))
+    assert result["a"].to_list() == [[5.0, 6.0]]
+    with pytest.raises(Exception, match="got 2 at row 4"):
+        ragged.select(pl.col("a").vec.max(every=2))
+    with pytest.raises(ValueError, match="every must be at least 1"):
+        pl.col("a").vec.mean(every=0)
+
+
+def test_reverse():
+    """Test reversing each row's list, and reverse_rows for diff."""
+    df = pl.DataFrame({"a": [[1, 2, 3], None, [], [4, None, 6]]})
+    result = df.select(pl.col("a").vec.reverse())
+    assert result["a"].to_list() == [[3, 2, 1], None, [], [6, None, 4]]
+    chunked = pl.concat([df[:2], df[2:]], rechunk=False)
+    assert chunked.select(polars_vec_ops.reverse("a")).equals(result)
+
+    arrays = pl.DataFrame({"a": [[1.0, 2.0], None, [3.0, 4.0]]})
+    arrays = arrays.cast(pl.Array(pl.Float64, 2))
+    result = arrays.select(pl.col("a").vec.reverse())
+    assert result.schema["a"] == pl.Array(pl.Float64, 2)
+    assert result["a"].to_list() == [[2.0, 1.0], None, [4.0, 3.0]]
+
+    # Differences against the following rows match diff() of the reversed rows
+    arr = np.random.default_rng(5).integers(-50, 50, size=(7, 3))
+    df = pl.DataFrame({"a": arr.tolist()})
+    for n in (1, 2):
+        result = df.select(pl.col("a").vec.diff(n=n, reverse_rows=True))["a"].to_list()
+        assert result[-n:] == [[None] * 3] * n
+        assert result[:-n] == np.diff(arr[::-1], n=n, axis=0)[::-1].tolist()
+    gaps = pl.DataFrame({"a": [[1.0], [3.0], None, [4.0], [8.0]]})
+    result = gaps.select(polars_vec_ops.diff("a", reverse_rows=True))
+    assert result["a"].to_list() == [[-2.0], [None], [None], [-4.0], [None]]
+
+
+def test_roll():
+    """Test circular and filling shifts within each row's list."""
+    df = pl.DataFrame({"a": [[1, 2, 3, 4], None, [], [5, None, 7]], "n": [1, 2, 3, -1]})
+    result = df.select(pl.col("a").vec.roll(1))
+    assert result["a"].to_list() == [[4, 1, 2, 3], None, [], [7, 5, None]]
+    result = df.select(pl.col("a").vec.roll("n"))
+    assert result["a"].to_list() == [[4, 1, 2, 3], None, [], [None, 7, 5]]
+    result = df.select(pl.col("a").vec.roll("n", mode="fill"))
+    assert result["a"].to_list() == [[None, 1, 2, 3], None, [], [None, 7, None]]
+    result = df.select(polars_vec_ops.roll("a", -2, mode="fill", fill_value=0))
+    assert result["a"].to_list() == [[3, 4, 0, 0], None, [], [7, 0, 0]]
+
+    # Matches numpy.roll, including shifts longer than the list; Array stays Array
+    arr = np.arange(12).reshape(3, 4)
+    arrays = pl.DataFrame({"a": arr.tolist()}).cast(pl.Array(pl.Int64, 4))
+    for n in (-5, 0, 3, 9):
+        result = arrays.select(pl.col("a").vec.roll(n))
+        assert result.schema["a"] == pl.Array(pl.Int64, 4)
+        assert result["a"].to_list() == np.roll(arr, n, axis=1).tolist()
+
+    with pytest.raises(ValueError, match="mode must be one of"):
+        pl.col("a").vec.roll(1, mode="clip")
+
+
 if __name__ == "__main__":
     pytest.main([__file__, "-s", "-v"])
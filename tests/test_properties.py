@@ -0,0 +1,526 @@
+"""
+Property-based tests: random List/Array columns checked against plain references.
+
+Columns vary in dtype, width, null rows, null and NaN elements, List vs Array and
+chunking. Element values are small multiples of 0.5, so sums are exact in Float32 as
+well and results can be compared exactly. The per-row kernels run on ragged columns
+and are checked against Python references and against their inverses; src/proptests.rs
+checks the same kernels from Rust. A new expression or kwarg should get a reference
+here alongside its example-based tests in test_expr.py.
+"""
+
+import math
+import operator
+from operator import mul
+
+import numpy as np
+import polars as pl
+from hypothesis import given, settings
+from hypothesis import strategies as st
+
+import polars_vec_ops
+
+DTYPES = [pl.Float64, pl.Float32, pl.Int64, pl.Int32]
+REDUCTIONS = ["sum", "mean", "min", "max", "ptp"]
+NULL_POLICIES = ["ignore", "propagate"]
+NAN_POLICIES = ["propagate", "ignore", "as_null"]
+
+
+@st.composite
+def list_columns(draw, missing=True):
+    """A List/Array column of uniform width, with the rows it was built from.
+
+    With ``missing=False`` there are no null rows, null elements or NaN.
+    """
+    dtype = draw(st.sampled_from(DTYPES))
+    width = draw(st.integers(1, 6))
+    if dtype.is_integer():
+        value = st.integers(-100, 100)
+    else:
+        value = st.integers(-200, 200).map(lambda v: v / 2)
+        if missing:
+            value |= st.just(math.nan)
+    element = st.one_of(value, value, value, st.none()) if missing else value
+    row = st.lists(element, min_size=width, max_size=width)
+    # At least one non-null row, so every reduction returns a single list
+    other = st.one_of(row, row, row, st.none()) if missing else row
+    others = draw(st.lists(other, max_size=11))
+    rows = draw(st.permutations([draw(row), *others]))
+
+    series = pl.Series("a", rows, dtype=pl.List(dtype))
+    if draw(st.booleans()):
+        series = series.cast(pl.Array(dtype, width))
+    return rows, draw(chunked(series))
+
+
+@st.composite
+def chunked(draw, series):
+    """The series split into up to three chunks."""
+    if series.is_empty():
+        return series
+    cuts = sorted(draw(st.lists(st.integers(1, len(series)), max_size=2)))
+    bounds = [0, *cuts, len(series)]
+    pieces = [series.slice(lo, hi - lo) for lo, hi in zip(bounds, bounds[1:])]
+    return pl.concat(pieces, rechunk=False)
+
+
+def small_values(dtype):
+    """Element values for ``dtype`` over a small range, so runs and ties are common."""
+    if dtype.is_integer():
+        return st.integers(-3, 3)
+    return st.integers(-6, 6).map(lambda v: v / 2)
+
+
+@st.composite
+def ragged_columns(draw, dtypes=DTYPES, missing=True):
+    """A List column of rows with different lengths, with the rows it was built from.
+
+    Elements are small, so runs and ties are common. There is no NaN; with
+    ``missing=False`` there are no null rows or null elements either.
+    """
+    dtype = draw(st.sampled_from(dtypes))
+    value = small_values(dtype)
+    element = st.one_of(value, value, value, st.none()) if missing else value
+    row = st.lists(element, max_size=6)
+    row_or_null = st.one_of(row, row, row, st.none()) if missing else row
+    rows = draw(st.lists(row_or_null, max_size=12))
+    series = pl.Series("a", rows, dtype=pl.List(dtype))
+    return rows, draw(chunked(series))
+
+
+def shaped_like(rows, element):
+    """Rows as long as ``rows``, null where those are null and at random elsewhere."""
+
+    def shaped(row):
+        if row is None:
+            return st.none()
+        return st.none() | st.lists(element, min_size=len(row), max_size=len(row))
+
+    return st.tuples(*map(shaped, rows)).map(list)
+
+
+def one_per_row(rows, element):
+    """A list of one ``element`` per row."""
+    return st.lists(element, min_size=len(rows), max_size=len(rows))
+
+
+def reference_reduce(op, rows, null_policy, nan_policy, min_count):
+    """Per-position reduction of the non-null rows, following the ReduceKwargs rules."""
+    rows = [row for row in rows if row is not None]
+    result = []
+    for column in zip(*rows):
+        values, missing = [], 0
+        for v in column:
+            if v is None or (math.isnan(v) and nan_policy == "as_null"):
+                missing += 1
+            elif not math.isnan(v) or nan_policy == "propagate":
+                values.append(v)
+        if len(values) < min_count or (null_policy == "propagate" and missing):
+            result.append(None)
+        elif any(math.isnan(v) for v in values):
+            result.append(math.nan)
+        elif not values:
+            result.append({"sum": 0, "mean": math.nan}.get(op))
+        elif op == "sum":
+            result.append(sum(values))
+        elif op == "mean":
+            result.append(sum(values) / len(values))
+        elif op == "min":
+            result.append(min(values))
+        elif op == "max":
+            result.append(max(values))
+        else:
+            result.append(max(values) - min(values))
+    return result
+
+
+def same_values(actual, expected):
+    """Element-wise equality that treats NaN as equal to NaN."""
+    return len(actual) == len(expected) and all(
+        a == e or (a is not None and e is not None and math.isnan(a) and math.isnan(e))
+        for a, e in zip(actual, expected)
+    )
+
+
+@settings(max_examples=300, deadline=None)
+@given(
+    column=list_columns(),
+    op=st.sampled_from(REDUCTIONS),
+    null_policy=st.sampled_from(NULL_POLICIES),
+    nan_policy=st.sampled_from(NAN_POLICIES),
+    min_count=st.integers(0, 3),
+)
+def test_reductions_match_reference(column, op, null_policy, nan_policy, min_count):
+    """Test every vertical reduction and policy combination against the reference."""
+    rows, series = column
+    expr = getattr(pl.col("a").vec, op)(
+        null_policy=null_policy, nan_policy=nan_policy, min_count=min_count
+    )
+    result = pl.DataFrame({"a": series}).select(expr)["a"][0].to_list()
+    expected = reference_reduce(op, rows, null_policy, nan_policy, min_count)
+    assert same_values(result, expected), (result, expected)
+
+
+@settings(max_examples=100, deadline=None)
+@given(column=list_columns(), op=st.sampled_from(["sum", "mean", "min", "max"]))
+def test_reduce_columns_matches_single_reductions(column, op):
+    """Test the multi-column reduction and keep_rows against the single-column ones."""
+    _, series = column
+    df = pl.DataFrame({"a": series, "b": series.rechunk()})
+    single = df.select(getattr(pl.col("a").vec, op)())["a"].to_list()
+    combined = df.select(polars_vec_ops.reduce_columns("a", "b", op=op))
+    kept = df.select(getattr(pl.col("a").vec, op)(keep_rows=True))["a"].to_list()
+    for values in [combined["a"].to_list(), combined["b"].to_list(), kept[:1]]:
+        assert len(values) == 1
+        assert same_values(values[0], single[0])
+    assert len(kept) == len(series)
+    assert all(same_values(row, single[0]) for row in kept)
+
+
+@settings(max_examples=200, deadline=None)
+@given(
+    column=list_columns(),
+    op=st.sampled_from(["sum", "mean", "min", "max"]),
+    offset=st.integers(-14, 14),
+    length=st.none() | st.integers(0, 14),
+    every=st.integers(1, 3),
+)
+def test_row_slice_matches_sliced_rows(column, op, offset, length, every):
+    """Test offset/length/every against reducing the same rows sliced in Python."""
+    rows, series = column
+    expr = getattr(pl.col("a").vec, op)(offset=offset, length=length, every=every)
+    result = pl.DataFrame({"a": series}).select(expr)["a"].to_list()
+    start = max(len(rows) + offset, 0) if offset < 0 else offset
+    selected = rows[start:][:length][::every]
+    assert len(result) == 1
+    if all(row is None for row in selected):
+        assert result[0] is None
+    else:
+        expected = reference_reduce(op, selected, "ignore", "propagate", 0)
+        assert same_values(result[0], expected), (result[0], expected)
+
+
+@settings(max_examples=200, deadline=None)
+@given(
+    column=list_columns(),
+    op=st.sampled_from(["sum", "mean", "min", "max"]),
+    null_policy=st.sampled_from(NULL_POLICIES),
+    min_count=st.integers(0, 3),
+)
+def test_nan_reductions_ignore_nan(column, op, null_policy, min_count):
+    """Test the nan* reductions against the reference with NaN skipped."""
+    rows, series = column
+    expr = getattr(pl.col("a").vec, f"nan{op}")(
+        null_policy=null_policy, min_count=min_count
+    )
+    result = pl.DataFrame({"a": series}).select(expr)["a"][0].to_list()
+    expected = reference_reduce(op, rows, null_policy, "ignore", min_count)
+    assert same_values(result, expected), (result, expected)
+
+
+def reference_diff(rows, n):
+    """Order-n difference of each row against the n rows before it."""
+    width = len(next(row for row in rows if row is not None))
+    coefficients = [(-1) ** k * math.comb(n, k) for k in range(n + 1)]
+    result = []
+    for i in range(len(rows)):
+        # The row itself first, then the n rows before it
+        window = rows[i - n : i + 1][::-1] if i >= n else [None]
+        if any(row is None for row in window):
+            result.append([None] * width)
+            continue
+        result.append(
+            [
+                None if None in column else sum(map(mul, coefficients, column))
+                for column in zip(*window)
+            ]
+        )
+    return result
+
+
+@settings(max_examples=200, deadline=None)
+@given(column=list_columns(), n=st.integers(1, 3), reverse_rows=st.booleans())
+def test_diff_matches_reference(column, n, reverse_rows):
+    """Test diff of every order, in both row directions, against the reference."""
+    rows, series = column
+    expr = pl.col("a").vec.diff(n, reverse_rows=reverse_rows)
+    result = pl.DataFrame({"a": series}).select(expr)["a"].to_list()
+    if reverse_rows:
+        expected = reference_diff(rows[::-1], n)[::-1]
+    else:
+        expected = reference_diff(rows, n)
+    assert len(result) == len(expected)
+    assert all(same_values(a, e) for a, e in zip(result, expected)), (result, expected)
+
+
+@settings(max_examples=100, deadline=None)
+@given(column=list_columns(missing=False))
+def test_cum_sum_from_inverts_diff(column):
+    """Test that cum_sum_from rebuilds the rows diff was taken of."""
+    rows, series = column
+    expr = pl.col("a").vec.diff().vec.cum_sum_from(pl.col("a").first())
+    result = pl.DataFrame({"a": series}).select(expr)["a"].to_list()
+    assert result == rows
+
+
+@settings(max_examples=100, deadline=None)
+@given(column=list_columns(missing=False), fortran=st.booleans())
+def test_matrix_round_trip(column, fortran):
+    """Test to_numpy_matrix and from_matrix against the rows and each other."""
+    rows, series = column
+    matrix = polars_vec_ops.to_numpy_matrix(series)
+    assert matrix.shape == (len(rows), len(rows[0]))
+    assert matrix.tolist() == rows
+    if fortran:
+        matrix = np.asfortranarray(matrix)
+    wrapped = polars_vec_ops.from_matrix("a", matrix)
+    assert wrapped.dtype == pl.Array(series.dtype.inner, len(rows[0]))
+    assert wrapped.to_list() == rows
+
+
+COMPARISONS = {
+    "eq": operator.eq,
+    "ne": operator.ne,
+    "gt": operator.gt,
+    "ge": operator.ge,
+    "lt": operator.lt,
+    "le": operator.le,
+}
+
+
+@settings(max_examples=200, deadline=None)
+@given(
+    column=ragged_columns(),
+    descending=st.booleans(),
+    nulls_last=st.booleans(),
+    data=st.data(),
+)
+def test_sort_by_matches_stable_sort(column, descending, nulls_last, data):
+    """Test sort_by against a stable sort of each row's positions by key."""
+    rows, series = column
+    keys = data.draw(shaped_like(rows, st.none() | st.integers(-3, 3)))
+    df = pl.DataFrame({"a": series, "k": pl.Series(keys, dtype=pl.List(pl.Int64))})
+    expr = pl.col("a").vec.sort_by("k", descending=descending, nulls_last=nulls_last)
+    result = df.select(expr)["a"].to_list()
+    expected = []
+    for row, key in zip(rows, keys):
+        if row is None or key is None:
+            expected.append(None)
+            continue
+        present = [i for i in range(len(row)) if key[i] is not None]
+        present = sorted(present, key=key.__getitem__, reverse=descending)
+        missing = [i for i in range(len(row)) if key[i] is None]
+        order = present + missing if nulls_last else missing + present
+        expected.append([row[i] for i in order])
+    assert result == expected
+
+
+@settings(max_examples=200, deadline=None)
+@given(column=ragged_columns(), oob=st.sampled_from(["null", "clamp"]), data=st.data())
+def test_gather_matches_indexing(column, oob, data):
+    """Test gather against Python indexing, with out-of-bounds nulled or clamped."""
+    rows, series = column
+    index = st.none() | st.integers(-8, 8)
+    indices = data.draw(one_per_row(rows, st.none() | st.lists(index, max_size=5)))
+    df = pl.DataFrame({"a": series, "i": pl.Series(indices, dtype=pl.List(pl.Int64))})
+    result = df.select(pl.col("a").vec.gather("i", oob=oob))["a"].to_list()
+
+    def take(row, i):
+        if i is None:
+            return None
+        if -len(row) <= i < len(row):
+            return row[i]
+        if oob == "clamp" and row:
+            return row[0] if i < 0 else row[-1]
+        return None
+
+    expected = [
+        None if row is None or idx is None else [take(row, i) for i in idx]
+        for row, idx in zip(rows, indices)
+    ]
+    assert result == expected
+
+
+@settings(max_examples=200, deadline=None)
+@given(column=ragged_columns(), data=st.data())
+def test_scatter_matches_assignment(column, data):
+    """Test scatter against assigning the updates in order, and gather of the result."""
+    rows, series = column
+    dtype = series.dtype.inner
+    indices, updates = [], []
+    for row in rows:
+        n = 0 if row is None else len(row)
+        position = st.integers(-n, n - 1) if n else st.nothing()
+        idx = data.draw(st.none() | st.lists(st.none() | position, max_size=2 * n))
+        update = st.none() | small_values(dtype)
+        size = 0 if idx is None else len(idx)
+        upd = data.draw(st.none() | st.lists(update, min_size=size, max_size=size))
+        indices.append(idx)
+        updates.append(upd)
+    df = pl.DataFrame(
+        {
+            "a": series,
+            "i": pl.Series(indices, dtype=pl.List(pl.Int64)),
+            "u": pl.Series(updates, dtype=pl.List(dtype)),
+        }
+    )
+    result = df.select(pl.col("a").vec.scatter("i", "u"))["a"].to_list()
+    expected = []
+    for row, idx, upd in zip(rows, indices, updates):
+        row = None if row is None else list(row)
+        if row is not None and idx is not None and upd is not None:
+            for i, u in zip(idx, upd):
+                if i is not None:
+                    row[i] = u
+        expected.append(row)
+    assert result == expected
+
+
+@settings(max_examples=200, deadline=None)
+@given(column=ragged_columns())
+def test_rle_decode_inverts_rle(column):
+    """Test that rle gives maximal runs and rle_decode rebuilds the rows from them."""
+    rows, series = column
+    df = pl.DataFrame({"a": series})
+    encoded = df.select(pl.col("a").vec.rle()).unnest("a")
+    for row, values, lengths in zip(rows, encoded["values"], encoded["lengths"]):
+        if row is None:
+            assert values is None and lengths is None
+            continue
+        values, lengths = values.to_list(), lengths.to_list()
+        assert all(a != b for a, b in zip(values, values[1:]))
+        assert all(n > 0 for n in lengths) and sum(lengths) == len(row)
+    decoded = df.select(pl.col("a").vec.rle().vec.rle_decode())["a"].to_list()
+    assert decoded == rows
+
+
+@settings(max_examples=200, deadline=None)
+@given(
+    column=ragged_columns(),
+    width=st.integers(0, 8),
+    fill=st.none() | st.integers(-3, 3),
+    align=st.sampled_from(["left", "right"]),
+)
+def test_pad_to_and_truncate_match_slicing(column, width, fill, align):
+    """Test pad_to and truncate against padding and slicing, and their composition."""
+    rows, series = column
+    df = pl.DataFrame({"a": series})
+    padded = df.select(pl.col("a").vec.pad_to(width, fill=fill, align=align))["a"]
+    truncated = df.select(pl.col("a").vec.truncate(width))["a"].to_list()
+    exact = padded.to_frame().select(pl.col("a").vec.truncate(width))["a"].to_list()
+
+    def pad(row):
+        padding = [fill] * max(width - len(row), 0)
+        return padding + row if align == "right" else row + padding
+
+    assert padded.to_list() == [None if row is None else pad(row) for row in rows]
+    assert truncated == [None if row is None else row[:width] for row in rows]
+    assert exact == [None if row is None else pad(row)[:width] for row in rows]
+
+
+@settings(max_examples=200, deadline=None)
+@given(
+    column=ragged_columns(),
+    mode=st.sampled_from(["wrap", "fill"]),
+    fill_value=st.none() | st.integers(-3, 3),
+    data=st.data(),
+)
+def test_roll_matches_reference(column, mode, fill_value, data):
+    """Test roll with a shift per row against indexing, and that wrap undoes itself."""
+    rows, series = column
+    shifts = data.draw(one_per_row(rows, st.none() | st.integers(-8, 8)))
+    df = pl.DataFrame({"a": series, "n": pl.Series(shifts, dtype=pl.Int64)})
+    expr = pl.col("a").vec.roll("n", mode=mode, fill_value=fill_value)
+    result = df.select(expr)["a"].to_list()
+
+    def roll(row, n):
+        if mode == "wrap":
+            return [row[(pos - n) % len(row)] for pos in range(len(row))]
+        return [
+            row[pos - n] if 0 <= pos - n < len(row) else fill_value
+            for pos in range(len(row))
+        ]
+
+    expected = [
+        None if row is None or n is None else roll(row, n)
+        for row, n in zip(rows, shifts)
+    ]
+    assert result == expected
+    if mode == "wrap":
+        undo = pl.col("a").vec.roll("n").vec.roll(-pl.col("n"))
+        undone = df.select(undo)["a"].to_list()
+        assert undone == [None if n is None else row for row, n in zip(rows, shifts)]
+
+
+@settings(max_examples=200, deadline=None)
+@given(
+    column=ragged_columns(dtypes=[pl.Float64, pl.Float32]),
+    scale=st.none() | st.floats(0.05, 10),
+    dtype=st.sampled_from([pl.Int8, pl.Int16, pl.Int32]),
+)
+def test_dequantize_is_within_half_a_step(column, scale, dtype):
+    """Test that quantize then dequantize moves no element by more than half a level."""
+    rows, series = column
+    df = pl.DataFrame({"a": series})
+    quantized = pl.col("a").vec.quantize(scale=scale, dtype=dtype)
+    restored = df.select(quantized.vec.dequantize(scale=scale))["a"].to_list()
+    if scale is None:
+        scales = df.select(quantized.struct.field("scale"))["scale"].to_list()
+    else:
+        scales = [scale] * len(rows)
+    for row, back, step in zip(rows, restored, scales):
+        if row is None:
+            assert back is None
+            continue
+        assert len(back) == len(row)
+        for v, b in zip(row, back):
+            assert (v is None) == (b is None)
+            if v is not None:
+                assert abs(b - v) <= step / 2 * (1 + 1e-9), (v, b, step)
+
+
+@settings(max_examples=200, deadline=None)
+@given(column=ragged_columns(), narrow=st.booleans())
+def test_delta_decode_inverts_delta_encode(column, narrow):
+    """Test that delta_decode rebuilds the rows exactly, also through Int16."""
+    rows, series = column
+    dtype = series.dtype.inner
+    encode_dtype = pl.Int16 if narrow and dtype.is_integer() else None
+    expr = pl.col("a").vec.delta_encode(encode_dtype).vec.delta_decode(dtype)
+    result = pl.DataFrame({"a": series}).select(expr)["a"]
+    assert result.dtype.inner == dtype
+    assert result.to_list() == rows
+
+
+@settings(max_examples=200, deadline=None)
+@given(
+    column=ragged_columns(),
+    op=st.sampled_from(sorted(COMPARISONS)),
+    broadcast=st.booleans(),
+    data=st.data(),
+)
+def test_comparisons_match_reference(column, op, broadcast, data):
+    """Test every comparison against Python operators, list-to-list and broadcast."""
+    rows, series = column
+    dtype = series.dtype.inner
+    element = st.none() | small_values(dtype)
+    if broadcast:
+        other = data.draw(one_per_row(rows, element))
+        other_series = pl.Series(other, dtype=dtype)
+        # A null scalar is compared like a null element, so the row stays valid
+        other_rows = [[b] * len(row or []) for row, b in zip(rows, other)]
+    else:
+        other = other_rows = data.draw(shaped_like(rows, element))
+        other_series = pl.Series(other, dtype=pl.List(dtype))
+    df = pl.DataFrame({"a": series, "b": other_series})
+    result = df.select(getattr(pl.col("a").vec, op)("b"))["a"].to_list()
+
+    def compare_row(row, right):
+        compare = COMPARISONS[op]
+        return [None if None in (a, b) else compare(a, b) for a, b in zip(row, right)]
+
+    expected = [
+        None if row is None or right is None else compare_row(row, right)
+        for row, right in zip(rows, other_rows)
+    ]
+    assert result == expected